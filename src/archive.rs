@@ -0,0 +1,457 @@
+//! Reading Markdown files from archives (`.zip`, `.tar`, `.tar.gz`, `.tgz`).
+//!
+//! Archives are read fully in memory and yield `(member path, content)` pairs for the
+//! Markdown members, which the CLI then lints like regular files. Zip archives are read
+//! with the `zip` crate (stored and deflated members), tar archives with `tar`, including
+//! GNU and pax long names, and gzip with `flate2`. Members are filtered by extension
+//! before they are decompressed, so other members never fail the archive, and the
+//! decompressed size of each member and of the whole archive is capped.
+
+use crate::file_discovery::has_markdown_extension;
+use std::io::{Cursor, Read};
+use std::path::Path;
+
+/// Errors raised while reading an archive
+#[derive(Debug, thiserror::Error)]
+pub enum ArchiveError {
+    /// Failed to read the archive file
+    #[error("Failed to read archive: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// The archive is malformed or uses an unsupported feature
+    #[error("Invalid archive: {0}")]
+    Invalid(String),
+}
+
+type ArchiveResult<T> = Result<T, ArchiveError>;
+
+fn invalid<T>(message: impl Into<String>) -> ArchiveResult<T> {
+    Err(ArchiveError::Invalid(message.into()))
+}
+
+/// Most bytes a single Markdown member may decompress to
+pub const MAX_MEMBER_BYTES: u64 = 64 << 20;
+
+/// Most bytes an archive may decompress to: its Markdown members together, and for
+/// `.tar.gz` the whole tar stream
+pub const MAX_ARCHIVE_BYTES: u64 = 512 << 20;
+
+/// Caps on decompressed sizes, in bytes
+#[derive(Debug, Clone, Copy)]
+struct Limits {
+    member: u64,
+    total: u64,
+}
+
+const LIMITS: Limits = Limits {
+    member: MAX_MEMBER_BYTES,
+    total: MAX_ARCHIVE_BYTES,
+};
+
+/// Supported archive formats
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ArchiveKind {
+    Zip,
+    Tar,
+    TarGz,
+}
+
+impl ArchiveKind {
+    fn from_path(path: &Path) -> Option<Self> {
+        let name = path
+            .file_name()?
+            .to_str()?
+            .to_lowercase();
+        if name.ends_with(".zip") {
+            Some(Self::Zip)
+        } else if name.ends_with(".tar.gz") || name.ends_with(".tgz") {
+            Some(Self::TarGz)
+        } else if name.ends_with(".tar") {
+            Some(Self::Tar)
+        } else {
+            None
+        }
+    }
+}
+
+/// Returns true if the path names a supported archive format
+pub fn is_archive_path(path: &Path) -> bool {
+    ArchiveKind::from_path(path).is_some()
+}
+
+/// Read the Markdown members of the archive at `path`.
+///
+/// Returns `(member path, content)` pairs sorted by path. Directories and members without
+/// one of the given Markdown extensions are skipped.
+pub fn read_markdown_members(
+    path: &Path,
+    extensions: &[String],
+) -> ArchiveResult<Vec<(String, String)>> {
+    let Some(kind) = ArchiveKind::from_path(path) else {
+        return invalid(format!("{} is not a supported archive", path.display()));
+    };
+    let bytes = std::fs::read(path)?;
+    markdown_members(kind, &bytes, extensions, LIMITS)
+}
+
+/// Read the Markdown members of an in-memory archive; `name` determines the format.
+pub fn markdown_members_from_bytes(
+    name: &str,
+    bytes: &[u8],
+    extensions: &[String],
+) -> ArchiveResult<Vec<(String, String)>> {
+    let Some(kind) = ArchiveKind::from_path(Path::new(name)) else {
+        return invalid(format!("{} is not a supported archive", name));
+    };
+    markdown_members(kind, bytes, extensions, LIMITS)
+}
+
+fn markdown_members(
+    kind: ArchiveKind,
+    bytes: &[u8],
+    extensions: &[String],
+    limits: Limits,
+) -> ArchiveResult<Vec<(String, String)>> {
+    let is_markdown = |name: &str| {
+        !name.is_empty()
+            && !name.ends_with('/')
+            && has_markdown_extension(Path::new(name), extensions)
+    };
+    let mut total = 0;
+    let mut members = Vec::new();
+    let mut add = |name: String, reader: &mut dyn Read, size: u64| -> ArchiveResult<()> {
+        if size > limits.member {
+            return invalid(format!(
+                "member {} is larger than {} bytes",
+                name, limits.member
+            ));
+        }
+        let mut data = Vec::new();
+        reader
+            .take(limits.member + 1)
+            .read_to_end(&mut data)?;
+        if data.len() as u64 > limits.member {
+            return invalid(format!(
+                "member {} inflates past {} bytes",
+                name, limits.member
+            ));
+        }
+        total += data.len() as u64;
+        if total > limits.total {
+            return invalid(format!(
+                "Markdown members inflate past {} bytes",
+                limits.total
+            ));
+        }
+        match String::from_utf8(data) {
+            Ok(content) => members.push((name, content)),
+            Err(_) => return invalid(format!("member {} is not valid UTF-8", name)),
+        }
+        Ok(())
+    };
+
+    match kind {
+        ArchiveKind::Zip => {
+            let zip_error = |e: zip::result::ZipError| ArchiveError::Invalid(e.to_string());
+            let mut archive = zip::ZipArchive::new(Cursor::new(bytes)).map_err(zip_error)?;
+            for index in 0..archive.len() {
+                let Some(name) = archive.name_for_index(index) else {
+                    continue;
+                };
+                let name = member_name(&name.map_err(zip_error)?);
+                if !is_markdown(&name) {
+                    continue;
+                }
+                let mut file = archive
+                    .by_index(index)
+                    .map_err(|e| ArchiveError::Invalid(format!("member {}: {}", name, e)))?;
+                let size = file.size();
+                add(name, &mut file, size)?;
+            }
+        }
+        ArchiveKind::Tar => tar_members(bytes, &is_markdown, &mut add)?,
+        ArchiveKind::TarGz => {
+            let stream = LimitedReader {
+                inner: flate2::read::GzDecoder::new(bytes),
+                remaining: limits.total,
+            };
+            tar_members(stream, &is_markdown, &mut add)?
+        }
+    }
+    members.sort_by(|a, b| a.0.cmp(&b.0));
+    Ok(members)
+}
+
+/// A member's path without a leading `./`
+fn member_name(name: &str) -> String {
+    name.trim_start_matches("./")
+        .to_string()
+}
+
+/// Pass the regular Markdown files of a tar stream to `add`
+fn tar_members(
+    stream: impl Read,
+    is_markdown: &dyn Fn(&str) -> bool,
+    add: &mut dyn FnMut(String, &mut dyn Read, u64) -> ArchiveResult<()>,
+) -> ArchiveResult<()> {
+    let mut archive = tar::Archive::new(stream);
+    for entry in archive.entries()? {
+        let mut entry = entry?;
+        if !entry.header().entry_type().is_file() {
+            continue;
+        }
+        let name = member_name(&entry.path()?.to_string_lossy());
+        if !is_markdown(&name) {
+            continue;
+        }
+        let size = entry.size();
+        add(name, &mut entry, size)?;
+    }
+    Ok(())
+}
+
+/// A reader failing once more than `remaining` bytes were read from it
+struct LimitedReader<R> {
+    inner: R,
+    remaining: u64,
+}
+
+impl<R: Read> Read for LimitedReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let read = self.inner.read(buf)?;
+        self.remaining = self
+            .remaining
+            .checked_sub(read as u64)
+            .ok_or_else(|| std::io::Error::other("archive inflates past the size limit"))?;
+        Ok(read)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MD050StrongStyle;
+    use crate::rule::Rule;
+    use crate::rules::strong_style::StrongStyle;
+
+    fn hex(s: &str) -> Vec<u8> {
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    fn extensions() -> Vec<String> {
+        vec!["md".to_string()]
+    }
+
+    /// Build a zip with stored (uncompressed) members
+    fn build_zip(files: &[(&str, &str)]) -> Vec<u8> {
+        build_zip_with_method(files, |_| 0)
+    }
+
+    /// Build a zip whose members are stored but labelled with the compression method
+    /// `method` gives for their name
+    fn build_zip_with_method(files: &[(&str, &str)], method: impl Fn(&str) -> u16) -> Vec<u8> {
+        let mut out = Vec::new();
+        let mut central = Vec::new();
+        for (name, content) in files {
+            let offset = out.len() as u32;
+            let mut crc = flate2::Crc::new();
+            crc.update(content.as_bytes());
+            let crc = crc.sum();
+            let size = content.len() as u32;
+            let method = method(name).to_le_bytes();
+            let mut header = Vec::new();
+            header.extend_from_slice(&0x0403_4b50u32.to_le_bytes());
+            header.extend_from_slice(&[20, 0, 0, 0, method[0], method[1], 0, 0, 0, 0]);
+            header.extend_from_slice(&crc.to_le_bytes());
+            header.extend_from_slice(&size.to_le_bytes());
+            header.extend_from_slice(&size.to_le_bytes());
+            header.extend_from_slice(&(name.len() as u16).to_le_bytes());
+            header.extend_from_slice(&0u16.to_le_bytes());
+            out.extend_from_slice(&header);
+            out.extend_from_slice(name.as_bytes());
+            out.extend_from_slice(content.as_bytes());
+
+            central.extend_from_slice(&0x0201_4b50u32.to_le_bytes());
+            central.extend_from_slice(&[
+                20, 0, 20, 0, 0, 0, method[0], method[1], 0, 0, 0, 0,
+            ]);
+            central.extend_from_slice(&crc.to_le_bytes());
+            central.extend_from_slice(&size.to_le_bytes());
+            central.extend_from_slice(&size.to_le_bytes());
+            central.extend_from_slice(&(name.len() as u16).to_le_bytes());
+            central.extend_from_slice(&[0; 12]);
+            central.extend_from_slice(&offset.to_le_bytes());
+            central.extend_from_slice(name.as_bytes());
+        }
+        let central_offset = out.len() as u32;
+        out.extend_from_slice(&central);
+        out.extend_from_slice(&0x0605_4b50u32.to_le_bytes());
+        out.extend_from_slice(&[0; 4]);
+        out.extend_from_slice(&(files.len() as u16).to_le_bytes());
+        out.extend_from_slice(&(files.len() as u16).to_le_bytes());
+        out.extend_from_slice(&(central.len() as u32).to_le_bytes());
+        out.extend_from_slice(&central_offset.to_le_bytes());
+        out.extend_from_slice(&0u16.to_le_bytes());
+        out
+    }
+
+    #[test]
+    fn test_lint_zip_members() {
+        let zip = build_zip(&[
+            ("docs/", ""),
+            ("docs/guide/clean.md", "# Clean\n\nAll **good** here.\n"),
+            ("docs/dirty.md", "# Dirty\n\nSome __bold__ text.\n"),
+            ("docs/image.png", "not markdown"),
+        ]);
+
+        let members = markdown_members_from_bytes("docs.zip", &zip, &extensions()).unwrap();
+        let names: Vec<_> = members
+            .iter()
+            .map(|(name, _)| name.as_str())
+            .collect();
+        assert_eq!(names, vec!["docs/dirty.md", "docs/guide/clean.md"]);
+
+        let rules: Vec<Box<dyn Rule>> = vec![Box::new(MD050StrongStyle::new(
+            StrongStyle::Asterisk,
+        ))];
+        let dirty = crate::lint(&members[0].1, &rules, false).unwrap();
+        assert_eq!(dirty.len(), 1);
+        assert_eq!(dirty[0].line, 3);
+        assert!(
+            crate::lint(&members[1].1, &rules, false)
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_other_members_are_not_decompressed() {
+        // A non-Markdown member using a method that cannot be decompressed (bzip2) is
+        // skipped without being read; a Markdown member using it fails the archive
+        let files = [
+            ("docs/guide.md", "# Guide\n"),
+            ("docs/data.bin", "binary data"),
+        ];
+        let zip = build_zip_with_method(&files, |name| if name.ends_with(".bin") { 12 } else { 0 });
+        let members = markdown_members_from_bytes("docs.zip", &zip, &extensions()).unwrap();
+        assert_eq!(
+            members,
+            vec![("docs/guide.md".to_string(), "# Guide\n".to_string())]
+        );
+
+        let zip = build_zip_with_method(&files, |name| if name.ends_with(".md") { 12 } else { 0 });
+        let err = markdown_members_from_bytes("docs.zip", &zip, &extensions()).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("docs/guide.md"),
+            "{}",
+            err
+        );
+    }
+
+    #[test]
+    fn test_decompressed_sizes_are_capped() {
+        let zip = build_zip(&[
+            ("a.md", "# A\n\nSome text\n"),
+            ("b.md", "# B\n\nSome text\n"),
+        ]);
+        let read = |member: u64, total: u64| {
+            markdown_members(
+                ArchiveKind::Zip,
+                &zip,
+                &extensions(),
+                Limits { member, total },
+            )
+        };
+        assert_eq!(read(100, 100).unwrap().len(), 2);
+        let err = read(10, 100).unwrap_err().to_string();
+        assert!(
+            err.contains("member a.md is larger than 10 bytes"),
+            "{}",
+            err
+        );
+        let err = read(100, 20).unwrap_err().to_string();
+        assert!(err.contains("inflate past 20 bytes"), "{}", err);
+
+        // A gzip stream inflating past the total fails even in non-Markdown members
+        let mut tar = tar::Builder::new(Vec::new());
+        let mut header = tar::Header::new_gnu();
+        header.set_size(4096);
+        header.set_cksum();
+        tar.append_data(&mut header, "zeros.bin", &[0u8; 4096][..])
+            .unwrap();
+        let mut gz = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        std::io::Write::write_all(&mut gz, &tar.into_inner().unwrap()).unwrap();
+        let tgz = gz.finish().unwrap();
+        assert!(
+            markdown_members(
+                ArchiveKind::TarGz,
+                &tgz,
+                &extensions(),
+                Limits {
+                    member: 100,
+                    total: 1000
+                }
+            )
+            .is_err()
+        );
+        assert!(
+            markdown_members(ArchiveKind::TarGz, &tgz, &extensions(), LIMITS)
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_tar_pax_long_names() {
+        let long = format!("docs/{}/guide.md", "nested".repeat(30));
+        let content = "# Guide\n";
+        let mut tar = tar::Builder::new(Vec::new());
+        tar.append_pax_extensions([("path", long.as_bytes())])
+            .unwrap();
+        let mut header = tar::Header::new_ustar();
+        header
+            .set_path("docs/short.md")
+            .unwrap();
+        header.set_size(content.len() as u64);
+        header.set_cksum();
+        tar.append(&header, content.as_bytes())
+            .unwrap();
+        let bytes = tar.into_inner().unwrap();
+
+        let members = markdown_members_from_bytes("docs.tar", &bytes, &extensions()).unwrap();
+        assert_eq!(members, vec![(long, content.to_string())]);
+    }
+
+    #[test]
+    fn test_tar_gz_members() {
+        let tgz = hex(concat!(
+            "1f8b0800000000000203edd5bb0a83301480e1cc3e45a0bb26de32973e8918915235e085f6f11bdb",
+            "a160073b5417ff6f3921cb19c24fac2b87a89eaeb68acaa62abab0b5e2df9497a7e96b7acba9549c",
+            "7ccef3bdd689d1422ab18369188bdeaf17c7749297f9d983e0dc34b276ce8681c071d8b9ffce8dd5",
+            "108e8f719b1dabfdeb6cd1bfcaf298fef7e09f5eb6457fb3eede51fe41fb7fffff9bed58edffeb6c",
+            "4c6e84cce81f00000000000000000000809f3c018aadcc1600280000"
+        ));
+        let members = markdown_members_from_bytes("docs.tar.gz", &tgz, &extensions()).unwrap();
+        assert_eq!(
+            members,
+            vec![(
+                "docs/guide/clean.md".to_string(),
+                "# Clean\n\nAll good.\n".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn test_is_archive_path() {
+        assert!(is_archive_path(Path::new("docs.zip")));
+        assert!(is_archive_path(Path::new("out/docs.TAR.GZ")));
+        assert!(is_archive_path(Path::new("docs.tgz")));
+        assert!(is_archive_path(Path::new("docs.tar")));
+        assert!(!is_archive_path(Path::new("docs.md")));
+        assert!(!is_archive_path(Path::new("zip")));
+    }
+}
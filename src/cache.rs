@@ -0,0 +1,356 @@
+//! Keeping lint results between runs, for `check --cache-dir`.
+//!
+//! The cache is a single JSON file in the cache directory. For every file linted it holds a
+//! hash of the file's content, the rules it was linted with, and the warnings found, so a
+//! file whose content and rules are unchanged is not linted again. The whole cache belongs
+//! to one configuration, identified by [`config_key`]: a cache saved under another key, or
+//! by another version of rumdl, is discarded on load. Saving drops the entries of files
+//! that no longer exist, so a run over part of a project keeps the rest of its cache.
+//! Rules that [read other files](crate::rule::Rule::reads_other_files) are left out of the
+//! cache by the caller, as their results can change while the file does not.
+
+use crate::config::Config;
+use crate::rule::{Fix, FixSafety, LintWarning, Rule, Severity};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Name of the cache file within the cache directory
+pub const CACHE_FILE: &str = "rumdl-cache.json";
+
+/// The key of the configuration results are cached under: the serialized `config` and the
+/// contents of any other config files in effect, such as nested directory configs
+pub fn config_key(config: &Config, other_configs: &[String]) -> u64 {
+    let mut key = serde_json::to_string(config).unwrap_or_default();
+    for other in other_configs {
+        key.push('\0');
+        key.push_str(other);
+    }
+    seahash::hash(key.as_bytes())
+}
+
+// Identifies the rule set a file was linted with. The configuration of the rules is
+// covered by the config key.
+fn rules_key(rules: &[Box<dyn Rule>]) -> u64 {
+    let names: Vec<&str> = rules
+        .iter()
+        .map(|rule| rule.name())
+        .collect();
+    seahash::hash(names.join(",").as_bytes())
+}
+
+/// Owned rules, as linted together
+pub type OwnedRules = Vec<Box<dyn Rule>>;
+
+/// Split `rules` into those whose results can be cached and those that
+/// [read other files](Rule::reads_other_files), which must run every time
+pub fn split_cacheable(rules: &[Box<dyn Rule>]) -> (OwnedRules, OwnedRules) {
+    rules
+        .iter()
+        .cloned()
+        .partition(|rule| !rule.reads_other_files())
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CachedWarning {
+    message: String,
+    line: usize,
+    column: usize,
+    end_line: usize,
+    end_column: usize,
+    severity: Severity,
+    // Byte range, replacement and safety
+    fix: Option<(usize, usize, String, FixSafety)>,
+    rule_name: Option<String>,
+    doc_anchor: Option<String>,
+    suppressed: bool,
+    id: Option<String>,
+}
+
+impl CachedWarning {
+    fn new(warning: &LintWarning) -> Self {
+        Self {
+            message: warning.message.clone(),
+            line: warning.line,
+            column: warning.column,
+            end_line: warning.end_line,
+            end_column: warning.end_column,
+            severity: warning.severity,
+            fix: warning.fix.as_ref().map(|fix| {
+                (
+                    fix.range.start,
+                    fix.range.end,
+                    fix.replacement.clone(),
+                    fix.safety,
+                )
+            }),
+            rule_name: warning.rule_name.map(str::to_string),
+            doc_anchor: warning.doc_anchor.clone(),
+            suppressed: warning.suppressed,
+            id: warning.id.clone(),
+        }
+    }
+
+    // The warning again, its rule name taken from `rules`; `None` if no rule has that name
+    fn to_warning(&self, rules: &[Box<dyn Rule>]) -> Option<LintWarning> {
+        let rule_name = match &self.rule_name {
+            Some(name) => Some(
+                rules
+                    .iter()
+                    .map(|rule| rule.name())
+                    .find(|rule_name| rule_name == name)?,
+            ),
+            None => None,
+        };
+        Some(LintWarning {
+            message: self.message.clone(),
+            line: self.line,
+            column: self.column,
+            end_line: self.end_line,
+            end_column: self.end_column,
+            severity: self.severity,
+            fix: self
+                .fix
+                .as_ref()
+                .map(|(start, end, replacement, safety)| {
+                    Fix::new(*start..*end, replacement.clone(), *safety)
+                }),
+            rule_name,
+            doc_anchor: self.doc_anchor.clone(),
+            suppressed: self.suppressed,
+            id: self.id.clone(),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CacheEntry {
+    content_hash: u64,
+    rules_key: u64,
+    warnings: Vec<CachedWarning>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct CacheFile {
+    version: String,
+    config_key: u64,
+    entries: HashMap<String, CacheEntry>,
+}
+
+/// Lint results of previous runs, by file path
+#[derive(Debug)]
+pub struct ResultCache {
+    path: PathBuf,
+    config_key: u64,
+    entries: HashMap<String, CacheEntry>,
+    // Paths looked up in this run, whose entries are saved even if they are not files on
+    // disk, such as archive members
+    seen: HashSet<String>,
+}
+
+impl ResultCache {
+    /// Load the cache kept in `dir` for the configuration `config_key`. A missing or
+    /// unreadable cache, or one saved for another configuration or version, starts empty.
+    pub fn open(dir: &Path, config_key: u64) -> Self {
+        let path = dir.join(CACHE_FILE);
+        let entries = fs::read_to_string(&path)
+            .ok()
+            .and_then(|content| serde_json::from_str::<CacheFile>(&content).ok())
+            .filter(|file| {
+                file.version == env!("CARGO_PKG_VERSION") && file.config_key == config_key
+            })
+            .map(|file| file.entries)
+            .unwrap_or_default();
+        Self {
+            path,
+            config_key,
+            entries,
+            seen: HashSet::new(),
+        }
+    }
+
+    /// The warnings cached for `content` at `path` when linted with `rules`, if the
+    /// content and rules are those they were found with
+    pub fn get(
+        &mut self,
+        path: &str,
+        content: &str,
+        rules: &[Box<dyn Rule>],
+    ) -> Option<Vec<LintWarning>> {
+        self.seen.insert(path.to_string());
+        let entry = self.entries.get(path)?;
+        if entry.content_hash != seahash::hash(content.as_bytes())
+            || entry.rules_key != rules_key(rules)
+        {
+            return None;
+        }
+        entry
+            .warnings
+            .iter()
+            .map(|warning| warning.to_warning(rules))
+            .collect()
+    }
+
+    /// Cache the `warnings` found in `content` at `path` with `rules`
+    pub fn insert(
+        &mut self,
+        path: &str,
+        content: &str,
+        rules: &[Box<dyn Rule>],
+        warnings: &[LintWarning],
+    ) {
+        self.seen.insert(path.to_string());
+        self.entries.insert(
+            path.to_string(),
+            CacheEntry {
+                content_hash: seahash::hash(content.as_bytes()),
+                rules_key: rules_key(rules),
+                warnings: warnings
+                    .iter()
+                    .map(CachedWarning::new)
+                    .collect(),
+            },
+        );
+    }
+
+    /// Number of cached files
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether no file is cached
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Save the cache, creating the cache directory if needed. Entries of files that no
+    /// longer exist and were not looked up in this run, such as deleted ones, are dropped.
+    pub fn save(&self) -> io::Result<()> {
+        let file = CacheFile {
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            config_key: self.config_key,
+            entries: self
+                .entries
+                .iter()
+                .filter(|(path, _)| self.seen.contains(*path) || Path::new(path).exists())
+                .map(|(path, entry)| (path.clone(), entry.clone()))
+                .collect(),
+        };
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(&self.path, serde_json::to_string(&file)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rule::TestRule;
+    use crate::rules::MD050StrongStyle;
+    use crate::rules::strong_style::StrongStyle;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    // MD050, counting how many times it checks a document
+    fn counting_rule(checks: Arc<AtomicUsize>) -> TestRule {
+        let inner = MD050StrongStyle::new(StrongStyle::Asterisk);
+        let fixer = inner.clone();
+        TestRule::new("MD050", move |ctx| {
+            checks.fetch_add(1, Ordering::SeqCst);
+            inner.check(ctx)
+        })
+        .with_fix(move |ctx| fixer.fix(ctx))
+    }
+
+    #[test]
+    fn test_unchanged_file_is_not_linted_again() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let checks = Arc::new(AtomicUsize::new(0));
+        let rules: Vec<Box<dyn Rule>> = vec![Box::new(counting_rule(checks.clone()))];
+        let key = config_key(&Config::default(), &[]);
+        let content = "# Notes\n\nSome __bold__ text\n";
+        // The files linted, which must exist on disk to stay in the cache
+        let docs = tempfile::tempdir().unwrap();
+        let path = |name: &str| {
+            let path = docs.path().join(name);
+            if !path.exists() {
+                std::fs::write(&path, "").unwrap();
+            }
+            path.to_string_lossy().into_owned()
+        };
+        let (notes, other, old) = (path("notes.md"), path("other.md"), path("old.md"));
+
+        // Lint each file through the cache, as `check --cache-dir` does
+        let run = |files: &[(&str, &str)], key: u64| {
+            let mut cache = ResultCache::open(temp_dir.path(), key);
+            let results: Vec<Vec<LintWarning>> = files
+                .iter()
+                .map(|(path, content)| {
+                    cache
+                        .get(path, content, &rules)
+                        .unwrap_or_else(|| {
+                            let warnings = crate::lint(content, &rules, false).unwrap();
+                            cache.insert(path, content, &rules, &warnings);
+                            warnings
+                        })
+                })
+                .collect();
+            cache.save().unwrap();
+            results
+        };
+
+        let first = run(
+            &[
+                (notes.as_str(), content),
+                (other.as_str(), "# Other\n"),
+                (old.as_str(), "# Old\n"),
+            ],
+            key,
+        );
+        assert_eq!(first[0].len(), 1);
+        assert_eq!(checks.load(Ordering::SeqCst), 3);
+
+        // The second run, over notes.md only, reports the same warnings without checking
+        // the unchanged file. other.md keeps its entry; old.md was deleted, so its entry
+        // is pruned.
+        std::fs::remove_file(&old).unwrap();
+        let second = run(&[(notes.as_str(), content)], key);
+        assert_eq!(second[0], first[0]);
+        assert_eq!(checks.load(Ordering::SeqCst), 3);
+        assert_eq!(ResultCache::open(temp_dir.path(), key).len(), 2);
+
+        // Changed content, or a changed configuration, is linted again
+        run(&[(notes.as_str(), "# Notes\n\nMore __bold__ text\n")], key);
+        assert_eq!(checks.load(Ordering::SeqCst), 4);
+        let mut config = Config::default();
+        config.global.strict = true;
+        let changed_key = config_key(&config, &[]);
+        assert_ne!(changed_key, key);
+        assert!(ResultCache::open(temp_dir.path(), changed_key).is_empty());
+        run(
+            &[(notes.as_str(), "# Notes\n\nMore __bold__ text\n")],
+            changed_key,
+        );
+        assert_eq!(checks.load(Ordering::SeqCst), 5);
+    }
+
+    #[test]
+    fn test_rules_reading_other_files_are_not_cached() {
+        let rules: Vec<Box<dyn Rule>> = vec![
+            Box::new(MD050StrongStyle::new(StrongStyle::Asterisk)),
+            Box::new(crate::rules::MD057ExistingRelativeLinks::new()),
+        ];
+        let (cached, uncached) = split_cacheable(&rules);
+        let names = |rules: &[Box<dyn Rule>]| {
+            rules
+                .iter()
+                .map(|rule| rule.name())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(names(&cached), vec!["MD050"]);
+        assert_eq!(names(&uncached), vec!["MD057"]);
+    }
+}
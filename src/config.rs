@@ -2,7 +2,7 @@
 //! This module defines configuration structures, loading logic, and provenance tracking for rumdl.
 //! Supports TOML, pyproject.toml, and markdownlint config formats, and provides merging and override logic.
 
-use crate::rule::Rule;
+use crate::rule::{Rule, Severity};
 use crate::rules;
 use lazy_static::lazy_static;
 use log;
@@ -11,7 +11,8 @@ use std::collections::BTreeMap;
 use std::collections::{BTreeSet, HashMap};
 use std::fs;
 use std::io;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
 use toml_edit::DocumentMut;
 
 lazy_static! {
@@ -94,12 +95,48 @@ pub struct GlobalConfig {
     /// Global line length setting (used by MD013 and other rules if not overridden)
     #[serde(default = "default_line_length")]
     pub line_length: u64,
+
+    /// File extensions treated as Markdown when discovering files
+    #[serde(default = "default_extensions")]
+    pub extensions: Vec<String>,
+
+    /// Report every warning as an error
+    #[serde(default)]
+    pub strict: bool,
+
+    /// Delimiters that open front matter on the first line of a document
+    #[serde(default = "default_front_matter_delimiters")]
+    pub front_matter_delimiters: Vec<String>,
+
+    /// Rules whose warnings are printed even with `--quiet`
+    #[serde(default)]
+    pub always_report: Vec<String>,
+
+    /// Rules that only report inside managed regions (see
+    /// [`crate::inline_config::ManagedRegions`])
+    #[serde(default)]
+    pub managed_only: Vec<String>,
+
+    /// Rules that only report outside managed regions
+    #[serde(default)]
+    pub unmanaged_only: Vec<String>,
 }
 
 fn default_respect_gitignore() -> bool {
     true
 }
 
+fn default_extensions() -> Vec<String> {
+    crate::file_discovery::DEFAULT_MARKDOWN_EXTENSIONS
+        .iter()
+        .map(|s| s.to_string())
+        .collect()
+}
+
+fn default_front_matter_delimiters() -> Vec<String> {
+    crate::rules::front_matter_utils::FrontMatterUtils::default_delimiters()
+}
+
 fn default_line_length() -> u64 {
     80
 }
@@ -114,6 +151,12 @@ impl Default for GlobalConfig {
             include: Vec::new(),
             respect_gitignore: true,
             line_length: 80,
+            extensions: default_extensions(),
+            strict: false,
+            front_matter_delimiters: default_front_matter_delimiters(),
+            always_report: Vec::new(),
+            managed_only: Vec::new(),
+            unmanaged_only: Vec::new(),
         }
     }
 }
@@ -509,6 +552,420 @@ line-length = 303
         let v = get_rule_config_value::<usize>(&config, "MD013", "line-length");
         assert_eq!(v, Some(303));
     }
+
+    #[test]
+    fn test_global_extensions_config() {
+        let temp_dir = tempdir().unwrap();
+        let config_path = temp_dir.path().join(".rumdl.toml");
+        fs::write(&config_path, "[global]\nextensions = [\"md\", \"mdx\"]\n").unwrap();
+        let sourced =
+            SourcedConfig::load_with_discovery(Some(config_path.to_str().unwrap()), None, true)
+                .unwrap();
+        assert_eq!(sourced.global.extensions.source, ConfigSource::RumdlToml);
+        let config: Config = sourced.into();
+        assert_eq!(config.global.extensions, vec!["md", "mdx"]);
+
+        // Defaults apply when the setting is absent
+        let config = Config::default();
+        assert!(
+            config
+                .global
+                .extensions
+                .contains(&"markdown".to_string())
+        );
+    }
+
+    #[test]
+    fn test_nested_configs_use_nearest_per_file() {
+        use crate::rule::Rule;
+        use crate::rules::MD050StrongStyle;
+
+        let temp_dir = tempdir().unwrap();
+        let root = temp_dir.path();
+        fs::create_dir_all(root.join("a")).unwrap();
+        fs::create_dir_all(root.join("b/sub")).unwrap();
+        fs::write(
+            root.join("a/.rumdl.toml"),
+            "[MD050]\nstyle = \"asterisk\"\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("b/.rumdl.toml"),
+            "[MD050]\nstyle = \"underscore\"\n",
+        )
+        .unwrap();
+        let content = "Some __underscore__ and **asterisk** text\n";
+        fs::write(root.join("a/doc.md"), content).unwrap();
+        fs::write(root.join("b/sub/doc.md"), content).unwrap();
+        fs::write(root.join("top.md"), content).unwrap();
+
+        let resolver = ConfigResolver::new(Some(root));
+        let warnings_for = |file: &str| {
+            let config_path = resolver
+                .nearest_config(&root.join(file))
+                .expect("nested config should be found");
+            let sourced =
+                SourcedConfig::load_with_discovery(config_path.to_str(), None, true).unwrap();
+            let config: Config = sourced.into();
+            let rule = MD050StrongStyle::from_config(&config);
+            rule.check(&crate::lint_context::LintContext::new(content))
+                .unwrap()
+        };
+
+        let a_warnings = warnings_for("a/doc.md");
+        assert_eq!(a_warnings.len(), 1);
+        assert_eq!(
+            a_warnings[0].column, 6,
+            "asterisk style flags __underscore__"
+        );
+
+        let b_warnings = warnings_for("b/sub/doc.md");
+        assert_eq!(b_warnings.len(), 1);
+        assert_eq!(
+            b_warnings[0].column, 25,
+            "underscore style flags **asterisk**"
+        );
+
+        // Files outside any nested directory fall back to the project config
+        assert_eq!(resolver.nearest_config(&root.join("top.md")), None);
+        // Cached lookups return the same result
+        assert_eq!(
+            resolver.nearest_config(&root.join("b/sub/doc.md")),
+            Some(
+                root.canonicalize()
+                    .unwrap()
+                    .join("b/.rumdl.toml")
+            )
+        );
+    }
+
+    #[test]
+    fn test_setting_origins_report_file_and_line() {
+        let temp_dir = tempdir().unwrap();
+        let config_path = temp_dir.path().join(".rumdl.toml");
+        fs::write(
+            &config_path,
+            "[global]\ndisable = [\"MD013\"]\n\n[MD050]\nstyle = \"underscore\"\n",
+        )
+        .unwrap();
+        let config_str = config_path.to_str().unwrap();
+        let sourced = SourcedConfig::load_with_discovery(Some(config_str), None, true).unwrap();
+        let registry = RuleRegistry::from_rules(&rules::all_rules(&Config::default()));
+
+        let origins = setting_origins(&sourced, &registry);
+        let origin_of = |key: &str| {
+            origins
+                .iter()
+                .find(|setting| setting.key == key)
+                .unwrap_or_else(|| panic!("no setting {}", key))
+        };
+
+        let style = origin_of("MD050.style");
+        assert_eq!(style.value, toml::Value::String("underscore".to_string()));
+        assert_eq!(style.origin, format!("{}:5", config_str));
+        assert_eq!(
+            origin_of("global.disable").origin,
+            format!("{}:2", config_str)
+        );
+        let names = origin_of("MD044.names");
+        assert_eq!(names.value, toml::Value::Array(Vec::new()));
+        assert_eq!(names.origin, "default");
+        assert_eq!(origin_of("global.strict").origin, "default");
+    }
+
+    #[test]
+    fn test_rule_enablement_precedence() {
+        let temp_dir = tempdir().unwrap();
+        let lint_with = |toml: &str| {
+            let config_path = temp_dir.path().join(".rumdl.toml");
+            fs::write(&config_path, toml).unwrap();
+            let sourced =
+                SourcedConfig::load_with_discovery(Some(config_path.to_str().unwrap()), None, true)
+                    .unwrap();
+            let config: Config = sourced.into();
+            let rules = rules::enabled_rules(&config);
+            let content = "# Notes\n\nSome __bold__ and **strong** javascript.\n";
+            let mut fired: Vec<_> = crate::lint(content, &rules, false)
+                .unwrap()
+                .into_iter()
+                .filter_map(|w| w.rule_name)
+                .filter(|name| matches!(*name, "MD044" | "MD050"))
+                .collect();
+            fired.sort_unstable();
+            fired.dedup();
+            fired
+        };
+        let names = "[MD044]\nnames = [\"JavaScript\"]\n";
+
+        assert_eq!(lint_with(names), vec!["MD044", "MD050"]);
+        // Disabling MD050 globally (top level or [global]) keeps MD044 running
+        assert_eq!(
+            lint_with(&format!("disable = [\"MD050\"]\n{}", names)),
+            vec!["MD044"]
+        );
+        assert_eq!(
+            lint_with(&format!("[global]\ndisable = [\"md050\"]\n\n{}", names)),
+            vec!["MD044"]
+        );
+        // A per-rule `enabled` overrides the global lists in both directions
+        assert_eq!(
+            lint_with(&format!(
+                "disable = [\"MD050\"]\n{}\n[MD050]\nenabled = true\n",
+                names
+            )),
+            vec!["MD044", "MD050"]
+        );
+        assert_eq!(
+            lint_with(&format!(
+                "enable = [\"MD044\", \"MD050\"]\n{}enabled = false\n",
+                names
+            )),
+            vec!["MD050"]
+        );
+
+        let registry = RuleRegistry::from_rules(&rules::all_rules(&Config::default()));
+        assert!(
+            registry
+                .config_keys_for("MD050")
+                .unwrap()
+                .contains("enabled")
+        );
+        assert_eq!(registry.default_enabled.get("MD050"), Some(&true));
+    }
+
+    #[test]
+    fn test_root_config_stops_inheritance() {
+        use crate::rule::Rule;
+        use crate::rules::MD044ProperNames;
+
+        let temp_dir = tempdir().unwrap();
+        let root = temp_dir.path();
+        fs::create_dir_all(root.join("inherit/docs")).unwrap();
+        fs::create_dir_all(root.join("isolated")).unwrap();
+        fs::write(
+            root.join(".rumdl.toml"),
+            "[MD044]\nnames = [\"JavaScript\"]\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("inherit/.rumdl.toml"),
+            "[MD050]\nstyle = \"asterisk\"\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("isolated/.rumdl.toml"),
+            "root = true\n\n[MD050]\nstyle = \"asterisk\"\n",
+        )
+        .unwrap();
+
+        let resolver = ConfigResolver::new(root.parent());
+        let md044_warnings_for = |file: &str| {
+            let chain = resolver.config_chain(&root.join(file));
+            let mut sourced = SourcedConfig::default();
+            for config_file in &chain.files {
+                sourced
+                    .merge_nested_file(config_file)
+                    .unwrap();
+            }
+            let config: Config = sourced.into();
+            assert_eq!(
+                get_rule_config_value::<String>(&config, "MD050", "style").as_deref(),
+                Some("asterisk")
+            );
+            let rule = MD044ProperNames::from_config(&config);
+            let ctx = crate::lint_context::LintContext::new("Written in javascript.\n");
+            (chain, rule.check(&ctx).unwrap())
+        };
+
+        let (chain, warnings) = md044_warnings_for("inherit/docs/doc.md");
+        assert_eq!(chain.files.len(), 2);
+        assert!(!chain.root);
+        assert_eq!(warnings.len(), 1, "non-root config inherits MD044 names");
+
+        let (chain, warnings) = md044_warnings_for("isolated/doc.md");
+        assert_eq!(chain.files.len(), 1);
+        assert!(chain.root);
+        assert!(
+            warnings.is_empty(),
+            "root config ignores ancestor MD044 names"
+        );
+    }
+
+    #[test]
+    fn test_nested_config_keeps_cli_precedence() {
+        let temp_dir = tempdir().unwrap();
+        let nested = temp_dir.path().join(".rumdl.toml");
+        fs::write(
+            &nested,
+            "[global]\ndisable = [\"MD013\"]\n\n[MD050]\nstyle = \"asterisk\"\n",
+        )
+        .unwrap();
+
+        let mut sourced = SourcedConfig::default();
+        sourced.global.disable.merge_override(
+            vec!["MD033".to_string()],
+            ConfigSource::Cli,
+            None,
+            None,
+        );
+        let mut rule_config = SourcedRuleConfig::default();
+        rule_config.values.insert(
+            "style".to_string(),
+            SourcedValue::new(
+                toml::Value::String("underscore".to_string()),
+                ConfigSource::Cli,
+            ),
+        );
+        sourced
+            .rules
+            .insert("MD050".to_string(), rule_config);
+
+        sourced
+            .merge_nested_file(&nested)
+            .unwrap();
+        assert_eq!(sourced.global.disable.value, vec!["MD033"]);
+        assert_eq!(sourced.global.disable.source, ConfigSource::Cli);
+        let style = &sourced.rules["MD050"].values["style"];
+        assert_eq!(style.value.as_str(), Some("underscore"));
+        assert_eq!(style.source, ConfigSource::Cli);
+    }
+
+    #[test]
+    fn test_extends_chain_merges_base_configs() {
+        let temp_dir = tempdir().unwrap();
+        let root = temp_dir.path();
+        fs::create_dir_all(root.join("shared")).unwrap();
+        fs::create_dir_all(root.join("repo")).unwrap();
+        fs::write(
+            root.join("shared/base.toml"),
+            "[MD044]\nnames = [\"JavaScript\", \"GitHub\"]\ncode-blocks = false\n\n[MD050]\nenabled = true\nstyle = \"asterisk\"\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("shared/team.toml"),
+            "extends = \"base.toml\"\n\n[MD044]\nnames = [\"Rust\"]\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("repo/.rumdl.toml"),
+            "extends = [\"../shared/team.toml\"]\n\n[MD050]\nenabled = false\n",
+        )
+        .unwrap();
+
+        let config_path = root.join("repo/.rumdl.toml");
+        let sourced = SourcedConfig::load(config_path.to_str(), None).unwrap();
+        assert_eq!(sourced.loaded_files.len(), 3);
+        assert_eq!(
+            sourced.rules["MD050"].values["enabled"].origin(),
+            format!("{}:4", config_path.display())
+        );
+        let config: Config = sourced.into();
+
+        // The child disables the rule its base enabled, keeping the base's other options
+        assert!(!rule_enabled(&config, "MD050", true));
+        assert_eq!(
+            get_rule_config_value::<String>(&config, "MD050", "style").as_deref(),
+            Some("asterisk")
+        );
+        // Arrays are replaced rather than concatenated; other options are inherited
+        assert_eq!(
+            get_rule_config_value::<Vec<String>>(&config, "MD044", "names"),
+            Some(vec!["Rust".to_string()])
+        );
+        assert_eq!(
+            get_rule_config_value::<bool>(&config, "MD044", "code-blocks"),
+            Some(false)
+        );
+
+        // A cycle is an error rather than an endless loop
+        fs::write(
+            root.join("shared/base.toml"),
+            "extends = \"../repo/.rumdl.toml\"\n",
+        )
+        .unwrap();
+        let err = SourcedConfig::load(config_path.to_str(), None).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("Cyclic extends"),
+            "{}",
+            err
+        );
+    }
+
+    #[test]
+    fn test_nearest_config_ignores_pyproject_without_rumdl_section() {
+        let temp_dir = tempdir().unwrap();
+        let root = temp_dir.path();
+        fs::create_dir_all(root.join("pkg/docs")).unwrap();
+        fs::write(
+            root.join("pkg/pyproject.toml"),
+            "[project]\nname = \"pkg\"\n",
+        )
+        .unwrap();
+        fs::write(root.join(".rumdl.toml"), "[MD050]\nstyle = \"asterisk\"\n").unwrap();
+        fs::write(root.join("pkg/docs/doc.md"), "# Doc\n").unwrap();
+
+        let resolver = ConfigResolver::new(None);
+        let nearest = resolver.nearest_config(&root.join("pkg/docs/doc.md"));
+        assert_eq!(
+            nearest,
+            Some(
+                root.canonicalize()
+                    .unwrap()
+                    .join(".rumdl.toml")
+            )
+        );
+    }
+
+    #[test]
+    fn test_lint_config_document_reports_precise_problems() {
+        let registry = RuleRegistry::from_rules(&rules::all_rules(&Config::default()));
+        let temp_dir = tempdir().unwrap();
+        fs::write(temp_dir.path().join("names.txt"), "JavaScript\n").unwrap();
+        let lint = |content: &str| {
+            lint_config_document(content, &registry, temp_dir.path(), temp_dir.path(), false)
+                .into_iter()
+                .map(|problem| (problem.line, problem.message))
+                .collect::<Vec<_>>()
+        };
+
+        let content =
+            "[global]\nstrict = true\n\n[MD044]\nnames = [\"GitHub\"]\nname = [\"Rust\"]\n";
+        assert_eq!(
+            lint(content),
+            vec![(6, "Unknown option for rule MD044: name".to_string())]
+        );
+
+        assert_eq!(
+            lint("[MD050]\nstyle = 2\n"),
+            vec![(
+                2,
+                "Type mismatch for MD050.style: expected string, got integer".to_string()
+            )]
+        );
+
+        assert!(lint("[MD044]\nnames_file = \"names.txt\"\n").is_empty());
+        assert_eq!(
+            lint("# Names\n[MD044]\nnames_file = \"missing.txt\"\n"),
+            vec![(
+                3,
+                "File not found for MD044.names_file: missing.txt".to_string()
+            )]
+        );
+
+        assert_eq!(
+            lint("[MD999]\nfoo = 1\n\n[global]\nstrict = \"yes\"\n"),
+            vec![
+                (1, "Unknown rule in config: MD999".to_string()),
+                (
+                    5,
+                    "Type mismatch for global option strict: expected boolean, got string"
+                        .to_string()
+                ),
+            ]
+        );
+        assert_eq!(lint("[MD050\n")[0].0, 1);
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -558,7 +1015,7 @@ impl<T: Clone> SourcedValue<T> {
         new_value: T,
         new_source: ConfigSource,
         new_file: Option<String>,
-        // TODO: Add new_line Option<usize> if needed later
+        new_line: Option<usize>,
     ) {
         // Helper function to get precedence, defined locally or globally
         fn source_precedence(src: ConfigSource) -> u8 {
@@ -578,11 +1035,46 @@ impl<T: Clone> SourcedValue<T> {
                 value: new_value,
                 source: new_source,
                 file: new_file,
-                line: None, // Placeholder for now
+                line: new_line,
             });
         }
     }
 
+    /// Merges the value parsed into a config fragment, keeping the file and line it was
+    /// read from. Uses source precedence, or, when `respect_precedence` is false, wins over
+    /// any config file value but still never over a command-line value.
+    fn merge_fragment(&mut self, respect_precedence: bool, fragment: SourcedValue<T>) {
+        let (file, line) = fragment
+            .overrides
+            .last()
+            .map_or((None, None), |o| (o.file.clone(), o.line));
+        if respect_precedence || self.source == ConfigSource::Cli {
+            self.merge_override(fragment.value, fragment.source, file, line);
+        } else {
+            self.push_override(fragment.value, fragment.source, file, line);
+        }
+    }
+
+    /// Describe where the effective value came from: `default`, `command line`, or the
+    /// config file (with the line, when known) that set it
+    pub fn origin(&self) -> String {
+        let last = self
+            .overrides
+            .last()
+            .filter(|o| o.source == self.source);
+        match (self.source, last.and_then(|o| o.file.as_deref())) {
+            (ConfigSource::Default, _) => "default".to_string(),
+            (ConfigSource::Cli, _) => "command line".to_string(),
+            (_, Some(file)) => match last.and_then(|o| o.line) {
+                Some(line) => format!("{}:{}", file, line),
+                None => file.to_string(),
+            },
+            (ConfigSource::RumdlToml, None) => ".rumdl.toml".to_string(),
+            (ConfigSource::PyprojectToml, None) => "pyproject.toml".to_string(),
+            (ConfigSource::Markdownlint, None) => "markdownlint config".to_string(),
+        }
+    }
+
     pub fn push_override(
         &mut self,
         value: T,
@@ -611,6 +1103,12 @@ pub struct SourcedGlobalConfig {
     pub include: SourcedValue<Vec<String>>,
     pub respect_gitignore: SourcedValue<bool>,
     pub line_length: SourcedValue<u64>,
+    pub extensions: SourcedValue<Vec<String>>,
+    pub strict: SourcedValue<bool>,
+    pub front_matter_delimiters: SourcedValue<Vec<String>>,
+    pub always_report: SourcedValue<Vec<String>>,
+    pub managed_only: SourcedValue<Vec<String>>,
+    pub unmanaged_only: SourcedValue<Vec<String>>,
 }
 
 impl Default for SourcedGlobalConfig {
@@ -622,6 +1120,15 @@ impl Default for SourcedGlobalConfig {
             include: SourcedValue::new(Vec::new(), ConfigSource::Default),
             respect_gitignore: SourcedValue::new(true, ConfigSource::Default),
             line_length: SourcedValue::new(80, ConfigSource::Default),
+            extensions: SourcedValue::new(default_extensions(), ConfigSource::Default),
+            strict: SourcedValue::new(false, ConfigSource::Default),
+            front_matter_delimiters: SourcedValue::new(
+                default_front_matter_delimiters(),
+                ConfigSource::Default,
+            ),
+            always_report: SourcedValue::new(Vec::new(), ConfigSource::Default),
+            managed_only: SourcedValue::new(Vec::new(), ConfigSource::Default),
+            unmanaged_only: SourcedValue::new(Vec::new(), ConfigSource::Default),
         }
     }
 }
@@ -652,59 +1159,47 @@ impl SourcedConfig {
     /// Merges another SourcedConfigFragment into this SourcedConfig.
     /// Uses source precedence to determine which values take effect.
     fn merge(&mut self, fragment: SourcedConfigFragment) {
+        self.merge_with(fragment, true);
+    }
+
+    /// Merges a fragment, either by source precedence or with the fragment winning over
+    /// other config files (used for nested directory configs, where the config closest to
+    /// the file takes effect). Command-line values win either way.
+    fn merge_with(&mut self, fragment: SourcedConfigFragment, respect_precedence: bool) {
         // Merge global config
-        self.global.enable.merge_override(
-            fragment.global.enable.value,
-            fragment.global.enable.source,
-            fragment
-                .global
-                .enable
-                .overrides
-                .first()
-                .and_then(|o| o.file.clone()),
-        );
-        self.global.disable.merge_override(
-            fragment.global.disable.value,
-            fragment.global.disable.source,
-            fragment
-                .global
-                .disable
-                .overrides
-                .first()
-                .and_then(|o| o.file.clone()),
-        );
-        self.global.include.merge_override(
-            fragment.global.include.value,
-            fragment.global.include.source,
-            fragment
-                .global
-                .include
-                .overrides
-                .first()
-                .and_then(|o| o.file.clone()),
-        );
-        self.global.exclude.merge_override(
-            fragment.global.exclude.value,
-            fragment.global.exclude.source,
-            fragment
-                .global
-                .exclude
-                .overrides
-                .first()
-                .and_then(|o| o.file.clone()),
-        );
+        self.global
+            .enable
+            .merge_fragment(respect_precedence, fragment.global.enable);
+        self.global
+            .disable
+            .merge_fragment(respect_precedence, fragment.global.disable);
+        self.global
+            .include
+            .merge_fragment(respect_precedence, fragment.global.include);
+        self.global
+            .exclude
+            .merge_fragment(respect_precedence, fragment.global.exclude);
         self.global
             .respect_gitignore
-            .merge_override(
-                fragment.global.respect_gitignore.value,
-                fragment.global.respect_gitignore.source,
-                fragment
-                    .global
-                    .respect_gitignore
-                    .overrides
-                    .first()
-                    .and_then(|o| o.file.clone()),
-            );
+            .merge_fragment(respect_precedence, fragment.global.respect_gitignore);
+        self.global
+            .extensions
+            .merge_fragment(respect_precedence, fragment.global.extensions);
+        self.global
+            .strict
+            .merge_fragment(respect_precedence, fragment.global.strict);
+        self.global
+            .front_matter_delimiters
+            .merge_fragment(respect_precedence, fragment.global.front_matter_delimiters);
+        self.global
+            .always_report
+            .merge_fragment(respect_precedence, fragment.global.always_report);
+        self.global
+            .managed_only
+            .merge_fragment(respect_precedence, fragment.global.managed_only);
+        self.global
+            .unmanaged_only
+            .merge_fragment(respect_precedence, fragment.global.unmanaged_only);
 
         // Merge rule configs
         for (rule_name, rule_fragment) in fragment.rules {
@@ -723,19 +1218,74 @@ impl SourcedConfig {
                             ConfigSource::Default,
                         )
                     });
-                let file_from_fragment = sourced_value_fragment
-                    .overrides
-                    .first()
-                    .and_then(|o| o.file.clone());
-                sv_entry.merge_override(
-                    sourced_value_fragment.value,  // Use the value from the fragment
-                    sourced_value_fragment.source, // Use the source from the fragment
-                    file_from_fragment,            // Pass the file path from the fragment override
-                );
+                sv_entry.merge_fragment(respect_precedence, sourced_value_fragment);
             }
         }
     }
 
+    /// Merge a `.rumdl.toml`-style config file on top of the files named by its `extends`
+    /// key, which are merged first, so the file's own values override theirs key by key.
+    /// `chain` holds the files being merged, to reject cyclic `extends`.
+    fn merge_rumdl_toml_file(
+        &mut self,
+        path: &Path,
+        respect_precedence: bool,
+        chain: &mut Vec<PathBuf>,
+    ) -> Result<(), ConfigError> {
+        let path_str = path.to_string_lossy().to_string();
+        let canonical = fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+        if chain.contains(&canonical) {
+            let cycle: Vec<String> = chain
+                .iter()
+                .chain([&canonical])
+                .map(|file| file.display().to_string())
+                .collect();
+            return Err(ConfigError::ParseError(format!(
+                "Cyclic extends: {}",
+                cycle.join(" -> ")
+            )));
+        }
+        let content = fs::read_to_string(path).map_err(|e| ConfigError::IoError {
+            source: e,
+            path: path_str.clone(),
+        })?;
+        let fragment = parse_rumdl_toml(&content, &path_str)?;
+
+        chain.push(canonical);
+        let base_dir = path.parent().unwrap_or(Path::new(""));
+        for base in extends_paths(&content, &path_str)? {
+            self.merge_rumdl_toml_file(&base_dir.join(base), respect_precedence, chain)?;
+        }
+        chain.pop();
+
+        self.merge_with(fragment, respect_precedence);
+        self.loaded_files.push(path_str);
+        Ok(())
+    }
+
+    /// Merge a nested directory config file (`.rumdl.toml`, `rumdl.toml` or `pyproject.toml`)
+    /// on top of this config. Its values take effect over those already merged from config
+    /// files, but not over command-line values.
+    pub fn merge_nested_file(&mut self, path: &Path) -> Result<(), ConfigError> {
+        if path
+            .file_name()
+            .and_then(|n| n.to_str())
+            != Some("pyproject.toml")
+        {
+            return self.merge_rumdl_toml_file(path, false, &mut Vec::new());
+        }
+        let path_str = path.to_string_lossy().to_string();
+        let content = fs::read_to_string(path).map_err(|e| ConfigError::IoError {
+            source: e,
+            path: path_str.clone(),
+        })?;
+        if let Some(fragment) = parse_pyproject_toml(&content, &path_str)? {
+            self.merge_with(fragment, false);
+            self.loaded_files.push(path_str);
+        }
+        Ok(())
+    }
+
     /// Load and merge configurations from files and CLI overrides.
     pub fn load(
         config_path: Option<&str>,
@@ -790,28 +1340,21 @@ impl SourcedConfig {
                 ".markdownlint.yml",
             ];
 
-            if filename == "pyproject.toml" || filename == ".rumdl.toml" || filename == "rumdl.toml"
-            {
+            if filename == "pyproject.toml" {
                 let content = std::fs::read_to_string(path).map_err(|e| ConfigError::IoError {
                     source: e,
                     path: path_str.clone(),
                 })?;
-                if filename == "pyproject.toml" {
-                    if let Some(fragment) = parse_pyproject_toml(&content, &path_str)? {
-                        sourced_config.merge(fragment);
-                        sourced_config
-                            .loaded_files
-                            .push(path_str.clone());
-                        loaded_toml_or_pyproject = true;
-                    }
-                } else {
-                    let fragment = parse_rumdl_toml(&content, &path_str)?;
+                if let Some(fragment) = parse_pyproject_toml(&content, &path_str)? {
                     sourced_config.merge(fragment);
                     sourced_config
                         .loaded_files
                         .push(path_str.clone());
                     loaded_toml_or_pyproject = true;
                 }
+            } else if filename == ".rumdl.toml" || filename == "rumdl.toml" {
+                sourced_config.merge_rumdl_toml_file(path_obj, true, &mut Vec::new())?;
+                loaded_toml_or_pyproject = true;
             } else if MARKDOWNLINT_FILENAMES.contains(&filename)
                 || path_str.ends_with(".json")
                 || path_str.ends_with(".jsonc")
@@ -827,15 +1370,7 @@ impl SourcedConfig {
                 // Do NOT set loaded_toml_or_pyproject = true; markdownlint is fallback only
             } else {
                 // Try TOML only
-                let content = std::fs::read_to_string(path).map_err(|e| ConfigError::IoError {
-                    source: e,
-                    path: path_str.clone(),
-                })?;
-                let fragment = parse_rumdl_toml(&content, &path_str)?;
-                sourced_config.merge(fragment);
-                sourced_config
-                    .loaded_files
-                    .push(path_str.clone());
+                sourced_config.merge_rumdl_toml_file(path_obj, true, &mut Vec::new())?;
                 loaded_toml_or_pyproject = true;
             }
         }
@@ -866,16 +1401,11 @@ impl SourcedConfig {
             for filename in [".rumdl.toml", "rumdl.toml"] {
                 if std::path::Path::new(filename).exists() {
                     log::debug!("[rumdl-config] Found {} in current directory", filename);
-                    let content =
-                        std::fs::read_to_string(filename).map_err(|e| ConfigError::IoError {
-                            source: e,
-                            path: filename.to_string(),
-                        })?;
-                    let fragment = parse_rumdl_toml(&content, filename)?;
-                    sourced_config.merge(fragment);
-                    sourced_config
-                        .loaded_files
-                        .push(filename.to_string());
+                    sourced_config.merge_rumdl_toml_file(
+                        Path::new(filename),
+                        true,
+                        &mut Vec::new(),
+                    )?;
                     loaded_toml_or_pyproject = true;
                     break; // Load only the first one found
                 } else {
@@ -909,23 +1439,29 @@ impl SourcedConfig {
             sourced_config
                 .global
                 .enable
-                .merge_override(cli.enable.value.clone(), ConfigSource::Cli, None);
+                .merge_override(cli.enable.value.clone(), ConfigSource::Cli, None, None);
             sourced_config
                 .global
                 .disable
-                .merge_override(cli.disable.value.clone(), ConfigSource::Cli, None);
+                .merge_override(cli.disable.value.clone(), ConfigSource::Cli, None, None);
             sourced_config
                 .global
                 .exclude
-                .merge_override(cli.exclude.value.clone(), ConfigSource::Cli, None);
+                .merge_override(cli.exclude.value.clone(), ConfigSource::Cli, None, None);
             sourced_config
                 .global
                 .include
-                .merge_override(cli.include.value.clone(), ConfigSource::Cli, None);
+                .merge_override(cli.include.value.clone(), ConfigSource::Cli, None, None);
             sourced_config
                 .global
                 .respect_gitignore
-                .merge_override(cli.respect_gitignore.value, ConfigSource::Cli, None);
+                .merge_override(cli.respect_gitignore.value, ConfigSource::Cli, None, None);
+            if cli.strict.source == ConfigSource::Cli {
+                sourced_config
+                    .global
+                    .strict
+                    .merge_override(cli.strict.value, ConfigSource::Cli, None, None);
+            }
             // No rule-specific CLI overrides implemented yet
         }
 
@@ -954,22 +1490,194 @@ impl From<SourcedConfig> for Config {
             include: sourced.global.include.value,
             respect_gitignore: sourced.global.respect_gitignore.value,
             line_length: sourced.global.line_length.value,
+            extensions: sourced.global.extensions.value,
+            strict: sourced.global.strict.value,
+            front_matter_delimiters: sourced
+                .global
+                .front_matter_delimiters
+                .value,
+            always_report: sourced.global.always_report.value,
+            managed_only: sourced.global.managed_only.value,
+            unmanaged_only: sourced.global.unmanaged_only.value,
         };
         Config { global, rules }
     }
 }
 
+/// An effective setting together with where it was set (see [`setting_origins`])
+#[derive(Debug, Clone, PartialEq)]
+pub struct SettingOrigin {
+    /// `global.<key>` or `<RULE>.<key>`
+    pub key: String,
+    pub value: toml::Value,
+    /// See [`SourcedValue::origin`]
+    pub origin: String,
+}
+
+/// List every effective setting, global ones first, then each rule's options (including
+/// defaults the config never mentions), with the place each value came from.
+pub fn setting_origins(sourced: &SourcedConfig, registry: &RuleRegistry) -> Vec<SettingOrigin> {
+    fn global<T: Serialize + Clone>(key: &str, value: &SourcedValue<T>) -> Option<SettingOrigin> {
+        Some(SettingOrigin {
+            key: format!("global.{}", key),
+            value: toml::Value::try_from(&value.value).ok()?,
+            origin: value.origin(),
+        })
+    }
+
+    let g = &sourced.global;
+    let mut origins: Vec<SettingOrigin> = [
+        global("enable", &g.enable),
+        global("disable", &g.disable),
+        global("exclude", &g.exclude),
+        global("include", &g.include),
+        global("respect-gitignore", &g.respect_gitignore),
+        global("line-length", &g.line_length),
+        global("extensions", &g.extensions),
+        global("strict", &g.strict),
+        global("front-matter-delimiters", &g.front_matter_delimiters),
+        global("always-report", &g.always_report),
+        global("managed-only", &g.managed_only),
+        global("unmanaged-only", &g.unmanaged_only),
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+
+    let mut rule_names: BTreeSet<&String> = registry.rule_schemas.keys().collect();
+    rule_names.extend(sourced.rules.keys());
+    for rule_name in rule_names {
+        let configured = sourced.rules.get(rule_name);
+        let mut values: BTreeMap<String, (toml::Value, String)> = registry
+            .rule_schemas
+            .get(rule_name)
+            .into_iter()
+            .flatten()
+            .map(|(key, value)| (normalize_key(key), (value.clone(), "default".to_string())))
+            .collect();
+        for (key, value) in configured
+            .into_iter()
+            .flat_map(|rule| &rule.values)
+        {
+            values.insert(key.clone(), (value.value.clone(), value.origin()));
+        }
+        origins.extend(
+            values
+                .into_iter()
+                .map(|(key, (value, origin))| SettingOrigin {
+                    key: format!("{}.{}", rule_name, key),
+                    value,
+                    origin,
+                }),
+        );
+    }
+    origins
+}
+
 /// Registry of all known rules and their config schemas
 pub struct RuleRegistry {
     /// Map of rule name (e.g. "MD013") to set of valid config keys and their TOML value types
     pub rule_schemas: std::collections::BTreeMap<String, toml::map::Map<String, toml::Value>>,
+    /// Map of rule name to whether the rule runs when config does not mention it
+    pub default_enabled: std::collections::BTreeMap<String, bool>,
+}
+
+/// Per-rule key that turns a rule on or off regardless of the global lists
+pub const RULE_ENABLED_KEY: &str = "enabled";
+
+/// Expected type of the per-rule `enabled` key
+static RULE_ENABLED_TYPE: toml::Value = toml::Value::Boolean(true);
+
+/// Per-rule key that sets the severity of the rule's warnings: `error`, `warning` or `info`
+pub const RULE_SEVERITY_KEY: &str = "severity";
+
+/// Expected type of the per-rule `severity` key
+static RULE_SEVERITY_TYPE: toml::Value = toml::Value::String(String::new());
+
+/// The severities rules are configured to report at with their `severity` key, by rule
+/// name. Fails on a value that is not a severity.
+pub fn severity_overrides(config: &Config) -> Result<HashMap<String, Severity>, ConfigError> {
+    let mut overrides = HashMap::new();
+    for (rule_name, rule_config) in &config.rules {
+        let Some(value) = rule_config
+            .values
+            .get(RULE_SEVERITY_KEY)
+        else {
+            continue;
+        };
+        let severity = parse_severity(value)
+            .ok_or_else(|| ConfigError::ParseError(invalid_severity_message(rule_name, value)))?;
+        overrides.insert(normalize_key(rule_name), severity);
+    }
+    Ok(overrides)
+}
+
+// The severity named by a `severity` value, in any case
+fn parse_severity(value: &toml::Value) -> Option<Severity> {
+    match value
+        .as_str()?
+        .to_ascii_lowercase()
+        .as_str()
+    {
+        "error" => Some(Severity::Error),
+        "warning" => Some(Severity::Warning),
+        "info" => Some(Severity::Info),
+        _ => None,
+    }
+}
+
+fn invalid_severity_message(rule_name: &str, value: &toml::Value) -> String {
+    format!(
+        "Invalid severity for rule {}: {} (expected \"error\", \"warning\" or \"info\")",
+        rule_name, value
+    )
+}
+
+/// Whether a rule is active under `config`. Precedence, highest first:
+///
+/// 1. the rule's own `enabled = true|false`
+/// 2. the global `disable` list (`disable = ["all"]` turns off every rule not in `enable`)
+/// 3. the global `enable` list: when non-empty, rules not listed are off
+/// 4. the rule's default (`default_enabled`)
+///
+/// CLI `--enable`/`--disable` are applied on top of this by the CLI.
+pub fn rule_enabled(config: &Config, rule_name: &str, default_enabled: bool) -> bool {
+    let name = normalize_key(rule_name);
+    let in_list = |list: &[String]| {
+        list.iter()
+            .any(|entry| normalize_key(entry) == name)
+    };
+
+    if let Some(enabled) = config
+        .rules
+        .get(&name)
+        .and_then(|rule_config| rule_config.values.get(RULE_ENABLED_KEY))
+        .and_then(|value| value.as_bool())
+    {
+        return enabled;
+    }
+    if in_list(&config.global.disable) {
+        return false;
+    }
+    if !config.global.enable.is_empty()
+        || config
+            .global
+            .disable
+            .iter()
+            .any(|entry| entry.eq_ignore_ascii_case("all"))
+    {
+        return in_list(&config.global.enable);
+    }
+    default_enabled
 }
 
 impl RuleRegistry {
     /// Build a registry from a list of rules
     pub fn from_rules(rules: &[Box<dyn Rule>]) -> Self {
         let mut rule_schemas = std::collections::BTreeMap::new();
+        let mut default_enabled = std::collections::BTreeMap::new();
         for rule in rules {
+            default_enabled.insert(normalize_key(rule.name()), rule.default_enabled());
             if let Some((name, toml::Value::Table(table))) = rule.default_config_section() {
                 let norm_name = normalize_key(&name); // Normalize the name from default_config_section
                 rule_schemas.insert(norm_name, table);
@@ -978,7 +1686,20 @@ impl RuleRegistry {
                 rule_schemas.insert(norm_name, toml::map::Map::new());
             }
         }
-        RuleRegistry { rule_schemas }
+        RuleRegistry {
+            rule_schemas,
+            default_enabled,
+        }
+    }
+
+    /// Whether a rule is active under `config`, using the rule's registered default
+    pub fn is_enabled(&self, rule: &str, config: &Config) -> bool {
+        let default_enabled = self
+            .default_enabled
+            .get(&normalize_key(rule))
+            .copied()
+            .unwrap_or(true);
+        rule_enabled(config, rule, default_enabled)
     }
 
     /// Get all known rule names
@@ -995,6 +1716,8 @@ impl RuleRegistry {
             .get(rule)
             .map(|schema| {
                 let mut all_keys = std::collections::BTreeSet::new();
+                all_keys.insert(RULE_ENABLED_KEY.to_string());
+                all_keys.insert(RULE_SEVERITY_KEY.to_string());
 
                 // Add original keys from schema
                 for key in schema.keys() {
@@ -1017,6 +1740,12 @@ impl RuleRegistry {
 
     /// Get the expected value type for a rule's configuration key, trying variants
     pub fn expected_value_for(&self, rule: &str, key: &str) -> Option<&toml::Value> {
+        if key == RULE_ENABLED_KEY && self.rule_schemas.contains_key(rule) {
+            return Some(&RULE_ENABLED_TYPE);
+        }
+        if key == RULE_SEVERITY_KEY && self.rule_schemas.contains_key(rule) {
+            return Some(&RULE_SEVERITY_TYPE);
+        }
         if let Some(schema) = self.rule_schemas.get(rule) {
             // Try the original key first
             if let Some(value) = schema.get(key) {
@@ -1138,6 +1867,286 @@ fn toml_value_type_matches(expected: &toml::Value, actual: &toml::Value) -> bool
     }
 }
 
+/// A problem found in a config file by [`lint_config_document`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigProblem {
+    /// 1-based line of the offending key, or of the syntax error
+    pub line: usize,
+    pub message: String,
+}
+
+/// Global options by normalized key, and whether each takes a list of strings (else a boolean)
+const GLOBAL_OPTIONS: &[(&str, bool)] = &[
+    ("enable", true),
+    ("disable", true),
+    ("include", true),
+    ("exclude", true),
+    ("extensions", true),
+    ("front-matter-delimiters", true),
+    ("always-report", true),
+    ("managed-only", true),
+    ("unmanaged-only", true),
+    ("respect-gitignore", false),
+    ("strict", false),
+];
+
+/// Rule options naming a file that must exist, as (rule, normalized key)
+pub(crate) const FILE_REFERENCE_OPTIONS: &[(&str, &str)] = &[("MD044", "names-file")];
+
+/// Check a global option: `None` if `key` is not one, else the type problem found, if any
+fn global_option_problem(key: &str, item: &toml_edit::Item) -> Option<Option<String>> {
+    let norm_key = normalize_key(key);
+    let &(_, is_list) = GLOBAL_OPTIONS
+        .iter()
+        .find(|(name, _)| *name == norm_key)?;
+    let valid = if is_list {
+        item.as_array()
+            .is_some_and(|array| array.iter().all(|v| v.is_str()))
+    } else {
+        item.as_bool().is_some()
+    };
+    Some((!valid).then(|| {
+        format!(
+            "Type mismatch for global option {}: expected {}, got {}",
+            key,
+            if is_list {
+                "array of strings"
+            } else {
+                "boolean"
+            },
+            item.type_name()
+        )
+    }))
+}
+
+/// Check a TOML config document on its own, as done by `rumdl lint-config`.
+///
+/// Reports TOML syntax errors, unknown sections, rules and options, values whose type does
+/// not match the option's default, and referenced files that do not exist: rule options are
+/// resolved against `base_dir`, `extends` against `config_dir`, the directory of the
+/// document. With `pyproject`, only the `[tool.rumdl]` table is checked. Problems are
+/// returned in document order.
+pub fn lint_config_document(
+    content: &str,
+    registry: &RuleRegistry,
+    base_dir: &Path,
+    config_dir: &Path,
+    pyproject: bool,
+) -> Vec<ConfigProblem> {
+    let line_at = |offset: usize| content[..offset].matches('\n').count() + 1;
+    let doc = match toml_edit::ImDocument::parse(content) {
+        Ok(doc) => doc,
+        Err(e) => {
+            return vec![ConfigProblem {
+                line: e
+                    .span()
+                    .map_or(1, |span| line_at(span.start)),
+                message: format!("Invalid TOML: {}", e.message().trim()),
+            }];
+        }
+    };
+    let root = if pyproject {
+        match doc
+            .get("tool")
+            .and_then(|tool| tool.get("rumdl"))
+            .and_then(|item| item.as_table_like())
+        {
+            Some(table) => table,
+            None => {
+                return vec![ConfigProblem {
+                    line: 1,
+                    message: "No [tool.rumdl] section".to_string(),
+                }];
+            }
+        }
+    } else {
+        doc.as_table() as &dyn toml_edit::TableLike
+    };
+
+    let mut problems = Vec::new();
+    let line_of_key = |table: &dyn toml_edit::TableLike, key: &str| {
+        table
+            .key(key)
+            .and_then(|k| k.span())
+            .map_or(1, |span| line_at(span.start))
+    };
+    let mut rule_tables = Vec::new();
+    for (key, item) in root.iter() {
+        let norm_key = normalize_key(key);
+        if norm_key == "global" && !pyproject {
+            match item.as_table_like() {
+                Some(global) => {
+                    for (global_key, global_item) in global.iter() {
+                        let problem = global_option_problem(global_key, global_item)
+                            .unwrap_or_else(|| {
+                                Some(format!("Unknown global option: {}", global_key))
+                            });
+                        if let Some(message) = problem {
+                            problems.push(ConfigProblem {
+                                line: line_of_key(global, global_key),
+                                message,
+                            });
+                        }
+                    }
+                }
+                None => problems.push(ConfigProblem {
+                    line: line_of_key(root, key),
+                    message: "Expected a [global] table".to_string(),
+                }),
+            }
+        } else if norm_key == "extends" && !pyproject {
+            let line = line_of_key(root, key);
+            let bases = item
+                .as_str()
+                .map(|base| vec![base])
+                .or_else(|| {
+                    item.as_array().and_then(|array| {
+                        array
+                            .iter()
+                            .map(|v| v.as_str())
+                            .collect()
+                    })
+                });
+            match bases {
+                Some(bases) => problems.extend(
+                    bases
+                        .into_iter()
+                        .filter(|base| !config_dir.join(base).is_file())
+                        .map(|base| ConfigProblem {
+                            line,
+                            message: format!("File not found for extends: {}", base),
+                        }),
+                ),
+                None => problems.push(ConfigProblem {
+                    line,
+                    message: format!(
+                        "Type mismatch for extends: expected string or array of strings, got {}",
+                        item.type_name()
+                    ),
+                }),
+            }
+        } else if norm_key == "root" {
+            if item.as_bool().is_none() {
+                problems.push(ConfigProblem {
+                    line: line_of_key(root, key),
+                    message: format!(
+                        "Type mismatch for root: expected boolean, got {}",
+                        item.type_name()
+                    ),
+                });
+            }
+        } else if let Some(problem) = (pyproject || norm_key == "enable" || norm_key == "disable")
+            .then(|| global_option_problem(key, item))
+            .flatten()
+        {
+            if let Some(message) = problem {
+                problems.push(ConfigProblem {
+                    line: line_of_key(root, key),
+                    message,
+                });
+            }
+        } else if pyproject && norm_key == "line-length" {
+            if item.as_integer().is_none() {
+                problems.push(ConfigProblem {
+                    line: line_of_key(root, key),
+                    message: format!(
+                        "Type mismatch for line-length: expected integer, got {}",
+                        item.type_name()
+                    ),
+                });
+            }
+        } else if registry
+            .rule_schemas
+            .contains_key(&norm_key)
+        {
+            match item.as_table_like() {
+                Some(table) => rule_tables.push((norm_key, table)),
+                None => problems.push(ConfigProblem {
+                    line: line_of_key(root, key),
+                    message: format!(
+                        "Expected a table for rule {}, got {}",
+                        norm_key,
+                        item.type_name()
+                    ),
+                }),
+            }
+        } else {
+            let is_rule_name = norm_key.len() == 5 && norm_key.starts_with("MD");
+            problems.push(ConfigProblem {
+                line: line_of_key(root, key),
+                message: if is_rule_name {
+                    format!("Unknown rule in config: {}", key)
+                } else {
+                    format!("Unknown section or option: {}", key)
+                },
+            });
+        }
+    }
+
+    for (rule, table) in rule_tables {
+        let valid_keys = registry
+            .config_keys_for(&rule)
+            .unwrap_or_default();
+        for (key, item) in table.iter() {
+            let norm_key = normalize_key(key);
+            let line = line_of_key(table, key);
+            if !valid_keys.contains(key) && !valid_keys.contains(&norm_key) {
+                problems.push(ConfigProblem {
+                    line,
+                    message: format!("Unknown option for rule {}: {}", rule, key),
+                });
+                continue;
+            }
+            let actual = match item {
+                toml_edit::Item::Value(value) => toml_edit_value_to_toml(value),
+                toml_edit::Item::Table(sub_table) => toml_edit_value_to_toml(
+                    &toml_edit::Value::InlineTable(sub_table.clone().into_inline_table()),
+                ),
+                _ => None,
+            };
+            if let (Some(expected), Some(actual)) =
+                (registry.expected_value_for(&rule, key), &actual)
+                && !toml_value_type_matches(expected, actual)
+            {
+                problems.push(ConfigProblem {
+                    line,
+                    message: format!(
+                        "Type mismatch for {}.{}: expected {}, got {}",
+                        rule,
+                        key,
+                        toml_type_name(expected),
+                        toml_type_name(actual)
+                    ),
+                });
+                continue;
+            }
+            if norm_key == RULE_SEVERITY_KEY
+                && let Some(value) = &actual
+                && parse_severity(value).is_none()
+            {
+                problems.push(ConfigProblem {
+                    line,
+                    message: invalid_severity_message(&rule, value),
+                });
+                continue;
+            }
+            if FILE_REFERENCE_OPTIONS.contains(&(rule.as_str(), norm_key.as_str()))
+                && let Some(toml::Value::String(file)) = &actual
+                && !file.is_empty()
+                && !base_dir.join(file).is_file()
+            {
+                problems.push(ConfigProblem {
+                    line,
+                    message: format!("File not found for {}.{}: {}", rule, key, file),
+                });
+            }
+        }
+    }
+
+    problems.sort_by_key(|problem| problem.line);
+    problems
+}
+
 /// Parses pyproject.toml content and extracts the [tool.rumdl] section if present.
 fn parse_pyproject_toml(
     content: &str,
@@ -1202,6 +2211,70 @@ fn parse_pyproject_toml(
                         .push_override(values, source, file.clone(), None);
                 }
             }
+            if let Some(extensions) = rumdl_table.get("extensions")
+                && let Ok(values) = Vec::<String>::deserialize(extensions.clone())
+            {
+                fragment
+                    .global
+                    .extensions
+                    .push_override(values, source, file.clone(), None);
+            }
+            if let Some(delimiters) = rumdl_table
+                .get("front-matter-delimiters")
+                .or_else(|| rumdl_table.get("front_matter_delimiters"))
+                && let Ok(values) = Vec::<String>::deserialize(delimiters.clone())
+            {
+                fragment
+                    .global
+                    .front_matter_delimiters
+                    .push_override(values, source, file.clone(), None);
+            }
+            if let Some(always_report) = rumdl_table
+                .get("always-report")
+                .or_else(|| rumdl_table.get("always_report"))
+                && let Ok(values) = Vec::<String>::deserialize(always_report.clone())
+            {
+                fragment
+                    .global
+                    .always_report
+                    .push_override(
+                        values
+                            .iter()
+                            .map(|s| normalize_key(s))
+                            .collect(),
+                        source,
+                        file.clone(),
+                        None,
+                    );
+            }
+            for (key, value) in [
+                ("managed-only", &mut fragment.global.managed_only),
+                ("unmanaged-only", &mut fragment.global.unmanaged_only),
+            ] {
+                if let Some(rules) = rumdl_table
+                    .get(key)
+                    .or_else(|| rumdl_table.get(key.replace('-', "_").as_str()))
+                    && let Ok(rules) = Vec::<String>::deserialize(rules.clone())
+                {
+                    value.push_override(
+                        rules
+                            .iter()
+                            .map(|s| normalize_key(s))
+                            .collect(),
+                        source,
+                        file.clone(),
+                        None,
+                    );
+                }
+            }
+            if let Some(strict) = rumdl_table.get("strict")
+                && let Ok(value) = bool::deserialize(strict.clone())
+            {
+                fragment
+                    .global
+                    .strict
+                    .push_override(value, source, file.clone(), None);
+            }
             if let Some(respect_gitignore) = rumdl_table
                 .get("respect-gitignore")
                 .or_else(|| rumdl_table.get("respect_gitignore"))
@@ -1253,6 +2326,16 @@ fn parse_pyproject_toml(
                     "disable",
                     "include",
                     "exclude",
+                    "extensions",
+                    "front-matter-delimiters",
+                    "always-report",
+                    "always_report",
+                    "managed-only",
+                    "managed_only",
+                    "unmanaged-only",
+                    "unmanaged_only",
+                    "strict",
+                    "root",
                     "respect_gitignore",
                     "respect-gitignore", // Added kebab-case here too
                     "line_length",
@@ -1385,6 +2468,77 @@ fn parse_pyproject_toml(
     }
 }
 
+/// Convert a `toml_edit` value (including arrays and inline tables) to a `toml::Value`
+fn toml_edit_value_to_toml(value: &toml_edit::Value) -> Option<toml::Value> {
+    Some(match value {
+        toml_edit::Value::String(formatted) => toml::Value::String(formatted.value().clone()),
+        toml_edit::Value::Integer(formatted) => toml::Value::Integer(*formatted.value()),
+        toml_edit::Value::Float(formatted) => toml::Value::Float(*formatted.value()),
+        toml_edit::Value::Boolean(formatted) => toml::Value::Boolean(*formatted.value()),
+        toml_edit::Value::Datetime(formatted) => toml::Value::Datetime(*formatted.value()),
+        toml_edit::Value::Array(array) => toml::Value::Array(
+            array
+                .iter()
+                .map(toml_edit_value_to_toml)
+                .collect::<Option<Vec<_>>>()?,
+        ),
+        toml_edit::Value::InlineTable(table) => toml::Value::Table(
+            table
+                .iter()
+                .map(|(k, v)| toml_edit_value_to_toml(v).map(|v| (k.to_string(), v)))
+                .collect::<Option<toml::map::Map<_, _>>>()?,
+        ),
+    })
+}
+
+/// 1-based line of `key` in the table at `table_path` (empty for the top level)
+fn key_line(
+    doc: Option<&toml_edit::ImDocument<&str>>,
+    table_path: &[&str],
+    key: &str,
+) -> Option<usize> {
+    let doc = doc?;
+    let mut table = doc.as_table();
+    for name in table_path {
+        table = table.get(name)?.as_table()?;
+    }
+    let span = table.key(key)?.span()?;
+    Some(
+        doc.raw()[..span.start]
+            .matches('\n')
+            .count()
+            + 1,
+    )
+}
+
+/// The files named by the top-level `extends` key of a config file: a path or a list of
+/// paths, relative to the file's directory
+fn extends_paths(content: &str, path: &str) -> Result<Vec<String>, ConfigError> {
+    let doc = content
+        .parse::<DocumentMut>()
+        .map_err(|e| ConfigError::ParseError(format!("{}: Failed to parse TOML: {}", path, e)))?;
+    let Some(item) = doc.get("extends") else {
+        return Ok(Vec::new());
+    };
+    if let Some(base) = item.as_str() {
+        return Ok(vec![base.to_string()]);
+    }
+    item.as_array()
+        .and_then(|array| {
+            array
+                .iter()
+                .map(|value| value.as_str().map(str::to_string))
+                .collect()
+        })
+        .ok_or_else(|| {
+            ConfigError::ParseError(format!(
+                "{}: extends must be a path or a list of paths, got {}",
+                path,
+                item.type_name()
+            ))
+        })
+}
+
 /// Parses rumdl.toml / .rumdl.toml content.
 fn parse_rumdl_toml(content: &str, path: &str) -> Result<SourcedConfigFragment, ConfigError> {
     let doc = content
@@ -1393,6 +2547,9 @@ fn parse_rumdl_toml(content: &str, path: &str) -> Result<SourcedConfigFragment,
     let mut fragment = SourcedConfigFragment::default();
     let source = ConfigSource::RumdlToml;
     let file = Some(path.to_string());
+    // DocumentMut drops source spans, so keep a span-preserving parse for line numbers
+    let spans = toml_edit::ImDocument::parse(content).ok();
+    let line_of = |table: &[&str], key: &str| key_line(spans.as_ref(), table, key);
 
     // Define known rules before the loop
     let all_rules = rules::all_rules(&Config::default());
@@ -1403,13 +2560,49 @@ fn parse_rumdl_toml(content: &str, path: &str) -> Result<SourcedConfigFragment,
         .map(|s| s.to_ascii_uppercase())
         .collect();
 
+    // Top-level `enable`/`disable` are shorthands for the same [global] keys,
+    // which are applied after them and so win when both are given
+    for key in ["enable", "disable"] {
+        if let Some(item) = doc.get(key) {
+            let Some(toml_edit::Value::Array(array)) = item.as_value() else {
+                log::warn!(
+                    "[WARN] Expected array for top-level key '{}' in {}, found {}",
+                    key,
+                    path,
+                    item.type_name()
+                );
+                continue;
+            };
+            let values: Vec<String> = array
+                .iter()
+                .filter_map(|value| value.as_str())
+                .map(normalize_key)
+                .collect();
+            let target = if key == "enable" {
+                &mut fragment.global.enable
+            } else {
+                &mut fragment.global.disable
+            };
+            target.push_override(values, source, file.clone(), line_of(&[], key));
+        }
+    }
+
     // Handle [global] section
     if let Some(global_item) = doc.get("global") {
         if let Some(global_table) = global_item.as_table() {
             for (key, value_item) in global_table.iter() {
                 let norm_key = normalize_key(key);
+                let line = line_of(&["global"], key);
                 match norm_key.as_str() {
-                    "enable" | "disable" | "include" | "exclude" => {
+                    "enable"
+                    | "disable"
+                    | "include"
+                    | "exclude"
+                    | "extensions"
+                    | "front-matter-delimiters"
+                    | "always-report"
+                    | "managed-only"
+                    | "unmanaged-only" => {
                         if let Some(toml_edit::Value::Array(formatted_array)) =
                             value_item.as_value()
                         {
@@ -1421,7 +2614,12 @@ fn parse_rumdl_toml(content: &str, path: &str) -> Result<SourcedConfigFragment,
                                 .collect();
 
                             // Normalize rule names for enable/disable
-                            let final_values = if norm_key == "enable" || norm_key == "disable" {
+                            let final_values = if norm_key == "enable"
+                                || norm_key == "disable"
+                                || norm_key == "always-report"
+                                || norm_key == "managed-only"
+                                || norm_key == "unmanaged-only"
+                            {
                                 // Corrected: Pass &str to normalize_key
                                 values
                                     .into_iter()
@@ -1436,26 +2634,46 @@ fn parse_rumdl_toml(content: &str, path: &str) -> Result<SourcedConfigFragment,
                                     final_values,
                                     source,
                                     file.clone(),
-                                    None,
+                                    line,
                                 ),
                                 "disable" => fragment.global.disable.push_override(
                                     final_values,
                                     source,
                                     file.clone(),
-                                    None,
+                                    line,
                                 ),
                                 "include" => fragment.global.include.push_override(
                                     final_values,
                                     source,
                                     file.clone(),
-                                    None,
+                                    line,
                                 ),
                                 "exclude" => fragment.global.exclude.push_override(
                                     final_values,
                                     source,
                                     file.clone(),
-                                    None,
+                                    line,
                                 ),
+                                "extensions" => fragment
+                                    .global
+                                    .extensions
+                                    .push_override(final_values, source, file.clone(), line),
+                                "front-matter-delimiters" => fragment
+                                    .global
+                                    .front_matter_delimiters
+                                    .push_override(final_values, source, file.clone(), line),
+                                "always-report" => fragment
+                                    .global
+                                    .always_report
+                                    .push_override(final_values, source, file.clone(), line),
+                                "managed-only" => fragment
+                                    .global
+                                    .managed_only
+                                    .push_override(final_values, source, file.clone(), line),
+                                "unmanaged-only" => fragment
+                                    .global
+                                    .unmanaged_only
+                                    .push_override(final_values, source, file.clone(), line),
                                 _ => unreachable!(), // Should not happen due to outer match
                             }
                         } else {
@@ -1467,16 +2685,18 @@ fn parse_rumdl_toml(content: &str, path: &str) -> Result<SourcedConfigFragment,
                             );
                         }
                     }
-                    "respect_gitignore" | "respect-gitignore" => {
+                    "respect_gitignore" | "respect-gitignore" | "strict" => {
                         // Handle both cases
                         if let Some(toml_edit::Value::Boolean(formatted_bool)) =
                             value_item.as_value()
                         {
                             let val = *formatted_bool.value();
-                            fragment
-                                .global
-                                .respect_gitignore
-                                .push_override(val, source, file.clone(), None);
+                            let target = if norm_key == "strict" {
+                                &mut fragment.global.strict
+                            } else {
+                                &mut fragment.global.respect_gitignore
+                            };
+                            target.push_override(val, source, file.clone(), line);
                         } else {
                             log::warn!(
                                 "[WARN] Expected boolean for global key '{}' in {}, found {}",
@@ -1529,24 +2749,27 @@ fn parse_rumdl_toml(content: &str, path: &str) -> Result<SourcedConfigFragment,
                     Some(toml_edit::Value::Datetime(formatted)) => {
                         Some(toml::Value::Datetime(*formatted.value()))
                     }
-                    Some(toml_edit::Value::Array(_)) => {
-                        log::warn!(
-                            "[WARN] Skipping array value for key '{}.{}' in {}. Array conversion not yet fully implemented in parser.",
-                            norm_rule_name,
-                            norm_rk,
-                            path
-                        );
-                        None
-                    }
-                    Some(toml_edit::Value::InlineTable(_)) => {
-                        log::warn!(
-                            "[WARN] Skipping inline table value for key '{}.{}' in {}. Table conversion not yet fully implemented in parser.",
-                            norm_rule_name,
-                            norm_rk,
-                            path
-                        );
-                        None
+                    Some(value @ toml_edit::Value::Array(_))
+                    | Some(value @ toml_edit::Value::InlineTable(_)) => {
+                        let converted = toml_edit_value_to_toml(value);
+                        if converted.is_none() {
+                            log::warn!(
+                                "[WARN] Skipping unsupported value for key '{}.{}' in {}.",
+                                norm_rule_name,
+                                norm_rk,
+                                path
+                            );
+                        }
+                        converted
                     }
+                    // A sub-table such as [MD044.synonyms] holds the same data as an inline table
+                    None if rv_item.is_table() => rv_item
+                        .as_table()
+                        .and_then(|sub_table| {
+                            toml_edit_value_to_toml(&toml_edit::Value::InlineTable(
+                                sub_table.clone().into_inline_table(),
+                            ))
+                        }),
                     None => {
                         log::warn!(
                             "[WARN] Skipping non-value item for key '{}.{}' in {}. Expected simple value.",
@@ -1564,7 +2787,7 @@ fn parse_rumdl_toml(content: &str, path: &str) -> Result<SourcedConfigFragment,
                         .or_insert_with(|| {
                             SourcedValue::new(toml_val.clone(), ConfigSource::Default)
                         });
-                    sv.push_override(toml_val, source, file.clone(), None);
+                    sv.push_override(toml_val, source, file.clone(), line_of(&[key], rk));
                 }
             }
         } else if item.is_value() {
@@ -1587,3 +2810,146 @@ fn load_from_markdownlint(path: &str) -> Result<SourcedConfigFragment, ConfigErr
         .map_err(|e| ConfigError::ParseError(format!("{}: {}", path, e)))?;
     Ok(ml_config.map_to_sourced_rumdl_config_fragment(Some(path)))
 }
+
+/// Config files looked for in each directory during per-directory discovery, in priority order.
+/// `pyproject.toml` only counts if it has a `[tool.rumdl]` section.
+const DIRECTORY_CONFIG_FILES: &[&str] = &[".rumdl.toml", "rumdl.toml", "pyproject.toml"];
+
+/// Returns the rumdl config file located directly in `dir`, if any.
+fn directory_config_file(dir: &Path) -> Option<PathBuf> {
+    DIRECTORY_CONFIG_FILES
+        .iter()
+        .map(|name| dir.join(name))
+        .find(|path| {
+            if !path.is_file() {
+                return false;
+            }
+            if path
+                .file_name()
+                .and_then(|n| n.to_str())
+                != Some("pyproject.toml")
+            {
+                return true;
+            }
+            fs::read_to_string(path)
+                .ok()
+                .and_then(|content| content.parse::<toml::Value>().ok())
+                .is_some_and(|doc| {
+                    doc.get("tool")
+                        .and_then(|tool| tool.get("rumdl"))
+                        .is_some()
+                })
+        })
+}
+
+/// Returns true if a directory config is marked `root = true` (a top-level key in
+/// `.rumdl.toml`/`rumdl.toml`, or in `[tool.rumdl]` for `pyproject.toml`).
+fn is_root_config(path: &Path) -> bool {
+    let Some(doc) = fs::read_to_string(path)
+        .ok()
+        .and_then(|content| content.parse::<toml::Value>().ok())
+    else {
+        return false;
+    };
+    let table = if path
+        .file_name()
+        .and_then(|n| n.to_str())
+        == Some("pyproject.toml")
+    {
+        doc.get("tool")
+            .and_then(|tool| tool.get("rumdl"))
+    } else {
+        Some(&doc)
+    };
+    table
+        .and_then(|t| t.get("root"))
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false)
+}
+
+/// The nested directory configs that apply to a file, ordered from outermost to innermost
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ConfigChain {
+    /// Config files to merge in order; later (closer) files take precedence
+    pub files: Vec<PathBuf>,
+    /// The outermost file is marked `root = true`, so no ancestor or project config applies
+    pub root: bool,
+}
+
+/// Finds the directory configs for each linted file by walking up from the file's
+/// directory, so nested projects can use their own `.rumdl.toml`.
+///
+/// Nested configs inherit from the configs above them. The walk stops before `stop_dir`
+/// (typically the directory whose config was already loaded as the project config) or at a
+/// config marked `root = true`. Lookups are cached per directory.
+#[derive(Debug, Default)]
+pub struct ConfigResolver {
+    stop_dir: Option<PathBuf>,
+    cache: Mutex<HashMap<PathBuf, ConfigChain>>,
+}
+
+impl ConfigResolver {
+    /// Create a resolver whose upward walk stops at `stop_dir` (exclusive)
+    pub fn new(stop_dir: Option<&Path>) -> Self {
+        Self {
+            stop_dir: stop_dir.map(|dir| {
+                dir.canonicalize()
+                    .unwrap_or_else(|_| dir.to_path_buf())
+            }),
+            cache: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Returns the directory configs that apply to `file`, outermost first.
+    pub fn config_chain(&self, file: &Path) -> ConfigChain {
+        let file = file
+            .canonicalize()
+            .unwrap_or_else(|_| file.to_path_buf());
+        let mut dir = file.parent().map(Path::to_path_buf);
+        // Directories walked without a cached result, innermost first
+        let mut visited: Vec<(PathBuf, Option<PathBuf>)> = Vec::new();
+        let mut cache = self
+            .cache
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+
+        let mut chain = loop {
+            let Some(current) = dir else {
+                break ConfigChain::default();
+            };
+            if let Some(cached) = cache.get(&current) {
+                break cached.clone();
+            }
+            if self.stop_dir.as_deref() == Some(current.as_path()) {
+                break ConfigChain::default();
+            }
+            let config = directory_config_file(&current);
+            let is_root = config
+                .as_deref()
+                .is_some_and(is_root_config);
+            dir = current.parent().map(Path::to_path_buf);
+            visited.push((current, config));
+            if is_root {
+                break ConfigChain {
+                    files: Vec::new(),
+                    root: true,
+                };
+            }
+        };
+
+        // Extend the chain back down towards the file, caching each directory's chain
+        for (visited_dir, config) in visited.into_iter().rev() {
+            if let Some(config) = config {
+                chain.files.push(config);
+            }
+            cache.insert(visited_dir, chain.clone());
+        }
+        chain
+    }
+
+    /// Returns the nearest config file for `file`, or `None` if no config exists between
+    /// the file's directory and the stop directory.
+    pub fn nearest_config(&self, file: &Path) -> Option<PathBuf> {
+        self.config_chain(file).files.pop()
+    }
+}
@@ -0,0 +1,655 @@
+//! File discovery for the CLI: walks the given paths and returns the Markdown files to lint.
+//!
+//! Which files count as Markdown is controlled by the `extensions` global setting.
+//! [`collect_inputs`] combines files, directories, globs, archives and stdin into the
+//! single ordered list of documents the CLI lints.
+
+use crate::config::Config;
+use ignore::WalkBuilder;
+use ignore::overrides::OverrideBuilder;
+use std::io::Read;
+use std::path::Path;
+
+/// Default file extensions treated as Markdown during discovery
+pub const DEFAULT_MARKDOWN_EXTENSIONS: &[&str] = &["md", "markdown", "mdown", "mkd", "mkdn"];
+
+/// Options controlling file discovery that come from the command line
+#[derive(Debug, Clone)]
+pub struct DiscoveryOptions {
+    /// Comma-separated include patterns from the CLI (overrides config)
+    pub include: Option<String>,
+    /// Comma-separated exclude patterns from the CLI (overrides config)
+    pub exclude: Option<String>,
+    /// Respect .gitignore and .ignore files
+    pub respect_gitignore: bool,
+    /// Maximum number of directory levels to descend below each input directory;
+    /// `Some(0)` means only the directory's direct files. `None` is unlimited.
+    pub max_depth: Option<usize>,
+    /// Print the effective exclude patterns
+    pub verbose: bool,
+    /// Path reported for content read from `-`, also used to resolve its config;
+    /// [`STDIN_PATH`] when not set
+    pub stdin_filename: Option<String>,
+    /// Read stdin as if `-` were given (the `--stdin` flag)
+    pub stdin: bool,
+}
+
+/// Path reported for content read from stdin when no `--stdin-filename` is given
+pub const STDIN_PATH: &str = "<stdin>";
+
+/// Where the content of an [`Input`] comes from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputKind {
+    /// A Markdown file on disk
+    File,
+    /// A Markdown member of an archive, under a virtual `<archive>!/<member>` path
+    ArchiveMember,
+    /// Content read from stdin (`-`)
+    Stdin,
+}
+
+/// One document to lint
+#[derive(Debug, Clone, PartialEq)]
+pub struct Input {
+    /// Path reported in diagnostics; virtual for archive members and stdin
+    pub path: String,
+    pub kind: InputKind,
+    /// Content already read into memory; `None` for files on disk, which are read when linted
+    pub content: Option<String>,
+}
+
+impl Default for DiscoveryOptions {
+    fn default() -> Self {
+        Self {
+            include: None,
+            exclude: None,
+            respect_gitignore: true,
+            max_depth: None,
+            verbose: false,
+            stdin_filename: None,
+            stdin: false,
+        }
+    }
+}
+
+/// Normalize a configured extension: strip a leading dot and surrounding whitespace
+fn normalize_extension(ext: &str) -> &str {
+    let ext = ext.trim();
+    ext.strip_prefix('.').unwrap_or(ext)
+}
+
+/// Returns true if the path has one of the given Markdown extensions
+pub fn has_markdown_extension(path: &Path, extensions: &[String]) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| {
+            extensions
+                .iter()
+                .any(|allowed| normalize_extension(allowed) == ext)
+        })
+}
+
+/// Find all Markdown files below `paths`, honoring include/exclude patterns,
+/// gitignore settings and the configured Markdown extensions.
+pub fn find_markdown_files(
+    paths: &[String],
+    options: &DiscoveryOptions,
+    config: &Config,
+) -> Vec<String> {
+    let mut file_paths = Vec::new();
+    let extensions = &config.global.extensions;
+
+    // --- Configure ignore::WalkBuilder ---
+    // Start with the first path, add others later
+    let first_path = paths
+        .first()
+        .cloned()
+        .unwrap_or_else(|| ".".to_string());
+    let mut walk_builder = WalkBuilder::new(first_path);
+
+    // Add remaining paths
+    for path in paths.iter().skip(1) {
+        walk_builder.add(path);
+    }
+
+    // --- Add Markdown File Type Definition ---
+    let mut types_builder = ignore::types::TypesBuilder::new();
+    types_builder.add_defaults(); // Add standard types
+    for ext in extensions {
+        let ext = normalize_extension(ext);
+        if ext.is_empty() {
+            continue;
+        }
+        if let Err(e) = types_builder.add("markdown", &format!("*.{}", ext)) {
+            eprintln!("Warning: Invalid Markdown extension '{}': {}", ext, e);
+        }
+    }
+    types_builder.select("markdown"); // Select ONLY markdown for processing
+    match types_builder.build() {
+        Ok(types) => {
+            walk_builder.types(types);
+        }
+        Err(e) => {
+            // No usable extensions: the final filter below will reject every file
+            eprintln!("Warning: Could not build Markdown file types: {}", e);
+        }
+    }
+    // -----------------------------------------
+
+    // Determine if running in discovery mode (e.g., "rumdl ." or "rumdl check ." or "rumdl check")
+    let is_discovery_mode = paths.is_empty() || paths == ["."];
+
+    // --- Determine Effective Include/Exclude Patterns ---
+
+    // Include patterns: CLI > Config (only in discovery mode) > Default (only in discovery mode)
+    let final_include_patterns: Vec<String> = if let Some(cli_include) = options.include.as_deref()
+    {
+        // 1. CLI --include always wins
+        cli_include
+            .split(',')
+            .map(|p| p.trim().to_string())
+            .filter(|p| !p.is_empty())
+            .collect()
+    } else if is_discovery_mode && !config.global.include.is_empty() {
+        // 2. Config include is used ONLY in discovery mode if specified
+        config.global.include.clone()
+    } else if is_discovery_mode {
+        // 3. Default include (one glob per Markdown extension) ONLY in discovery mode
+        extensions
+            .iter()
+            .map(|ext| normalize_extension(ext))
+            .filter(|ext| !ext.is_empty())
+            .map(|ext| format!("*.{}", ext))
+            .collect()
+    } else {
+        // 4. Explicit path mode: No includes applied by default. Walk starts from explicit paths.
+        Vec::new()
+    };
+
+    // Exclude patterns: CLI > Config
+    let final_exclude_patterns: Vec<String> = if let Some(cli_exclude) = options.exclude.as_deref()
+    {
+        cli_exclude
+            .split(',')
+            .map(|p| p.trim().to_string())
+            .filter(|p| !p.is_empty())
+            .collect()
+    } else {
+        config.global.exclude.clone()
+    };
+
+    // Debug: Log exclude patterns
+    if options.verbose {
+        eprintln!("Exclude patterns: {:?}", final_exclude_patterns);
+    }
+    // --- End Pattern Determination ---
+
+    // Apply overrides using the determined patterns
+    if !final_include_patterns.is_empty() || !final_exclude_patterns.is_empty() {
+        let mut override_builder = OverrideBuilder::new("."); // Root context
+
+        // Add includes (these act as positive filters)
+        for pattern in &final_include_patterns {
+            // Important: In ignore crate, bare patterns act as includes if no exclude (!) is present.
+            // If we add excludes later, these includes ensure *only* matching files are considered.
+            // If no excludes are added, these effectively define the set of files to walk.
+            if let Err(e) = override_builder.add(pattern) {
+                eprintln!("Warning: Invalid include pattern '{}': {}", pattern, e);
+            }
+        }
+
+        // Add excludes (these filter *out* files) - MUST start with '!'
+        for pattern in &final_exclude_patterns {
+            // Ensure exclude patterns start with '!' for ignore crate overrides
+            let exclude_rule = if pattern.starts_with('!') {
+                pattern.clone() // Already formatted
+            } else {
+                format!("!{}", pattern)
+            };
+            if let Err(e) = override_builder.add(&exclude_rule) {
+                eprintln!("Warning: Invalid exclude pattern '{}': {}", pattern, e);
+            }
+        }
+
+        // Build and apply the overrides
+        match override_builder.build() {
+            Ok(overrides) => {
+                walk_builder.overrides(overrides);
+            }
+            Err(e) => {
+                eprintln!("Error building path overrides: {}", e);
+            }
+        };
+    }
+
+    // Configure gitignore handling *SECOND*
+    let use_gitignore = options.respect_gitignore;
+
+    walk_builder.ignore(use_gitignore); // Enable/disable .ignore
+    walk_builder.git_ignore(use_gitignore); // Enable/disable .gitignore
+    walk_builder.git_global(use_gitignore); // Enable/disable global gitignore
+    walk_builder.git_exclude(use_gitignore); // Enable/disable .git/info/exclude
+    walk_builder.parents(use_gitignore); // Enable/disable parent ignores
+    walk_builder.hidden(true); // Keep hidden files ignored unconditionally
+    walk_builder.require_git(false); // Process git ignores even if no repo detected
+
+    // The walker counts the input path itself as depth 0, so its direct files are depth 1
+    walk_builder.max_depth(options.max_depth.map(|depth| depth + 1));
+
+    // Add support for .markdownlintignore file
+    walk_builder.add_custom_ignore_filename(".markdownlintignore");
+
+    // --- Execute Walk ---
+
+    for result in walk_builder.build() {
+        match result {
+            Ok(entry) => {
+                let path = entry.path();
+                // We are primarily interested in files. ignore crate handles dir traversal.
+                // Check if it's a file and if it wasn't explicitly excluded by overrides
+                if path.is_file() {
+                    let file_path = path.to_string_lossy().to_string();
+                    // Clean the path before pushing
+                    let cleaned_path = if let Some(stripped) = file_path.strip_prefix("./") {
+                        stripped.to_string()
+                    } else {
+                        file_path
+                    };
+                    file_paths.push(cleaned_path);
+                }
+            }
+            Err(err) => eprintln!("Error walking directory: {}", err),
+        }
+    }
+
+    // Remove duplicate paths if WalkBuilder might yield them (e.g. multiple input paths)
+    file_paths.sort();
+    file_paths.dedup();
+
+    // --- Final Explicit Markdown Filter ---
+    // Ensure only files with a configured Markdown extension are returned,
+    // regardless of how ignore crate overrides interacted with type filters.
+    file_paths.retain(|path_str| has_markdown_extension(Path::new(path_str), extensions));
+    // -------------------------------------
+
+    file_paths
+}
+
+/// Whether a command-line argument is a glob pattern rather than a plain path
+fn is_glob_pattern(arg: &str) -> bool {
+    arg.contains(['*', '?', '['])
+}
+
+/// Strip a leading `./` so command-line paths compare equal to discovered ones
+fn strip_current_dir(path: &str) -> &str {
+    path.strip_prefix("./").unwrap_or(path)
+}
+
+/// Collect every document named on the command line, in argument order.
+///
+/// Arguments may be files, directories, glob patterns the shell did not expand, archives and
+/// `-` for stdin (read once, from `stdin`; `options.stdin` adds it). Files found below each
+/// directory are sorted by path, and a file named by several arguments appears once, at its
+/// first position.
+/// With no arguments, the current directory is discovered. Fails if `--stdin-filename` names
+/// one of the files found.
+pub fn collect_inputs(
+    paths: &[String],
+    options: &DiscoveryOptions,
+    config: &Config,
+    stdin: &mut dyn Read,
+) -> Result<Vec<Input>, String> {
+    let mut paths = paths.to_vec();
+    if options.stdin && !paths.iter().any(|path| path == "-") {
+        paths.push("-".to_string());
+    }
+
+    // Expand glob patterns that do not name an existing path
+    let mut args = Vec::new();
+    for path in &paths {
+        if path != "-" && !Path::new(path).exists() && is_glob_pattern(path) {
+            let mut matches: Vec<String> = glob::glob(path)
+                .map_err(|e| format!("Invalid glob pattern '{}': {}", path, e))?
+                .filter_map(Result::ok)
+                .map(|p| p.to_string_lossy().to_string())
+                .collect();
+            if matches.is_empty() {
+                eprintln!("Warning: No files match pattern '{}'", path);
+            }
+            matches.sort();
+            args.extend(matches);
+        } else {
+            args.push(path.clone());
+        }
+    }
+
+    let is_archive = |arg: &str| {
+        let path = Path::new(arg);
+        path.is_file() && crate::archive::is_archive_path(path)
+    };
+    let disk_args: Vec<String> = args
+        .iter()
+        .filter(|arg| *arg != "-" && !is_archive(arg))
+        .cloned()
+        .collect();
+    let mut disk_files = if disk_args.is_empty() && !paths.is_empty() {
+        Vec::new()
+    } else {
+        find_markdown_files(&disk_args, options, config)
+    };
+
+    let mut inputs = Vec::new();
+    let mut read_stdin = false;
+    for arg in &args {
+        if arg == "-" {
+            if read_stdin {
+                continue;
+            }
+            read_stdin = true;
+            let mut content = String::new();
+            stdin
+                .read_to_string(&mut content)
+                .map_err(|e| format!("Failed to read stdin: {}", e))?;
+            inputs.push(Input {
+                path: options
+                    .stdin_filename
+                    .clone()
+                    .unwrap_or_else(|| STDIN_PATH.to_string()),
+                kind: InputKind::Stdin,
+                content: Some(content),
+            });
+        } else if is_archive(arg) {
+            let members =
+                crate::archive::read_markdown_members(Path::new(arg), &config.global.extensions)
+                    .map_err(|e| format!("Failed to read {}: {}", arg, e))?;
+            let mut members: Vec<(String, String)> = members.into_iter().collect();
+            members.sort();
+            inputs.extend(
+                members
+                    .into_iter()
+                    .map(|(member, content)| Input {
+                        path: format!("{}!/{}", arg, member),
+                        kind: InputKind::ArchiveMember,
+                        content: Some(content),
+                    }),
+            );
+        } else {
+            // The discovered files below this argument, already sorted by path
+            let root = strip_current_dir(arg);
+            let (below, rest): (Vec<String>, Vec<String>) =
+                disk_files
+                    .into_iter()
+                    .partition(|file| {
+                        root.is_empty()
+                            || root == "."
+                            || Path::new(strip_current_dir(file)).starts_with(root)
+                    });
+            disk_files = rest;
+            inputs.extend(below.into_iter().map(|path| Input {
+                path,
+                kind: InputKind::File,
+                content: None,
+            }));
+        }
+    }
+    // Files from implicit discovery, or not attributable to one argument
+    inputs.extend(
+        disk_files
+            .into_iter()
+            .map(|path| Input {
+                path,
+                kind: InputKind::File,
+                content: None,
+            }),
+    );
+
+    // Inputs are held and reported by path, so stdin cannot take the name of a linted file
+    if let Some(stdin) = inputs
+        .iter()
+        .find(|input| input.kind == InputKind::Stdin)
+        && inputs.iter().any(|input| {
+            input.kind == InputKind::File
+                && strip_current_dir(&input.path) == strip_current_dir(&stdin.path)
+        })
+    {
+        return Err(format!(
+            "--stdin-filename '{}' names a file that is also being checked",
+            stdin.path
+        ));
+    }
+
+    Ok(inputs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::tempdir;
+
+    fn config_with_extensions(extensions: &[&str]) -> Config {
+        let mut config = Config::default();
+        config.global.extensions = extensions
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        config
+    }
+
+    #[test]
+    fn test_has_markdown_extension() {
+        let exts = vec!["md".to_string(), ".mdx".to_string()];
+        assert!(has_markdown_extension(Path::new("a/README.md"), &exts));
+        assert!(has_markdown_extension(Path::new("page.mdx"), &exts));
+        assert!(!has_markdown_extension(Path::new("notes.markdown"), &exts));
+        assert!(!has_markdown_extension(Path::new("Makefile"), &exts));
+    }
+
+    #[test]
+    fn test_markdown_extension_included_or_skipped() {
+        let temp_dir = tempdir().unwrap();
+        let dir = temp_dir.path();
+        fs::write(dir.join("a.md"), "# A\n").unwrap();
+        fs::write(dir.join("b.markdown"), "# B\n").unwrap();
+        fs::write(dir.join("c.txt"), "C\n").unwrap();
+        let paths = vec![dir.to_string_lossy().to_string()];
+        let options = DiscoveryOptions::default();
+
+        let found = find_markdown_files(&paths, &options, &Config::default());
+        assert_eq!(found.len(), 2, "Default extensions: {:?}", found);
+        assert!(
+            found
+                .iter()
+                .any(|p| p.ends_with("b.markdown"))
+        );
+
+        let found = find_markdown_files(&paths, &options, &config_with_extensions(&["md"]));
+        assert_eq!(found.len(), 1, "Only .md: {:?}", found);
+        assert!(found[0].ends_with("a.md"));
+
+        let found = find_markdown_files(&paths, &options, &config_with_extensions(&["markdown"]));
+        assert_eq!(found.len(), 1, "Only .markdown: {:?}", found);
+        assert!(found[0].ends_with("b.markdown"));
+    }
+
+    #[test]
+    fn test_max_depth_limits_recursion() {
+        let temp_dir = tempdir().unwrap();
+        let dir = temp_dir.path();
+        fs::create_dir_all(dir.join("one/two")).unwrap();
+        fs::write(dir.join("top.md"), "# Top\n").unwrap();
+        fs::write(dir.join("one/first.md"), "# First\n").unwrap();
+        fs::write(dir.join("one/two/second.md"), "# Second\n").unwrap();
+        let paths = vec![dir.to_string_lossy().to_string()];
+
+        let names_at = |max_depth: Option<usize>| {
+            let options = DiscoveryOptions {
+                max_depth,
+                ..Default::default()
+            };
+            let mut names: Vec<String> = find_markdown_files(&paths, &options, &Config::default())
+                .iter()
+                .map(|p| {
+                    Path::new(p)
+                        .file_name()
+                        .unwrap()
+                        .to_string_lossy()
+                        .to_string()
+                })
+                .collect();
+            names.sort();
+            names
+        };
+
+        assert_eq!(names_at(Some(0)), vec!["top.md"]);
+        assert_eq!(names_at(Some(1)), vec!["first.md", "top.md"]);
+        assert_eq!(names_at(None), vec!["first.md", "second.md", "top.md"]);
+
+        // An explicitly given file is always linted
+        let file = vec![
+            dir.join("one/two/second.md")
+                .to_string_lossy()
+                .to_string(),
+        ];
+        let options = DiscoveryOptions {
+            max_depth: Some(0),
+            ..Default::default()
+        };
+        assert_eq!(
+            find_markdown_files(&file, &options, &Config::default()).len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_collect_inputs_combines_sources_in_argument_order() {
+        let temp_dir = tempdir().unwrap();
+        let dir = temp_dir.path();
+        fs::create_dir_all(dir.join("zdocs/sub")).unwrap();
+        fs::write(dir.join("zdocs/b.md"), "# B\n").unwrap();
+        fs::write(dir.join("zdocs/sub/a.md"), "# A\n").unwrap();
+        fs::write(dir.join("first.md"), "# First\n").unwrap();
+        fs::write(dir.join("notes.txt"), "not markdown\n").unwrap();
+        let path = |name: &str| {
+            dir.join(name)
+                .to_string_lossy()
+                .to_string()
+        };
+
+        let paths = vec![
+            path("zdocs"),
+            path("first.md"),
+            "-".to_string(),
+            path("zdocs/b.md"),
+        ];
+        let options = DiscoveryOptions {
+            stdin_filename: Some("piped.md".to_string()),
+            ..Default::default()
+        };
+        let mut stdin = "# Piped\n".as_bytes();
+        let inputs = collect_inputs(&paths, &options, &Config::default(), &mut stdin).unwrap();
+
+        let listed: Vec<(&str, InputKind)> = inputs
+            .iter()
+            .map(|input| (input.path.as_str(), input.kind))
+            .collect();
+        let (b, a, first) = (path("zdocs/b.md"), path("zdocs/sub/a.md"), path("first.md"));
+        assert_eq!(
+            listed,
+            vec![
+                (b.as_str(), InputKind::File),
+                (a.as_str(), InputKind::File),
+                (first.as_str(), InputKind::File),
+                ("piped.md", InputKind::Stdin),
+            ]
+        );
+        assert_eq!(inputs[3].content.as_deref(), Some("# Piped\n"));
+
+        // Unexpanded globs are expanded; stdin alone does not trigger discovery
+        let paths = vec![path("zdocs/*.md"), path("first.md")];
+        let inputs =
+            collect_inputs(&paths, &options, &Config::default(), &mut "".as_bytes()).unwrap();
+        let listed: Vec<&str> = inputs
+            .iter()
+            .map(|input| input.path.as_str())
+            .collect();
+        assert_eq!(listed, vec![b.as_str(), first.as_str()]);
+
+        let inputs = collect_inputs(
+            &["-".to_string()],
+            &DiscoveryOptions::default(),
+            &Config::default(),
+            &mut "x".as_bytes(),
+        )
+        .unwrap();
+        assert_eq!(inputs.len(), 1);
+        assert_eq!(inputs[0].path, STDIN_PATH);
+
+        // Stdin may not take the name of a file that is also checked
+        let options = DiscoveryOptions {
+            stdin_filename: Some(path("first.md")),
+            ..Default::default()
+        };
+        let error = collect_inputs(
+            &[path("first.md"), "-".to_string()],
+            &options,
+            &Config::default(),
+            &mut "x".as_bytes(),
+        )
+        .unwrap_err();
+        assert!(error.contains("--stdin-filename"), "{}", error);
+    }
+
+    #[test]
+    fn test_stdin_input_is_reported_and_fixed_under_its_filename() {
+        use crate::rule::Rule;
+        use crate::rules::MD050StrongStyle;
+        use crate::rules::strong_style::StrongStyle;
+
+        let rules: Vec<Box<dyn Rule>> = vec![Box::new(MD050StrongStyle::new(
+            StrongStyle::Consistent,
+        ))];
+        let piped = |stdin_filename: Option<&str>, buffer: &str| {
+            let options = DiscoveryOptions {
+                stdin_filename: stdin_filename.map(str::to_string),
+                ..Default::default()
+            };
+            let inputs = collect_inputs(
+                &["-".to_string()],
+                &options,
+                &Config::default(),
+                &mut buffer.as_bytes(),
+            )
+            .unwrap();
+            assert_eq!(inputs.len(), 1);
+            inputs[0].clone()
+        };
+
+        // As `check - --stdin-filename docs/notes.md --fix`: diagnostics name the given
+        // path and the fixed content is what goes to stdout
+        let input = piped(
+            Some("docs/notes.md"),
+            "# Notes\n\nSome **bold** and __more__\n",
+        );
+        let content = input.content.unwrap();
+        let warnings = crate::lint(&content, &rules, false).unwrap();
+        let diagnostics: Vec<_> = warnings
+            .iter()
+            .map(|w| crate::warning_json(&input.path, w))
+            .collect();
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0]["file"], "docs/notes.md");
+        let mut stdout = content.clone();
+        crate::apply_rule_fixes(&mut stdout, &rules, &warnings, false, &mut Vec::new());
+        assert_eq!(stdout, "# Notes\n\nSome **bold** and **more**\n");
+
+        // Without a filename stdin is `<stdin>`; empty stdin has nothing to report
+        let input = piped(None, "");
+        assert_eq!(input.path, STDIN_PATH);
+        assert!(
+            crate::lint(&input.content.unwrap(), &rules, false)
+                .unwrap()
+                .is_empty()
+        );
+    }
+}
@@ -0,0 +1,210 @@
+//! Finding and re-staging the files staged for commit, for `check --changed-only`.
+//!
+//! Shells out to `git`, run in a given directory; all paths are relative to it. A staged
+//! file that also has unstaged changes is reported as such, so callers can lint its staged
+//! content without touching the working tree copy.
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Errors raised while querying or updating the git index
+#[derive(Debug, thiserror::Error)]
+pub enum GitError {
+    /// git could not be started
+    #[error("Failed to run git: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// git ran but reported an error, e.g. outside a repository
+    #[error("git {command} failed: {stderr}")]
+    Command { command: String, stderr: String },
+}
+
+type GitResult<T> = Result<T, GitError>;
+
+/// A file with changes staged for commit
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StagedFile {
+    pub path: PathBuf,
+    /// Whether the working tree copy differs from the staged copy
+    pub has_unstaged_changes: bool,
+}
+
+fn git(dir: &Path, args: &[&str]) -> GitResult<Vec<u8>> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(dir)
+        .args(args)
+        .output()?;
+    if !output.status.success() {
+        return Err(GitError::Command {
+            command: args
+                .first()
+                .copied()
+                .unwrap_or_default()
+                .to_string(),
+            stderr: String::from_utf8_lossy(&output.stderr)
+                .trim()
+                .to_string(),
+        });
+    }
+    Ok(output.stdout)
+}
+
+fn null_separated_paths(output: &[u8]) -> Vec<PathBuf> {
+    output
+        .split(|&b| b == 0)
+        .filter(|path| !path.is_empty())
+        .map(|path| PathBuf::from(String::from_utf8_lossy(path).into_owned()))
+        .collect()
+}
+
+/// Files added, copied, modified or renamed in the index below `dir`, limited to
+/// `pathspecs` when any are given. Deleted files are left out.
+pub fn staged_files(dir: &Path, pathspecs: &[String]) -> GitResult<Vec<StagedFile>> {
+    // Fails with a plain "not a git repository" outside a work tree, where `git diff`
+    // would print its usage instead
+    git(dir, &["rev-parse", "--is-inside-work-tree"])?;
+    let diff = |extra: &[&str]| {
+        let mut args = vec!["diff", "--name-only", "-z", "--relative"];
+        args.extend_from_slice(extra);
+        args.push("--");
+        args.extend(pathspecs.iter().map(String::as_str));
+        git(dir, &args).map(|out| null_separated_paths(&out))
+    };
+    let staged = diff(&["--cached", "--diff-filter=ACMR"])?;
+    let unstaged = diff(&[])?;
+    Ok(staged
+        .into_iter()
+        .map(|path| StagedFile {
+            has_unstaged_changes: unstaged.contains(&path),
+            path,
+        })
+        .collect())
+}
+
+/// The staged content of `path`
+pub fn staged_content(dir: &Path, path: &Path) -> GitResult<String> {
+    let object = format!(":./{}", path.display());
+    git(dir, &["show", &object]).map(|out| String::from_utf8_lossy(&out).into_owned())
+}
+
+/// Stage the working tree copies of `paths`
+pub fn stage(dir: &Path, paths: &[PathBuf]) -> GitResult<()> {
+    if paths.is_empty() {
+        return Ok(());
+    }
+    let mut args = vec!["add".to_string(), "--".to_string()];
+    args.extend(
+        paths
+            .iter()
+            .map(|path| path.display().to_string()),
+    );
+    let args: Vec<&str> = args
+        .iter()
+        .map(String::as_str)
+        .collect();
+    git(dir, &args).map(|_| ())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rule::Rule;
+    use crate::rules::strong_style::StrongStyle;
+    use crate::rules::{MD024NoDuplicateHeading, MD050StrongStyle};
+    use std::fs;
+
+    #[test]
+    fn test_fix_and_restage_only_fully_staged_files() {
+        let temp = tempfile::tempdir().unwrap();
+        let dir = temp.path();
+        git(dir, &["init", "-q"]).unwrap();
+
+        // fixed.md is staged as is; dirty.md has further unstaged edits
+        fs::write(dir.join("fixed.md"), "# Notes\n\nSome __bold__ text\n").unwrap();
+        fs::write(dir.join("dirty.md"), "# Dirty\n\nStaged __bold__\n").unwrap();
+        fs::write(dir.join("unstaged.md"), "# Not staged\n").unwrap();
+        stage(
+            dir,
+            &[
+                PathBuf::from("fixed.md"),
+                PathBuf::from("dirty.md"),
+            ],
+        )
+        .unwrap();
+        fs::write(
+            dir.join("dirty.md"),
+            "# Dirty\n\nStaged __bold__\n\nWork in progress\n",
+        )
+        .unwrap();
+
+        let staged = staged_files(dir, &[]).unwrap();
+        assert_eq!(
+            staged,
+            vec![
+                StagedFile {
+                    path: PathBuf::from("dirty.md"),
+                    has_unstaged_changes: true,
+                },
+                StagedFile {
+                    path: PathBuf::from("fixed.md"),
+                    has_unstaged_changes: false,
+                },
+            ]
+        );
+        assert_eq!(
+            staged_content(dir, Path::new("dirty.md")).unwrap(),
+            "# Dirty\n\nStaged __bold__\n"
+        );
+
+        // Fix the fully staged file as the hook does, then re-stage it
+        let rules: Vec<Box<dyn Rule>> = vec![
+            Box::new(MD050StrongStyle::new(StrongStyle::Asterisk)),
+            Box::new(MD024NoDuplicateHeading::default()),
+        ];
+        let mut content = fs::read_to_string(dir.join("fixed.md")).unwrap();
+        let warnings = crate::lint(&content, &rules, false).unwrap();
+        let fixed =
+            crate::apply_rule_fixes(&mut content, &rules, &warnings, false, &mut Vec::new());
+        assert_eq!(fixed, 1);
+        fs::write(dir.join("fixed.md"), &content).unwrap();
+        stage(dir, &[PathBuf::from("fixed.md")]).unwrap();
+
+        assert_eq!(
+            staged_content(dir, Path::new("fixed.md")).unwrap(),
+            "# Notes\n\nSome **bold** text\n"
+        );
+        let staged = staged_files(dir, &[]).unwrap();
+        assert!(
+            staged
+                .iter()
+                .all(|file| file.path != Path::new("fixed.md") || !file.has_unstaged_changes)
+        );
+        // The partially staged file's working copy is left untouched
+        assert_eq!(
+            fs::read_to_string(dir.join("dirty.md")).unwrap(),
+            "# Dirty\n\nStaged __bold__\n\nWork in progress\n"
+        );
+
+        // An issue without a fix remains after fixing, which fails the hook
+        let mut content = "# Notes\n\n# Notes\n".to_string();
+        let warnings = crate::lint(&content, &rules, false).unwrap();
+        assert_eq!(
+            crate::apply_rule_fixes(&mut content, &rules, &warnings, false, &mut Vec::new()),
+            0
+        );
+        assert_eq!(
+            crate::lint(&content, &rules, false)
+                .unwrap()
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_staged_files_outside_repository_fails() {
+        let temp = tempfile::tempdir().unwrap();
+        let err = staged_files(temp.path(), &[]).unwrap_err();
+        assert!(matches!(err, GitError::Command { .. }));
+    }
+}
@@ -10,16 +10,81 @@
 //! - `<!-- markdownlint-capture -->` - Capture current configuration state
 //! - `<!-- markdownlint-restore -->` - Restore captured configuration state
 //!
-//! Also supports rumdl-specific syntax with same semantics.
+//! Also supports rumdl-specific syntax with same semantics. Each of these comments also
+//! accepts warning ids (see [`crate::rule::LintWarning::id`]) in place of rule ids, to
+//! disable a single warning. Rule ids match regardless of case, and an enable comment
+//! naming rules re-enables just those after a disable of all rules. A line holding nothing
+//! but one of these comments is never reported by any rule.
+//!
+//! `<!-- begin:managed -->` and `<!-- end:managed -->` mark managed regions, such as a
+//! generated section, which the global `managed-only` and `unmanaged-only` settings limit
+//! rules to or keep rules out of (see [`ManagedRegions`]).
 
 use std::collections::{HashMap, HashSet};
 
+/// Rules disabled by persistent disable/enable comments
+#[derive(Debug, Clone, Default, PartialEq)]
+struct DisabledRules {
+    /// Every rule is disabled, except those in `rules`
+    all: bool,
+    /// Uppercase rule or warning ids: the disabled ones, or the re-enabled ones if `all`
+    rules: HashSet<String>,
+}
+
+impl DisabledRules {
+    fn disable(&mut self, rules: &[&str]) {
+        if rules.is_empty() {
+            self.all = true;
+            self.rules.clear();
+        } else if self.all {
+            for rule in rules {
+                self.rules
+                    .remove(&rule.to_ascii_uppercase());
+            }
+        } else {
+            self.rules.extend(
+                rules
+                    .iter()
+                    .map(|rule| rule.to_ascii_uppercase()),
+            );
+        }
+    }
+
+    fn enable(&mut self, rules: &[&str]) {
+        if rules.is_empty() {
+            self.all = false;
+            self.rules.clear();
+        } else if self.all {
+            self.rules.extend(
+                rules
+                    .iter()
+                    .map(|rule| rule.to_ascii_uppercase()),
+            );
+        } else {
+            for rule in rules {
+                self.rules
+                    .remove(&rule.to_ascii_uppercase());
+            }
+        }
+    }
+
+    fn contains(&self, rule_name: &str) -> bool {
+        self.all
+            != self
+                .rules
+                .contains(&rule_name.to_ascii_uppercase())
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct InlineConfig {
-    /// Rules that are disabled at each line (1-indexed line -> set of disabled rules)
-    disabled_at_line: HashMap<usize, HashSet<String>>,
-    /// Rules disabled for specific lines via disable-line (1-indexed)
+    /// Rules that are disabled at each line (1-indexed line -> disabled rules), with one
+    /// more entry past the last line for the state at the end of the document
+    disabled_at_line: HashMap<usize, DisabledRules>,
+    /// Rules disabled for specific lines via disable-line (1-indexed, uppercase ids)
     line_disabled_rules: HashMap<usize, HashSet<String>>,
+    /// Lines holding nothing but an inline configuration comment (1-indexed)
+    comment_lines: HashSet<usize>,
 }
 
 impl InlineConfig {
@@ -27,6 +92,7 @@ impl InlineConfig {
         Self {
             disabled_at_line: HashMap::new(),
             line_disabled_rules: HashMap::new(),
+            comment_lines: HashSet::new(),
         }
     }
 
@@ -36,8 +102,8 @@ impl InlineConfig {
         let lines: Vec<&str> = content.lines().collect();
 
         // Track current state of disabled rules
-        let mut currently_disabled = HashSet::new();
-        let mut capture_stack: Vec<HashSet<String>> = Vec::new();
+        let mut currently_disabled = DisabledRules::default();
+        let mut capture_stack: Vec<DisabledRules> = Vec::new();
 
         for (idx, line) in lines.iter().enumerate() {
             let line_num = idx + 1; // 1-indexed
@@ -48,6 +114,10 @@ impl InlineConfig {
                 .disabled_at_line
                 .insert(line_num, currently_disabled.clone());
 
+            if is_config_comment_line(line) {
+                config.comment_lines.insert(line_num);
+            }
+
             // Process comments in order of specificity to avoid conflicts
 
             // Check for disable-next-line first (more specific than disable)
@@ -61,9 +131,11 @@ impl InlineConfig {
                     // Disable all rules for next line
                     line_rules.insert("*".to_string());
                 } else {
-                    for rule in rules {
-                        line_rules.insert(rule.to_string());
-                    }
+                    line_rules.extend(
+                        rules
+                            .iter()
+                            .map(|rule| rule.to_ascii_uppercase()),
+                    );
                 }
             }
             // Check for disable-line (more specific than disable)
@@ -76,9 +148,11 @@ impl InlineConfig {
                     // Disable all rules for current line
                     line_rules.insert("*".to_string());
                 } else {
-                    for rule in rules {
-                        line_rules.insert(rule.to_string());
-                    }
+                    line_rules.extend(
+                        rules
+                            .iter()
+                            .map(|rule| rule.to_ascii_uppercase()),
+                    );
                 }
             }
             // Check for capture
@@ -93,61 +167,67 @@ impl InlineConfig {
             }
             // Check for disable (persistent)
             else if let Some(rules) = parse_disable_comment(line) {
-                if rules.is_empty() {
-                    // Disable all rules - we'll use "*" as a marker
-                    currently_disabled.clear();
-                    currently_disabled.insert("*".to_string());
-                } else {
-                    for rule in rules {
-                        currently_disabled.insert(rule.to_string());
-                    }
-                }
+                currently_disabled.disable(&rules);
             }
             // Check for enable (persistent)
             else if let Some(rules) = parse_enable_comment(line) {
-                if rules.is_empty() {
-                    // Enable all rules
-                    currently_disabled.clear();
-                } else {
-                    // Enable specific rules
-                    for rule in rules {
-                        currently_disabled.remove(rule);
-                    }
-                }
+                currently_disabled.enable(&rules);
             }
         }
+        config
+            .disabled_at_line
+            .insert(lines.len() + 1, currently_disabled);
 
         config
     }
 
-    /// Check if a rule is disabled at a specific line
+    /// Whether no rule is disabled anywhere, as in content without configuration comments
+    pub fn is_empty(&self) -> bool {
+        self.comment_lines.is_empty()
+            && self.line_disabled_rules.is_empty()
+            && self
+                .disabled_at_line
+                .values()
+                .all(|disabled| *disabled == DisabledRules::default())
+    }
+
+    /// Check if a rule is disabled at a specific line. Lines holding only an inline
+    /// configuration comment have every rule disabled.
     pub fn is_rule_disabled(&self, rule_name: &str, line_number: usize) -> bool {
+        if self
+            .comment_lines
+            .contains(&line_number)
+        {
+            return true;
+        }
+
         // Check line-specific disables first (disable-line, disable-next-line)
         if let Some(line_rules) = self
             .line_disabled_rules
             .get(&line_number)
         {
-            if line_rules.contains("*") || line_rules.contains(rule_name) {
+            if line_rules.contains("*") || line_rules.contains(&rule_name.to_ascii_uppercase()) {
                 return true;
             }
         }
 
         // Check persistent disables at this line
-        if let Some(disabled_set) = self.disabled_at_line.get(&line_number) {
-            disabled_set.contains("*") || disabled_set.contains(rule_name)
-        } else {
-            false
-        }
+        self.disabled_at_line
+            .get(&line_number)
+            .is_some_and(|disabled| disabled.contains(rule_name))
     }
 
-    /// Get all disabled rules at a specific line
+    /// Get all disabled rules at a specific line, in uppercase. `*` stands for all rules;
+    /// rules re-enabled after a disable of all rules are not listed.
     pub fn get_disabled_rules(&self, line_number: usize) -> HashSet<String> {
         let mut disabled = HashSet::new();
 
         // Add persistent disables
-        if let Some(disabled_set) = self.disabled_at_line.get(&line_number) {
-            for rule in disabled_set {
-                disabled.insert(rule.clone());
+        if let Some(disabled_rules) = self.disabled_at_line.get(&line_number) {
+            if disabled_rules.all {
+                disabled.insert("*".to_string());
+            } else {
+                disabled.extend(disabled_rules.rules.iter().cloned());
             }
         }
 
@@ -165,6 +245,65 @@ impl InlineConfig {
     }
 }
 
+/// Line ranges between `<!-- begin:managed -->` and `<!-- end:managed -->` comments, used by
+/// the global `managed-only` and `unmanaged-only` settings to limit rules to one side of
+/// them. The marker lines themselves are outside every region; a region left open runs to
+/// the end of the document.
+#[derive(Debug, Clone, Default)]
+pub struct ManagedRegions {
+    /// Inclusive 1-indexed (first, last) line of each region
+    ranges: Vec<(usize, usize)>,
+}
+
+impl ManagedRegions {
+    /// Find the managed regions of `content`
+    pub fn from_content(content: &str) -> Self {
+        let mut ranges = Vec::new();
+        let mut open: Option<usize> = None;
+        let mut line_count = 0;
+        for (idx, line) in content.lines().enumerate() {
+            let line_num = idx + 1;
+            line_count = line_num;
+            match (managed_marker(line), open) {
+                (Some("begin:managed"), None) => open = Some(line_num + 1),
+                (Some("end:managed"), Some(first)) => {
+                    if first < line_num {
+                        ranges.push((first, line_num - 1));
+                    }
+                    open = None;
+                }
+                _ => {}
+            }
+        }
+        if let Some(first) = open
+            && first <= line_count
+        {
+            ranges.push((first, line_count));
+        }
+        Self { ranges }
+    }
+
+    /// Whether `content` has no managed regions
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Whether the 1-indexed `line_number` is inside a managed region
+    pub fn contains(&self, line_number: usize) -> bool {
+        self.ranges
+            .iter()
+            .any(|&(first, last)| (first..=last).contains(&line_number))
+    }
+}
+
+// The text of a line holding nothing but an HTML comment, such as "begin:managed"
+fn managed_marker(line: &str) -> Option<&str> {
+    line.trim()
+        .strip_prefix("<!--")?
+        .strip_suffix("-->")
+        .map(str::trim)
+}
+
 /// Parse a disable comment and return the list of rules (empty vec means all rules)
 pub fn parse_disable_comment(line: &str) -> Option<Vec<&str>> {
     // Check for both rumdl-disable and markdownlint-disable
@@ -293,6 +432,31 @@ pub fn parse_disable_next_line_comment(line: &str) -> Option<Vec<&str>> {
     None
 }
 
+// Whether `line` holds nothing but an inline configuration comment: a disable, enable,
+// capture or restore comment
+fn is_config_comment_line(line: &str) -> bool {
+    let Some(text) = managed_marker(line) else {
+        return false;
+    };
+    ["rumdl-", "markdownlint-"]
+        .iter()
+        .any(|prefix| {
+            text.strip_prefix(prefix)
+                .and_then(|directive| directive.split_whitespace().next())
+                .is_some_and(|directive| {
+                    matches!(
+                        directive,
+                        "disable"
+                            | "enable"
+                            | "disable-line"
+                            | "disable-next-line"
+                            | "capture"
+                            | "restore"
+                    )
+                })
+        })
+}
+
 /// Check if line contains a capture comment
 pub fn is_capture_comment(line: &str) -> bool {
     line.contains("<!-- markdownlint-capture -->") || line.contains("<!-- rumdl-capture -->")
@@ -407,4 +571,40 @@ Some content after restore
         assert!(!config.is_rule_disabled("MD002", 5));
         assert!(!config.is_rule_disabled("MD003", 5));
     }
+
+    #[test]
+    fn test_overlapping_disable_enable_pairs() {
+        let content = r#"<!-- rumdl-disable MD044 -->
+javascript
+<!-- rumdl-disable -->
+javascript and __bold__
+<!-- rumdl-enable md050 -->
+javascript and __bold__
+<!-- rumdl-enable MD044 -->
+javascript and __bold__
+<!-- rumdl-enable -->
+javascript and __bold__ <!-- rumdl-disable-line MD044 -->
+"#;
+
+        let config = InlineConfig::from_content(content);
+        let disabled = |line| {
+            ["MD044", "MD050", "MD013"]
+                .into_iter()
+                .filter(|rule| config.is_rule_disabled(rule, line))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(disabled(2), vec!["MD044"]);
+        // A disable with no rule names means all rules
+        assert_eq!(disabled(4), vec!["MD044", "MD050", "MD013"]);
+        // Enabling one rule carves it out of the disable of all rules
+        assert_eq!(disabled(6), vec!["MD044", "MD013"]);
+        assert_eq!(disabled(8), vec!["MD013"]);
+        assert_eq!(disabled(10), vec!["MD044"]);
+
+        // The comment lines themselves are never reported
+        for line in [1, 3, 5, 7, 9] {
+            assert_eq!(disabled(line), vec!["MD044", "MD050", "MD013"]);
+        }
+    }
 }
@@ -1,28 +1,43 @@
+pub mod archive;
+pub mod cache;
 pub mod config;
+pub mod file_discovery;
+pub mod git;
 pub mod init;
 pub mod inline_config;
 pub mod lint_context;
 pub mod lsp;
 pub mod markdownlint_config;
+pub mod notebook;
 pub mod parallel;
 pub mod performance;
 pub mod profiling;
 pub mod rule;
+pub mod sarif;
 pub mod vscode;
+pub mod webhook;
 #[macro_use]
 pub mod rule_config;
 #[macro_use]
 pub mod rule_config_serde;
 pub mod rules;
+pub mod selftest;
 pub mod utils;
 
 pub use rules::heading_utils::{Heading, HeadingStyle};
 pub use rules::*;
 
 pub use crate::lint_context::{BareUrl, LineInfo, LintContext, ListItemInfo};
-use crate::rule::{LintResult, Rule, RuleCategory};
+use crate::rule::{
+    Fix, FixSafety, LintError, LintResult, LintWarning, MarkdownAst, Rule, RuleCategory, Severity,
+    SkippedFix,
+};
 use crate::utils::document_structure::DocumentStructure;
-use std::time::Instant;
+use std::collections::HashMap;
+use std::ops::Range;
+use std::sync::Arc;
+use std::sync::mpsc;
+use std::time::{Duration, Instant};
 
 /// Content characteristics for efficient rule filtering
 #[derive(Debug, Default)]
@@ -129,10 +144,200 @@ impl ContentCharacteristics {
     }
 }
 
+/// Run a single rule, trying the optimized paths in order of preference
+fn run_rule(
+    rule: &dyn Rule,
+    lint_ctx: &LintContext,
+    ast: Option<&MarkdownAst>,
+    structure: &DocumentStructure,
+) -> LintResult {
+    if rule.uses_ast() {
+        if let Some(ast_ref) = ast {
+            // 1. AST-based path
+            rule.as_maybe_ast()
+                .and_then(|ext| ext.check_with_ast_opt(lint_ctx, ast_ref))
+                .unwrap_or_else(|| rule.check_with_ast(lint_ctx, ast_ref))
+        } else {
+            // Fallback to regular check if no AST
+            rule.as_maybe_document_structure()
+                .and_then(|ext| ext.check_with_structure_opt(lint_ctx, structure))
+                .unwrap_or_else(|| rule.check(lint_ctx))
+        }
+    } else {
+        // 2. Document structure path
+        rule.as_maybe_document_structure()
+            .and_then(|ext| ext.check_with_structure_opt(lint_ctx, structure))
+            .unwrap_or_else(|| rule.check(lint_ctx))
+    }
+}
+
+/// Computes the stable [`LintWarning::id`]s of the warnings in one document
+struct WarningIds<'a> {
+    content: &'a str,
+    line_starts: Vec<usize>,
+}
+
+impl<'a> WarningIds<'a> {
+    /// Bytes of context hashed on each side of the flagged text, within its own lines
+    const WINDOW: usize = 16;
+
+    fn new(content: &'a str) -> Self {
+        let line_starts = std::iter::once(0)
+            .chain(
+                content
+                    .match_indices('\n')
+                    .map(|(i, _)| i + 1),
+            )
+            .collect();
+        Self {
+            content,
+            line_starts,
+        }
+    }
+
+    /// Byte range of a 1-indexed line, without its line ending
+    fn line_range(&self, line: usize) -> std::ops::Range<usize> {
+        let start = self
+            .line_starts
+            .get(line.saturating_sub(1))
+            .copied()
+            .unwrap_or(self.content.len());
+        let end = self
+            .line_starts
+            .get(line)
+            .map_or(self.content.len(), |next| next - 1);
+        start..end.max(start)
+    }
+
+    /// Byte offset of a 1-indexed line and character column, clamped to the line
+    fn offset(&self, line: usize, column: usize) -> usize {
+        let range = self.line_range(line);
+        self.content[range.clone()]
+            .char_indices()
+            .nth(column.saturating_sub(1))
+            .map_or(range.end, |(i, _)| range.start + i)
+    }
+
+    /// Set the id of each of one rule's warnings from the rule name, the flagged text and
+    /// up to [`Self::WINDOW`] bytes on either side of it. Warnings with the same
+    /// fingerprint are told apart by their order in the document.
+    fn assign(&self, rule_name: &str, warnings: &mut [LintWarning]) {
+        let mut order: Vec<usize> = (0..warnings.len()).collect();
+        order.sort_by(|&a, &b| warnings[a].canonical_cmp(&warnings[b]));
+        let mut seen: HashMap<u64, u64> = HashMap::new();
+        for index in order {
+            let warning = &mut warnings[index];
+            let start = self.offset(warning.line, warning.column);
+            let end = self
+                .offset(warning.end_line, warning.end_column)
+                .max(start);
+            let before = self.content.floor_char_boundary(
+                start
+                    .saturating_sub(Self::WINDOW)
+                    .max(self.line_range(warning.line).start),
+            );
+            let after = self.content.ceil_char_boundary(
+                (end + Self::WINDOW).min(
+                    self.line_range(warning.end_line)
+                        .end
+                        .max(end),
+                ),
+            );
+            let mut key = Vec::with_capacity(rule_name.len() + after - before + 3);
+            for part in [
+                rule_name,
+                &self.content[before..start],
+                &self.content[start..end],
+                &self.content[end..after],
+            ] {
+                key.extend_from_slice(part.as_bytes());
+                key.push(0);
+            }
+            let fingerprint = seahash::hash(&key);
+            let occurrence = seen.entry(fingerprint).or_default();
+            key.extend_from_slice(&occurrence.to_le_bytes());
+            *occurrence += 1;
+            warning.id = Some(format!("{}-{:08x}", rule_name, seahash::hash(&key) as u32));
+        }
+    }
+}
+
+/// Add a rule's warnings to `out` with their ids set, dropping those in regions where the
+/// rule or the warning's id is disabled via inline comments, or keeping them marked as
+/// suppressed if `include_suppressed` is set
+fn push_rule_warnings(
+    out: &mut Vec<LintWarning>,
+    inline_config: &crate::inline_config::InlineConfig,
+    ids: &WarningIds,
+    rule_name: &str,
+    mut rule_warnings: Vec<LintWarning>,
+    include_suppressed: bool,
+) {
+    ids.assign(rule_name, &mut rule_warnings);
+    for mut warning in rule_warnings {
+        // warning.line is already 1-indexed
+        if inline_config.is_rule_disabled(rule_name, warning.line)
+            || warning
+                .id
+                .as_deref()
+                .is_some_and(|id| inline_config.is_rule_disabled(id, warning.line))
+        {
+            if !include_suppressed {
+                continue;
+            }
+            mark_suppressed(std::slice::from_mut(&mut warning));
+        }
+        out.push(warning);
+    }
+}
+
+/// Sort warnings into the canonical output order given by [`LintWarning::canonical_cmp`],
+/// so the order never depends on which rule ran first.
+pub fn sort_warnings(warnings: &mut [LintWarning]) {
+    warnings.sort_by(LintWarning::canonical_cmp);
+}
+
+/// Mark warnings as suppressed: reported at `Info` severity for audit purposes only.
+pub fn mark_suppressed(warnings: &mut [LintWarning]) {
+    for warning in warnings {
+        warning.severity = Severity::Info;
+        warning.suppressed = true;
+    }
+}
+
 /// Lint a file against the given rules with intelligent rule filtering
 /// Assumes the provided `rules` vector contains the final,
 /// configured, and filtered set of rules to be executed.
-pub fn lint(content: &str, rules: &[Box<dyn Rule>], _verbose: bool) -> LintResult {
+pub fn lint(content: &str, rules: &[Box<dyn Rule>], verbose: bool) -> LintResult {
+    lint_impl(content, rules, verbose, false, None, None)
+}
+
+/// Lint like [`lint`], but only report the warnings overlapping or touching the byte
+/// `range`, such as the region an editor just changed. Warnings have the same positions as
+/// in a full lint. Rules whose warnings depend on the whole document
+/// ([`Rule::is_document_wide`]) still report all of them.
+pub fn lint_range(content: &str, rules: &[Box<dyn Rule>], range: Range<usize>) -> LintResult {
+    lint_impl(content, rules, false, false, Some(range), None)
+}
+
+/// Lint like [`lint`], but keep warnings in regions disabled via inline comments,
+/// marked as suppressed (see [`mark_suppressed`]) instead of dropping them.
+pub fn lint_including_suppressed(
+    content: &str,
+    rules: &[Box<dyn Rule>],
+    verbose: bool,
+) -> LintResult {
+    lint_impl(content, rules, verbose, true, None, None)
+}
+
+fn lint_impl(
+    content: &str,
+    rules: &[Box<dyn Rule>],
+    _verbose: bool,
+    include_suppressed: bool,
+    range: Option<Range<usize>>,
+    mut timed: Option<&mut TimedRules>,
+) -> LintResult {
     let mut warnings = Vec::new();
     let _overall_start = Instant::now();
 
@@ -143,6 +348,7 @@ pub fn lint(content: &str, rules: &[Box<dyn Rule>], _verbose: bool) -> LintResul
 
     // Parse inline configuration comments once
     let inline_config = crate::inline_config::InlineConfig::from_content(content);
+    let ids = WarningIds::new(content);
 
     // Analyze content characteristics for rule filtering
     let characteristics = ContentCharacteristics::analyze(content);
@@ -157,59 +363,47 @@ pub fn lint(content: &str, rules: &[Box<dyn Rule>], _verbose: bool) -> LintResul
     let _total_rules = rules.len();
     let _applicable_count = applicable_rules.len();
 
-    // Parse DocumentStructure once
-    let structure = DocumentStructure::new(content);
+    // Parse DocumentStructure once, unless the rules run on a timed worker with its own
+    let structure = std::cell::LazyCell::new(|| DocumentStructure::new(content));
 
     // Parse AST once for rules that can benefit from it
     let ast_rules_count = applicable_rules
         .iter()
         .filter(|rule| rule.uses_ast())
         .count();
-    let ast = if ast_rules_count > 0 {
+    let ast = if ast_rules_count > 0 && timed.is_none() {
         Some(crate::utils::ast_utils::get_cached_ast(content))
     } else {
         None
     };
 
     // Parse LintContext once (migration step)
-    let lint_ctx = crate::lint_context::LintContext::new(content);
+    let lint_ctx = std::cell::LazyCell::new(|| crate::lint_context::LintContext::new(content));
 
     for rule in applicable_rules {
         let _rule_start = Instant::now();
 
-        // Try optimized paths in order of preference
-        let result = if rule.uses_ast() {
-            if let Some(ref ast_ref) = ast {
-                // 1. AST-based path
-                rule.as_maybe_ast()
-                    .and_then(|ext| ext.check_with_ast_opt(&lint_ctx, ast_ref))
-                    .unwrap_or_else(|| rule.check_with_ast(&lint_ctx, ast_ref))
-            } else {
-                // Fallback to regular check if no AST
-                rule.as_maybe_document_structure()
-                    .and_then(|ext| ext.check_with_structure_opt(&lint_ctx, &structure))
-                    .unwrap_or_else(|| rule.check(&lint_ctx))
+        let result = match (&range, timed.as_deref_mut()) {
+            (Some(range), _) if !rule.is_document_wide() => {
+                rule.check_range(&lint_ctx, range.clone())
             }
-        } else {
-            // 2. Document structure path
-            rule.as_maybe_document_structure()
-                .and_then(|ext| ext.check_with_structure_opt(&lint_ctx, &structure))
-                .unwrap_or_else(|| rule.check(&lint_ctx))
+            (_, Some(timed)) => match timed.run(rule.as_ref()) {
+                Some(result) => result,
+                None => continue,
+            },
+            _ => run_rule(rule.as_ref(), &lint_ctx, ast.as_deref(), &structure),
         };
 
         match result {
             Ok(rule_warnings) => {
-                // Filter out warnings for rules disabled via inline comments
-                let filtered_warnings: Vec<_> = rule_warnings
-                    .into_iter()
-                    .filter(|warning| {
-                        !inline_config.is_rule_disabled(
-                            rule.name(),
-                            warning.line, // Already 1-indexed
-                        )
-                    })
-                    .collect();
-                warnings.extend(filtered_warnings);
+                push_rule_warnings(
+                    &mut warnings,
+                    &inline_config,
+                    &ids,
+                    rule.name(),
+                    rule_warnings,
+                    include_suppressed,
+                );
             }
             Err(e) => {
                 log::error!("Error checking rule {}: {}", rule.name(), e);
@@ -241,9 +435,929 @@ pub fn lint(content: &str, rules: &[Box<dyn Rule>], _verbose: bool) -> LintResul
         }
     }
 
+    sort_warnings(&mut warnings);
     Ok(warnings)
 }
 
+/// The number of warnings [`lint`] reports for `content`. Rules count with
+/// [`Rule::check_count`], so those that can count their violations cheaply need not build
+/// the warnings; rules with an AST or document structure path are counted from it, as
+/// [`lint`] runs them. Content with inline configuration comments is linted in full, since
+/// the warnings they disable depend on where each warning is.
+pub fn lint_count(content: &str, rules: &[Box<dyn Rule>]) -> Result<usize, LintError> {
+    if content.is_empty() {
+        return Ok(0);
+    }
+    if !crate::inline_config::InlineConfig::from_content(content).is_empty() {
+        return Ok(lint(content, rules, false)?.len());
+    }
+
+    let characteristics = ContentCharacteristics::analyze(content);
+    let applicable_rules: Vec<_> = rules
+        .iter()
+        .filter(|rule| !characteristics.should_skip_rule(rule.as_ref()))
+        .collect();
+    let structured = |rule: &dyn Rule| {
+        rule.uses_ast()
+            || rule
+                .as_maybe_document_structure()
+                .is_some()
+    };
+    let structure = applicable_rules
+        .iter()
+        .any(|rule| structured(rule.as_ref()))
+        .then(|| DocumentStructure::new(content));
+    let ast = applicable_rules
+        .iter()
+        .any(|rule| rule.uses_ast())
+        .then(|| crate::utils::ast_utils::get_cached_ast(content));
+    let lint_ctx = crate::lint_context::LintContext::new(content);
+
+    let mut count = 0;
+    for rule in applicable_rules {
+        count += match &structure {
+            Some(structure) if structured(rule.as_ref()) => {
+                run_rule(rule.as_ref(), &lint_ctx, ast.as_deref(), structure)?.len()
+            }
+            _ => rule.check_count(&lint_ctx)?,
+        };
+    }
+    Ok(count)
+}
+
+/// The most workers left running by rules that timed out, across all calls of
+/// [`lint_with_timeout`]. Once reached, no new worker is started until one of them finishes,
+/// and the rules that would need one are reported as timed out without running.
+pub const MAX_ABANDONED_WORKERS: usize = 4;
+
+/// Workers currently running a rule that timed out.
+static ABANDONED_WORKERS: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
+
+/// A worker thread running rules on its own copy of the content.
+struct TimedWorker {
+    jobs: mpsc::Sender<Box<dyn Rule>>,
+    results: mpsc::Receiver<LintResult>,
+    abandoned: Arc<std::sync::atomic::AtomicBool>,
+}
+
+impl TimedWorker {
+    fn spawn(content: Arc<str>) -> Option<Self> {
+        let (jobs, job_rx) = mpsc::channel::<Box<dyn Rule>>();
+        let (result_tx, results) = mpsc::channel();
+        let (ready_tx, ready_rx) = mpsc::channel();
+        let abandoned = Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let worker_abandoned = Arc::clone(&abandoned);
+        std::thread::spawn(move || {
+            let lint_ctx = LintContext::new(&content);
+            let structure = DocumentStructure::new(&content);
+            let mut ast = None;
+            if ready_tx.send(()).is_ok() {
+                for rule in job_rx {
+                    if rule.uses_ast() && ast.is_none() {
+                        ast = Some(crate::utils::ast_utils::get_cached_ast(&content));
+                    }
+                    let result = run_rule(rule.as_ref(), &lint_ctx, ast.as_deref(), &structure);
+                    if result_tx.send(result).is_err() {
+                        break;
+                    }
+                }
+            }
+            if worker_abandoned.load(std::sync::atomic::Ordering::SeqCst) {
+                ABANDONED_WORKERS.fetch_sub(1, std::sync::atomic::Ordering::SeqCst);
+            }
+        });
+        // The timeout covers the rules only, not parsing the document
+        ready_rx.recv().ok()?;
+        Some(Self {
+            jobs,
+            results,
+            abandoned,
+        })
+    }
+
+    /// Leave the worker to finish its rule in the background, counted in
+    /// [`ABANDONED_WORKERS`] until it does.
+    fn abandon(self) {
+        ABANDONED_WORKERS.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        self.abandoned
+            .store(true, std::sync::atomic::Ordering::SeqCst);
+    }
+}
+
+/// Runs the rules of [`lint_with_timeout`] for `lint_impl`, each on a worker thread given
+/// `timeout` to finish it.
+struct TimedRules {
+    content: Arc<str>,
+    timeout: Duration,
+    worker: Option<TimedWorker>,
+    timed_out: Vec<&'static str>,
+}
+
+impl TimedRules {
+    /// The rule's result, or `None` when it timed out or panicked and is skipped.
+    fn run(&mut self, rule: &(dyn Rule + 'static)) -> Option<LintResult> {
+        if self.worker.is_none() {
+            if ABANDONED_WORKERS.load(std::sync::atomic::Ordering::SeqCst) >= MAX_ABANDONED_WORKERS
+            {
+                log::warn!(
+                    "Rule {} skipped: {} timed out rules are still running",
+                    rule.name(),
+                    MAX_ABANDONED_WORKERS
+                );
+                self.timed_out.push(rule.name());
+                return None;
+            }
+            match TimedWorker::spawn(Arc::clone(&self.content)) {
+                Some(worker) => self.worker = Some(worker),
+                None => {
+                    return Some(Err(LintError::ParsingError(
+                        "Lint worker failed while parsing the document".to_string(),
+                    )));
+                }
+            }
+        }
+        let worker = self.worker.as_ref()?;
+
+        // A rule that times out keeps running, so it must share no cache with `rule`
+        let copy = rule
+            .uncached_copy()
+            .unwrap_or_else(|| dyn_clone::clone_box(rule));
+        let received = match worker.jobs.send(copy) {
+            Ok(()) => worker
+                .results
+                .recv_timeout(self.timeout),
+            Err(_) => Err(mpsc::RecvTimeoutError::Disconnected),
+        };
+        match received {
+            Ok(result) => Some(result),
+            Err(mpsc::RecvTimeoutError::Timeout) => {
+                log::warn!(
+                    "Rule {} exceeded the {:?} timeout",
+                    rule.name(),
+                    self.timeout
+                );
+                self.timed_out.push(rule.name());
+                self.worker.take()?.abandon();
+                None
+            }
+            Err(mpsc::RecvTimeoutError::Disconnected) => {
+                log::error!("Rule {} panicked", rule.name());
+                self.worker = None;
+                None
+            }
+        }
+    }
+}
+
+/// Lint a file like [`lint`], but give each rule at most `timeout` to finish.
+///
+/// Rules run on a worker thread that owns its own copy of the content and of each rule,
+/// made with [`Rule::uncached_copy`], so a rule that overruns cannot leave shared state
+/// half-updated. It is abandoned, its results are discarded, and the remaining rules
+/// continue on a fresh worker. Threads cannot be cancelled, so the abandoned worker runs
+/// until the rule returns; at most [`MAX_ABANDONED_WORKERS`] are left running at a time.
+/// Returns the warnings together with the names of the rules that timed out.
+/// Inline-suppressed warnings are kept and marked when `include_suppressed` is set.
+pub fn lint_with_timeout(
+    content: &str,
+    rules: &[Box<dyn Rule>],
+    timeout: Duration,
+    include_suppressed: bool,
+) -> Result<(Vec<LintWarning>, Vec<&'static str>), LintError> {
+    let mut timed = TimedRules {
+        content: Arc::from(content),
+        timeout,
+        worker: None,
+        timed_out: Vec::new(),
+    };
+    let warnings = lint_impl(
+        content,
+        rules,
+        false,
+        include_suppressed,
+        None,
+        Some(&mut timed),
+    )?;
+    Ok((warnings, timed.timed_out))
+}
+
+/// Report the warnings of each rule in `overrides` at its configured severity, as set with
+/// the rule's `severity` key (see [`config::severity_overrides`]).
+///
+/// Applied after `lint` returns and before [`apply_strict`]. Suppressed warnings keep their
+/// `Info` severity.
+pub fn apply_severity_overrides(
+    warnings: &mut [LintWarning],
+    overrides: &HashMap<String, Severity>,
+) {
+    for warning in warnings
+        .iter_mut()
+        .filter(|w| !w.suppressed)
+    {
+        if let Some(severity) = warning
+            .rule_name
+            .and_then(|name| overrides.get(name))
+        {
+            warning.severity = *severity;
+        }
+    }
+}
+
+/// Upgrade every `Warning`-severity result to `Error`, as done by `--strict`.
+///
+/// Applied after `lint` returns, so it takes precedence over the severity a rule reports.
+/// Suppressed warnings keep their `Info` severity.
+pub fn apply_strict(warnings: &mut [LintWarning]) {
+    for warning in warnings
+        .iter_mut()
+        .filter(|w| !w.suppressed)
+    {
+        warning.severity = Severity::Error;
+    }
+}
+
+/// Whether `warning` is printed despite `--quiet`, because its rule is listed in the
+/// global `always-report` setting. Suppressed warnings never are.
+pub fn is_always_reported(warning: &LintWarning, always_report: &[String]) -> bool {
+    !warning.suppressed
+        && warning.rule_name.is_some_and(|name| {
+            always_report
+                .iter()
+                .any(|rule| rule.eq_ignore_ascii_case(name))
+        })
+}
+
+// Whether `rule_name` is listed in one of the global rule lists
+fn rule_listed(rules: &[String], rule_name: &str) -> bool {
+    rules
+        .iter()
+        .any(|rule| rule.eq_ignore_ascii_case(rule_name))
+}
+
+/// Whether `rule_name` is limited to one side of managed regions by the global
+/// `managed-only` or `unmanaged-only` setting
+pub fn is_region_limited(rule_name: &str, global: &config::GlobalConfig) -> bool {
+    rule_listed(&global.managed_only, rule_name) || rule_listed(&global.unmanaged_only, rule_name)
+}
+
+/// Drop the warnings that fall on the wrong side of the managed regions of `content` (see
+/// [`inline_config::ManagedRegions`]): those of `managed-only` rules outside every region,
+/// and those of `unmanaged-only` rules inside one. A warning belongs to the line it starts on.
+pub fn filter_managed_regions(
+    content: &str,
+    warnings: Vec<LintWarning>,
+    global: &config::GlobalConfig,
+) -> Vec<LintWarning> {
+    if global.managed_only.is_empty() && global.unmanaged_only.is_empty() {
+        return warnings;
+    }
+    let regions = inline_config::ManagedRegions::from_content(content);
+    warnings
+        .into_iter()
+        .filter(|warning| {
+            let Some(rule_name) = warning.rule_name else {
+                return true;
+            };
+            let inside = regions.contains(warning.line);
+            !(rule_listed(&global.managed_only, rule_name) && !inside
+                || rule_listed(&global.unmanaged_only, rule_name) && inside)
+        })
+        .collect()
+}
+
+/// Add each rule's warnings among `warnings` to `counts`, as shown by `--statistics`.
+/// Suppressed warnings are left out.
+pub fn count_warnings_by_rule(
+    warnings: &[LintWarning],
+    counts: &mut std::collections::BTreeMap<&'static str, usize>,
+) {
+    for rule_name in warnings
+        .iter()
+        .filter(|w| !w.suppressed)
+        .filter_map(|w| w.rule_name)
+    {
+        *counts.entry(rule_name).or_default() += 1;
+    }
+}
+
+/// The problems found in a run, as summarized at its end: how many, how many have a fix
+/// that `--fix` would apply, the files they are in and how many each rule reported
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RunSummary {
+    pub problems: usize,
+    pub fixable: usize,
+    /// Files with at least one problem
+    pub files: usize,
+    pub by_rule: std::collections::BTreeMap<&'static str, usize>,
+}
+
+impl RunSummary {
+    /// Add the warnings found in one file. Suppressed warnings are left out, and unsafe
+    /// fixes only count as fixable with `unsafe_fixes`.
+    pub fn add_file(&mut self, warnings: &[LintWarning], unsafe_fixes: bool) {
+        let problems: Vec<&LintWarning> = warnings
+            .iter()
+            .filter(|w| !w.suppressed)
+            .collect();
+        if problems.is_empty() {
+            return;
+        }
+        self.files += 1;
+        self.problems += problems.len();
+        self.fixable += problems
+            .iter()
+            .filter(|w| {
+                w.fix
+                    .as_ref()
+                    .is_some_and(|fix| fix.is_applicable(unsafe_fixes))
+            })
+            .count();
+        count_warnings_by_rule(warnings, &mut self.by_rule);
+    }
+}
+
+impl std::fmt::Display for RunSummary {
+    /// `3 problems (2 fixable) across 2 files`, then each rule's count on the next line,
+    /// most first: `MD044: 2, MD050: 1`
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let plural = |count: usize, word: &str| {
+            if count == 1 {
+                format!("{} {}", count, word)
+            } else {
+                format!("{} {}s", count, word)
+            }
+        };
+        write!(
+            f,
+            "{} ({} fixable) across {}",
+            plural(self.problems, "problem"),
+            self.fixable,
+            plural(self.files, "file")
+        )?;
+        let mut by_rule: Vec<(&str, usize)> = self
+            .by_rule
+            .iter()
+            .map(|(rule_name, count)| (*rule_name, *count))
+            .collect();
+        by_rule.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+        if !by_rule.is_empty() {
+            let counts: Vec<String> = by_rule
+                .iter()
+                .map(|(rule_name, count)| format!("{}: {}", rule_name, count))
+                .collect();
+            write!(f, "\n{}", counts.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+/// A warning as text output lists it, standing with `--dedupe` for the later warnings of
+/// its file with the same rule and message
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShownWarning<'a> {
+    pub warning: &'a LintWarning,
+    /// Later warnings folded into this one
+    pub more: usize,
+}
+
+impl ShownWarning<'_> {
+    /// ` (and 4 more)` after a warning standing for others, otherwise empty
+    pub fn more_suffix(&self) -> String {
+        if self.more == 0 {
+            String::new()
+        } else {
+            format!(" (and {} more)", self.more)
+        }
+    }
+}
+
+/// The warnings of a file listed together in text output: with `--group-by-rule` those of
+/// one rule, otherwise all of them
+#[derive(Debug, Clone, PartialEq)]
+pub struct WarningGroup<'a> {
+    /// The rule of the group's warnings, `None` when not grouping by rule
+    pub rule_name: Option<&'static str>,
+    pub shown: Vec<ShownWarning<'a>>,
+}
+
+impl WarningGroup<'_> {
+    /// Number of warnings in the group, including those folded by `--dedupe`
+    pub fn len(&self) -> usize {
+        self.shown
+            .iter()
+            .map(|shown| shown.more + 1)
+            .sum()
+    }
+
+    /// Whether the group has no warnings
+    pub fn is_empty(&self) -> bool {
+        self.shown.is_empty()
+    }
+}
+
+/// Lay out the `warnings` of one file for text output. With `group_by_rule` they are split
+/// into one group per rule, by rule id; otherwise they form a single group. With `dedupe`
+/// a warning whose rule and message were already shown is folded into the first one, so
+/// a name misspelled throughout a file is listed once. Suppressed warnings are only folded
+/// into suppressed ones. Warnings keep their order within a group.
+pub fn group_warnings<'a>(
+    warnings: impl IntoIterator<Item = &'a LintWarning>,
+    group_by_rule: bool,
+    dedupe: bool,
+) -> Vec<WarningGroup<'a>> {
+    let mut shown: Vec<ShownWarning<'a>> = Vec::new();
+    let mut first_of: std::collections::HashMap<(Option<&str>, &str, bool), usize> =
+        std::collections::HashMap::new();
+    for warning in warnings {
+        if dedupe {
+            let key = (
+                warning.rule_name,
+                warning.message.as_str(),
+                warning.suppressed,
+            );
+            if let Some(&index) = first_of.get(&key) {
+                shown[index].more += 1;
+                continue;
+            }
+            first_of.insert(key, shown.len());
+        }
+        shown.push(ShownWarning { warning, more: 0 });
+    }
+
+    if !group_by_rule {
+        return vec![WarningGroup {
+            rule_name: None,
+            shown,
+        }];
+    }
+    let mut by_rule: std::collections::BTreeMap<&'static str, Vec<ShownWarning<'a>>> =
+        std::collections::BTreeMap::new();
+    for entry in shown {
+        by_rule
+            .entry(
+                entry
+                    .warning
+                    .rule_name
+                    .unwrap_or("unknown"),
+            )
+            .or_default()
+            .push(entry);
+    }
+    by_rule
+        .into_iter()
+        .map(|(rule_name, shown)| WarningGroup {
+            rule_name: Some(rule_name),
+            shown,
+        })
+        .collect()
+}
+
+/// The distinct ids of the rules that reported `warnings`, sorted, as shown by
+/// `--output rules-fired`. Suppressed warnings are left out.
+pub fn rules_fired(warnings: &[LintWarning]) -> Vec<&'static str> {
+    let mut rules: Vec<&'static str> = warnings
+        .iter()
+        .filter(|w| !w.suppressed)
+        .filter_map(|w| w.rule_name)
+        .collect();
+    rules.sort_unstable();
+    rules.dedup();
+    rules
+}
+
+/// The object describing `warning` in `--output json`: the warning's fields, plus the
+/// `file` it was found in and whether a fix is available as `fixable`
+pub fn warning_json(file: &str, warning: &LintWarning) -> serde_json::Value {
+    let mut json = serde_json::to_value(warning).expect("serializing a warning cannot fail");
+    if let Some(obj) = json.as_object_mut() {
+        obj.insert("file".to_string(), file.into());
+        obj.insert("fixable".to_string(), warning.fix.is_some().into());
+    }
+    json
+}
+
+/// Whether a run with `--max-warnings max` fails, given the number of issues found across
+/// all files and how many of them are errors. Errors, such as every issue under `--strict`,
+/// always fail the run; other issues only once there are more than `max`.
+pub fn exceeds_max_warnings(issues: usize, errors: usize, max: usize) -> bool {
+    errors > 0 || issues > max
+}
+
+/// Collect the fixes each rule would apply to `content`, keyed by rule name.
+///
+/// Runs the same checks as [`lint`] (including inline disable comments) and groups the
+/// fixes attached to the resulting warnings. Rules without any fix are omitted.
+/// Fixes within each rule are sorted in document order.
+pub fn fixes_by_rule(
+    content: &str,
+    rules: &[Box<dyn Rule>],
+) -> Result<HashMap<&'static str, Vec<Fix>>, LintError> {
+    let mut fixes: HashMap<&'static str, Vec<Fix>> = HashMap::new();
+
+    for warning in lint(content, rules, false)? {
+        if let (Some(rule_name), Some(fix)) = (warning.rule_name, warning.fix) {
+            fixes
+                .entry(rule_name)
+                .or_default()
+                .push(fix);
+        }
+    }
+
+    for rule_fixes in fixes.values_mut() {
+        rule_fixes.sort_by_key(|fix| (fix.range.start, fix.range.end));
+    }
+
+    Ok(fixes)
+}
+
+/// Most passes [`apply_rule_fixes`] makes over a document. Each pass applies the changes
+/// that do not overlap one another and defers the rest to the next, so this bounds how
+/// long a chain of conflicting fixes can take to settle.
+pub const MAX_FIX_PASSES: usize = 10;
+
+/// Apply the fixes of every rule that reported warnings outside inline-disabled regions.
+///
+/// Every rule fixes the same content, and the lines each one changes are collected and
+/// applied together, from the end of the content to the start. A change overlapping
+/// another rule's change, not merely next to it, is deferred: its rule fixes the updated content again in the next
+/// pass, up to [`MAX_FIX_PASSES`] passes. Which of two overlapping changes goes first
+/// depends on the changes themselves, not on the order of `rules`.
+///
+/// Unsafe fixes are only applied with `unsafe_fixes`; a rule reporting some is limited to
+/// the fixes attached to its other warnings. Returns the number of warnings fixed: those
+/// of the rules that changed the content which their checks no longer report, told apart
+/// by [`LintWarning::id`]. Fixes that were attempted but not applied, including those of a rule whose fix
+/// failed and those still conflicting after the last pass, are appended to `skipped`.
+pub fn apply_rule_fixes(
+    content: &mut String,
+    rules: &[Box<dyn Rule>],
+    warnings: &[LintWarning],
+    unsafe_fixes: bool,
+    skipped: &mut Vec<SkippedFix>,
+) -> usize {
+    let held_back = |w: &LintWarning| {
+        w.fix
+            .as_ref()
+            .is_some_and(|fix| !fix.is_applicable(unsafe_fixes))
+    };
+    let original = content.clone();
+    // The warnings of each rule in non-disabled regions
+    let inline_config = crate::inline_config::InlineConfig::from_content(content);
+    let rule_warnings: Vec<(&dyn Rule, Vec<&LintWarning>)> = rules
+        .iter()
+        .map(|rule| {
+            let active = warnings
+                .iter()
+                .filter(|w| {
+                    w.rule_name == Some(rule.name())
+                        && !w.suppressed
+                        && !inline_config.is_rule_disabled(rule.name(), w.line.max(1))
+                })
+                .collect();
+            (rule.as_ref(), active)
+        })
+        .filter(|(_, active): &(_, Vec<_>)| !active.is_empty())
+        .collect();
+
+    let mut pending: Vec<&(&dyn Rule, Vec<&LintWarning>)> = rule_warnings.iter().collect();
+    let mut changed: Vec<&str> = Vec::new();
+    for pass in 0..MAX_FIX_PASSES {
+        if pending.is_empty() {
+            break;
+        }
+        // Rules fixing the content again only report what they skip the first time
+        let mut repeated = Vec::new();
+        let report = if pass == 0 {
+            &mut *skipped
+        } else {
+            &mut repeated
+        };
+
+        // The lines each rule changes
+        let pass_inline_config = crate::inline_config::InlineConfig::from_content(content);
+        let mut changes = Vec::new();
+        for entry in pending {
+            let (rule, active) = entry;
+            let fixed = if active.iter().any(|w| held_back(w)) {
+                let ctx = LintContext::new(content);
+                let applicable: Vec<LintWarning> = rule
+                    .check(&ctx)
+                    .unwrap_or_default()
+                    .into_iter()
+                    .filter(|w| {
+                        w.fix.is_some()
+                            && !held_back(w)
+                            && !pass_inline_config.is_rule_disabled(rule.name(), w.line.max(1))
+                    })
+                    .collect();
+                if applicable.is_empty() {
+                    continue;
+                }
+                utils::fix_utils::apply_warning_fixes(content, &applicable)
+            } else {
+                rule.fix_with_report(&LintContext::new(content), report)
+                    .map_err(|err| err.to_string())
+            };
+            match fixed {
+                // Only the lines between the first and last change are diffed
+                Ok(fixed) if fixed == *content => changes.push((entry, Vec::new())),
+                Ok(fixed) => changes.push((entry, utils::diff_utils::line_fixes(content, &fixed))),
+                Err(reason) => report.push(SkippedFix {
+                    rule: rule.name().to_string(),
+                    line: active[0].line,
+                    column: active[0].column,
+                    reason,
+                }),
+            }
+        }
+
+        // A rule's changes are applied all together or not at all, as they only make sense
+        // together. Rules are taken by name, each unless one of its changes overlaps a
+        // different change already taken; those left wait for the next pass. Changes of
+        // adjacent lines do not conflict, but an insertion where another change starts
+        // does, as either could go first.
+        let conflict = |a: &Fix, b: &Fix| {
+            a != b
+                && ((a.range.start < b.range.end && b.range.start < a.range.end)
+                    || (a.range.start == b.range.start
+                        && (a.range.is_empty() || b.range.is_empty())))
+        };
+        changes.sort_by_key(|((rule, _), _)| rule.name());
+        let mut taken: Vec<Fix> = Vec::new();
+        pending = Vec::new();
+        for (entry, fixes) in changes {
+            if fixes.iter().any(|fix| {
+                taken
+                    .iter()
+                    .any(|other| conflict(fix, other))
+            }) {
+                pending.push(entry);
+                continue;
+            }
+            if !fixes.is_empty() {
+                changed.push(entry.0.name());
+            }
+            taken.extend(fixes);
+        }
+
+        // The changes taken are applied from the end of the content to the start, each
+        // change made by several rules once
+        taken.sort_by(|a, b| {
+            (b.range.start, b.range.end)
+                .cmp(&(a.range.start, a.range.end))
+                .then_with(|| a.replacement.cmp(&b.replacement))
+        });
+        taken.dedup();
+        for fix in &taken {
+            content.replace_range(fix.range.clone(), &fix.replacement);
+        }
+    }
+    for (rule, active) in pending {
+        skipped.push(SkippedFix {
+            rule: rule.name().to_string(),
+            line: active[0].line,
+            column: active[0].column,
+            reason: format!(
+                "Fix still conflicts with another rule's fix after {} passes",
+                MAX_FIX_PASSES
+            ),
+        });
+    }
+
+    // A warning is fixed once its rule no longer reports it. Ids can change along with the
+    // text next to a warning, so no more are counted than the rule's warnings went down by.
+    let (original_ids, fixed_ids) = (WarningIds::new(&original), WarningIds::new(content));
+    let fixed_inline_config = crate::inline_config::InlineConfig::from_content(content);
+    rule_warnings
+        .iter()
+        .filter(|(rule, _)| changed.contains(&rule.name()))
+        .map(|(rule, active)| {
+            let mut before: Vec<LintWarning> = active
+                .iter()
+                .map(|w| (*w).clone())
+                .collect();
+            if before.iter().any(|w| w.id.is_none()) {
+                original_ids.assign(rule.name(), &mut before);
+            }
+            let mut after = rule
+                .check(&LintContext::new(content))
+                .unwrap_or_default();
+            fixed_ids.assign(rule.name(), &mut after);
+            let gone = before
+                .iter()
+                .filter(|w| {
+                    !after
+                        .iter()
+                        .any(|other| other.id == w.id)
+                })
+                .count();
+            let remaining = after
+                .iter()
+                .filter(|w| !fixed_inline_config.is_rule_disabled(rule.name(), w.line.max(1)))
+                .count();
+            gone.min(before.len().saturating_sub(remaining))
+        })
+        .sum()
+}
+
+/// Apply fixes like [`apply_rule_fixes`], given warnings filtered by
+/// [`filter_managed_regions`]. Rules limited to one side of managed regions only apply the
+/// fixes attached to their remaining warnings, as rewriting the whole document would also
+/// change the content they do not check.
+pub fn apply_fixes_in_regions(
+    content: &mut String,
+    rules: &[Box<dyn Rule>],
+    warnings: &[LintWarning],
+    global: &config::GlobalConfig,
+    unsafe_fixes: bool,
+    skipped: &mut Vec<SkippedFix>,
+) -> usize {
+    let (limited, others): (Vec<&LintWarning>, Vec<&LintWarning>) = warnings
+        .iter()
+        .filter(|w| !w.suppressed)
+        .partition(|w| {
+            w.rule_name
+                .is_some_and(|name| is_region_limited(name, global))
+        });
+    if limited.is_empty() {
+        return apply_rule_fixes(content, rules, warnings, unsafe_fixes, skipped);
+    }
+
+    // The limited rules' fixes go first, while their ranges still match the content
+    let limited: Vec<LintWarning> = limited
+        .into_iter()
+        .filter(|w| {
+            w.fix
+                .as_ref()
+                .is_some_and(|fix| fix.is_applicable(unsafe_fixes))
+        })
+        .cloned()
+        .collect();
+    let mut warnings_fixed = 0;
+    match utils::fix_utils::apply_warning_fixes(content, &limited) {
+        Ok(fixed_content) => {
+            if fixed_content != *content {
+                *content = fixed_content;
+                warnings_fixed += limited.len();
+            }
+        }
+        Err(reason) => {
+            if let Some(first) = limited.first() {
+                skipped.push(SkippedFix {
+                    rule: first
+                        .rule_name
+                        .unwrap_or("unknown")
+                        .to_string(),
+                    line: first.line,
+                    column: first.column,
+                    reason,
+                });
+            }
+        }
+    }
+
+    let other_rules: Vec<Box<dyn Rule>> = rules
+        .iter()
+        .filter(|rule| !is_region_limited(rule.name(), global))
+        .cloned()
+        .collect();
+    let others: Vec<LintWarning> = others.into_iter().cloned().collect();
+    warnings_fixed + apply_rule_fixes(content, &other_rules, &others, unsafe_fixes, skipped)
+}
+
+/// Return the warnings in `after` that are not in `before`, as needed to verify that
+/// fixes did not introduce new issues.
+///
+/// Warnings are compared by rule name and message, counting duplicates. Positions only
+/// decide which of several identical warnings is the new one, since fixes move content
+/// around. Suppressed warnings are ignored.
+pub fn introduced_warnings<'a>(
+    before: &[LintWarning],
+    after: &'a [LintWarning],
+) -> Vec<&'a LintWarning> {
+    let mut remaining: HashMap<(Option<&str>, &str), Vec<usize>> = HashMap::new();
+    for warning in before.iter().filter(|w| !w.suppressed) {
+        remaining
+            .entry((warning.rule_name, warning.message.as_str()))
+            .or_default()
+            .push(warning.line);
+    }
+
+    let after: Vec<&LintWarning> = after
+        .iter()
+        .filter(|w| !w.suppressed)
+        .collect();
+    let mut matched = vec![false; after.len()];
+
+    // Pair up warnings that stayed on the same line first, then any leftovers
+    for (i, warning) in after.iter().enumerate() {
+        if let Some(lines) = remaining.get_mut(&(warning.rule_name, warning.message.as_str()))
+            && let Some(pos) = lines
+                .iter()
+                .position(|&line| line == warning.line)
+        {
+            lines.swap_remove(pos);
+            matched[i] = true;
+        }
+    }
+    for (i, warning) in after.iter().enumerate() {
+        if !matched[i]
+            && let Some(lines) = remaining.get_mut(&(warning.rule_name, warning.message.as_str()))
+        {
+            matched[i] = lines.pop().is_some();
+        }
+    }
+
+    after
+        .into_iter()
+        .zip(matched)
+        .filter(|(_, matched)| !matched)
+        .map(|(warning, _)| warning)
+        .collect()
+}
+
+/// Merge warnings from the same rule that sit next to each other on one line into a
+/// single warning spanning them, as done by `--merge-adjacent`.
+///
+/// Two warnings are adjacent when nothing but spaces or tabs separates them. The merged
+/// warning joins both messages with "; " and, when both have fixes, carries one fix
+/// covering the whole span. A warning with a fix is never merged with one without.
+/// Expects warnings in the order produced by [`sort_warnings`].
+pub fn merge_adjacent_warnings(content: &str, warnings: Vec<LintWarning>) -> Vec<LintWarning> {
+    let lines: Vec<&str> = content.lines().collect();
+    let mut merged: Vec<LintWarning> = Vec::with_capacity(warnings.len());
+    for warning in warnings {
+        if let Some(last) = merged.last_mut()
+            && let Some(combined) = merge_warning_pair(content, &lines, last, &warning)
+        {
+            *last = combined;
+            continue;
+        }
+        merged.push(warning);
+    }
+    merged
+}
+
+fn merge_warning_pair(
+    content: &str,
+    lines: &[&str],
+    first: &LintWarning,
+    second: &LintWarning,
+) -> Option<LintWarning> {
+    let single_line = |w: &LintWarning| w.line == w.end_line && !w.suppressed;
+    if first.rule_name != second.rule_name
+        || first.line != second.line
+        || !single_line(first)
+        || !single_line(second)
+        || second.column < first.end_column
+    {
+        return None;
+    }
+
+    let is_gap = |gap: &str| {
+        gap.chars()
+            .all(|c| c == ' ' || c == '\t')
+    };
+    let fix = match (&first.fix, &second.fix) {
+        (Some(a), Some(b)) => {
+            let gap = content.get(a.range.end..b.range.start)?;
+            if !is_gap(gap) {
+                return None;
+            }
+            Some(Fix::new(
+                a.range.start..b.range.end,
+                format!("{}{}{}", a.replacement, gap, b.replacement),
+                // The merged fix is only as safe as its parts
+                if a.safety == FixSafety::Safe {
+                    b.safety
+                } else {
+                    a.safety
+                },
+            ))
+        }
+        (None, None) => {
+            let line = lines.get(first.line.checked_sub(1)?)?;
+            let gap: String = line
+                .chars()
+                .skip(first.end_column.saturating_sub(1))
+                .take(second.column - first.end_column)
+                .collect();
+            if !is_gap(&gap) {
+                return None;
+            }
+            None
+        }
+        _ => return None,
+    };
+
+    Some(LintWarning {
+        end_column: second.end_column,
+        message: format!("{}; {}", first.message, second.message),
+        fix,
+        ..first.clone()
+    })
+}
+
 /// Get the profiling report
 pub fn get_profiling_report() -> String {
     profiling::get_report()
@@ -328,3 +1442,976 @@ pub fn get_cache_performance_report() -> String {
 
     report
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rule::TestRule;
+    use crate::rules::strong_style::StrongStyle;
+
+    #[test]
+    fn test_fixes_by_rule_groups_and_orders_fixes() {
+        let rules: Vec<Box<dyn Rule>> = vec![
+            Box::new(MD044ProperNames::new(vec!["JavaScript".to_string()], true)),
+            Box::new(MD050StrongStyle::new(StrongStyle::Asterisk)),
+        ];
+        let content = "# Notes\n\n__one__ javascript and __two__\n\nMore javascript, javascript and __three__.\n";
+
+        let fixes = fixes_by_rule(content, &rules).unwrap();
+
+        assert_eq!(fixes.len(), 2);
+        assert_eq!(fixes["MD044"].len(), 3);
+        assert_eq!(fixes["MD050"].len(), 3);
+        for rule_fixes in fixes.values() {
+            assert!(
+                rule_fixes
+                    .windows(2)
+                    .all(|w| w[0].range.start < w[1].range.start)
+            );
+        }
+        assert!(
+            fixes["MD044"]
+                .iter()
+                .all(|fix| fix.replacement == "JavaScript")
+        );
+    }
+
+    #[test]
+    fn test_apply_strict_upgrades_warnings_to_errors() {
+        let rules: Vec<Box<dyn Rule>> = vec![Box::new(MD050StrongStyle::new(
+            StrongStyle::Asterisk,
+        ))];
+        let mut warnings = lint("Some __bold__ text\n", &rules, false).unwrap();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].severity, Severity::Warning);
+
+        apply_strict(&mut warnings);
+        assert_eq!(warnings[0].severity, Severity::Error);
+    }
+
+    #[test]
+    fn test_fixes_by_rule_empty_when_clean() {
+        let rules: Vec<Box<dyn Rule>> = vec![Box::new(MD050StrongStyle::new(
+            StrongStyle::Asterisk,
+        ))];
+        let fixes = fixes_by_rule("# Title\n\n**ok**\n", &rules).unwrap();
+        assert!(fixes.is_empty());
+    }
+
+    /// Test-only rule that sleeps long enough to trip a small rule timeout
+    fn slow_rule() -> TestRule {
+        TestRule::new("SLOW", |_| {
+            std::thread::sleep(Duration::from_secs(2));
+            Ok(Vec::new())
+        })
+    }
+
+    /// Test-only rule whose fix turns `TODO` into `__TODO__`, which MD050 then flags
+    fn emphasize_todo_rule() -> TestRule {
+        TestRule::new("TODO", |ctx| {
+            Ok(ctx
+                .content
+                .match_indices("TODO")
+                .filter(|(pos, _)| !ctx.content[..*pos].ends_with('_'))
+                .map(|(pos, _)| {
+                    let line = ctx.content[..pos].matches('\n').count() + 1;
+                    LintWarning::new("TODO", line, 1, 1, 1, "Emphasize TODO")
+                })
+                .collect())
+        })
+        .with_fix(|ctx| Ok(ctx.content.replace("TODO", "__TODO__")))
+    }
+
+    /// Test rule replacing `from`, in any case, with `to`
+    fn replace_rule(name: &'static str, from: &'static str, to: &'static str) -> TestRule {
+        TestRule::new(name, move |ctx| {
+            Ok(ctx
+                .content
+                .to_ascii_lowercase()
+                .match_indices(from)
+                .map(|(pos, _)| pos..pos + from.len())
+                .filter(|range| ctx.content[range.clone()] != *to)
+                .map(|range| {
+                    let line = ctx.content[..range.start]
+                        .matches('\n')
+                        .count()
+                        + 1;
+                    LintWarning::new(name, line, 1, 1, 1, format!("Use {}", to))
+                        .with_fix(Fix::safe(range, to))
+                })
+                .collect())
+        })
+    }
+
+    /// Test rule whose warnings carry overlapping fixes, so its fix always fails
+    fn overlapping_fix_rule() -> TestRule {
+        fn check(ctx: &LintContext) -> LintResult {
+            Ok(ctx
+                .content
+                .match_indices("abc")
+                .flat_map(|(pos, _)| [pos..pos + 2, pos + 1..pos + 3])
+                .map(|range| {
+                    LintWarning::new("OVERLAP", 3, 1, 3, 4, "Overlap")
+                        .with_fix(Fix::safe(range, "x"))
+                })
+                .collect())
+        }
+        TestRule::new("OVERLAP", check).with_fix(|ctx| {
+            let fixes: Vec<Fix> = check(ctx)?
+                .into_iter()
+                .filter_map(|w| w.fix)
+                .collect();
+            utils::fix_utils::validate_fixes(&fixes)
+                .map_err(|conflict| LintError::FixFailed(conflict.to_string()))?;
+            Ok(ctx.content.to_string())
+        })
+    }
+
+    #[test]
+    fn test_apply_rule_fixes_reports_skipped_fix() {
+        let rules: Vec<Box<dyn Rule>> = vec![
+            Box::new(overlapping_fix_rule()),
+            Box::new(MD050StrongStyle::new(StrongStyle::Asterisk)),
+        ];
+        let mut content = "# Notes\n\nabc and __bold__\n".to_string();
+        let warnings = lint(&content, &rules, false).unwrap();
+
+        let mut skipped = Vec::new();
+        let fixed = apply_rule_fixes(&mut content, &rules, &warnings, false, &mut skipped);
+
+        // MD050 still fixes its warning; the overlapping rule is skipped with a reason
+        assert_eq!(fixed, 1);
+        assert_eq!(content, "# Notes\n\nabc and **bold**\n");
+        assert_eq!(skipped.len(), 1);
+        assert_eq!(skipped[0].rule, "OVERLAP");
+        assert_eq!((skipped[0].line, skipped[0].column), (3, 1));
+        assert!(
+            skipped[0].reason.contains("overlap"),
+            "unexpected reason: {}",
+            skipped[0].reason
+        );
+    }
+
+    #[test]
+    fn test_apply_rule_fixes_counts_only_fixed_warnings() {
+        // MD050 reports both, but cannot fix strong text ending in its own marker
+        let rules: Vec<Box<dyn Rule>> = vec![Box::new(MD050StrongStyle::new(
+            StrongStyle::Underscore,
+        ))];
+        for content in [
+            "# Notes\n\nKeep **snake_** as is\n\nSome **bold** text\n",
+            "# Notes\n\nKeep **snake_** and **bold**\n",
+        ] {
+            let mut fixed = content.to_string();
+            let warnings = lint(&fixed, &rules, false).unwrap();
+            assert_eq!(warnings.len(), 2);
+            assert_eq!(
+                apply_rule_fixes(&mut fixed, &rules, &warnings, false, &mut Vec::new()),
+                1,
+                "{:?}",
+                content
+            );
+            assert_eq!(
+                lint(&fixed, &rules, false)
+                    .unwrap()
+                    .len(),
+                1
+            );
+        }
+    }
+
+    #[test]
+    fn test_lint_count_matches_lint() {
+        let rules: Vec<Box<dyn Rule>> = vec![
+            Box::new(MD050StrongStyle::new(StrongStyle::Asterisk)),
+            Box::new(MD044ProperNames::new(vec!["JavaScript".to_string()], true)),
+            Box::new(emphasize_todo_rule()),
+            // Run through its document structure path
+            Box::new(MD007ULIndent::default()),
+        ];
+        for content in [
+            "",
+            "# Clean\n",
+            "# List\n\n* Chapter\n   * Section\n      * Subsection\n",
+            "# Notes\n\nUse javascript, __bold__ and javascript\n\nTODO: more\n",
+            // Inline configuration disables some of the warnings
+            "# Notes\n\n<!-- rumdl-disable MD044 -->\njavascript __a__\n<!-- rumdl-enable MD044 -->\njavascript __b__ <!-- rumdl-disable-line MD050 -->\n",
+        ] {
+            assert_eq!(
+                lint_count(content, &rules).unwrap(),
+                lint(content, &rules, false)
+                    .unwrap()
+                    .len(),
+                "{:?}",
+                content
+            );
+        }
+    }
+
+    #[test]
+    fn test_run_summary_counts_problems_by_rule() {
+        let rules: Vec<Box<dyn Rule>> = vec![
+            Box::new(MD050StrongStyle::new(StrongStyle::Asterisk)),
+            Box::new(MD044ProperNames::new(vec!["JavaScript".to_string()], true)),
+            Box::new(emphasize_todo_rule()),
+        ];
+        let files = [
+            "# Notes\n\nUse javascript, __bold__ and javascript\n",
+            "# Clean\n\nNothing to see\n",
+            "# Plan\n\nTODO: more __bold__\n",
+        ];
+        let mut summary = RunSummary::default();
+        let mut with_unsafe = RunSummary::default();
+        let mut json = Vec::new();
+        for content in files {
+            let warnings = lint(content, &rules, false).unwrap();
+            summary.add_file(&warnings, false);
+            with_unsafe.add_file(&warnings, true);
+            json.extend(
+                warnings
+                    .iter()
+                    .map(|w| warning_json("doc.md", w)),
+            );
+        }
+
+        assert_eq!(
+            summary.to_string(),
+            "5 problems (2 fixable) across 2 files\nMD044: 2, MD050: 2, TODO: 1"
+        );
+        // The MD044 fixes are unsafe; with them the counts agree with the JSON output
+        assert_eq!(json.len(), with_unsafe.problems);
+        assert_eq!(
+            json.iter()
+                .filter(|w| w["fixable"] == true)
+                .count(),
+            with_unsafe.fixable
+        );
+        assert_eq!(with_unsafe.fixable, 4);
+
+        let mut single = RunSummary::default();
+        single.add_file(&lint(files[2], &rules[..1], false).unwrap(), false);
+        assert_eq!(
+            single.to_string(),
+            "1 problem (1 fixable) across 1 file\nMD050: 1"
+        );
+    }
+
+    #[test]
+    fn test_group_warnings_dedupes_repeated_messages() {
+        let rules: Vec<Box<dyn Rule>> = vec![
+            Box::new(MD050StrongStyle::new(StrongStyle::Asterisk)),
+            Box::new(MD044ProperNames::new(vec!["JavaScript".to_string()], true)),
+        ];
+        let content = "# Notes\n\njavascript and javascript\n\n__bold__ javascript\n\njavascript javascript\n";
+        let warnings = lint(content, &rules, false).unwrap();
+        assert_eq!(warnings.len(), 6);
+        let render = |groups: &[WarningGroup]| -> Vec<String> {
+            groups
+                .iter()
+                .flat_map(|group| {
+                    group
+                        .rule_name
+                        .map(|name| format!("{} ({})", name, group.len()))
+                        .into_iter()
+                        .chain(group.shown.iter().map(|shown| {
+                            format!(
+                                "{}:{}: [{}] {}{}",
+                                shown.warning.line,
+                                shown.warning.column,
+                                shown.warning.rule_name.unwrap(),
+                                shown.warning.message,
+                                shown.more_suffix()
+                            )
+                        }))
+                })
+                .collect()
+        };
+
+        // The five MD044 warnings share their message, and are shown once at the first
+        let deduped = group_warnings(&warnings, false, true);
+        assert_eq!(deduped.len(), 1);
+        assert_eq!(deduped[0].len(), 6);
+        let message = &warnings
+            .iter()
+            .find(|w| w.rule_name == Some("MD044"))
+            .unwrap()
+            .message;
+        assert_eq!(
+            render(&deduped),
+            vec![
+                format!("3:1: [MD044] {} (and 4 more)", message),
+                "5:1: [MD050] Strong emphasis should use ** instead of __".to_string(),
+            ]
+        );
+
+        // Grouped by rule id, every warning is still listed
+        let grouped = group_warnings(&warnings, true, false);
+        assert_eq!(
+            grouped
+                .iter()
+                .map(|group| (group.rule_name.unwrap(), group.len(), group.shown.len()))
+                .collect::<Vec<_>>(),
+            vec![("MD044", 5, 5), ("MD050", 1, 1)]
+        );
+        assert_eq!(
+            render(&group_warnings(&warnings, true, true))[..2],
+            [
+                "MD044 (5)".to_string(),
+                format!("3:1: [MD044] {} (and 4 more)", message)
+            ]
+        );
+
+        // Without either, the warnings are listed as found
+        let plain = group_warnings(&warnings, false, false);
+        assert_eq!(plain[0].shown.len(), 6);
+        assert!(
+            plain[0]
+                .shown
+                .iter()
+                .all(|shown| shown.more == 0)
+        );
+    }
+
+    #[test]
+    fn test_lint_range_keeps_document_wide_warnings() {
+        let content = "# Notes\n\nSome **bold** text\n\nMore __bold__ text\n";
+        let first = content.find("Some").unwrap();
+        let range = first..first + 4;
+        let lines = |rules: &[Box<dyn Rule>]| -> Vec<usize> {
+            lint_range(content, rules, range.clone())
+                .unwrap()
+                .iter()
+                .map(|w| w.line)
+                .collect()
+        };
+
+        // A consistent style depends on the first bold text, so editing it can change
+        // warnings anywhere; a fixed style only reports around the range
+        let consistent: Vec<Box<dyn Rule>> = vec![Box::new(MD050StrongStyle::new(
+            StrongStyle::Consistent,
+        ))];
+        assert!(consistent[0].is_document_wide());
+        assert_eq!(lines(&consistent), vec![5]);
+        let asterisk: Vec<Box<dyn Rule>> = vec![Box::new(MD050StrongStyle::new(
+            StrongStyle::Asterisk,
+        ))];
+        assert!(!asterisk[0].is_document_wide());
+        assert!(lines(&asterisk).is_empty());
+        assert_eq!(
+            lint(content, &asterisk, false)
+                .unwrap()
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_overlapping_fixes_of_two_rules_apply_in_turn() {
+        let capitalize = || -> Box<dyn Rule> {
+            Box::new(replace_rule("CAPITALIZE", "hello world", "Hello World"))
+        };
+        let earth = || -> Box<dyn Rule> { Box::new(replace_rule("EARTH", "world", "Earth")) };
+        let original = "# Notes\n\nSay hello world, twice: hello world\n";
+
+        // Both rules fix the same spans; whatever their order, one fix goes first and the
+        // other rule fixes the result in the next pass
+        for rules in [
+            vec![capitalize(), earth()],
+            vec![earth(), capitalize()],
+        ] {
+            let mut content = original.to_string();
+            let warnings = lint(&content, &rules, false).unwrap();
+            assert_eq!(warnings.len(), 4);
+            let mut skipped = Vec::new();
+            let fixed = apply_rule_fixes(&mut content, &rules, &warnings, false, &mut skipped);
+
+            assert_eq!(content, "# Notes\n\nSay Hello Earth, twice: Hello Earth\n");
+            assert_eq!(fixed, 4);
+            assert!(
+                skipped.is_empty(),
+                "unexpected skipped fixes: {:?}",
+                skipped
+            );
+            assert!(
+                lint(&content, &rules, false)
+                    .unwrap()
+                    .is_empty()
+            );
+        }
+    }
+
+    #[test]
+    fn test_conflicting_fixes_are_reported_after_max_passes() {
+        // More rules changing the same line than there are passes, one applied per pass
+        const NAMES: [&str; MAX_FIX_PASSES + 1] = [
+            "R00", "R01", "R02", "R03", "R04", "R05", "R06", "R07", "R08", "R09", "R10",
+        ];
+        const WORDS: [(&str, &str); MAX_FIX_PASSES + 1] = [
+            ("a", "A"),
+            ("b", "B"),
+            ("c", "C"),
+            ("d", "D"),
+            ("e", "E"),
+            ("f", "F"),
+            ("g", "G"),
+            ("h", "H"),
+            ("i", "I"),
+            ("j", "J"),
+            ("k", "K"),
+        ];
+        let rules: Vec<Box<dyn Rule>> = NAMES
+            .iter()
+            .zip(WORDS)
+            .map(|(name, (from, to))| Box::new(replace_rule(name, from, to)) as Box<dyn Rule>)
+            .collect();
+        let mut content = "# X\n\na b c d e f g h i j k\n".to_string();
+        let warnings = lint(&content, &rules, false).unwrap();
+        let mut skipped = Vec::new();
+        let fixed = apply_rule_fixes(&mut content, &rules, &warnings, false, &mut skipped);
+
+        assert_eq!(content, "# X\n\nA B C D E F G H I J k\n");
+        assert_eq!(fixed, MAX_FIX_PASSES);
+        assert_eq!(skipped.len(), 1);
+        assert_eq!(skipped[0].rule, "R10");
+        assert!(
+            skipped[0]
+                .reason
+                .contains("after 10 passes"),
+            "unexpected reason: {}",
+            skipped[0].reason
+        );
+    }
+
+    #[test]
+    fn test_fixes_of_adjacent_lines_apply_in_one_pass() {
+        // More rules than there are passes, each changing its own line: none conflict
+        const NAMES: [&str; MAX_FIX_PASSES + 1] = [
+            "R00", "R01", "R02", "R03", "R04", "R05", "R06", "R07", "R08", "R09", "R10",
+        ];
+        let rules: Vec<Box<dyn Rule>> = NAMES
+            .iter()
+            .zip([
+                "aa", "bb", "cc", "dd", "ee", "ff", "gg", "hh", "ii", "jj", "kk",
+            ])
+            .zip([
+                "AA", "BB", "CC", "DD", "EE", "FF", "GG", "HH", "II", "JJ", "KK",
+            ])
+            .map(|((name, from), to)| Box::new(replace_rule(name, from, to)) as Box<dyn Rule>)
+            .collect();
+        let mut content = "# X\n\naa\nbb\ncc\ndd\nee\nff\ngg\nhh\nii\njj\nkk\n".to_string();
+        let warnings = lint(&content, &rules, false).unwrap();
+        let mut skipped = Vec::new();
+        let fixed = apply_rule_fixes(&mut content, &rules, &warnings, false, &mut skipped);
+
+        assert_eq!(
+            content,
+            "# X\n\nAA\nBB\nCC\nDD\nEE\nFF\nGG\nHH\nII\nJJ\nKK\n"
+        );
+        assert_eq!(fixed, MAX_FIX_PASSES + 1);
+        assert!(
+            skipped.is_empty(),
+            "unexpected skipped fixes: {:?}",
+            skipped
+        );
+    }
+
+    #[test]
+    fn test_fixing_md050_introduces_no_warnings() {
+        let rules: Vec<Box<dyn Rule>> = vec![
+            Box::new(MD044ProperNames::new(vec!["JavaScript".to_string()], true)),
+            Box::new(MD050StrongStyle::new(StrongStyle::Asterisk)),
+        ];
+        let content = "# Notes\n\nUse __javascript__ with __bold__ text\n";
+
+        let before = lint(content, &rules, false).unwrap();
+        let mut fixed = content.to_string();
+        for rule in &rules {
+            fixed = rule
+                .fix(&LintContext::new(&fixed))
+                .unwrap();
+        }
+        let after = lint(&fixed, &rules, false).unwrap();
+
+        assert_eq!(fixed, "# Notes\n\nUse **JavaScript** with **bold** text\n");
+        assert!(after.is_empty());
+        assert!(introduced_warnings(&before, &after).is_empty());
+    }
+
+    #[test]
+    fn test_introduced_warnings_detects_fix_regression() {
+        let rules: Vec<Box<dyn Rule>> = vec![
+            Box::new(emphasize_todo_rule()),
+            Box::new(MD050StrongStyle::new(StrongStyle::Asterisk)),
+        ];
+        let content = "# Notes\n\nTODO: write **more**\n\nAnd __more__\n";
+
+        let before = lint(content, &rules, false).unwrap();
+        let fixed = emphasize_todo_rule()
+            .fix(&LintContext::new(content))
+            .unwrap();
+        let after = lint(&fixed, &rules, false).unwrap();
+
+        // The existing MD050 warning is still there but not new; the one on line 3 is
+        let introduced = introduced_warnings(&before, &after);
+        assert_eq!(introduced.len(), 1);
+        assert_eq!(introduced[0].rule_name, Some("MD050"));
+        assert_eq!(introduced[0].line, 3);
+        assert!(introduced_warnings(&after, &after).is_empty());
+    }
+
+    #[test]
+    fn test_lint_with_timeout_skips_slow_rule() {
+        let rules: Vec<Box<dyn Rule>> = vec![
+            Box::new(MD044ProperNames::new(vec!["JavaScript".to_string()], true)),
+            Box::new(slow_rule()),
+            Box::new(MD050StrongStyle::new(StrongStyle::Asterisk)),
+        ];
+        let content = "# Notes\n\nUse javascript with __bold__ text\n";
+
+        let start = Instant::now();
+        let (warnings, timed_out) =
+            lint_with_timeout(content, &rules, Duration::from_millis(200), false).unwrap();
+
+        assert!(start.elapsed() < Duration::from_secs(2));
+        assert_eq!(timed_out, vec!["SLOW"]);
+        let mut fired: Vec<_> = warnings
+            .iter()
+            .filter_map(|w| w.rule_name)
+            .collect();
+        fired.sort();
+        assert_eq!(fired, vec!["MD044", "MD050"]);
+        assert_eq!(lint(content, &rules[..1], false).unwrap(), warnings[..1]);
+    }
+
+    #[test]
+    fn test_inline_disabled_warnings_reported_only_when_requested() {
+        let rules: Vec<Box<dyn Rule>> = vec![Box::new(MD050StrongStyle::new(
+            StrongStyle::Asterisk,
+        ))];
+        let content =
+            "<!-- rumdl-disable MD050 -->\n__hidden__\n<!-- rumdl-enable MD050 -->\n__shown__\n";
+
+        let warnings = lint(content, &rules, false).unwrap();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].line, 4);
+        assert!(!warnings[0].suppressed);
+
+        let mut all = lint_including_suppressed(content, &rules, false).unwrap();
+        assert_eq!(all.len(), 2);
+        assert_eq!(all[0].line, 2);
+        assert!(all[0].suppressed);
+        assert_eq!(all[0].severity, Severity::Info);
+        assert!(!all[1].suppressed);
+        assert_eq!(all[1].severity, Severity::Warning);
+
+        // --strict leaves suppressed warnings at Info
+        apply_strict(&mut all);
+        assert_eq!(all[0].severity, Severity::Info);
+        assert_eq!(all[1].severity, Severity::Error);
+    }
+
+    #[test]
+    fn test_warning_ids_survive_unrelated_edits() {
+        let rules: Vec<Box<dyn Rule>> = vec![Box::new(MD050StrongStyle::new(
+            StrongStyle::Asterisk,
+        ))];
+        let ids = |content: &str| -> Vec<String> {
+            lint(content, &rules, false)
+                .unwrap()
+                .into_iter()
+                .map(|w| w.id.unwrap())
+                .collect()
+        };
+
+        let content = "# Notes\n\nSome __bold__ text\n\nOther __words__ here\n";
+        let before = ids(content);
+        assert_eq!(before.len(), 2);
+        assert!(before[0].starts_with("MD050-"));
+        // A genuinely different warning gets a different id
+        assert_ne!(before[0], before[1]);
+
+        // Edits elsewhere move the warnings but keep their ids
+        let edited =
+            "# Notes\n\nA new paragraph.\n\nSome __bold__ text\n\nOther __words__ here, edited\n";
+        let after = ids(edited);
+        assert_eq!(after[0], before[0]);
+        assert_ne!(after[1], before[1]);
+
+        // Identical warnings are told apart by their order
+        let repeated = ids("Some __bold__ text\n\nSome __bold__ text\n");
+        assert_ne!(repeated[0], repeated[1]);
+
+        // The id disables that warning alone
+        let disabled = format!(
+            "# Notes\n\n<!-- rumdl-disable-next-line {} -->\nSome __bold__ text\n\nOther __words__ here\n",
+            before[0]
+        );
+        assert_eq!(ids(&disabled), vec![before[1].clone()]);
+    }
+
+    #[test]
+    fn test_warning_ids_after_non_ascii_text() {
+        let rules: Vec<Box<dyn Rule>> = vec![Box::new(MD050StrongStyle::new(
+            StrongStyle::Asterisk,
+        ))];
+        let ids = |content: &str| -> Vec<String> {
+            lint(content, &rules, false)
+                .unwrap()
+                .into_iter()
+                .map(|w| w.id.unwrap())
+                .collect()
+        };
+
+        let content = "# Notes\n\nÉté à Zürich, déjà vu; as we say here, __bold__ and __more__\n";
+        let before = ids(content);
+        assert_eq!(before.len(), 2);
+        assert_ne!(before[0], before[1]);
+        assert_eq!(
+            ids(
+                "# Notes\n\nA new paragraph.\n\nÉté à Zürich, déjà vu; as we say here, __bold__ and __more__\n"
+            ),
+            before
+        );
+        // Only the text near each warning is hashed, counted in bytes from the warning
+        assert_eq!(
+            ids("# Notes\n\nSpring in Bern; as we say here, __bold__ and __more__\n"),
+            before
+        );
+    }
+
+    #[test]
+    fn test_managed_regions_limit_where_rules_report() {
+        let rules: Vec<Box<dyn Rule>> = vec![
+            Box::new(MD044ProperNames::new(vec!["JavaScript".to_string()], true)),
+            Box::new(MD050StrongStyle::new(StrongStyle::Asterisk)),
+        ];
+        let content = "# Notes\n\nWritten javascript\n\n<!-- begin:managed -->\nGenerated javascript\n<!-- end:managed -->\n\nSome __bold__ text\n";
+        let lines = |warnings: &[LintWarning]| {
+            warnings
+                .iter()
+                .map(|w| (w.rule_name.unwrap(), w.line))
+                .collect::<Vec<_>>()
+        };
+        let warnings = lint(content, &rules, false).unwrap();
+        assert_eq!(
+            lines(&warnings),
+            vec![("MD044", 3), ("MD044", 6), ("MD050", 9)]
+        );
+
+        // Inside the region the name is reported, the identical one outside is not
+        let mut global = config::GlobalConfig {
+            managed_only: vec!["MD044".to_string()],
+            ..Default::default()
+        };
+        assert_eq!(
+            lines(&filter_managed_regions(content, warnings.clone(), &global)),
+            vec![("MD044", 6), ("MD050", 9)]
+        );
+
+        // And the inverse, without affecting other rules
+        global.managed_only.clear();
+        global.unmanaged_only = vec!["md044".to_string()];
+        let outside = filter_managed_regions(content, warnings, &global);
+        assert_eq!(lines(&outside), vec![("MD044", 3), ("MD050", 9)]);
+
+        // Fixes stay on the side the rule checks
+        let mut fixed = content.to_string();
+        let count =
+            apply_fixes_in_regions(&mut fixed, &rules, &outside, &global, true, &mut Vec::new());
+        assert_eq!(count, 2);
+        assert_eq!(
+            fixed,
+            "# Notes\n\nWritten JavaScript\n\n<!-- begin:managed -->\nGenerated javascript\n<!-- end:managed -->\n\nSome **bold** text\n"
+        );
+    }
+
+    #[test]
+    fn test_unsafe_fixes_need_opt_in() {
+        let rules: Vec<Box<dyn Rule>> = vec![
+            Box::new(MD044ProperNames::new(vec!["JavaScript".to_string()], true)),
+            Box::new(MD050StrongStyle::new(StrongStyle::Asterisk)),
+        ];
+        let content = "# Notes\n\nSome __bold__ javascript\n";
+        let warnings = lint(content, &rules, false).unwrap();
+        let safety = |rule: &str| {
+            warnings
+                .iter()
+                .find(|w| w.rule_name == Some(rule))
+                .and_then(|w| w.fix.as_ref())
+                .map(|fix| fix.safety)
+        };
+        assert_eq!(safety("MD044"), Some(FixSafety::Unsafe));
+        assert_eq!(safety("MD050"), Some(FixSafety::Safe));
+
+        // By default the name is left as written and still reported
+        let mut fixed = content.to_string();
+        assert_eq!(
+            apply_rule_fixes(&mut fixed, &rules, &warnings, false, &mut Vec::new()),
+            1
+        );
+        assert_eq!(fixed, "# Notes\n\nSome **bold** javascript\n");
+        let remaining = lint(&fixed, &rules, false).unwrap();
+        assert_eq!(remaining.len(), 1);
+        assert_eq!(remaining[0].rule_name, Some("MD044"));
+
+        // With unsafe fixes both are applied
+        let mut fixed = content.to_string();
+        assert_eq!(
+            apply_rule_fixes(&mut fixed, &rules, &warnings, true, &mut Vec::new()),
+            2
+        );
+        assert_eq!(fixed, "# Notes\n\nSome **bold** JavaScript\n");
+    }
+
+    #[test]
+    fn test_warnings_sorted_regardless_of_rule_order() {
+        let md044 = || -> Box<dyn Rule> {
+            Box::new(MD044ProperNames::new(vec!["JavaScript".to_string()], true))
+        };
+        let md050 = || -> Box<dyn Rule> { Box::new(MD050StrongStyle::new(StrongStyle::Asterisk)) };
+        let content = "# Notes\n\n__a__ javascript\n\njavascript __b__ javascript\n";
+
+        let forward = lint(content, &[md044(), md050()], false).unwrap();
+        let reverse = lint(content, &[md050(), md044()], false).unwrap();
+        assert_eq!(forward, reverse);
+
+        let order: Vec<_> = forward
+            .iter()
+            .map(|w| (w.line, w.column, w.rule_name.unwrap()))
+            .collect();
+        assert_eq!(
+            order,
+            vec![
+                (3, 1, "MD050"),
+                (3, 7, "MD044"),
+                (5, 1, "MD044"),
+                (5, 12, "MD050"),
+                (5, 18, "MD044"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_always_report_rules_print_under_quiet() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let config_path = temp_dir.path().join(".rumdl.toml");
+        std::fs::write(&config_path, "[global]\nalways-report = [\"md044\"]\n").unwrap();
+        let config: config::Config = config::SourcedConfig::load_with_discovery(
+            Some(config_path.to_str().unwrap()),
+            None,
+            false,
+        )
+        .unwrap()
+        .into();
+        assert_eq!(config.global.always_report, vec!["MD044"]);
+        let rules: Vec<Box<dyn Rule>> = vec![
+            Box::new(MD050StrongStyle::new(StrongStyle::Asterisk)),
+            Box::new(MD044ProperNames::new(vec!["GitHub".to_string()], true)),
+        ];
+        let warnings = lint("# Notes\n\n__bold__ on github\n", &rules, false).unwrap();
+        let printed: Vec<_> = warnings
+            .iter()
+            .filter(|w| is_always_reported(w, &config.global.always_report))
+            .map(|w| w.rule_name.unwrap())
+            .collect();
+        assert_eq!(printed, vec!["MD044"]);
+    }
+
+    #[test]
+    fn test_statistics_count_pseudo_heading_lines() {
+        let rules: Vec<Box<dyn Rule>> = vec![
+            Box::new(crate::rules::MD036NoEmphasisAsHeading::default()),
+            Box::new(MD050StrongStyle::new(StrongStyle::Asterisk)),
+        ];
+        let content = "*Introduction*\n\nText\n\n__Setup__\n\n***Usage***\n\n- **In a list**\n\n> **Quoted**\n\n```\n**In code**\n```\n";
+        let warnings = lint(content, &rules, false).unwrap();
+
+        let mut counts = std::collections::BTreeMap::new();
+        count_warnings_by_rule(&warnings, &mut counts);
+        count_warnings_by_rule(&warnings, &mut counts);
+        // Counts add up across files, here the same file twice
+        assert_eq!(
+            counts.into_iter().collect::<Vec<_>>(),
+            vec![("MD036", 6), ("MD050", 2)]
+        );
+    }
+
+    #[test]
+    fn test_rules_fired_lists_distinct_sorted_ids() {
+        let rules: Vec<Box<dyn Rule>> = vec![
+            Box::new(MD050StrongStyle::new(StrongStyle::Asterisk)),
+            Box::new(MD044ProperNames::new(vec!["GitHub".to_string()], true)),
+        ];
+        let content = "# Notes\n\n__a__ on github, __b__ on github\n";
+        let warnings = lint(content, &rules, false).unwrap();
+        assert_eq!(warnings.len(), 4);
+        assert_eq!(rules_fired(&warnings), vec!["MD044", "MD050"]);
+
+        let clean = lint("# Notes\n\n**a** on GitHub\n", &rules, false).unwrap();
+        assert!(rules_fired(&clean).is_empty());
+    }
+
+    #[test]
+    fn test_warning_json_schema() {
+        let rules: Vec<Box<dyn Rule>> = vec![
+            Box::new(MD050StrongStyle::new(StrongStyle::Asterisk)),
+            Box::new(MD044ProperNames::new(vec!["GitHub".to_string()], true)),
+        ];
+        let warnings = lint("# Notes\n\n__a__ on github\n", &rules, false).unwrap();
+        let json: Vec<_> = warnings
+            .iter()
+            .map(|w| warning_json("docs/notes.md", w))
+            .collect();
+        assert_eq!(
+            serde_json::to_string_pretty(&json).unwrap(),
+            r#"[
+  {
+    "column": 1,
+    "doc_anchor": "asterisk-style",
+    "end_column": 6,
+    "end_line": 3,
+    "file": "docs/notes.md",
+    "fix": {
+      "range": {
+        "end": 14,
+        "start": 9
+      },
+      "replacement": "**a**",
+      "safety": "safe"
+    },
+    "fixable": true,
+    "id": "MD050-5c440121",
+    "line": 3,
+    "message": "Strong emphasis should use ** instead of __",
+    "rule_name": "MD050",
+    "severity": "warning"
+  },
+  {
+    "column": 10,
+    "end_column": 16,
+    "end_line": 3,
+    "file": "docs/notes.md",
+    "fix": {
+      "range": {
+        "end": 24,
+        "start": 18
+      },
+      "replacement": "GitHub",
+      "safety": "unsafe"
+    },
+    "fixable": true,
+    "id": "MD044-9a7aa5c8",
+    "line": 3,
+    "message": "Proper name 'github' should be 'GitHub'",
+    "rule_name": "MD044",
+    "severity": "warning"
+  }
+]"#
+        );
+        let clean = lint("# Notes\n\n**a** on GitHub\n", &rules, false).unwrap();
+        assert_eq!(serde_json::to_string_pretty(&clean).unwrap(), "[]");
+    }
+
+    #[test]
+    fn test_max_warnings_budget() {
+        let rules: Vec<Box<dyn Rule>> = vec![Box::new(MD050StrongStyle::new(
+            StrongStyle::Asterisk,
+        ))];
+        let content = "# Notes\n\n__a__ and __b__\n\n__c__\n";
+        let mut warnings = lint(content, &rules, false).unwrap();
+        let errors = |warnings: &[LintWarning]| {
+            warnings
+                .iter()
+                .filter(|w| w.severity == Severity::Error)
+                .count()
+        };
+        assert_eq!(warnings.len(), 3);
+        assert!(!exceeds_max_warnings(warnings.len(), errors(&warnings), 3));
+        assert!(exceeds_max_warnings(warnings.len(), errors(&warnings), 2));
+
+        // Errors fail the run whatever the budget
+        apply_strict(&mut warnings);
+        assert!(exceeds_max_warnings(warnings.len(), errors(&warnings), 10));
+    }
+
+    #[test]
+    fn test_rule_severity_overrides() {
+        let mut config = config::Config::default();
+        let set_severity = |config: &mut config::Config, severity: &str| {
+            config.rules.insert(
+                "MD044".to_string(),
+                config::RuleConfig {
+                    values: [(
+                        config::RULE_SEVERITY_KEY.to_string(),
+                        toml::Value::String(severity.to_string()),
+                    )]
+                    .into(),
+                },
+            );
+        };
+        set_severity(&mut config, "Error");
+        let overrides = config::severity_overrides(&config).unwrap();
+
+        let rules: Vec<Box<dyn Rule>> = vec![
+            Box::new(MD050StrongStyle::new(StrongStyle::Asterisk)),
+            Box::new(MD044ProperNames::new(vec!["GitHub".to_string()], true)),
+        ];
+        let content = "# Notes\n\n__a__ on github\n";
+        let mut warnings = lint(content, &rules, false).unwrap();
+        let errors = |warnings: &[LintWarning]| {
+            warnings
+                .iter()
+                .filter(|w| w.severity == Severity::Error)
+                .count()
+        };
+        assert!(!exceeds_max_warnings(warnings.len(), errors(&warnings), 10));
+
+        apply_severity_overrides(&mut warnings, &overrides);
+        assert_eq!(
+            warnings
+                .iter()
+                .map(|w| (w.rule_name.unwrap(), w.severity))
+                .collect::<Vec<_>>(),
+            vec![
+                ("MD050", Severity::Warning),
+                ("MD044", Severity::Error)
+            ]
+        );
+        // The error fails the run, exiting with status 1, whatever the budget
+        assert!(exceeds_max_warnings(warnings.len(), errors(&warnings), 10));
+
+        set_severity(&mut config, "fatal");
+        let err = config::severity_overrides(&config).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Failed to parse config: Invalid severity for rule MD044: \"fatal\" (expected \"error\", \"warning\" or \"info\")"
+        );
+    }
+
+    #[test]
+    fn test_merge_adjacent_warnings_combines_range_and_fix() {
+        let rules: Vec<Box<dyn Rule>> = vec![Box::new(MD044ProperNames::new(
+            vec!["GitHub".to_string(), "Actions".to_string()],
+            true,
+        ))];
+        let content = "# CI\n\nWe use github actions, and github.\n";
+        let warnings = lint(content, &rules, false).unwrap();
+        assert_eq!(warnings.len(), 3);
+
+        let merged = merge_adjacent_warnings(content, warnings);
+        assert_eq!(merged.len(), 2);
+        let combined = &merged[0];
+        assert_eq!((combined.line, combined.column), (3, 8));
+        assert_eq!(combined.end_column, 22);
+        assert_eq!(
+            combined.message,
+            "Proper name 'github' should be 'GitHub'; Proper name 'actions' should be 'Actions'"
+        );
+        let fix = combined.fix.as_ref().unwrap();
+        assert_eq!(&content[fix.range.clone()], "github actions");
+        assert_eq!(fix.replacement, "GitHub Actions");
+
+        // The later, separate occurrence keeps its own warning
+        assert_eq!(merged[1].column, 28);
+    }
+}
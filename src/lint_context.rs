@@ -1,3 +1,4 @@
+use crate::rules::front_matter_utils::FrontMatterUtils;
 use crate::utils::code_block_utils::CodeBlockUtils;
 use lazy_static::lazy_static;
 use markdown::{ParseOptions, mdast::Node, to_mdast};
@@ -54,6 +55,11 @@ lazy_static! {
     static ref ANGLE_BRACKET_PATTERN: Regex = Regex::new(
         r"<((?:https?|ftp)://[^>]+|[^@\s]+@[^@\s]+\.[^@\s>]+)>"
     ).unwrap();
+
+    // Opening tag of an inline HTML element whose content is code-like
+    static ref HTML_CODE_OPEN_PATTERN: Regex = Regex::new(
+        r"(?i)<(code|kbd|samp)(?:\s[^>]*)?>"
+    ).unwrap();
 }
 
 /// Pre-computed information about a line
@@ -108,9 +114,9 @@ pub enum HeadingStyle {
 pub struct ParsedLink {
     /// Line number (1-indexed)
     pub line: usize,
-    /// Start column (0-indexed) in the line
+    /// Start byte column (0-indexed) in the line, see [`LintContext::char_column`]
     pub start_col: usize,
-    /// End column (0-indexed) in the line
+    /// End byte column (0-indexed) in the line
     pub end_col: usize,
     /// Byte offset in document
     pub byte_offset: usize,
@@ -131,9 +137,9 @@ pub struct ParsedLink {
 pub struct ParsedImage {
     /// Line number (1-indexed)
     pub line: usize,
-    /// Start column (0-indexed) in the line
+    /// Start byte column (0-indexed) in the line, see [`LintContext::char_column`]
     pub start_col: usize,
-    /// End column (0-indexed) in the line
+    /// End byte column (0-indexed) in the line
     pub end_col: usize,
     /// Byte offset in document
     pub byte_offset: usize,
@@ -167,9 +173,9 @@ pub struct ReferenceDef {
 pub struct CodeSpan {
     /// Line number (1-indexed)
     pub line: usize,
-    /// Start column (0-indexed) in the line
+    /// Start byte column (0-indexed) in the line, see [`LintContext::char_column`]
     pub start_col: usize,
-    /// End column (0-indexed) in the line
+    /// End byte column (0-indexed) in the line
     pub end_col: usize,
     /// Byte offset in document
     pub byte_offset: usize,
@@ -228,9 +234,9 @@ pub struct BlockquoteInfo {
 pub struct BareUrl {
     /// Line number (1-indexed)
     pub line: usize,
-    /// Start column (0-indexed) in the line
+    /// Start byte column (0-indexed) in the line, see [`LintContext::char_column`]
     pub start_col: usize,
-    /// End column (0-indexed) in the line
+    /// End byte column (0-indexed) in the line
     pub end_col: usize,
     /// Byte offset in document
     pub byte_offset: usize,
@@ -273,10 +279,18 @@ pub struct LintContext<'a> {
     pub code_spans: Vec<CodeSpan>,        // Pre-parsed inline code spans
     pub list_blocks: Vec<ListBlock>,      // Pre-parsed list blocks
     pub bare_urls: Vec<BareUrl>,          // Pre-parsed bare URLs and emails
+    pub html_code_ranges: Vec<(usize, usize)>, // Inline <code>, <kbd> and <samp> elements
 }
 
 impl<'a> LintContext<'a> {
+    /// Parse `content`, recognizing front matter by the configured delimiters (see
+    /// [`FrontMatterUtils::configured_delimiters`])
     pub fn new(content: &'a str) -> Self {
+        Self::with_front_matter_delimiters(content, &FrontMatterUtils::configured_delimiters())
+    }
+
+    /// Parse `content`, recognizing front matter opened by one of `delimiters`
+    pub fn with_front_matter_delimiters(content: &'a str, delimiters: &[String]) -> Self {
         // Check for problematic patterns that cause the markdown crate to panic
         if content_has_problematic_lists(content) {
             log::debug!("Detected problematic list patterns in LintContext, skipping AST parsing");
@@ -296,7 +310,7 @@ impl<'a> LintContext<'a> {
             let code_blocks = CodeBlockUtils::detect_code_blocks(content);
 
             // Pre-compute line information
-            let lines = Self::compute_line_info(content, &line_offsets, &code_blocks);
+            let lines = Self::compute_line_info(content, &line_offsets, &code_blocks, delimiters);
 
             // Parse links, images, references, code spans, and list blocks
             let links = Self::parse_links(content, &lines, &code_blocks);
@@ -312,6 +326,7 @@ impl<'a> LintContext<'a> {
                 &images,
                 &reference_defs,
             );
+            let html_code_ranges = Self::parse_html_code_ranges(content, &code_blocks);
 
             return Self {
                 content,
@@ -325,6 +340,7 @@ impl<'a> LintContext<'a> {
                 code_spans,
                 list_blocks,
                 bare_urls,
+                html_code_ranges,
             };
         }
 
@@ -365,7 +381,7 @@ impl<'a> LintContext<'a> {
         let code_blocks = CodeBlockUtils::detect_code_blocks(content);
 
         // Pre-compute line information
-        let lines = Self::compute_line_info(content, &line_offsets, &code_blocks);
+        let lines = Self::compute_line_info(content, &line_offsets, &code_blocks, delimiters);
 
         // Parse links, images, references, code spans, and list blocks
         let links = Self::parse_links(content, &lines, &code_blocks);
@@ -381,6 +397,7 @@ impl<'a> LintContext<'a> {
             &images,
             &reference_defs,
         );
+        let html_code_ranges = Self::parse_html_code_ranges(content, &code_blocks);
 
         Self {
             content,
@@ -394,10 +411,25 @@ impl<'a> LintContext<'a> {
             code_spans,
             list_blocks,
             bare_urls,
+            html_code_ranges,
+        }
+    }
+
+    /// The 0-indexed char column of the 0-indexed byte column `byte_col` on the 1-indexed
+    /// `line`, for reporting the `start_col` and `end_col` of parsed elements in warnings
+    pub fn char_column(&self, line: usize, byte_col: usize) -> usize {
+        let Some(info) = self.lines.get(line.wrapping_sub(1)) else {
+            return byte_col;
+        };
+        let text = info.content.as_str();
+        let mut end = byte_col.min(text.len());
+        while !text.is_char_boundary(end) {
+            end -= 1;
         }
+        text[..end].chars().count() + (byte_col - end)
     }
 
-    /// Map a byte offset to (line, column)
+    /// Map a byte offset to (line, column), the column counted in chars as warnings report it
     pub fn offset_to_line_col(&self, offset: usize) -> (usize, usize) {
         match self.line_offsets.binary_search(&offset) {
             Ok(line) => (line + 1, 1),
@@ -407,24 +439,40 @@ impl<'a> LintContext<'a> {
                     .get(line.wrapping_sub(1))
                     .copied()
                     .unwrap_or(0);
-                (line, offset - line_start + 1)
+                let column = match self.content.get(line_start..offset) {
+                    Some(before) => before.chars().count(),
+                    None => offset - line_start,
+                };
+                (line, column + 1)
             }
         }
     }
 
     /// Check if a position is within a code block or code span
     pub fn is_in_code_block_or_span(&self, pos: usize) -> bool {
-        // Check code blocks first
-        if CodeBlockUtils::is_in_code_block_or_span(&self.code_blocks, pos) {
-            return true;
-        }
+        self.is_in_code_block(pos) || self.is_in_code_span(pos)
+    }
+
+    /// Check if a position is within a fenced or indented code block
+    pub fn is_in_code_block(&self, pos: usize) -> bool {
+        CodeBlockUtils::is_in_code_block_or_span(&self.code_blocks, pos)
+    }
 
-        // Check inline code spans
+    /// Check if a position is within an inline code span
+    pub fn is_in_code_span(&self, pos: usize) -> bool {
         self.code_spans
             .iter()
             .any(|span| pos >= span.byte_offset && pos < span.byte_end)
     }
 
+    /// Check if a position is within an inline HTML `<code>`, `<kbd>` or `<samp>` element,
+    /// whose content renders as code even though it is not a Markdown code span
+    pub fn is_in_html_code_element(&self, pos: usize) -> bool {
+        self.html_code_ranges
+            .iter()
+            .any(|&(start, end)| pos >= start && pos < end)
+    }
+
     /// Get line information by line number (1-indexed)
     pub fn line_info(&self, line_num: usize) -> Option<&LineInfo> {
         if line_num > 0 {
@@ -716,6 +764,7 @@ impl<'a> LintContext<'a> {
         content: &str,
         line_offsets: &[usize],
         code_blocks: &[(usize, usize)],
+        front_matter_delimiters: &[String],
     ) -> Vec<LineInfo> {
         let mut lines = Vec::new();
         let content_lines: Vec<&str> = content.lines().collect();
@@ -886,18 +935,10 @@ impl<'a> LintContext<'a> {
             });
         }
 
-        // Detect front matter boundaries
-        let mut in_front_matter = false;
-        let mut front_matter_end = 0;
-        if content_lines.first().map(|l| l.trim()) == Some("---") {
-            in_front_matter = true;
-            for (idx, line) in content_lines.iter().enumerate().skip(1) {
-                if line.trim() == "---" {
-                    front_matter_end = idx;
-                    break;
-                }
-            }
-        }
+        // Detect front matter boundaries (YAML, TOML or JSON, only at the very top)
+        let front_matter_end =
+            FrontMatterUtils::front_matter_closing_line(&content_lines, front_matter_delimiters);
+        let in_front_matter = |idx: usize| front_matter_end.is_some_and(|end| idx <= end);
 
         // Second pass: detect headings (including Setext which needs look-ahead) and blockquotes
         for i in 0..content_lines.len() {
@@ -906,7 +947,7 @@ impl<'a> LintContext<'a> {
             }
 
             // Skip lines in front matter
-            if in_front_matter && i <= front_matter_end {
+            if in_front_matter(i) {
                 continue;
             }
 
@@ -1015,7 +1056,7 @@ impl<'a> LintContext<'a> {
                 let next_line = content_lines[i + 1];
                 if !lines[i + 1].in_code_block && setext_underline_regex.is_match(next_line) {
                     // Skip if next line is front matter delimiter
-                    if in_front_matter && i + 1 <= front_matter_end {
+                    if in_front_matter(i + 1) {
                         continue;
                     }
 
@@ -1396,6 +1437,35 @@ impl<'a> LintContext<'a> {
         list_blocks
     }
 
+    /// Find the byte ranges of `<code>`, `<kbd>` and `<samp>` elements outside code blocks,
+    /// from the opening tag through the matching closing tag. Unclosed elements are ignored.
+    fn parse_html_code_ranges(
+        content: &str,
+        code_blocks: &[(usize, usize)],
+    ) -> Vec<(usize, usize)> {
+        let mut ranges = Vec::new();
+        if !content.contains('<') {
+            return ranges;
+        }
+
+        let lowercase = content.to_ascii_lowercase();
+        let mut pos = 0;
+        while let Some(cap) = HTML_CODE_OPEN_PATTERN.captures_at(content, pos) {
+            let open = cap.get(0).unwrap();
+            pos = open.end();
+            if CodeBlockUtils::is_in_code_block_or_span(code_blocks, open.start()) {
+                continue;
+            }
+            let closing = format!("</{}>", cap[1].to_ascii_lowercase());
+            if let Some(rel) = lowercase[open.end()..].find(&closing) {
+                let end = open.end() + rel + closing.len();
+                ranges.push((open.start(), end));
+                pos = end;
+            }
+        }
+        ranges
+    }
+
     /// Parse all bare URLs and emails in the content
     fn parse_bare_urls(
         content: &str,
@@ -1844,4 +1914,27 @@ mod tests {
         assert_eq!(ctx.offset_to_line_col(4), (3, 1)); // 'c'
         assert_eq!(ctx.offset_to_line_col(5), (3, 2)); // after 'c'
     }
+
+    #[test]
+    fn test_front_matter_delimiters() {
+        let content = "+++\n> not a quote\n+++\n\n> quote\n";
+        let ctx = LintContext::new(content);
+        assert!(ctx.lines[1].blockquote.is_none());
+        assert!(ctx.lines[4].blockquote.is_some());
+
+        // Without `+++` among the delimiters the block is ordinary content
+        let ctx = LintContext::with_front_matter_delimiters(content, &["---".to_string()]);
+        assert!(ctx.lines[1].blockquote.is_some());
+    }
+
+    #[test]
+    fn test_columns_count_chars() {
+        let content = "é\nçà [x](y)";
+        let ctx = LintContext::new(content);
+        assert_eq!(ctx.offset_to_line_col(2), (1, 2)); // after 'é'
+        assert_eq!(ctx.offset_to_line_col(8), (2, 4)); // '['
+        assert_eq!(ctx.links[0].start_col, 5);
+        assert_eq!(ctx.char_column(2, ctx.links[0].start_col), 3);
+        assert_eq!(ctx.char_column(2, ctx.links[0].end_col), 9);
+    }
 }
@@ -10,7 +10,7 @@ pub mod server;
 pub mod types;
 
 pub use server::RumdlLanguageServer;
-pub use types::{RumdlLspConfig, warning_to_code_action, warning_to_diagnostic};
+pub use types::{PositionEncoding, RumdlLspConfig, warning_to_code_action, warning_to_diagnostic};
 
 use anyhow::Result;
 use tokio::net::TcpListener;
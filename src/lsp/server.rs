@@ -3,8 +3,9 @@
 //! This module implements the core LSP server following Ruff's architecture.
 //! It provides real-time markdown linting, diagnostics, and code actions.
 
-use std::collections::HashMap;
-use std::sync::Arc;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
 
 use anyhow::Result;
 use tokio::sync::RwLock;
@@ -13,9 +14,21 @@ use tower_lsp::lsp_types::*;
 use tower_lsp::{Client, LanguageServer};
 
 use crate::config::Config;
-use crate::lsp::types::{RumdlLspConfig, warning_to_code_action, warning_to_diagnostic};
+use crate::lsp::types::{
+    LINE_COLLAPSED_RULES, LintOn, PositionEncoding, RumdlLspConfig, document_edits,
+    fix_all_code_action, line_warnings_to_code_action, warning_to_code_action,
+    warning_to_diagnostic,
+};
 use crate::rules;
 
+/// How long to wait after a watched-file notification for more to arrive, so a burst of
+/// changes (e.g. a git checkout) re-lints each document once
+const WATCHED_FILES_COALESCE_DELAY: Duration = Duration::from_millis(50);
+
+/// Names of the config files discovery looks for, whose creation or change reloads the
+/// configuration
+const CONFIG_FILE_NAMES: [&str; 2] = [".rumdl.toml", "rumdl.toml"];
+
 /// Main LSP server for rumdl
 ///
 /// Following Ruff's pattern, this server provides:
@@ -29,8 +42,16 @@ pub struct RumdlLanguageServer {
     config: Arc<RwLock<RumdlLspConfig>>,
     /// Rumdl core configuration
     rumdl_config: Arc<RwLock<Config>>,
+    /// Files the rumdl configuration was loaded from
+    config_files: Arc<RwLock<Vec<std::path::PathBuf>>>,
     /// Document store for open files
     documents: Arc<RwLock<HashMap<Url, String>>>,
+    /// Files reported changed on disk and not yet re-linted
+    pending_watched_files: Arc<Mutex<HashSet<Url>>>,
+    /// Whether the client lets us register file watchers at runtime
+    can_register_watchers: Arc<RwLock<bool>>,
+    /// Unit of `Position.character` agreed with the client
+    position_encoding: Arc<RwLock<PositionEncoding>>,
 }
 
 impl RumdlLanguageServer {
@@ -39,10 +60,95 @@ impl RumdlLanguageServer {
             client,
             config: Arc::new(RwLock::new(RumdlLspConfig::default())),
             rumdl_config: Arc::new(RwLock::new(Config::default())),
+            config_files: Arc::new(RwLock::new(Vec::new())),
             documents: Arc::new(RwLock::new(HashMap::new())),
+            pending_watched_files: Arc::new(Mutex::new(HashSet::new())),
+            can_register_watchers: Arc::new(RwLock::new(false)),
+            position_encoding: Arc::new(RwLock::new(PositionEncoding::default())),
+        }
+    }
+
+    /// The rules to run: every rule configured by the rumdl config, minus the LSP
+    /// `disable_rules`
+    async fn lsp_rules(&self) -> Vec<Box<dyn crate::rule::Rule>> {
+        let disable_rules = self
+            .config
+            .read()
+            .await
+            .disable_rules
+            .clone();
+        let rumdl_config = self.rumdl_config.read().await;
+        rules::all_rules(&rumdl_config)
+            .into_iter()
+            .filter(|rule| {
+                !disable_rules
+                    .iter()
+                    .any(|name| name.eq_ignore_ascii_case(rule.name()))
+            })
+            .collect()
+    }
+
+    /// Re-read the open documents among `uris` from disk and re-lint them
+    async fn relint_from_disk(&self, uris: Vec<Url>) {
+        for uri in uris {
+            if !self
+                .documents
+                .read()
+                .await
+                .contains_key(&uri)
+            {
+                continue;
+            }
+            let Some(text) = uri
+                .to_file_path()
+                .ok()
+                .and_then(|path| std::fs::read_to_string(path).ok())
+            else {
+                continue;
+            };
+            self.documents
+                .write()
+                .await
+                .insert(uri.clone(), text.clone());
+            self.update_diagnostics(uri, text).await;
         }
     }
 
+    /// Re-lint every open document, so no stale diagnostics remain from a previous
+    /// configuration
+    async fn relint_open_documents(&self) {
+        let documents: Vec<(Url, String)> = self
+            .documents
+            .read()
+            .await
+            .iter()
+            .map(|(uri, text)| (uri.clone(), text.clone()))
+            .collect();
+        for (uri, text) in documents {
+            self.update_diagnostics(uri, text).await;
+        }
+    }
+
+    /// Whether a change to the file at `uri` affects the rumdl configuration: it is one of
+    /// the files the configuration was loaded from, or a config file discovery would find
+    async fn is_config_change(&self, uri: &Url) -> bool {
+        let Ok(path) = uri.to_file_path() else {
+            return false;
+        };
+        if path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| CONFIG_FILE_NAMES.contains(&name))
+        {
+            return true;
+        }
+        let path = std::fs::canonicalize(&path).unwrap_or(path);
+        self.config_files
+            .read()
+            .await
+            .contains(&path)
+    }
+
     /// Lint a document and return diagnostics
     async fn lint_document(&self, uri: &Url, text: &str) -> Result<Vec<Diagnostic>> {
         let config_guard = self.config.read().await;
@@ -52,19 +158,24 @@ impl RumdlLanguageServer {
             return Ok(Vec::new());
         }
 
+        let rule_timeout = config_guard.rule_timeout_ms;
+        let doc_base_url = config_guard.rule_doc_base_url.clone();
         drop(config_guard); // Release config lock early
 
-        // Get rumdl configuration
-        let rumdl_config = self.rumdl_config.read().await;
-        let all_rules = rules::all_rules(&rumdl_config);
-        drop(rumdl_config); // Release config lock early
+        let all_rules = self.lsp_rules().await;
+        let encoding = *self.position_encoding.read().await;
 
         // Run rumdl linting
-        match crate::lint(text, &all_rules, false) {
+        match self
+            .run_lint(uri, text, &all_rules, rule_timeout)
+            .await
+        {
             Ok(warnings) => {
                 let diagnostics = warnings
                     .iter()
-                    .map(warning_to_diagnostic)
+                    .map(|warning| {
+                        warning_to_diagnostic(warning, text, encoding, doc_base_url.as_deref())
+                    })
                     .collect();
                 Ok(diagnostics)
             }
@@ -75,6 +186,38 @@ impl RumdlLanguageServer {
         }
     }
 
+    /// Lint text, giving each rule at most `rule_timeout_ms` when set.
+    /// Rules skipped for running too long are reported to the client log.
+    async fn run_lint(
+        &self,
+        uri: &Url,
+        text: &str,
+        rules: &[Box<dyn crate::rule::Rule>],
+        rule_timeout_ms: Option<u64>,
+    ) -> crate::rule::LintResult {
+        let Some(timeout_ms) = rule_timeout_ms else {
+            return crate::lint(text, rules, false);
+        };
+        let (warnings, timed_out) = crate::lint_with_timeout(
+            text,
+            rules,
+            std::time::Duration::from_millis(timeout_ms),
+            false,
+        )?;
+        for rule_name in timed_out {
+            self.client
+                .log_message(
+                    MessageType::INFO,
+                    format!(
+                        "Skipped rule {} on {}: exceeded the {}ms rule timeout",
+                        rule_name, uri, timeout_ms
+                    ),
+                )
+                .await;
+        }
+        Ok(warnings)
+    }
+
     /// Update diagnostics for a document
     async fn update_diagnostics(&self, uri: Url, text: String) {
         match self.lint_document(&uri, &text).await {
@@ -89,29 +232,118 @@ impl RumdlLanguageServer {
         }
     }
 
-    /// Get code actions for diagnostics at a position
+    /// Get code actions for diagnostics at a position, plus a `source.fixAll` action for the
+    /// whole document. `only` restricts the kinds returned, as in the request context.
     async fn get_code_actions(
         &self,
         uri: &Url,
         text: &str,
         range: Range,
+        only: Option<&[CodeActionKind]>,
     ) -> Result<Vec<CodeAction>> {
-        let rumdl_config = self.rumdl_config.read().await;
-        let all_rules = rules::all_rules(&rumdl_config);
-        drop(rumdl_config);
+        // A requested kind also covers its sub-kinds, e.g. "source" covers "source.fixAll"
+        let wanted = |kind: &CodeActionKind| {
+            only.is_none_or(|only| {
+                only.iter().any(|requested| {
+                    kind.as_str() == requested.as_str()
+                        || kind
+                            .as_str()
+                            .starts_with(&format!("{}.", requested.as_str()))
+                })
+            })
+        };
+        let (collapse_line_fixes, rule_timeout, unsafe_fixes, doc_base_url) = {
+            let config = self.config.read().await;
+            (
+                config.collapse_line_fixes,
+                config.rule_timeout_ms,
+                config.unsafe_fixes,
+                config.rule_doc_base_url.clone(),
+            )
+        };
+        let doc_base_url = doc_base_url.as_deref();
+        let all_rules = self.lsp_rules().await;
+        let encoding = *self.position_encoding.read().await;
 
-        match crate::lint(text, &all_rules, false) {
+        match self
+            .run_lint(uri, text, &all_rules, rule_timeout)
+            .await
+        {
             Ok(warnings) => {
                 let mut actions = Vec::new();
+                if wanted(&CodeActionKind::SOURCE_FIX_ALL)
+                    && let Some(action) = fix_all_code_action(
+                        &warnings,
+                        uri,
+                        text,
+                        encoding,
+                        unsafe_fixes,
+                        doc_base_url,
+                    )
+                {
+                    actions.push(action);
+                }
+                if !wanted(&CodeActionKind::QUICKFIX) {
+                    return Ok(actions);
+                }
 
-                for warning in warnings {
-                    // Check if warning is within the requested range
+                // Check if warning is within the requested range
+                let in_range = |warning: &crate::rule::LintWarning| {
                     let warning_line = (warning.line.saturating_sub(1)) as u32;
-                    if warning_line >= range.start.line && warning_line <= range.end.line {
-                        if let Some(action) = warning_to_code_action(&warning, uri, text) {
-                            actions.push(action);
+                    warning_line >= range.start.line && warning_line <= range.end.line
+                };
+
+                // Group fixable warnings of line-collapsed rules by (rule, line)
+                let mut line_groups: HashMap<(&str, usize), Vec<&crate::rule::LintWarning>> =
+                    HashMap::new();
+                if collapse_line_fixes {
+                    for warning in warnings
+                        .iter()
+                        .filter(|w| in_range(w) && w.fix.is_some())
+                    {
+                        if let Some(rule_name) = warning
+                            .rule_name
+                            .filter(|name| LINE_COLLAPSED_RULES.contains(name))
+                        {
+                            line_groups
+                                .entry((rule_name, warning.line))
+                                .or_default()
+                                .push(warning);
                         }
                     }
+                    line_groups.retain(|_, group| group.len() > 1);
+                }
+
+                let mut emitted_groups = std::collections::HashSet::new();
+                for warning in warnings.iter().filter(|w| in_range(w)) {
+                    let group_key = warning
+                        .rule_name
+                        .map(|name| (name, warning.line));
+                    if let Some(group) = group_key.and_then(|key| line_groups.get(&key)) {
+                        // One line-spanning edit for the whole group
+                        if emitted_groups.insert(group_key) {
+                            if let Some(action) = line_warnings_to_code_action(
+                                group,
+                                uri,
+                                text,
+                                encoding,
+                                doc_base_url,
+                            ) {
+                                actions.push(action);
+                                continue;
+                            }
+                            // Fall back to one action per warning if the line can't be rebuilt
+                            actions.extend(group.iter().filter_map(|w| {
+                                warning_to_code_action(w, uri, text, encoding, doc_base_url)
+                            }));
+                        }
+                        continue;
+                    }
+                    if let Some(action) =
+                        warning_to_code_action(warning, uri, text, encoding, doc_base_url)
+                    {
+                        actions.push(action);
+                    }
                 }
 
                 Ok(actions)
@@ -123,8 +355,58 @@ impl RumdlLanguageServer {
         }
     }
 
-    /// Load or reload rumdl configuration from files
-    async fn load_configuration(&self, notify_client: bool) {
+    /// The edits applying every fix of the enabled rules to a document, or `None` when
+    /// linting is disabled
+    async fn format_document(&self, uri: &Url, text: &str) -> Option<Vec<TextEdit>> {
+        let (enable_linting, rule_timeout, unsafe_fixes) = {
+            let config = self.config.read().await;
+            (
+                config.enable_linting,
+                config.rule_timeout_ms,
+                config.unsafe_fixes,
+            )
+        };
+        if !enable_linting {
+            return None;
+        }
+        let all_rules = self.lsp_rules().await;
+        let encoding = *self.position_encoding.read().await;
+
+        let warnings = match self
+            .run_lint(uri, text, &all_rules, rule_timeout)
+            .await
+        {
+            Ok(warnings) => warnings,
+            Err(e) => {
+                log::error!("Failed to format document {}: {}", uri, e);
+                return Some(Vec::new());
+            }
+        };
+        let mut fixed = text.to_string();
+        let mut skipped = Vec::new();
+        crate::apply_rule_fixes(
+            &mut fixed,
+            &all_rules,
+            &warnings,
+            unsafe_fixes,
+            &mut skipped,
+        );
+        for skip in skipped {
+            log::debug!(
+                "Skipped fix for {}:{}:{}: [{}] {}",
+                uri,
+                skip.line,
+                skip.column,
+                skip.rule,
+                skip.reason
+            );
+        }
+        Some(document_edits(text, &fixed, encoding))
+    }
+
+    /// Load or reload rumdl configuration from files. A configuration that fails to load
+    /// leaves the previous one active; returns whether the configuration was loaded.
+    async fn load_configuration(&self, notify_client: bool) -> bool {
         let config_guard = self.config.read().await;
         let explicit_config_path = config_guard.config_path.clone();
         drop(config_guard);
@@ -133,7 +415,18 @@ impl RumdlLanguageServer {
         match Self::load_config_for_lsp(explicit_config_path.as_deref()) {
             Ok(sourced_config) => {
                 let loaded_files = sourced_config.loaded_files.clone();
-                *self.rumdl_config.write().await = sourced_config.into();
+                let config: Config = sourced_config.into();
+                crate::rules::front_matter_utils::FrontMatterUtils::set_configured_delimiters(
+                    config
+                        .global
+                        .front_matter_delimiters
+                        .clone(),
+                );
+                *self.rumdl_config.write().await = config;
+                *self.config_files.write().await = loaded_files
+                    .iter()
+                    .map(|file| std::fs::canonicalize(file).unwrap_or_else(|_| file.into()))
+                    .collect();
 
                 if !loaded_files.is_empty() {
                     let message = format!("Loaded rumdl config from: {}", loaded_files.join(", "));
@@ -146,24 +439,24 @@ impl RumdlLanguageServer {
                 } else {
                     log::info!("Using default rumdl configuration (no config files found)");
                 }
+                true
             }
             Err(e) => {
                 let message = format!("Failed to load rumdl config: {}", e);
                 log::warn!("{}", message);
                 if notify_client {
                     self.client
-                        .log_message(MessageType::WARNING, &message)
+                        .show_message(MessageType::ERROR, &message)
                         .await;
                 }
-                // Use default configuration
-                *self.rumdl_config.write().await = crate::config::Config::default();
+                false
             }
         }
     }
 
     /// Reload rumdl configuration from files (with client notification)
-    async fn reload_configuration(&self) {
-        self.load_configuration(true).await;
+    async fn reload_configuration(&self) -> bool {
+        self.load_configuration(true).await
     }
 
     /// Load configuration for LSP - similar to CLI loading but returns Result
@@ -187,15 +480,46 @@ impl LanguageServer for RumdlLanguageServer {
             }
         }
 
+        *self.can_register_watchers.write().await = params
+            .capabilities
+            .workspace
+            .as_ref()
+            .and_then(|workspace| {
+                workspace
+                    .did_change_watched_files
+                    .as_ref()
+            })
+            .and_then(|watched| watched.dynamic_registration)
+            .unwrap_or(false);
+
+        let position_encoding = PositionEncoding::negotiate(
+            params
+                .capabilities
+                .general
+                .as_ref()
+                .and_then(|general| general.position_encodings.as_deref()),
+        );
+        *self.position_encoding.write().await = position_encoding;
+
         // Load rumdl configuration with auto-discovery
         self.load_configuration(false).await;
 
         Ok(InitializeResult {
             capabilities: ServerCapabilities {
+                position_encoding: Some(position_encoding.kind()),
                 text_document_sync: Some(TextDocumentSyncCapability::Kind(
                     TextDocumentSyncKind::FULL,
                 )),
-                code_action_provider: Some(CodeActionProviderCapability::Simple(true)),
+                code_action_provider: Some(CodeActionProviderCapability::Options(
+                    CodeActionOptions {
+                        code_action_kinds: Some(vec![
+                            CodeActionKind::QUICKFIX,
+                            CodeActionKind::SOURCE_FIX_ALL,
+                        ]),
+                        ..Default::default()
+                    },
+                )),
+                document_formatting_provider: Some(OneOf::Left(true)),
                 diagnostic_provider: Some(DiagnosticServerCapabilities::Options(
                     DiagnosticOptions {
                         identifier: Some("rumdl".to_string()),
@@ -226,6 +550,102 @@ impl LanguageServer for RumdlLanguageServer {
         self.client
             .log_message(MessageType::INFO, "rumdl Language Server started")
             .await;
+
+        // Ask to be told about Markdown and config files changed outside the editor
+        if *self.can_register_watchers.read().await {
+            let config_files = self.config_files.read().await.clone();
+            let watchers = [
+                "**/*.{md,markdown}".to_string(),
+                format!("**/{{{}}}", CONFIG_FILE_NAMES.join(",")),
+            ]
+            .into_iter()
+            .chain(
+                config_files
+                    .iter()
+                    .map(|file| file.display().to_string()),
+            )
+            .map(|pattern| FileSystemWatcher {
+                glob_pattern: GlobPattern::String(pattern),
+                kind: None,
+            })
+            .collect();
+            let options = DidChangeWatchedFilesRegistrationOptions { watchers };
+            let registration = Registration {
+                id: "rumdl-watched-files".to_string(),
+                method: "workspace/didChangeWatchedFiles".to_string(),
+                register_options: serde_json::to_value(options).ok(),
+            };
+            if let Err(e) = self
+                .client
+                .register_capability(vec![registration])
+                .await
+            {
+                log::warn!("Failed to register file watchers: {}", e);
+            }
+        }
+    }
+
+    async fn did_change_configuration(&self, params: DidChangeConfigurationParams) {
+        // Settings may be sent directly or nested under a "rumdl" section
+        let settings = params
+            .settings
+            .get("rumdl")
+            .cloned()
+            .unwrap_or(params.settings);
+
+        match serde_json::from_value::<RumdlLspConfig>(settings) {
+            Ok(config) => {
+                *self.config.write().await = config;
+                self.load_configuration(false).await;
+            }
+            Err(e) => {
+                log::warn!("Ignoring invalid rumdl settings: {}", e);
+                return;
+            }
+        }
+
+        self.relint_open_documents().await;
+    }
+
+    async fn did_change_watched_files(&self, params: DidChangeWatchedFilesParams) {
+        // An edited config file takes effect at once; one that fails to load is reported and
+        // the previous configuration stays active
+        let mut config_changed = false;
+        for event in &params.changes {
+            config_changed |= self.is_config_change(&event.uri).await;
+        }
+        if config_changed && self.reload_configuration().await {
+            self.relint_open_documents().await;
+        }
+
+        // Deleted files keep their editor buffer and diagnostics
+        let changed = params
+            .changes
+            .into_iter()
+            .filter(|event| event.typ != FileChangeType::DELETED)
+            .map(|event| event.uri);
+
+        // The first notification of a burst waits for the rest, then re-lints them all once
+        let first_of_burst = {
+            let mut pending = self
+                .pending_watched_files
+                .lock()
+                .unwrap();
+            let was_empty = pending.is_empty();
+            pending.extend(changed);
+            was_empty && !pending.is_empty()
+        };
+        if !first_of_burst {
+            return;
+        }
+        tokio::time::sleep(WATCHED_FILES_COALESCE_DELAY).await;
+        let uris: Vec<Url> = self
+            .pending_watched_files
+            .lock()
+            .unwrap()
+            .drain()
+            .collect();
+        self.relint_from_disk(uris).await;
     }
 
     async fn did_change_workspace_folders(&self, _params: DidChangeWorkspaceFoldersParams) {
@@ -269,6 +689,15 @@ impl LanguageServer for RumdlLanguageServer {
                 .await
                 .insert(uri.clone(), text.clone());
 
+            // In save mode, diagnostics are only refreshed by didSave. Until then, clear the
+            // ones for the saved content, whose positions no longer match the edited text.
+            if self.config.read().await.lint_on == LintOn::Save {
+                self.client
+                    .publish_diagnostics(uri, Vec::new(), None)
+                    .await;
+                return;
+            }
+
             // Update diagnostics
             self.update_diagnostics(uri, text).await;
         }
@@ -319,7 +748,7 @@ impl LanguageServer for RumdlLanguageServer {
 
         if let Some(text) = self.documents.read().await.get(&uri) {
             match self
-                .get_code_actions(&uri, text, range)
+                .get_code_actions(&uri, text, range, params.context.only.as_deref())
                 .await
             {
                 Ok(actions) => {
@@ -339,6 +768,23 @@ impl LanguageServer for RumdlLanguageServer {
         }
     }
 
+    async fn formatting(
+        &self,
+        params: DocumentFormattingParams,
+    ) -> JsonRpcResult<Option<Vec<TextEdit>>> {
+        let uri = params.text_document.uri;
+        let Some(text) = self
+            .documents
+            .read()
+            .await
+            .get(&uri)
+            .cloned()
+        else {
+            return Ok(None);
+        };
+        Ok(self.format_document(&uri, &text).await)
+    }
+
     async fn diagnostic(
         &self,
         params: DocumentDiagnosticParams,
@@ -382,3 +828,414 @@ impl LanguageServer for RumdlLanguageServer {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::StreamExt;
+    use serde_json::json;
+    use std::time::Duration;
+    use tower::Service;
+    use tower_lsp::LspService;
+    use tower_lsp::jsonrpc::Request;
+
+    async fn call(service: &mut LspService<RumdlLanguageServer>, request: Request) {
+        std::future::poll_fn(|cx| service.poll_ready(cx))
+            .await
+            .unwrap();
+        service.call(request).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_watched_file_change_relints_open_document() {
+        let (mut service, socket) = LspService::new(RumdlLanguageServer::new);
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        tokio::spawn(async move {
+            let mut socket = socket;
+            while let Some(message) = socket.next().await {
+                if message.method() == "textDocument/publishDiagnostics" {
+                    let _ = tx.send(message.params().cloned());
+                }
+            }
+        });
+        let mut next_publish = async || {
+            tokio::time::timeout(Duration::from_millis(500), rx.recv())
+                .await
+                .ok()
+                .flatten()
+                .flatten()
+        };
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("watched.md");
+        std::fs::write(&path, "# Title\n").unwrap();
+        let uri = Url::from_file_path(&path).unwrap();
+
+        let params = json!({
+            "processId": null,
+            "rootUri": null,
+            "capabilities": {},
+            "initializationOptions": { "disable_rules": ["md041"] },
+        });
+        call(
+            &mut service,
+            Request::build("initialize")
+                .params(params)
+                .id(1)
+                .finish(),
+        )
+        .await;
+        let open = json!({ "textDocument": {
+            "uri": uri, "languageId": "markdown", "version": 1, "text": "# Title\n"
+        }});
+        call(
+            &mut service,
+            Request::build("textDocument/didOpen")
+                .params(open)
+                .finish(),
+        )
+        .await;
+        let published = next_publish().await.unwrap();
+        assert_eq!(published["diagnostics"], json!([]));
+
+        // A checkout rewrites the file; the client reports it twice in quick succession
+        std::fs::write(&path, "Intro\n\n#Title\n").unwrap();
+        let changed = || {
+            Request::build("workspace/didChangeWatchedFiles")
+                .params(json!({ "changes": [{ "uri": uri, "type": 2 }] }))
+                .finish()
+        };
+        std::future::poll_fn(|cx| service.poll_ready(cx))
+            .await
+            .unwrap();
+        let first = service.call(changed());
+        std::future::poll_fn(|cx| service.poll_ready(cx))
+            .await
+            .unwrap();
+        let second = service.call(changed());
+        let (first, second) = futures::join!(first, second);
+        first.unwrap();
+        second.unwrap();
+
+        let published = next_publish()
+            .await
+            .expect("the changed document should be re-linted");
+        let codes: Vec<_> = published["diagnostics"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|d| d["code"].clone())
+            .collect();
+        assert!(codes.contains(&json!("MD018")), "{:?}", codes);
+        // disable_rules is honored: the missing top-level heading is not reported
+        assert!(!codes.contains(&json!("MD041")), "{:?}", codes);
+        assert!(
+            next_publish().await.is_none(),
+            "a burst of events should re-lint once"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_config_file_change_reloads_configuration() {
+        let (mut service, socket) = LspService::new(RumdlLanguageServer::new);
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        tokio::spawn(async move {
+            let mut socket = socket;
+            while let Some(message) = socket.next().await {
+                if matches!(
+                    message.method(),
+                    "textDocument/publishDiagnostics" | "window/showMessage"
+                ) {
+                    let _ = tx.send((message.method().to_string(), message.params().cloned()));
+                }
+            }
+        });
+        let mut next_message = async || {
+            tokio::time::timeout(Duration::from_millis(500), rx.recv())
+                .await
+                .ok()
+                .flatten()
+        };
+        let codes = |params: &Option<serde_json::Value>| -> Vec<serde_json::Value> {
+            params.as_ref().unwrap()["diagnostics"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|d| d["code"].clone())
+                .collect()
+        };
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let config_path = temp_dir.path().join(".rumdl.toml");
+        std::fs::write(&config_path, "[MD044]\nnames = [\"JavaScript\"]\n").unwrap();
+        let config_uri = Url::from_file_path(&config_path).unwrap();
+
+        let init = json!({
+            "processId": null,
+            "rootUri": null,
+            "capabilities": {},
+            "initializationOptions": { "config_path": config_path },
+        });
+        call(
+            &mut service,
+            Request::build("initialize")
+                .params(init)
+                .id(1)
+                .finish(),
+        )
+        .await;
+        let open = json!({ "textDocument": {
+            "uri": "file:///tmp/reload.md", "languageId": "markdown", "version": 1,
+            "text": "# Notes\n\nWrite javascript and rust.\n"
+        }});
+        call(
+            &mut service,
+            Request::build("textDocument/didOpen")
+                .params(open)
+                .finish(),
+        )
+        .await;
+        let (_, published) = next_message().await.unwrap();
+        assert_eq!(codes(&published), vec![json!("MD044")]);
+
+        let changed = || {
+            Request::build("workspace/didChangeWatchedFiles")
+                .params(json!({ "changes": [{ "uri": config_uri, "type": 2 }] }))
+                .finish()
+        };
+
+        // The edited names apply to the open document without a restart
+        std::fs::write(&config_path, "[MD044]\nnames = [\"Python\"]\n").unwrap();
+        call(&mut service, changed()).await;
+        let (method, published) = next_message()
+            .await
+            .expect("the open document should be re-linted");
+        assert_eq!(method, "textDocument/publishDiagnostics");
+        assert!(codes(&published).is_empty());
+
+        // A config that fails to parse is reported, and the previous one stays active
+        std::fs::write(&config_path, "[MD044\nnames = [\"JavaScript\"]\n").unwrap();
+        call(&mut service, changed()).await;
+        let (method, shown) = next_message().await.unwrap();
+        assert_eq!(method, "window/showMessage");
+        assert_eq!(shown.as_ref().unwrap()["type"], 1);
+        assert!(next_message().await.is_none());
+
+        std::fs::write(&config_path, "[MD044]\nnames = [\"Rust\"]\n").unwrap();
+        call(&mut service, changed()).await;
+        let (_, published) = next_message().await.unwrap();
+        assert_eq!(codes(&published), vec![json!("MD044")]);
+    }
+
+    #[tokio::test]
+    async fn test_lint_on_save_clears_diagnostics_on_did_change() {
+        let (mut service, socket) = LspService::new(RumdlLanguageServer::new);
+
+        // Forward published diagnostics from the client socket
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        tokio::spawn(async move {
+            let mut socket = socket;
+            while let Some(message) = socket.next().await {
+                if message.method() == "textDocument/publishDiagnostics" {
+                    let _ = tx.send(message.params().cloned());
+                }
+            }
+        });
+        let mut next_publish = async || {
+            tokio::time::timeout(Duration::from_millis(500), rx.recv())
+                .await
+                .ok()
+                .flatten()
+        };
+
+        let uri = "file:///tmp/lint_on_save.md";
+        let params = json!({
+            "processId": null,
+            "rootUri": null,
+            "capabilities": {},
+            "initializationOptions": { "lint_on": "save" },
+        });
+        call(
+            &mut service,
+            Request::build("initialize")
+                .params(params)
+                .id(1)
+                .finish(),
+        )
+        .await;
+        call(
+            &mut service,
+            Request::build("initialized")
+                .params(json!({}))
+                .finish(),
+        )
+        .await;
+
+        let open = json!({ "textDocument": {
+            "uri": uri, "languageId": "markdown", "version": 1, "text": "#Title\n"
+        }});
+        call(
+            &mut service,
+            Request::build("textDocument/didOpen")
+                .params(open)
+                .finish(),
+        )
+        .await;
+        let published = next_publish()
+            .await
+            .flatten()
+            .expect("didOpen should publish diagnostics");
+        assert_eq!(published["diagnostics"][0]["range"]["start"]["line"], 0);
+
+        let change = json!({
+            "textDocument": { "uri": uri, "version": 2 },
+            "contentChanges": [{ "text": "Intro\n\n#Title\n" }],
+        });
+        call(
+            &mut service,
+            Request::build("textDocument/didChange")
+                .params(change)
+                .finish(),
+        )
+        .await;
+        let published = next_publish()
+            .await
+            .flatten()
+            .expect("didChange should clear the saved content's diagnostics");
+        assert_eq!(
+            published["diagnostics"],
+            json!([]),
+            "didChange should not lint in save mode"
+        );
+
+        let save = json!({ "textDocument": { "uri": uri } });
+        call(
+            &mut service,
+            Request::build("textDocument/didSave")
+                .params(save)
+                .finish(),
+        )
+        .await;
+        let published = next_publish()
+            .await
+            .flatten()
+            .expect("didSave should publish diagnostics");
+        let diagnostics = published["diagnostics"]
+            .as_array()
+            .unwrap();
+        assert!(
+            diagnostics
+                .iter()
+                .any(|d| d["code"] == "MD018" && d["range"]["start"]["line"] == 2),
+            "Saved content should be linted: {:?}",
+            diagnostics
+        );
+    }
+
+    #[tokio::test]
+    async fn test_formatting_applies_all_fixes() {
+        let (mut service, socket) = LspService::new(RumdlLanguageServer::new);
+        // Drain the client socket so published diagnostics don't block the server
+        tokio::spawn(socket.for_each(|_| async {}));
+        let temp_dir = tempfile::tempdir().unwrap();
+        let config_path = temp_dir.path().join(".rumdl.toml");
+        std::fs::write(
+            &config_path,
+            "[MD044]\nnames = [\"JavaScript\"]\n\n[MD050]\nstyle = \"asterisk\"\n",
+        )
+        .unwrap();
+
+        let request = async |service: &mut LspService<RumdlLanguageServer>,
+                             method: &'static str,
+                             params: serde_json::Value| {
+            std::future::poll_fn(|cx| service.poll_ready(cx))
+                .await
+                .unwrap();
+            let request = Request::build(method)
+                .params(params)
+                .id(1)
+                .finish();
+            let response = service
+                .call(request)
+                .await
+                .unwrap()
+                .unwrap();
+            response.result().cloned().unwrap()
+        };
+        let open = |uri: &str, text: &str| {
+            Request::build("textDocument/didOpen")
+                .params(json!({ "textDocument": {
+                    "uri": uri, "languageId": "markdown", "version": 1, "text": text
+                }}))
+                .finish()
+        };
+        let format = |uri: &str| {
+            json!({
+                "textDocument": { "uri": uri },
+                "options": { "tabSize": 4, "insertSpaces": true },
+            })
+        };
+
+        let init = json!({
+            "processId": null,
+            "rootUri": null,
+            "capabilities": {},
+            "initializationOptions": { "config_path": config_path, "unsafe_fixes": true },
+        });
+        let capabilities = request(&mut service, "initialize", init).await;
+        assert_eq!(
+            capabilities["capabilities"]["documentFormattingProvider"],
+            json!(true)
+        );
+
+        let uri = "file:///tmp/format.md";
+        let text = "# Notes\n\nWrite javascript with __bold__ text.\n";
+        call(&mut service, open(uri, text)).await;
+        let edits: Vec<TextEdit> = serde_json::from_value(
+            request(&mut service, "textDocument/formatting", format(uri)).await,
+        )
+        .unwrap();
+        assert_eq!(edits.len(), 1);
+
+        // Apply the edit; the document is ASCII, so characters are bytes
+        let offset = |position: Position| -> usize {
+            text.split_inclusive('\n')
+                .take(position.line as usize)
+                .map(str::len)
+                .sum::<usize>()
+                + position.character as usize
+        };
+        let mut formatted = text.to_string();
+        formatted.replace_range(
+            offset(edits[0].range.start)..offset(edits[0].range.end),
+            &edits[0].new_text,
+        );
+        assert_eq!(
+            formatted,
+            "# Notes\n\nWrite JavaScript with **bold** text.\n"
+        );
+
+        // A clean document needs no edits
+        let clean = "file:///tmp/clean.md";
+        call(&mut service, open(clean, &formatted)).await;
+        assert_eq!(
+            request(&mut service, "textDocument/formatting", format(clean)).await,
+            json!([])
+        );
+
+        // With linting disabled the formatter does not run
+        call(
+            &mut service,
+            Request::build("workspace/didChangeConfiguration")
+                .params(json!({ "settings": {
+                    "config_path": config_path, "enable_linting": false
+                }}))
+                .finish(),
+        )
+        .await;
+        assert_eq!(
+            request(&mut service, "textDocument/formatting", format(uri)).await,
+            json!(null)
+        );
+    }
+}
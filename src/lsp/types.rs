@@ -8,6 +8,7 @@ use tower_lsp::lsp_types::*;
 
 /// Configuration for the rumdl LSP server
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct RumdlLspConfig {
     /// Path to rumdl configuration file
     pub config_path: Option<String>,
@@ -17,8 +18,40 @@ pub struct RumdlLspConfig {
     pub enable_auto_fix: bool,
     /// Rules to disable in the LSP server
     pub disable_rules: Vec<String>,
+    /// Combine several same-line fixes from one rule (e.g. MD044) into a single
+    /// edit that rewrites the whole line
+    #[serde(default = "default_collapse_line_fixes")]
+    pub collapse_line_fixes: bool,
+    /// When to re-lint a document: on every change ("type") or only on save ("save")
+    #[serde(default)]
+    pub lint_on: LintOn,
+    /// Skip a rule on a document if it runs longer than this many milliseconds
+    pub rule_timeout_ms: Option<u64>,
+    /// Apply unsafe fixes when fixing the whole document, as `--unsafe-fixes` does
+    pub unsafe_fixes: bool,
+    /// Base URL of the rule documentation pages diagnostics link to, such as an internal
+    /// mirror; the pages on GitHub when unset
+    pub rule_doc_base_url: Option<String>,
 }
 
+/// When the LSP server re-lints an open document
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LintOn {
+    /// Lint on every change, as the user types
+    #[default]
+    Type,
+    /// Lint only when the document is opened or saved; edits clear the diagnostics until then
+    Save,
+}
+
+fn default_collapse_line_fixes() -> bool {
+    true
+}
+
+/// Rules whose same-line fixes are combined into one line-spanning edit
+pub const LINE_COLLAPSED_RULES: &[&str] = &["MD044"];
+
 impl Default for RumdlLspConfig {
     fn default() -> Self {
         Self {
@@ -26,26 +59,110 @@ impl Default for RumdlLspConfig {
             enable_linting: true,
             enable_auto_fix: false,
             disable_rules: Vec::new(),
+            collapse_line_fixes: default_collapse_line_fixes(),
+            lint_on: LintOn::default(),
+            rule_timeout_ms: None,
+            unsafe_fixes: false,
+            rule_doc_base_url: None,
         }
     }
 }
 
-/// Convert rumdl warnings to LSP diagnostics
-pub fn warning_to_diagnostic(warning: &crate::rule::LintWarning) -> Diagnostic {
-    let start_position = Position {
-        line: (warning.line.saturating_sub(1)) as u32,
-        character: (warning.column.saturating_sub(1)) as u32,
-    };
+/// The unit `Position.character` counts within a line, as negotiated with the client
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PositionEncoding {
+    /// UTF-8 code units, i.e. bytes
+    Utf8,
+    /// UTF-16 code units, the protocol's default
+    #[default]
+    Utf16,
+    /// Unicode scalar values
+    Utf32,
+}
+
+impl PositionEncoding {
+    /// The first of the client's `general.positionEncodings`, in its order of preference,
+    /// that the server supports. UTF-16 when the client declares none.
+    pub fn negotiate(client_encodings: Option<&[PositionEncodingKind]>) -> Self {
+        client_encodings
+            .unwrap_or_default()
+            .iter()
+            .find_map(|kind| match kind.as_str() {
+                "utf-8" => Some(Self::Utf8),
+                "utf-16" => Some(Self::Utf16),
+                "utf-32" => Some(Self::Utf32),
+                _ => None,
+            })
+            .unwrap_or_default()
+    }
+
+    /// The encoding as announced in the server capabilities
+    pub fn kind(self) -> PositionEncodingKind {
+        match self {
+            Self::Utf8 => PositionEncodingKind::UTF8,
+            Self::Utf16 => PositionEncodingKind::UTF16,
+            Self::Utf32 => PositionEncodingKind::UTF32,
+        }
+    }
+
+    // Code units `ch` takes up
+    fn len(self, ch: char) -> u32 {
+        match self {
+            Self::Utf8 => ch.len_utf8() as u32,
+            Self::Utf16 => ch.len_utf16() as u32,
+            Self::Utf32 => 1,
+        }
+    }
+}
+
+// The LSP position of a warning's 1-indexed line and char column. Columns past the end of
+// the line count one unit per missing char.
+fn warning_position(
+    document_text: &str,
+    line: usize,
+    column: usize,
+    encoding: PositionEncoding,
+) -> Position {
+    let line_text = document_text
+        .split('\n')
+        .nth(line.saturating_sub(1))
+        .unwrap_or_default();
+    let chars = column.saturating_sub(1);
+    let mut character = 0;
+    let mut counted = 0;
+    for ch in line_text.chars().take(chars) {
+        character += encoding.len(ch);
+        counted += 1;
+    }
+    Position {
+        line: (line.saturating_sub(1)) as u32,
+        character: character + (chars - counted) as u32,
+    }
+}
+
+/// Convert rumdl warnings to LSP diagnostics, with positions in `encoding` units of
+/// `document_text`, the text the warning was reported on. The rule's documentation is
+/// linked among the pages at `doc_base_url`, or on GitHub if `None`.
+pub fn warning_to_diagnostic(
+    warning: &crate::rule::LintWarning,
+    document_text: &str,
+    encoding: PositionEncoding,
+    doc_base_url: Option<&str>,
+) -> Diagnostic {
+    let start_position = warning_position(document_text, warning.line, warning.column, encoding);
 
     // Use proper range from warning
-    let end_position = Position {
-        line: (warning.end_line.saturating_sub(1)) as u32,
-        character: (warning.end_column.saturating_sub(1)) as u32,
-    };
+    let end_position = warning_position(
+        document_text,
+        warning.end_line,
+        warning.end_column,
+        encoding,
+    );
 
     let severity = match warning.severity {
         crate::rule::Severity::Error => DiagnosticSeverity::ERROR,
         crate::rule::Severity::Warning => DiagnosticSeverity::WARNING,
+        crate::rule::Severity::Info => DiagnosticSeverity::INFORMATION,
     };
 
     // Create clickable link to rule documentation
@@ -53,10 +170,12 @@ pub fn warning_to_diagnostic(warning: &crate::rule::LintWarning) -> Diagnostic {
         .rule_name
         .as_ref()
         .and_then(|rule_name| {
-            // Create a link to the rule documentation
-            Url::parse(&format!(
-                "https://github.com/rvben/rumdl/blob/main/docs/{}.md",
-                rule_name.to_lowercase()
+            // Create a link to the rule documentation, deep-linking to the
+            // specific violation when the rule provides an anchor
+            Url::parse(&crate::rule::doc_url_in(
+                doc_base_url.unwrap_or(crate::rule::DEFAULT_DOC_BASE_URL),
+                rule_name,
+                warning.doc_anchor.as_deref(),
             ))
             .ok()
             .map(|href| CodeDescription { href })
@@ -76,12 +195,19 @@ pub fn warning_to_diagnostic(warning: &crate::rule::LintWarning) -> Diagnostic {
         related_information: None,
         tags: None,
         code_description,
-        data: None,
+        data: warning
+            .id
+            .as_ref()
+            .map(|id| serde_json::json!({ "id": id })),
     }
 }
 
-/// Convert byte range to LSP range
-fn byte_range_to_lsp_range(text: &str, byte_range: std::ops::Range<usize>) -> Option<Range> {
+/// Convert byte range to LSP range, counting characters in `encoding` units
+fn byte_range_to_lsp_range(
+    text: &str,
+    byte_range: std::ops::Range<usize>,
+    encoding: PositionEncoding,
+) -> Option<Range> {
     let mut line = 0u32;
     let mut character = 0u32;
     let mut byte_pos = 0;
@@ -102,7 +228,7 @@ fn byte_range_to_lsp_range(text: &str, byte_range: std::ops::Range<usize>) -> Op
             line += 1;
             character = 0;
         } else {
-            character += 1;
+            character += encoding.len(ch);
         }
 
         byte_pos += ch.len_utf8();
@@ -119,15 +245,57 @@ fn byte_range_to_lsp_range(text: &str, byte_range: std::ops::Range<usize>) -> Op
     }
 }
 
-/// Create a code action from a rumdl warning with fix
+/// The edits turning `original` into `fixed`: none when they are equal, otherwise a single
+/// edit replacing the span between their common prefix and common suffix. One edit keeps
+/// clients from having to reconcile overlapping per-fix ranges after rules rewrote each
+/// other's output.
+pub fn document_edits(original: &str, fixed: &str, encoding: PositionEncoding) -> Vec<TextEdit> {
+    if original == fixed {
+        return Vec::new();
+    }
+    let mut prefix = original
+        .bytes()
+        .zip(fixed.bytes())
+        .take_while(|(a, b)| a == b)
+        .count();
+    while !original.is_char_boundary(prefix) || !fixed.is_char_boundary(prefix) {
+        prefix -= 1;
+    }
+    let max_suffix = original.len().min(fixed.len()) - prefix;
+    let mut suffix = original
+        .bytes()
+        .rev()
+        .zip(fixed.bytes().rev())
+        .take(max_suffix)
+        .take_while(|(a, b)| a == b)
+        .count();
+    while !original.is_char_boundary(original.len() - suffix)
+        || !fixed.is_char_boundary(fixed.len() - suffix)
+    {
+        suffix -= 1;
+    }
+
+    byte_range_to_lsp_range(original, prefix..original.len() - suffix, encoding)
+        .map(|range| TextEdit {
+            range,
+            new_text: fixed[prefix..fixed.len() - suffix].to_string(),
+        })
+        .into_iter()
+        .collect()
+}
+
+/// Create a code action from a rumdl warning with fix. Unsafe fixes are labelled as such
+/// and not marked preferred, so editors do not apply them unasked.
 pub fn warning_to_code_action(
     warning: &crate::rule::LintWarning,
     uri: &Url,
     document_text: &str,
+    encoding: PositionEncoding,
+    doc_base_url: Option<&str>,
 ) -> Option<CodeAction> {
     if let Some(fix) = &warning.fix {
         // Convert fix range (byte offsets) to LSP positions
-        let range = byte_range_to_lsp_range(document_text, fix.range.clone())?;
+        let range = byte_range_to_lsp_range(document_text, fix.range.clone(), encoding)?;
 
         let edit = TextEdit {
             range,
@@ -143,13 +311,23 @@ pub fn warning_to_code_action(
             change_annotations: None,
         };
 
+        let safe = fix.safety == crate::rule::FixSafety::Safe;
         Some(CodeAction {
-            title: format!("Fix: {}", warning.message),
+            title: if safe {
+                format!("Fix: {}", warning.message)
+            } else {
+                format!("Fix (unsafe): {}", warning.message)
+            },
             kind: Some(CodeActionKind::QUICKFIX),
-            diagnostics: Some(vec![warning_to_diagnostic(warning)]),
+            diagnostics: Some(vec![warning_to_diagnostic(
+                warning,
+                document_text,
+                encoding,
+                doc_base_url,
+            )]),
             edit: Some(workspace_edit),
             command: None,
-            is_preferred: Some(true),
+            is_preferred: Some(safe),
             disabled: None,
             data: None,
         })
@@ -157,3 +335,360 @@ pub fn warning_to_code_action(
         None
     }
 }
+
+/// Create a `source.fixAll` code action applying the fixes of all `warnings` in one edit.
+///
+/// Fixes are taken from the end of the document to the start; a fix overlapping one already
+/// taken is left out, so the result does not depend on the order rules reported them.
+/// Unsafe fixes are left out unless `unsafe_fixes` is set. Returns `None` when no warning
+/// has a fix to apply.
+pub fn fix_all_code_action(
+    warnings: &[crate::rule::LintWarning],
+    uri: &Url,
+    document_text: &str,
+    encoding: PositionEncoding,
+    unsafe_fixes: bool,
+    doc_base_url: Option<&str>,
+) -> Option<CodeAction> {
+    let mut fixable: Vec<&crate::rule::LintWarning> = warnings
+        .iter()
+        .filter(|w| {
+            w.fix
+                .as_ref()
+                .is_some_and(|fix| fix.is_applicable(unsafe_fixes))
+                && !w.suppressed
+        })
+        .collect();
+    fixable.sort_by_key(|w| {
+        let range = &w.fix.as_ref().unwrap().range;
+        std::cmp::Reverse((range.start, range.end))
+    });
+
+    let mut applied: Vec<crate::rule::LintWarning> = Vec::new();
+    let mut limit = usize::MAX;
+    for warning in fixable {
+        let range = &warning.fix.as_ref().unwrap().range;
+        // Adjacent fixes are kept; one ending past the start of a taken fix is not
+        if range.end <= limit {
+            limit = range.start;
+            applied.push(warning.clone());
+        }
+    }
+    if applied.is_empty() {
+        return None;
+    }
+
+    let fixed = crate::utils::fix_utils::apply_warning_fixes(document_text, &applied).ok()?;
+    let edits = document_edits(document_text, &fixed, encoding);
+    let mut changes = std::collections::HashMap::new();
+    changes.insert(uri.clone(), edits);
+
+    Some(CodeAction {
+        title: "Fix all rumdl issues".to_string(),
+        kind: Some(CodeActionKind::SOURCE_FIX_ALL),
+        diagnostics: Some(
+            applied
+                .iter()
+                .map(|w| warning_to_diagnostic(w, document_text, encoding, doc_base_url))
+                .collect(),
+        ),
+        edit: Some(WorkspaceEdit {
+            changes: Some(changes),
+            document_changes: None,
+            change_annotations: None,
+        }),
+        command: None,
+        is_preferred: None,
+        disabled: None,
+        data: None,
+    })
+}
+
+/// Create a single code action for several warnings on the same line, whose one edit
+/// replaces the entire line with all of their fixes applied.
+///
+/// Returns `None` if any fix is missing, reaches outside the line, or overlaps another. An
+/// action including unsafe fixes is labelled and not preferred, as for a single warning.
+pub fn line_warnings_to_code_action(
+    warnings: &[&crate::rule::LintWarning],
+    uri: &Url,
+    document_text: &str,
+    encoding: PositionEncoding,
+    doc_base_url: Option<&str>,
+) -> Option<CodeAction> {
+    let first = warnings.first()?;
+    let line_start: usize = document_text
+        .split_inclusive('\n')
+        .take(first.line.saturating_sub(1))
+        .map(str::len)
+        .sum();
+    let line_end = document_text[line_start..]
+        .find('\n')
+        .map_or(document_text.len(), |i| line_start + i);
+
+    let mut fixes: Vec<&crate::rule::Fix> = warnings
+        .iter()
+        .map(|w| w.fix.as_ref())
+        .collect::<Option<_>>()?;
+    let safe = fixes
+        .iter()
+        .all(|fix| fix.is_applicable(false));
+    fixes.sort_by_key(|fix| fix.range.start);
+
+    // Rebuild the line from left to right, rejecting overlapping fixes
+    let mut new_text = String::new();
+    let mut pos = line_start;
+    for fix in &fixes {
+        if fix.range.start < pos || fix.range.end > line_end {
+            return None;
+        }
+        new_text.push_str(&document_text[pos..fix.range.start]);
+        new_text.push_str(&fix.replacement);
+        pos = fix.range.end;
+    }
+    new_text.push_str(&document_text[pos..line_end]);
+
+    let edit = TextEdit {
+        range: byte_range_to_lsp_range(document_text, line_start..line_end, encoding)?,
+        new_text,
+    };
+
+    let mut changes = std::collections::HashMap::new();
+    changes.insert(uri.clone(), vec![edit]);
+
+    Some(CodeAction {
+        title: format!(
+            "Fix {} {} issues on line {}{}",
+            warnings.len(),
+            first.rule_name.unwrap_or("rumdl"),
+            first.line,
+            if safe { "" } else { " (unsafe)" }
+        ),
+        kind: Some(CodeActionKind::QUICKFIX),
+        diagnostics: Some(
+            warnings
+                .iter()
+                .map(|w| warning_to_diagnostic(w, document_text, encoding, doc_base_url))
+                .collect(),
+        ),
+        edit: Some(WorkspaceEdit {
+            changes: Some(changes),
+            document_changes: None,
+            change_annotations: None,
+        }),
+        command: None,
+        is_preferred: Some(safe),
+        disabled: None,
+        data: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rule::Rule;
+    use crate::rules::MD044ProperNames;
+
+    #[test]
+    fn test_line_fixes_collapse_into_single_edit() {
+        let rule = MD044ProperNames::new(
+            vec![
+                "JavaScript".to_string(),
+                "GitHub".to_string(),
+            ],
+            true,
+        );
+        let content = "# Intro\n\nUse javascript on github today.\n";
+        let ctx = crate::lint_context::LintContext::new(content);
+        let warnings = rule.check(&ctx).unwrap();
+        assert_eq!(warnings.len(), 2);
+
+        let uri = Url::parse("file:///test.md").unwrap();
+        let refs: Vec<_> = warnings.iter().collect();
+        let action =
+            line_warnings_to_code_action(&refs, &uri, content, PositionEncoding::Utf16, None)
+                .unwrap();
+        let edits = &action.edit.unwrap().changes.unwrap()[&uri];
+
+        assert_eq!(edits.len(), 1);
+        assert_eq!(edits[0].new_text, "Use JavaScript on GitHub today.");
+        assert_eq!(
+            edits[0].range.start,
+            Position {
+                line: 2,
+                character: 0
+            }
+        );
+        assert_eq!(
+            edits[0].range.end,
+            Position {
+                line: 2,
+                character: 31
+            }
+        );
+        assert_eq!(action.diagnostics.unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_line_fixes_reject_overlap() {
+        let content = "abc\n";
+        let warning = |range: std::ops::Range<usize>| {
+            crate::rule::LintWarning::new("MD044", 1, range.start + 1, 1, range.end + 1, "m")
+                .with_fix(crate::rule::Fix::safe(range, "x"))
+        };
+        let (a, b) = (warning(0..2), warning(1..3));
+        let uri = Url::parse("file:///test.md").unwrap();
+        assert!(
+            line_warnings_to_code_action(&[&a, &b], &uri, content, PositionEncoding::Utf16, None)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_positions_count_utf16_code_units() {
+        let rule = MD044ProperNames::new(vec!["JavaScript".to_string()], true);
+        let content = "# Intro\n\n\u{1F600} javascript\n";
+        let ctx = crate::lint_context::LintContext::new(content);
+        let warnings = rule.check(&ctx).unwrap();
+        assert_eq!(warnings.len(), 1);
+        let uri = Url::parse("file:///test.md").unwrap();
+
+        // The emoji is 2 UTF-16 code units and 4 UTF-8 ones
+        for (encoding, start) in [
+            (PositionEncoding::Utf16, 3),
+            (PositionEncoding::Utf8, 5),
+            (PositionEncoding::Utf32, 2),
+        ] {
+            let expected = Range {
+                start: Position {
+                    line: 2,
+                    character: start,
+                },
+                end: Position {
+                    line: 2,
+                    character: start + 10,
+                },
+            };
+            let diagnostic = warning_to_diagnostic(&warnings[0], content, encoding, None);
+            assert_eq!(diagnostic.range, expected);
+
+            let action =
+                warning_to_code_action(&warnings[0], &uri, content, encoding, None).unwrap();
+            let edits = &action.edit.unwrap().changes.unwrap()[&uri];
+            assert_eq!(edits[0].range, expected);
+            assert_eq!(edits[0].new_text, "JavaScript");
+        }
+
+        assert_eq!(PositionEncoding::negotiate(None), PositionEncoding::Utf16);
+        assert_eq!(
+            PositionEncoding::negotiate(Some(&[
+                PositionEncodingKind::from("utf-7"),
+                PositionEncodingKind::UTF8,
+                PositionEncodingKind::UTF16,
+            ])),
+            PositionEncoding::Utf8
+        );
+    }
+
+    #[test]
+    fn test_diagnostics_link_to_configured_docs() {
+        use crate::rules::MD050StrongStyle;
+        use crate::rules::strong_style::StrongStyle;
+
+        let content = "# Notes\n\nSome __bold__ text\n";
+        let ctx = crate::lint_context::LintContext::new(content);
+        let mut warning = MD050StrongStyle::new(StrongStyle::Asterisk)
+            .check(&ctx)
+            .unwrap()
+            .remove(0);
+        let config: RumdlLspConfig =
+            serde_json::from_str(r#"{"rule_doc_base_url": "https://docs.example.com/rumdl/"}"#)
+                .unwrap();
+        let href = |warning: &crate::rule::LintWarning, base: Option<&str>| {
+            warning_to_diagnostic(warning, content, PositionEncoding::Utf16, base)
+                .code_description
+                .map(|description| description.href.to_string())
+        };
+
+        assert_eq!(
+            href(&warning, config.rule_doc_base_url.as_deref()).as_deref(),
+            Some("https://docs.example.com/rumdl/md050.md#asterisk-style")
+        );
+        assert_eq!(
+            href(&warning, None).as_deref(),
+            Some("https://github.com/rvben/rumdl/blob/main/docs/md050.md#asterisk-style")
+        );
+        warning.rule_name = None;
+        assert_eq!(href(&warning, config.rule_doc_base_url.as_deref()), None);
+    }
+
+    #[test]
+    fn test_fix_all_matches_sequential_rule_fixes() {
+        use crate::rules::MD050StrongStyle;
+        use crate::rules::strong_style::StrongStyle;
+
+        let names = MD044ProperNames::new(
+            vec![
+                "JavaScript".to_string(),
+                "GitHub".to_string(),
+            ],
+            true,
+        );
+        let strong = MD050StrongStyle::new(StrongStyle::Asterisk);
+        let content = "# Intro\n\nUse javascript and __bold__ text on github.\n";
+        let ctx = crate::lint_context::LintContext::new(content);
+        let mut warnings = names.check(&ctx).unwrap();
+        warnings.extend(strong.check(&ctx).unwrap());
+        assert_eq!(warnings.len(), 3);
+
+        let uri = Url::parse("file:///test.md").unwrap();
+        let action = fix_all_code_action(
+            &warnings,
+            &uri,
+            content,
+            PositionEncoding::Utf16,
+            true,
+            None,
+        )
+        .unwrap();
+        assert_eq!(action.kind, Some(CodeActionKind::SOURCE_FIX_ALL));
+        assert_eq!(action.diagnostics.unwrap().len(), 3);
+        let edits = &action.edit.unwrap().changes.unwrap()[&uri];
+        assert_eq!(edits.len(), 1);
+
+        // The document is ASCII, so characters are bytes
+        let line_start: usize = content
+            .split_inclusive('\n')
+            .take(edits[0].range.start.line as usize)
+            .map(str::len)
+            .sum();
+        let mut combined = content.to_string();
+        combined.replace_range(
+            line_start + edits[0].range.start.character as usize
+                ..line_start + edits[0].range.end.character as usize,
+            &edits[0].new_text,
+        );
+
+        let sequential = names.fix(&ctx).unwrap();
+        let sequential = strong
+            .fix(&crate::lint_context::LintContext::new(&sequential))
+            .unwrap();
+        assert_eq!(combined, sequential);
+        assert_eq!(
+            combined,
+            "# Intro\n\nUse JavaScript and **bold** text on GitHub.\n"
+        );
+
+        // By default the unsafe name fixes are left out
+        let action = fix_all_code_action(
+            &warnings,
+            &uri,
+            content,
+            PositionEncoding::Utf16,
+            false,
+            None,
+        )
+        .unwrap();
+        assert_eq!(action.diagnostics.unwrap().len(), 1);
+    }
+}
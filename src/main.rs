@@ -1,20 +1,15 @@
 use clap::{Args, Parser, Subcommand};
 use colored::*;
-use ignore::WalkBuilder;
-use ignore::overrides::OverrideBuilder;
 use memmap2::Mmap;
-use rayon::prelude::*;
 use std::collections::HashSet;
 use std::error::Error;
 use std::fs;
-use std::io::{self, Read, Write};
+use std::io::{self, Write};
 use std::path::Path;
 use std::process;
-use std::sync::Arc;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 use rumdl::config as rumdl_config;
-use rumdl::lint_context::LintContext;
 use rumdl::rule::Rule;
 use rumdl::rules::code_block_utils::CodeBlockStyle;
 use rumdl::rules::code_fence_utils::CodeFenceStyle;
@@ -183,7 +178,7 @@ struct Cli {
 #[derive(Subcommand)]
 enum Commands {
     /// Lint Markdown files and print warnings/errors
-    Check(CheckArgs),
+    Check(Box<CheckArgs>),
     /// Initialize a new configuration file
     Init {
         /// Generate configuration for pyproject.toml instead of .rumdl.toml
@@ -237,6 +232,21 @@ enum Commands {
         #[arg(long)]
         dry_run: bool,
     },
+    /// Check a configuration file for syntax errors, unknown rules or options, wrongly typed
+    /// values and missing referenced files
+    LintConfig {
+        /// Path to the config file (.rumdl.toml, rumdl.toml or pyproject.toml)
+        file: String,
+    },
+    /// Check that the examples in the rule documentation behave as documented: incorrect
+    /// examples are reported, correct and fixed ones are not
+    Selftest {
+        /// Rules to check (default: every rule with a documentation page)
+        rules: Vec<String>,
+        /// Directory holding the `{rule}.md` documentation pages
+        #[arg(long, default_value = "docs")]
+        docs: String,
+    },
     /// Install the rumdl VS Code extension
     Vscode {
         /// Force reinstall even if already installed
@@ -281,6 +291,17 @@ struct CheckArgs {
     #[arg(short, long)]
     enable: Option<String>,
 
+    /// Run only the rules with these ids or tags (comma-separated), such as
+    /// `--select style,MD044`. See `rumdl rule <RULE>` for a rule's tags
+    #[arg(long, value_name = "RULES")]
+    select: Option<String>,
+
+    /// Don't run the rules with these ids or tags (comma-separated). A rule named by id in
+    /// one of --select and --ignore and by tag in the other follows the id; when both name
+    /// it alike, --ignore wins
+    #[arg(long, value_name = "RULES")]
+    ignore: Option<String>,
+
     /// Exclude specific files or directories (comma-separated glob patterns)
     #[arg(long)]
     exclude: Option<String>,
@@ -297,6 +318,10 @@ struct CheckArgs {
     )]
     respect_gitignore: bool,
 
+    /// Descend at most N directory levels below each input directory (0 = only its direct files)
+    #[arg(long, value_name = "N")]
+    max_depth: Option<usize>,
+
     /// Show detailed output
     #[arg(short, long)]
     verbose: bool,
@@ -309,7 +334,9 @@ struct CheckArgs {
     #[arg(short, long)]
     quiet: bool,
 
-    /// Output format: text (default) or json
+    /// Output format: text (default), json, sarif (SARIF 2.1.0, e.g. for GitHub code
+    /// scanning), patch (fixes as a git-apply-able patch), review (each fix previewed with
+    /// its context), or rules-fired (the rule ids reported for each file)
     #[arg(
         long,
         short = 'o',
@@ -317,12 +344,130 @@ struct CheckArgs {
     )]
     output: String,
 
-    /// Read from stdin instead of files
+    /// Input format: markdown (default), or ipynb to lint the markdown cells of Jupyter
+    /// notebooks. Notebook warnings are reported per cell, with lines relative to it
+    #[arg(
+        long,
+        value_name = "FORMAT",
+        default_value = "markdown",
+        value_parser = ["markdown", "ipynb"]
+    )]
+    input_format: String,
+
+    /// Units of the column numbers in text output: chars (default), or graphemes to count
+    /// each grapheme cluster, such as a letter with combining accents, as one column
+    #[arg(
+        long,
+        value_name = "UNITS",
+        default_value = "chars",
+        value_parser = ["chars", "graphemes"]
+    )]
+    column_units: String,
+
+    /// Read from stdin, as `-` does
     #[arg(
         long,
-        help = "Read from stdin instead of files"
+        help = "Read from stdin, as `-` does"
     )]
     stdin: bool,
+
+    /// Path to report for content read from stdin, also used to find the configuration of `-`
+    #[arg(long, value_name = "PATH")]
+    stdin_filename: Option<String>,
+
+    /// Keep the results in this directory and skip files unchanged since the last run
+    #[arg(long, value_name = "DIR")]
+    cache_dir: Option<String>,
+
+    /// Report all warnings as errors, which fail the run even within --max-warnings
+    #[arg(long)]
+    strict: bool,
+
+    /// Skip a rule on a file if it runs longer than this many milliseconds
+    #[arg(long, value_name = "MS")]
+    rule_timeout: Option<u64>,
+
+    /// Also report, at info severity and marked suppressed, what disabled rules and
+    /// inline-disabled regions would have reported
+    #[arg(long)]
+    report_disabled: bool,
+
+    /// Combine warnings from the same rule on adjacent words of a line into a single
+    /// warning spanning them
+    #[arg(long)]
+    merge_adjacent: bool,
+
+    /// With --fix, lint the fixed content again and exit with status 2, without writing
+    /// the file, if the fixes introduced new issues
+    #[arg(long, requires = "_fix")]
+    verify_after: bool,
+
+    /// With --fix, print the changes the fixes would make as a unified diff per file
+    /// instead of writing them, and exit with status 1 if any file would change
+    #[arg(long, requires = "_fix")]
+    diff: bool,
+
+    /// Also apply unsafe fixes, which may change what the content says, such as MD044's
+    /// name replacements
+    #[arg(long)]
+    unsafe_fixes: bool,
+
+    /// Lint up to N files at a time (default: one per logical core). With -j 1 files
+    /// are linted one after another
+    #[arg(
+        long,
+        short = 'j',
+        value_name = "N",
+        value_parser = clap::value_parser!(u32).range(1..)
+    )]
+    jobs: Option<u32>,
+
+    /// Exit with status 1 only if more than N issues are found across all files. Issues
+    /// reported as errors, such as every issue under --strict, still always fail the run
+    #[arg(long, value_name = "N")]
+    max_warnings: Option<usize>,
+
+    /// After the summary, print how many issues each rule reported
+    #[arg(long)]
+    statistics: bool,
+
+    /// Don't print the closing summary of problems, fixable problems and counts per rule.
+    /// With --output json the summary goes to stderr
+    #[arg(long)]
+    no_summary: bool,
+
+    /// In text output, list each file's warnings in a section per rule id, each warning
+    /// by its location and message
+    #[arg(long)]
+    group_by_rule: bool,
+
+    /// In text output, show a warning whose rule and message already appeared in the file
+    /// only once, followed by how many more there are
+    #[arg(long)]
+    dedupe: bool,
+
+    /// Only print the total number of issues and of files with issues, exiting with status
+    /// 1 if there are any. Rules count their issues without building full warnings where
+    /// they can, for a fast clean-or-not gate
+    #[arg(long, conflicts_with = "_fix")]
+    quiet_count: bool,
+
+    /// With --output json, also POST the results to this http:// or https:// URL. Proxies
+    /// are taken from https_proxy, http_proxy, all_proxy and no_proxy; failing to report
+    /// does not change the exit status
+    #[arg(long, value_name = "URL")]
+    report_url: Option<String>,
+
+    /// Only lint the Markdown files staged in git, limited to PATHS if given. With --fix,
+    /// fixed files are re-staged and the exit status only reflects the issues left unfixed.
+    /// Files with unstaged changes are linted as staged and never fixed.
+    #[arg(long)]
+    changed_only: bool,
+
+    /// Print every effective setting with the file and line (or default, or command
+    /// line) it came from, then exit without linting
+    #[arg(long)]
+    print_config_origin: bool,
 }
 
 // Get a complete set of enabled rules based on CLI options and config
@@ -330,10 +475,7 @@ fn get_enabled_rules_from_checkargs(
     args: &CheckArgs,
     config: &rumdl_config::Config,
 ) -> Vec<Box<dyn Rule>> {
-    // 1. Initialize all available rules using from_config only
-    let all_rules: Vec<Box<dyn Rule>> = rumdl::rules::all_rules(config);
-
-    // 2. Determine the final list of enabled rules based on precedence
+    // Determine the final list of enabled rules based on precedence
     let final_rules: Vec<Box<dyn Rule>>;
 
     // Rule names provided via CLI flags
@@ -350,62 +492,25 @@ fn get_enabled_rules_from_checkargs(
             .collect()
     });
 
-    // Rule names provided via config file
-    let config_enable_set: HashSet<&str> = config
-        .global
-        .enable
-        .iter()
-        .map(|s| s.as_str())
-        .collect();
-
-    let config_disable_set: HashSet<&str> = config
-        .global
-        .disable
-        .iter()
-        .map(|s| s.as_str())
-        .collect();
-
     if let Some(enabled_cli) = &cli_enable_set {
         // Normalize CLI enable values
         let enabled_cli_normalized: HashSet<String> = enabled_cli
             .iter()
             .map(|s| normalize_key(s))
             .collect();
-        let _all_rule_names: Vec<String> = all_rules
-            .iter()
-            .map(|r| normalize_key(r.name()))
-            .collect();
-        final_rules = all_rules
+        final_rules = rumdl::rules::all_rules(config)
             .into_iter()
             .filter(|rule| enabled_cli_normalized.contains(&normalize_key(rule.name())))
             .collect();
         // Note: CLI --disable is IGNORED if CLI --enable is present.
     } else {
         // --- Case 2: No CLI --enable ---
-        // Start with the configured rules.
-        let mut current_rules = all_rules;
-
-        // Step 2a: Apply config `enable` (if specified).
-        // If config.enable is not empty, it acts as an *exclusive* list.
-        if !config_enable_set.is_empty() {
-            current_rules.retain(|rule| {
-                let normalized_rule_name = normalize_key(rule.name());
-                config_enable_set.contains(normalized_rule_name.as_str())
-            });
-        }
+        // Start with the rules enabled by config: per-rule `enabled`, then the global
+        // `disable` and `enable` lists, then each rule's default (see config::rule_enabled)
+        let mut current_rules = rumdl::rules::enabled_rules(config);
 
-        // Step 2b: Apply config `disable`.
-        // Remove rules specified in config.disable from the current set.
-        if !config_disable_set.is_empty() {
-            current_rules.retain(|rule| {
-                let normalized_rule_name = normalize_key(rule.name());
-                let is_disabled = config_disable_set.contains(normalized_rule_name.as_str());
-                !is_disabled // Keep if NOT disabled
-            });
-        }
-
-        // Step 2c: Apply CLI `disable`.
-        // Remove rules specified in cli.disable from the result of steps 2a & 2b.
+        // Apply CLI `disable`.
+        // Remove rules specified in cli.disable from the config-enabled set.
         if let Some(disabled_cli) = &cli_disable_set {
             current_rules.retain(|rule| {
                 let rule_name_upper = rule.name();
@@ -418,7 +523,31 @@ fn get_enabled_rules_from_checkargs(
         final_rules = current_rules; // Assign the final filtered vector
     }
 
-    // 4. Print enabled rules if verbose
+    // CLI `select` picks among all rules, like `enable`; `ignore` narrows either set
+    let selectors = |s: &str| -> Vec<String> {
+        s.split(',')
+            .map(|r| r.trim().to_string())
+            .filter(|r| !r.is_empty())
+            .collect()
+    };
+    let select = args.select.as_deref().map(selectors);
+    let ignore = args
+        .ignore
+        .as_deref()
+        .map(selectors)
+        .unwrap_or_default();
+    let final_rules = if select.is_some() || !ignore.is_empty() {
+        let candidates = if select.is_some() {
+            rumdl::rules::all_rules(config)
+        } else {
+            final_rules
+        };
+        rumdl::rules::select_rules(candidates, select.as_deref(), &ignore)
+    } else {
+        final_rules
+    };
+
+    // Print enabled rules if verbose
     if args.verbose {
         println!("Enabled rules:");
         for rule in &final_rules {
@@ -430,177 +559,22 @@ fn get_enabled_rules_from_checkargs(
     final_rules
 }
 
-// Find all markdown files using the `ignore` crate, returning Result
-fn find_markdown_files(
+// Collect the files, directories, globs, archives and stdin named on the command line
+fn collect_inputs(
     paths: &[String],
     args: &CheckArgs,
     config: &rumdl_config::Config,
-) -> Result<Vec<String>, Box<dyn Error>> {
-    let mut file_paths = Vec::new();
-
-    // --- Configure ignore::WalkBuilder ---
-    // Start with the first path, add others later
-    let first_path = paths
-        .first()
-        .cloned()
-        .unwrap_or_else(|| ".".to_string());
-    let mut walk_builder = WalkBuilder::new(first_path);
-
-    // Add remaining paths
-    for path in paths.iter().skip(1) {
-        walk_builder.add(path);
-    }
-
-    // --- Add Markdown File Type Definition ---
-    let mut types_builder = ignore::types::TypesBuilder::new();
-    types_builder.add_defaults(); // Add standard types
-    types_builder
-        .add("markdown", "*.md")
-        .unwrap();
-    types_builder
-        .add("markdown", "*.markdown")
-        .unwrap();
-    types_builder.select("markdown"); // Select ONLY markdown for processing
-    let types = types_builder.build().unwrap();
-    walk_builder.types(types);
-    // -----------------------------------------
-
-    // Determine if running in discovery mode (e.g., "rumdl ." or "rumdl check ." or "rumdl check")
-    // Adjusted to handle both legacy and subcommand paths
-    let is_discovery_mode = paths.is_empty() || paths == ["."];
-
-    // --- Determine Effective Include/Exclude Patterns ---
-
-    // Include patterns: CLI > Config (only in discovery mode) > Default (only in discovery mode)
-    let final_include_patterns: Vec<String> = if let Some(cli_include) = args.include.as_deref() {
-        // 1. CLI --include always wins
-        cli_include
-            .split(',')
-            .map(|p| p.trim().to_string())
-            .filter(|p| !p.is_empty())
-            .collect()
-    } else if is_discovery_mode && !config.global.include.is_empty() {
-        // 2. Config include is used ONLY in discovery mode if specified
-        config.global.include.clone()
-    } else if is_discovery_mode {
-        // 3. Default include (*.md, *.markdown) ONLY in discovery mode if no CLI/Config include
-        vec!["*.md".to_string(), "*.markdown".to_string()]
-    } else {
-        // 4. Explicit path mode: No includes applied by default. Walk starts from explicit paths.
-        Vec::new()
-    };
-
-    // Exclude patterns: CLI > Config
-    let final_exclude_patterns: Vec<String> = if let Some(cli_exclude) = args.exclude.as_deref() {
-        cli_exclude
-            .split(',')
-            .map(|p| p.trim().to_string())
-            .filter(|p| !p.is_empty())
-            .collect()
-    } else {
-        config.global.exclude.clone()
-    };
-
-    // Debug: Log exclude patterns
-    if args.verbose {
-        eprintln!("Exclude patterns: {:?}", final_exclude_patterns);
-    }
-    // --- End Pattern Determination ---
-
-    // Apply overrides using the determined patterns
-    if !final_include_patterns.is_empty() || !final_exclude_patterns.is_empty() {
-        let mut override_builder = OverrideBuilder::new("."); // Root context
-
-        // Add includes (these act as positive filters)
-        for pattern in &final_include_patterns {
-            // Important: In ignore crate, bare patterns act as includes if no exclude (!) is present.
-            // If we add excludes later, these includes ensure *only* matching files are considered.
-            // If no excludes are added, these effectively define the set of files to walk.
-            if let Err(e) = override_builder.add(pattern) {
-                eprintln!("Warning: Invalid include pattern '{}': {}", pattern, e);
-            }
-        }
-
-        // Add excludes (these filter *out* files) - MUST start with '!'
-        for pattern in &final_exclude_patterns {
-            // Ensure exclude patterns start with '!' for ignore crate overrides
-            let exclude_rule = if pattern.starts_with('!') {
-                pattern.clone() // Already formatted
-            } else {
-                format!("!{}", pattern)
-            };
-            if let Err(e) = override_builder.add(&exclude_rule) {
-                eprintln!("Warning: Invalid exclude pattern '{}': {}", pattern, e);
-            }
-        }
-
-        // Build and apply the overrides
-        match override_builder.build() {
-            Ok(overrides) => {
-                walk_builder.overrides(overrides);
-            }
-            Err(e) => {
-                eprintln!("Error building path overrides: {}", e);
-            }
-        };
-    }
-
-    // Configure gitignore handling *SECOND*
-    let use_gitignore = if args.respect_gitignore {
-        true // If respect is true, always include gitignore
-    } else {
-        false // If respect is false, always exclude gitignore
+) -> Result<Vec<rumdl::file_discovery::Input>, String> {
+    let options = rumdl::file_discovery::DiscoveryOptions {
+        include: args.include.clone(),
+        exclude: args.exclude.clone(),
+        respect_gitignore: args.respect_gitignore,
+        max_depth: args.max_depth,
+        verbose: args.verbose,
+        stdin_filename: args.stdin_filename.clone(),
+        stdin: args.stdin,
     };
-
-    walk_builder.ignore(use_gitignore); // Enable/disable .ignore
-    walk_builder.git_ignore(use_gitignore); // Enable/disable .gitignore
-    walk_builder.git_global(use_gitignore); // Enable/disable global gitignore
-    walk_builder.git_exclude(use_gitignore); // Enable/disable .git/info/exclude
-    walk_builder.parents(use_gitignore); // Enable/disable parent ignores
-    walk_builder.hidden(true); // Keep hidden files ignored unconditionally
-    walk_builder.require_git(false); // Process git ignores even if no repo detected
-
-    // Add support for .markdownlintignore file
-    walk_builder.add_custom_ignore_filename(".markdownlintignore");
-
-    // --- Execute Walk ---
-
-    for result in walk_builder.build() {
-        match result {
-            Ok(entry) => {
-                let path = entry.path();
-                // We are primarily interested in files. ignore crate handles dir traversal.
-                // Check if it's a file and if it wasn't explicitly excluded by overrides
-                if path.is_file() {
-                    let file_path = path.to_string_lossy().to_string();
-                    // Clean the path before pushing
-                    let cleaned_path = if let Some(stripped) = file_path.strip_prefix("./") {
-                        stripped.to_string()
-                    } else {
-                        file_path
-                    };
-                    file_paths.push(cleaned_path);
-                }
-            }
-            Err(err) => eprintln!("Error walking directory: {}", err),
-        }
-    }
-
-    // Remove duplicate paths if WalkBuilder might yield them (e.g. multiple input paths)
-    file_paths.sort();
-    file_paths.dedup();
-
-    // --- Final Explicit Markdown Filter ---
-    // Ensure only files with .md or .markdown extensions are returned,
-    // regardless of how ignore crate overrides interacted with type filters.
-    file_paths.retain(|path_str| {
-        let path = Path::new(path_str);
-        path.extension()
-            .is_some_and(|ext| ext == "md" || ext == "markdown")
-    });
-    // -------------------------------------
-
-    Ok(file_paths) // Ensure the function returns the result
+    rumdl::file_discovery::collect_inputs(paths, &options, config, &mut io::stdin())
 }
 
 // Define a struct to hold the print results arguments
@@ -612,6 +586,7 @@ pub(crate) struct PrintResultsArgs<'a> {
     pub total_issues_fixed: usize,
     pub total_fixable_issues: usize,
     pub total_files_processed: usize,
+    pub total_fixes_skipped: usize,
     pub duration_ms: u64,
 }
 
@@ -624,6 +599,7 @@ fn print_results_from_checkargs(params: PrintResultsArgs) {
         total_issues_fixed,
         total_fixable_issues,
         total_files_processed,
+        total_fixes_skipped,
         duration_ms,
     } = params;
     // Choose singular or plural form of "file" based on count
@@ -651,6 +627,18 @@ fn print_results_from_checkargs(params: PrintResultsArgs) {
                 file_with_issues_text,
                 duration_ms
             );
+            if total_fixes_skipped > 0 {
+                println!(
+                    "{} {} {} could not be applied",
+                    "Skipped:".yellow().bold(),
+                    total_fixes_skipped,
+                    if total_fixes_skipped == 1 {
+                        "fix"
+                    } else {
+                        "fixes"
+                    }
+                );
+            }
         } else {
             // In non-fix mode, show issues summary with simplified count when appropriate
             let files_display = if files_with_issues == total_files_processed {
@@ -728,6 +716,24 @@ fn print_config_with_provenance(sourced: &rumdl_config::SourcedConfig) {
             format!("respect_gitignore = {}", g.respect_gitignore.value),
             format!("[from {}]", format_provenance(g.respect_gitignore.source)),
         ),
+        (
+            format!("extensions = {:?}", g.extensions.value),
+            format!("[from {}]", format_provenance(g.extensions.source)),
+        ),
+        (
+            format!("strict = {}", g.strict.value),
+            format!("[from {}]", format_provenance(g.strict.source)),
+        ),
+        (
+            format!(
+                "front_matter_delimiters = {:?}",
+                g.front_matter_delimiters.value
+            ),
+            format!(
+                "[from {}]",
+                format_provenance(g.front_matter_delimiters.source)
+            ),
+        ),
         (String::new(), String::new()),
     ];
     all_lines.extend(global_lines);
@@ -761,7 +767,7 @@ fn print_config_with_provenance(sourced: &rumdl_config::SourcedConfig) {
         Box::new(MD031BlanksAroundFences {}),
         Box::new(MD032BlanksAroundLists::default()),
         Box::new(MD033NoInlineHtml::default()),
-        Box::new(MD034NoBareUrls {}),
+        Box::new(MD034NoBareUrls::default()),
         Box::new(MD035HRStyle::default()),
         Box::new(MD036NoEmphasisAsHeading::new(".,;:!?".to_string())),
         Box::new(MD037NoSpaceInEmphasis),
@@ -785,6 +791,7 @@ fn print_config_with_provenance(sourced: &rumdl_config::SourcedConfig) {
         Box::new(MD055TablePipeStyle::default()),
         Box::new(MD056TableColumnCount),
         Box::new(MD058BlanksAroundTables),
+        Box::new(MD063HeadingCapitalization::default()),
     ];
     let mut rule_names: Vec<_> = all_rules
         .iter()
@@ -893,6 +900,58 @@ fn format_toml_value(val: &toml::Value) -> String {
     }
 }
 
+/// Print each effective setting with its origin, for `check --print-config-origin`.
+///
+/// Command-line rule and file selection flags are shown as overriding the config.
+fn print_config_origins(
+    sourced: &rumdl_config::SourcedConfig,
+    registry: &rumdl_config::RuleRegistry,
+    args: &CheckArgs,
+) {
+    let mut sourced = sourced.clone();
+    let split = |list: &str| -> Vec<String> {
+        list.split(',')
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty())
+            .collect()
+    };
+    let cli = rumdl_config::ConfigSource::Cli;
+    let g = &mut sourced.global;
+    for (flag, target) in [
+        (&args.enable, &mut g.enable),
+        (&args.disable, &mut g.disable),
+        (&args.exclude, &mut g.exclude),
+        (&args.include, &mut g.include),
+    ] {
+        if let Some(list) = flag {
+            target.merge_override(split(list), cli, None, None);
+        }
+    }
+    if args.strict {
+        g.strict
+            .merge_override(true, cli, None, None);
+    }
+
+    let origins = rumdl_config::setting_origins(&sourced, registry);
+    let lines: Vec<String> = origins
+        .iter()
+        .map(|setting| format!("{} = {}", setting.key, setting.value))
+        .collect();
+    let width = lines
+        .iter()
+        .map(|line| line.len())
+        .max()
+        .unwrap_or(0);
+    for (line, setting) in lines.iter().zip(&origins) {
+        println!(
+            "{:<width$}  {}",
+            line,
+            setting.origin.dimmed(),
+            width = width
+        );
+    }
+}
+
 /// Offer to install the VS Code extension during init
 fn offer_vscode_extension_install() {
     use rumdl::vscode::VsCodeExtension;
@@ -1176,7 +1235,7 @@ build-backend = \"setuptools.build_meta\"
                     Box::new(MD031BlanksAroundFences {}),
                     Box::new(MD032BlanksAroundLists::default()),
                     Box::new(MD033NoInlineHtml::default()),
-                    Box::new(MD034NoBareUrls {}),
+                    Box::new(MD034NoBareUrls::default()),
                     Box::new(MD035HRStyle::default()),
                     Box::new(MD036NoEmphasisAsHeading::new(".,;:!?".to_string())),
                     Box::new(MD037NoSpaceInEmphasis),
@@ -1200,7 +1259,20 @@ build-backend = \"setuptools.build_meta\"
                     Box::new(MD055TablePipeStyle::default()),
                     Box::new(MD056TableColumnCount),
                     Box::new(MD058BlanksAroundTables),
+                    Box::new(MD063HeadingCapitalization::default()),
                 ];
+                // Effective enablement under the discovered (or --config) configuration
+                let config: rumdl_config::Config =
+                    load_config_with_cli_error_handling(cli.config.as_deref(), cli.no_config)
+                        .into();
+                let registry = rumdl_config::RuleRegistry::from_rules(&all_rules);
+                let status = |rule: &dyn Rule| {
+                    if registry.is_enabled(rule.name(), &config) {
+                        ""
+                    } else {
+                        " (disabled)"
+                    }
+                };
                 if let Some(rule_query) = rule {
                     let rule_query = rule_query.to_ascii_uppercase();
                     let found = all_rules.iter().find(|r| {
@@ -1210,11 +1282,15 @@ build-backend = \"setuptools.build_meta\"
                     });
                     if let Some(rule) = found {
                         println!(
-                            "{} - {}\n\nDescription:\n  {}",
+                            "{} - {}{}\n\nDescription:\n  {}",
                             rule.name(),
                             rule.description(),
+                            status(rule.as_ref()),
                             rule.description()
                         );
+                        if !rule.tags().is_empty() {
+                            println!("\nTags:\n  {}", rule.tags().join(", "));
+                        }
                     } else {
                         eprintln!("Rule '{}' not found.", rule_query);
                         std::process::exit(1);
@@ -1222,7 +1298,12 @@ build-backend = \"setuptools.build_meta\"
                 } else {
                     println!("Available rules:");
                     for rule in &all_rules {
-                        println!("  {} - {}", rule.name(), rule.description());
+                        println!(
+                            "  {} - {}{}",
+                            rule.name(),
+                            rule.description(),
+                            status(rule.as_ref())
+                        );
                     }
                 }
             }
@@ -1303,6 +1384,35 @@ build-backend = \"setuptools.build_meta\"
                                         toml::Value::Boolean(final_config.global.respect_gitignore),
                                         sourced.global.respect_gitignore.source,
                                     )),
+                                    "extensions" => Some((
+                                        toml::Value::Array(
+                                            final_config
+                                                .global
+                                                .extensions
+                                                .iter()
+                                                .map(|s| toml::Value::String(s.clone()))
+                                                .collect(),
+                                        ),
+                                        sourced.global.extensions.source,
+                                    )),
+                                    "strict" => Some((
+                                        toml::Value::Boolean(final_config.global.strict),
+                                        sourced.global.strict.source,
+                                    )),
+                                    "front-matter-delimiters" => Some((
+                                        toml::Value::Array(
+                                            final_config
+                                                .global
+                                                .front_matter_delimiters
+                                                .iter()
+                                                .map(|s| toml::Value::String(s.clone()))
+                                                .collect(),
+                                        ),
+                                        sourced
+                                            .global
+                                            .front_matter_delimiters
+                                            .source,
+                                    )),
                                     _ => None,
                                 };
 
@@ -1828,6 +1938,120 @@ build-backend = \"setuptools.build_meta\"
                     }
                 }
             }
+            Some(Commands::LintConfig { file }) => {
+                let content = match fs::read_to_string(file) {
+                    Ok(content) => content,
+                    Err(e) => {
+                        eprintln!("{}: Failed to read {}: {}", "Error".red().bold(), file, e);
+                        std::process::exit(1);
+                    }
+                };
+                let all_rules = rumdl::rules::all_rules(&rumdl_config::Config::default());
+                let registry = rumdl_config::RuleRegistry::from_rules(&all_rules);
+                let pyproject = Path::new(file)
+                    .file_name()
+                    .is_some_and(|name| name == "pyproject.toml");
+                let problems = rumdl_config::lint_config_document(
+                    &content,
+                    &registry,
+                    Path::new("."),
+                    Path::new(file)
+                        .parent()
+                        .unwrap_or(Path::new(".")),
+                    pyproject,
+                );
+                for problem in &problems {
+                    println!(
+                        "{}:{}: {}",
+                        file.blue().underline(),
+                        problem.line.to_string().cyan(),
+                        problem.message
+                    );
+                }
+                if problems.is_empty() {
+                    println!(
+                        "{} No problems found in {}",
+                        "Success:".green().bold(),
+                        file
+                    );
+                } else {
+                    println!(
+                        "\n{} Found {} problem(s) in {}",
+                        "Issues:".yellow().bold(),
+                        problems.len(),
+                        file
+                    );
+                    std::process::exit(1);
+                }
+            }
+            Some(Commands::Selftest { rules, docs }) => {
+                let registry = rumdl::rules::RuleFactory::new();
+                let mut pages = 0;
+                let mut failed_pages = 0;
+                let mut failures = 0;
+                for rule_name in registry.ids() {
+                    if !rules.is_empty()
+                        && !rules
+                            .iter()
+                            .any(|rule| rule.eq_ignore_ascii_case(rule_name))
+                    {
+                        continue;
+                    }
+                    let path = Path::new(docs).join(format!("{}.md", rule_name.to_lowercase()));
+                    let Ok(doc) = fs::read_to_string(&path) else {
+                        if !rules.is_empty() {
+                            eprintln!(
+                                "{}: No documentation page for {} at {}",
+                                "Error".red().bold(),
+                                rule_name,
+                                path.display()
+                            );
+                            failed_pages += 1;
+                        }
+                        continue;
+                    };
+                    pages += 1;
+                    let page_failures = rumdl::selftest::check_rule_doc(rule_name, &doc, &registry);
+                    for failure in &page_failures {
+                        let claim = match failure.kind {
+                            rumdl::selftest::ExampleKind::Correct => "correct",
+                            rumdl::selftest::ExampleKind::Incorrect => "incorrect",
+                            rumdl::selftest::ExampleKind::Fixed => "fixed",
+                        };
+                        println!(
+                            "{}:{}: [{:5}] {} example reports {} warning(s)",
+                            path.display()
+                                .to_string()
+                                .blue()
+                                .underline(),
+                            failure.line.to_string().cyan(),
+                            rule_name,
+                            claim,
+                            failure.warnings
+                        );
+                    }
+                    if !page_failures.is_empty() {
+                        failed_pages += 1;
+                        failures += page_failures.len();
+                    }
+                }
+                if failed_pages == 0 {
+                    println!(
+                        "{} The examples of {} rule page(s) behave as documented",
+                        "Success:".green().bold(),
+                        pages
+                    );
+                } else {
+                    println!(
+                        "\n{} Found {} failing example(s) in {} of {} rule page(s)",
+                        "Issues:".yellow().bold(),
+                        failures,
+                        failed_pages,
+                        pages
+                    );
+                    std::process::exit(1);
+                }
+            }
             Some(Commands::Vscode { force, status }) => {
                 // Handle VS Code extension installation
                 match rumdl::vscode::handle_vscode_command(*force, *status) {
@@ -1851,14 +2075,38 @@ build-backend = \"setuptools.build_meta\"
                         list_rules: cli.list_rules,
                         disable: cli.disable.clone(),
                         enable: cli.enable.clone(),
+                        select: None,
+                        ignore: None,
                         exclude: cli.exclude.clone(),
                         include: cli.include.clone(),
                         respect_gitignore: cli.respect_gitignore,
+                        max_depth: None,
                         verbose: cli.verbose,
                         profile: cli.profile,
                         quiet: cli.quiet,
                         output: "text".to_string(),
+                        input_format: "markdown".to_string(),
+                        column_units: "chars".to_string(),
                         stdin: false,
+                        stdin_filename: None,
+                        cache_dir: None,
+                        strict: false,
+                        rule_timeout: None,
+                        report_disabled: false,
+                        merge_adjacent: false,
+                        verify_after: false,
+                        diff: false,
+                        unsafe_fixes: false,
+                        changed_only: false,
+                        max_warnings: None,
+                        jobs: None,
+                        report_url: None,
+                        statistics: false,
+                        no_summary: false,
+                        group_by_rule: false,
+                        dedupe: false,
+                        quiet_count: false,
+                        print_config_origin: false,
                     };
                     eprintln!(
                         "{}: Deprecation warning: Running 'rumdl .' or 'rumdl [PATHS...]' without a subcommand is deprecated and will be removed in a future release. Please use 'rumdl check .' instead.",
@@ -1883,83 +2131,6 @@ build-backend = \"setuptools.build_meta\"
     }
 }
 
-/// Process markdown content from stdin
-fn process_stdin(rules: &[Box<dyn Rule>], args: &CheckArgs) {
-    // Read all content from stdin
-    let mut content = String::new();
-    if let Err(e) = io::stdin().read_to_string(&mut content) {
-        if !args.quiet {
-            eprintln!("Error reading from stdin: {}", e);
-        }
-        process::exit(1);
-    }
-
-    // Create a lint context for the stdin content
-    let ctx = LintContext::new(&content);
-    let mut all_warnings = Vec::new();
-
-    // Run all enabled rules on the content
-    for rule in rules {
-        match rule.check(&ctx) {
-            Ok(warnings) => {
-                // Set file path to "<stdin>" for all warnings
-                // The warnings already have the correct character ranges
-                all_warnings.extend(warnings);
-            }
-            Err(e) => {
-                if !args.quiet {
-                    eprintln!("Error running rule {}: {}", rule.name(), e);
-                }
-            }
-        }
-    }
-
-    // Output results
-    if args.output == "json" {
-        // For JSON output, modify warnings to show "<stdin>" as filename
-        let mut json_warnings = Vec::new();
-        for warning in all_warnings {
-            let mut json_warning = serde_json::to_value(&warning).unwrap();
-            if let Some(obj) = json_warning.as_object_mut() {
-                obj.insert(
-                    "file".to_string(),
-                    serde_json::Value::String("<stdin>".to_string()),
-                );
-            }
-            json_warnings.push(json_warning);
-        }
-        println!("{}", serde_json::to_string_pretty(&json_warnings).unwrap());
-    } else {
-        // Text output
-        let has_issues = !all_warnings.is_empty();
-        if has_issues {
-            for warning in &all_warnings {
-                let rule_name = warning.rule_name.unwrap_or("unknown");
-                println!(
-                    "<stdin>:{}:{}: {} {}",
-                    warning.line.to_string().cyan(),
-                    warning.column.to_string().cyan(),
-                    format!("[{:5}]", rule_name).yellow(), // Align rule names consistently
-                    warning.message
-                );
-            }
-        }
-
-        if !args.quiet {
-            if has_issues {
-                println!("\nFound {} issue(s) in stdin", all_warnings.len());
-            } else {
-                println!("No issues found in stdin");
-            }
-        }
-
-        // Exit with error code if issues found
-        if has_issues {
-            process::exit(1);
-        }
-    }
-}
-
 fn run_check(args: &CheckArgs, global_config_path: Option<&str>, no_config: bool) {
     // 1. Determine the directory for config discovery
     // Only use the path's directory for discovery if it's an absolute path
@@ -1995,58 +2166,523 @@ fn run_check(args: &CheckArgs, global_config_path: Option<&str>, no_config: bool
         // Do NOT exit; continue with valid config
     }
 
+    if args.print_config_origin {
+        print_config_origins(&sourced, &registry, args);
+        return;
+    }
+
     // 3. Convert to Config for the rest of the linter
-    let config: rumdl_config::Config = sourced.into();
+    let mut config: rumdl_config::Config = sourced.clone().into();
+    if args.input_format == "ipynb" {
+        config.global.extensions = vec!["ipynb".to_string()];
+    }
+    rumdl::rules::front_matter_utils::FrontMatterUtils::set_configured_delimiters(
+        config
+            .global
+            .front_matter_delimiters
+            .clone(),
+    );
 
     // Initialize rules with configuration
     let enabled_rules = get_enabled_rules_from_checkargs(args, &config);
-
-    // Handle stdin input
-    if args.stdin {
-        process_stdin(&enabled_rules, args);
-        return;
-    }
-
-    // Find all markdown files to check
-    let file_paths = match find_markdown_files(&args.paths, args, &config) {
-        Ok(paths) => paths,
+    let strict = args.strict || config.global.strict;
+    let severities = match rumdl_config::severity_overrides(&config) {
+        Ok(severities) => severities,
         Err(e) => {
-            eprintln!(
-                "{}: Failed to find markdown files: {}",
-                "Error".red().bold(),
-                e
-            );
+            eprintln!("{}: {}", "Config error".red().bold(), e);
             process::exit(1);
         }
     };
-    if file_paths.is_empty() {
-        if !args.quiet {
-            println!("No markdown files found to check.");
-        }
-        return;
+    let all_configured_rules = rumdl::rules::all_rules(&config);
+
+    if args.report_url.is_some() && args.output != "json" {
+        eprintln!(
+            "{}: --report-url requires --output json",
+            "Error".red().bold()
+        );
+        process::exit(1);
     }
 
-    // JSON output mode: collect all warnings and print as JSON
-    if args.output == "json" {
-        let mut all_warnings = Vec::new();
-        for file_path in &file_paths {
-            let warnings = process_file_collect_warnings(
-                file_path,
-                &enabled_rules,
-                args._fix,
-                args.verbose,
-                args.quiet,
-            );
-            all_warnings.extend(warnings);
-        }
-        println!("{}", serde_json::to_string_pretty(&all_warnings).unwrap());
+    // With --changed-only the staged files replace the paths, which only narrow them down
+    let staged = if args.changed_only {
+        match rumdl::git::staged_files(Path::new("."), &args.paths) {
+            Ok(staged) if staged.is_empty() => {
+                if !args.quiet {
+                    println!("No staged files to check.");
+                }
+                return;
+            }
+            Ok(staged) => staged,
+            Err(e) => {
+                eprintln!("{}: {}", "Error".red().bold(), e);
+                process::exit(1);
+            }
+        }
+    } else {
+        Vec::new()
+    };
+    let input_paths: Vec<String> = if args.changed_only {
+        staged
+            .iter()
+            .map(|file| file.path.display().to_string())
+            .collect()
+    } else {
+        args.paths.clone()
+    };
+
+    // Files are read from disk when linted; archive members and stdin are held in memory
+    // under their virtual paths
+    let inputs = match collect_inputs(&input_paths, args, &config) {
+        Ok(inputs) => inputs,
+        Err(e) => {
+            eprintln!("{}: {}", "Error".red().bold(), e);
+            process::exit(1);
+        }
+    };
+    let mut virtual_inputs: std::collections::HashMap<String, String> =
+        std::collections::HashMap::new();
+    let mut file_paths = Vec::with_capacity(inputs.len());
+    let mut input_kinds = Vec::with_capacity(inputs.len());
+    for input in inputs {
+        if let Some(content) = input.content {
+            virtual_inputs.insert(input.path.clone(), content);
+        }
+        // Partially staged files are linted as staged, and so become read-only like stdin
+        let partially_staged = staged.iter().any(|file| {
+            file.has_unstaged_changes
+                && Path::new(
+                    input
+                        .path
+                        .strip_prefix("./")
+                        .unwrap_or(&input.path),
+                ) == file.path
+        });
+        if partially_staged {
+            match rumdl::git::staged_content(Path::new("."), Path::new(&input.path)) {
+                Ok(content) => {
+                    virtual_inputs.insert(input.path.clone(), content);
+                }
+                Err(e) => {
+                    eprintln!("{}: {}", "Error".red().bold(), e);
+                    process::exit(1);
+                }
+            }
+        }
+        input_kinds.push(input.kind);
+        file_paths.push(input.path);
+    }
+
+    let statistics = std::sync::Mutex::new(std::collections::BTreeMap::new());
+    let summary = std::sync::Mutex::new(rumdl::RunSummary::default());
+    let mut lint_options = LintOptions {
+        fix: args._fix,
+        unsafe_fixes: args.unsafe_fixes,
+        verbose: args.verbose,
+        quiet: args.quiet,
+        strict,
+        severities: &severities,
+        rule_timeout: args
+            .rule_timeout
+            .map(Duration::from_millis),
+        report_disabled: None,
+        virtual_inputs: &virtual_inputs,
+        verify_after: args.verify_after,
+        merge_adjacent: args.merge_adjacent,
+        always_report: &config.global.always_report,
+        global: &config.global,
+        statistics: args.statistics.then_some(&statistics),
+        summary: (!args.no_summary).then_some(&summary),
+        grapheme_columns: args.column_units == "graphemes",
+        group_by_rule: args.group_by_rule,
+        dedupe: args.dedupe,
+        cache: None,
+    };
+
+    if file_paths.is_empty() {
+        if !args.quiet {
+            println!("No markdown files found to check.");
+        }
+        return;
+    }
+
+    // Per-directory config discovery: nested .rumdl.toml files are merged over the project
+    // config, closest last, unless one is marked `root = true`.
+    // An explicit --config or --no-config applies a single config to every file.
+    let mut nested_rules: std::collections::HashMap<std::path::PathBuf, Vec<Box<dyn Rule>>> =
+        std::collections::HashMap::new();
+    // Every rule as configured by each nested config, for --report-disabled
+    let mut nested_all_rules: std::collections::HashMap<std::path::PathBuf, Vec<Box<dyn Rule>>> =
+        std::collections::HashMap::new();
+    let mut nested_config_paths: Vec<Option<std::path::PathBuf>> = Vec::new();
+    let mut nested_configs: Vec<String> = Vec::new();
+    if global_config_path.is_none() && !no_config {
+        let cwd = std::env::current_dir().ok();
+        let stop_dir = discovery_dir
+            .and_then(|dir| {
+                if dir.is_dir() {
+                    Some(dir)
+                } else {
+                    dir.parent()
+                }
+            })
+            .or(cwd.as_deref());
+        let resolver = rumdl_config::ConfigResolver::new(stop_dir);
+        for (file_path, kind) in file_paths.iter().zip(&input_kinds) {
+            // Archive members have no directory of their own and use the project config, as
+            // does stdin unless `--stdin-filename` places it
+            let located = match kind {
+                rumdl::file_discovery::InputKind::File => true,
+                rumdl::file_discovery::InputKind::Stdin => args.stdin_filename.is_some(),
+                rumdl::file_discovery::InputKind::ArchiveMember => false,
+            };
+            if !located {
+                nested_config_paths.push(None);
+                continue;
+            }
+            let chain = resolver.config_chain(Path::new(file_path));
+            let nearest = chain.files.last().cloned();
+            if let Some(config_path) = &nearest
+                && !nested_rules.contains_key(config_path)
+            {
+                if args.verbose {
+                    eprintln!("Using nested config: {}", config_path.display());
+                }
+                let mut nested_sourced = if chain.root {
+                    rumdl_config::SourcedConfig::default()
+                } else {
+                    sourced.clone()
+                };
+                for config_file in &chain.files {
+                    if let Err(e) = nested_sourced.merge_nested_file(config_file) {
+                        eprintln!("{}: {}", "Config error".red().bold(), e);
+                        process::exit(1);
+                    }
+                }
+                for warn in rumdl_config::validate_config_sourced(&nested_sourced, &registry) {
+                    eprintln!("\x1b[33m[config warning]\x1b[0m {}", warn.message);
+                }
+                let nested_config: rumdl_config::Config = nested_sourced.into();
+                nested_configs.push(serde_json::to_string(&nested_config).unwrap_or_default());
+                if args.report_disabled {
+                    nested_all_rules
+                        .insert(config_path.clone(), rumdl::rules::all_rules(&nested_config));
+                }
+                nested_rules.insert(
+                    config_path.clone(),
+                    get_enabled_rules_from_checkargs(args, &nested_config),
+                );
+            }
+            nested_config_paths.push(nearest);
+        }
+    }
+    let nearest_config = |i: usize| {
+        nested_config_paths
+            .get(i)
+            .and_then(|nearest| nearest.as_ref())
+    };
+    let files_with_rules: Vec<(&String, &[Box<dyn Rule>])> = file_paths
+        .iter()
+        .enumerate()
+        .map(|(i, file_path)| {
+            let rules = nearest_config(i)
+                .and_then(|config_path| nested_rules.get(config_path))
+                .unwrap_or(&enabled_rules);
+            (file_path, rules.as_slice())
+        })
+        .collect();
+    let report_disabled: Option<std::collections::HashMap<&str, &[Box<dyn Rule>]>> =
+        args.report_disabled.then(|| {
+            file_paths
+                .iter()
+                .enumerate()
+                .map(|(i, file_path)| {
+                    let rules = nearest_config(i)
+                        .and_then(|config_path| nested_all_rules.get(config_path))
+                        .unwrap_or(&all_configured_rules);
+                    (file_path.as_str(), rules.as_slice())
+                })
+                .collect()
+        });
+    lint_options.report_disabled = report_disabled.as_ref();
+
+    // Results cached under another configuration, including the nested ones, are discarded
+    let cache = args.cache_dir.as_ref().map(|dir| {
+        let key = rumdl::cache::config_key(&config, &nested_configs);
+        std::sync::Mutex::new(rumdl::cache::ResultCache::open(Path::new(dir), key))
+    });
+    lint_options.cache = cache.as_ref();
+
+    // Stdin on its own: nothing to report for empty content, and with --fix the fixed
+    // content is written to stdout in place of a file
+    let only_stdin = input_kinds == [rumdl::file_discovery::InputKind::Stdin];
+    if only_stdin
+        && virtual_inputs
+            .values()
+            .all(String::is_empty)
+    {
+        return;
+    }
+    if only_stdin && args._fix && !args.diff {
+        let (file_path, rules) = files_with_rules[0];
+        if let Some((_, fixed, _)) = process_file_fixed_content(file_path, rules, &lint_options) {
+            let remaining = lint_file(file_path, &fixed, rules, &lint_options).unwrap_or_default();
+            save_result_cache(&lint_options);
+            if write_fixed_stdin(file_path, &fixed, &remaining, args.quiet) {
+                process::exit(1);
+            }
+        }
+        return;
+    }
+
+    // Count-only mode: the totals, without printing or building more than needed
+    if args.quiet_count {
+        if args.input_format == "ipynb" {
+            eprintln!(
+                "{}: --quiet-count does not support --input-format ipynb",
+                "Error".red().bold()
+            );
+            process::exit(1);
+        }
+        let (mut total_issues, mut files_with_issues) = (0, 0);
+        for (file_path, rules) in &files_with_rules {
+            let issues = count_file_issues(file_path, rules, &lint_options);
+            total_issues += issues;
+            files_with_issues += usize::from(issues > 0);
+        }
+        println!(
+            "{} {} in {} {}",
+            total_issues,
+            if total_issues == 1 { "issue" } else { "issues" },
+            files_with_issues,
+            if files_with_issues == 1 {
+                "file"
+            } else {
+                "files"
+            }
+        );
+        if total_issues > 0 {
+            process::exit(1);
+        }
+        return;
+    }
+
+    // Notebook input: lint the markdown cells of each notebook, reporting `path[cell N]`
+    if args.input_format == "ipynb" {
+        process_notebooks(&files_with_rules, args, &lint_options);
+        return;
+    }
+
+    // Diff mode: print what --fix would change in each file, leaving the files untouched
+    if args.diff {
+        let mut would_change = false;
+        for (file_path, rules) in &files_with_rules {
+            if let Some((original, fixed, _)) =
+                process_file_fixed_content(file_path, rules, &lint_options)
+            {
+                let diff = rumdl::utils::diff_utils::unified_diff(
+                    &original,
+                    &fixed,
+                    &format!("{} (original)", file_path),
+                    &format!("{} (fixed)", file_path),
+                );
+                would_change |= !diff.is_empty();
+                print!("{}", diff);
+            }
+        }
+        save_result_cache(&lint_options);
+        if would_change {
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    // JSON output mode: collect all warnings and print as JSON
+    if args.output == "json" {
+        let mut all_warnings = Vec::new();
+        let (mut total_issues, mut total_errors) = (0, 0);
+        for (file_path, rules) in &files_with_rules {
+            let (warnings, skipped) =
+                process_file_collect_warnings(file_path, rules, &lint_options);
+            for warning in warnings {
+                if !warning.suppressed {
+                    total_issues += 1;
+                    total_errors += usize::from(warning.severity == rumdl::rule::Severity::Error);
+                }
+                let mut json_warning = rumdl::warning_json(file_path, &warning);
+                // Mark the warnings whose fix was attempted with --fix but not applied
+                if let Some(skip) = skipped.iter().find(|skip| {
+                    warning.rule_name == Some(skip.rule.as_str())
+                        && (warning.line, warning.column) == (skip.line, skip.column)
+                }) && let Some(obj) = json_warning.as_object_mut()
+                {
+                    obj.insert(
+                        "fix_skipped".to_string(),
+                        serde_json::Value::String(skip.reason.clone()),
+                    );
+                }
+                all_warnings.push(json_warning);
+            }
+        }
+        let json = serde_json::to_string_pretty(&all_warnings).unwrap();
+        println!("{}", json);
+        // On stderr, keeping stdout valid JSON
+        let summary = summary.lock().unwrap();
+        if !args.no_summary && !args.quiet && summary.problems > 0 {
+            eprintln!("{}", summary);
+        }
+        if let Some(url) = &args.report_url {
+            report_results(url, &json);
+        }
+        // Fail as the text output does, within the --max-warnings budget if one is given
+        let failed = match args.max_warnings {
+            Some(max) => {
+                total_issues > 0 && rumdl::exceeds_max_warnings(total_issues, total_errors, max)
+            }
+            None => total_issues > 0,
+        };
+        save_result_cache(&lint_options);
+        if failed {
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    // SARIF output mode: one SARIF 2.1.0 report covering every file
+    if args.output == "sarif" {
+        let mut results = Vec::new();
+        for (file_path, rules) in &files_with_rules {
+            // Fixes are located in the content before `--fix` applies them
+            let content = read_input(file_path, &lint_options).unwrap_or_default();
+            let (warnings, _) = process_file_collect_warnings(file_path, rules, &lint_options);
+            results.push((file_path.as_str(), content, warnings));
+        }
+        let results: Vec<(&str, &str, &[rumdl::rule::LintWarning])> = results
+            .iter()
+            .map(|(file_path, content, warnings)| {
+                (*file_path, content.as_str(), warnings.as_slice())
+            })
+            .collect();
+        let rules: Vec<&dyn Rule> = all_configured_rules
+            .iter()
+            .map(|rule| rule.as_ref())
+            .collect();
+        let report = rumdl::sarif::sarif_report(&results, &rules);
+        println!("{}", serde_json::to_string_pretty(&report).unwrap());
+        save_result_cache(&lint_options);
+        if results
+            .iter()
+            .any(|(_, _, warnings)| warnings.iter().any(|w| !w.suppressed))
+        {
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    // Review output mode: preview each warning's fix with its surrounding lines
+    if args.output == "review" {
+        let mut has_issues = false;
+        for (file_path, rules) in &files_with_rules {
+            let content = match read_input(file_path, &lint_options) {
+                Ok(content) => content,
+                Err(e) => {
+                    if !args.quiet {
+                        eprintln!("Error reading file {}: {}", file_path, e);
+                    }
+                    continue;
+                }
+            };
+            let warnings = lint_file(file_path, &content, rules, &lint_options).unwrap_or_default();
+            for warning in warnings
+                .iter()
+                .filter(|w| !w.suppressed)
+            {
+                has_issues = true;
+                let Some(fix) = &warning.fix else {
+                    continue;
+                };
+                println!(
+                    "{}:{}:{}: {} {}{}",
+                    file_path.blue().underline(),
+                    warning.line.to_string().cyan(),
+                    display_column(&content, warning, lint_options.grapheme_columns)
+                        .to_string()
+                        .cyan(),
+                    format!("[{:5}]", warning.rule_name.unwrap_or("unknown")).yellow(),
+                    warning.message,
+                    warning_id_suffix(warning).dimmed()
+                );
+                for line in rumdl::utils::diff_utils::fix_preview(&content, fix, 2).lines() {
+                    match line.chars().next() {
+                        Some('-') => println!("{}", line.red()),
+                        Some('+') => println!("{}", line.green()),
+                        _ => println!("{}", line.dimmed()),
+                    }
+                }
+                println!();
+            }
+        }
+        save_result_cache(&lint_options);
+        if has_issues {
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    // Rules-fired output mode: one `path: MD001,MD002` line per file with issues
+    if args.output == "rules-fired" {
+        let mut has_issues = false;
+        for (file_path, rules) in &files_with_rules {
+            let Ok(content) = read_input(file_path, &lint_options) else {
+                continue;
+            };
+            let warnings = lint_file(file_path, &content, rules, &lint_options).unwrap_or_default();
+            let fired = rumdl::rules_fired(&warnings);
+            if !fired.is_empty() {
+                has_issues = true;
+                println!("{}: {}", file_path, fired.join(","));
+            }
+        }
+        save_result_cache(&lint_options);
+        if has_issues {
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    // Patch output mode: print the fixes for all files as one git-apply-able patch
+    if args.output == "patch" {
+        let mut has_issues = false;
+        let mut patch_files = Vec::new();
+        for (file_path, rules) in &files_with_rules {
+            if virtual_inputs.contains_key(file_path.as_str()) {
+                if !args.quiet {
+                    eprintln!("Skipping {}: patches apply to files on disk", file_path);
+                }
+                continue;
+            }
+            if let Some((original, fixed, file_has_issues)) =
+                process_file_fixed_content(file_path, rules, &lint_options)
+            {
+                has_issues |= file_has_issues;
+                patch_files.push((patch_path(file_path), original, fixed));
+            }
+        }
+        print!("{}", rumdl::utils::diff_utils::git_patch(&patch_files));
+        save_result_cache(&lint_options);
+        if has_issues {
+            std::process::exit(1);
+        }
         return;
     }
 
     let start_time = Instant::now();
+    let mut verify_failed = false;
+    let mut total_fixes_skipped = 0;
+    let mut total_errors = 0;
 
     // Choose processing strategy based on file count and fix mode
     let use_parallel = file_paths.len() > 1 && !args._fix; // Don't parallelize fixes due to file I/O conflicts
+    let jobs = args.jobs.map(|jobs| jobs as usize);
 
     let (
         has_issues,
@@ -2056,21 +2692,15 @@ fn run_check(args: &CheckArgs, global_config_path: Option<&str>, no_config: bool
         total_fixable_issues,
         total_files_processed,
     ) = if use_parallel {
-        // Parallel processing for multiple files without fixes
-        let enabled_rules_arc = Arc::new(enabled_rules);
-
-        let results: Vec<_> = file_paths
-            .par_iter()
-            .map(|file_path| {
-                process_file(
-                    file_path,
-                    &enabled_rules_arc,
-                    args._fix,
-                    args.verbose,
-                    args.quiet,
-                )
-            })
-            .collect();
+        // Parallel processing for multiple files without fixes. Each file's warnings are
+        // printed once all files are linted, in the order of the files, so the output is
+        // the same as when linting them one after another.
+        let results =
+            rumdl::parallel::map_in_order(&files_with_rules, jobs, |(file_path, rules)| {
+                let mut out = String::new();
+                let result = process_file(file_path, rules, &lint_options, &mut out);
+                (result, out)
+            });
 
         // Aggregate results
         let mut has_issues = false;
@@ -2080,14 +2710,18 @@ fn run_check(args: &CheckArgs, global_config_path: Option<&str>, no_config: bool
         let mut total_fixable_issues = 0;
         let total_files_processed = results.len();
 
-        for (file_has_issues, issues_found, issues_fixed, fixable_issues) in results {
-            total_issues_fixed += issues_fixed;
-            total_fixable_issues += fixable_issues;
+        for (result, out) in results {
+            print!("{}", out);
+            verify_failed |= result.verify_failed;
+            total_fixes_skipped += result.fixes_skipped;
+            total_errors += result.errors;
+            total_issues_fixed += result.fixed;
+            total_fixable_issues += result.fixable;
 
-            if file_has_issues {
+            if result.issues > 0 {
                 has_issues = true;
                 files_with_issues += 1;
-                total_issues += issues_found;
+                total_issues += result.issues;
             }
         }
 
@@ -2107,27 +2741,38 @@ fn run_check(args: &CheckArgs, global_config_path: Option<&str>, no_config: bool
         let mut total_issues_fixed = 0;
         let mut total_fixable_issues = 0;
         let mut total_files_processed = 0;
-
-        for file_path in &file_paths {
-            let (file_has_issues, issues_found, issues_fixed, fixable_issues) = process_file(
-                file_path,
-                &enabled_rules,
-                args._fix,
-                args.verbose,
-                args.quiet,
-            );
+        let mut restage = Vec::new();
+
+        for (file_path, rules) in &files_with_rules {
+            let result = {
+                let mut out = String::new();
+                let result = process_file(file_path, rules, &lint_options, &mut out);
+                print!("{}", out);
+                result
+            };
+            verify_failed |= result.verify_failed;
+            total_fixes_skipped += result.fixes_skipped;
+            total_errors += result.errors;
+            if args.changed_only && result.fixed > 0 {
+                restage.push(std::path::PathBuf::from(file_path.as_str()));
+            }
 
             total_files_processed += 1;
-            total_issues_fixed += issues_fixed;
-            total_fixable_issues += fixable_issues;
+            total_issues_fixed += result.fixed;
+            total_fixable_issues += result.fixable;
 
-            if file_has_issues {
+            if result.issues > 0 {
                 has_issues = true;
                 files_with_issues += 1;
-                total_issues += issues_found;
+                total_issues += result.issues;
             }
         }
 
+        if let Err(e) = rumdl::git::stage(Path::new("."), &restage) {
+            eprintln!("{}: {}", "Error".red().bold(), e);
+            process::exit(1);
+        }
+
         (
             has_issues,
             files_with_issues,
@@ -2138,6 +2783,7 @@ fn run_check(args: &CheckArgs, global_config_path: Option<&str>, no_config: bool
         )
     };
 
+    save_result_cache(&lint_options);
     let duration = start_time.elapsed();
     let duration_ms = duration.as_secs() * 1000 + duration.subsec_millis() as u64;
 
@@ -2151,10 +2797,20 @@ fn run_check(args: &CheckArgs, global_config_path: Option<&str>, no_config: bool
             total_issues_fixed,
             total_fixable_issues,
             total_files_processed,
+            total_fixes_skipped,
             duration_ms,
         });
     }
 
+    if args.statistics && !args.quiet {
+        print_statistics(&statistics.lock().unwrap(), &all_configured_rules);
+    }
+
+    let summary = summary.lock().unwrap();
+    if !args.no_summary && !args.quiet && summary.problems > 0 {
+        println!("\n{}", summary);
+    }
+
     // Print profiling information if enabled and not in quiet mode
     if args.profile && !args.quiet {
         match std::panic::catch_unwind(rumdl::profiling::get_report) {
@@ -2163,165 +2819,648 @@ fn run_check(args: &CheckArgs, global_config_path: Option<&str>, no_config: bool
         }
     }
 
+    // Fixes rejected by --verify-after take precedence over ordinary issues
+    if verify_failed {
+        std::process::exit(2);
+    }
+
+    // Fixed and re-staged issues do not fail a --changed-only --fix run, such as a
+    // pre-commit hook; only the issues still found in the fixed files do
+    let has_issues = if args.changed_only && args._fix {
+        has_issues
+            && files_with_rules
+                .iter()
+                .any(|(file_path, rules)| {
+                    read_input(file_path, &lint_options).is_ok_and(|content| {
+                        lint_file(file_path, &content, rules, &lint_options)
+                            .unwrap_or_default()
+                            .iter()
+                            .any(|w| !w.suppressed)
+                    })
+                })
+    } else {
+        has_issues
+    };
+
+    // With --max-warnings, issues only fail the run once they exceed the budget
+    let has_issues = match args.max_warnings {
+        Some(max) => {
+            let exceeded =
+                has_issues && rumdl::exceeds_max_warnings(total_issues, total_errors, max);
+            if has_issues && !args.quiet {
+                if total_errors > 0 {
+                    println!(
+                        "{} {} errors fail the run regardless of --max-warnings {}",
+                        "Failed:".red().bold(),
+                        total_errors,
+                        max
+                    );
+                } else if exceeded {
+                    println!(
+                        "{} {} issues exceed --max-warnings {}",
+                        "Failed:".red().bold(),
+                        total_issues,
+                        max
+                    );
+                } else {
+                    println!(
+                        "{} {} issues are within --max-warnings {}",
+                        "Passed:".green().bold(),
+                        total_issues,
+                        max
+                    );
+                }
+            }
+            exceeded
+        }
+        None => has_issues,
+    };
+
     // Exit with non-zero status if issues were found
     if has_issues {
         std::process::exit(1);
     }
 }
 
+/// Settings shared by the per-file processing functions
+struct LintOptions<'a> {
+    fix: bool,
+    /// Apply unsafe fixes as well, with `--unsafe-fixes`
+    unsafe_fixes: bool,
+    verbose: bool,
+    quiet: bool,
+    strict: bool,
+    /// Severities configured per rule with its `severity` key
+    severities: &'a std::collections::HashMap<String, rumdl::rule::Severity>,
+    rule_timeout: Option<Duration>,
+    /// All rules as configured for each file (by its nested config, if any), keyed by the
+    /// file path. Set with `--report-disabled`, so that the rules not enabled for a file
+    /// can report what they would have found
+    report_disabled: Option<&'a std::collections::HashMap<&'a str, &'a [Box<dyn Rule>]>>,
+    /// Contents of inputs held in memory (archive members and stdin), keyed by their
+    /// virtual path
+    virtual_inputs: &'a std::collections::HashMap<String, String>,
+    /// Re-lint fixed content and refuse fixes that introduce new warnings
+    verify_after: bool,
+    /// Merge adjacent warnings from the same rule into one
+    merge_adjacent: bool,
+    /// Rules whose warnings are printed even in quiet mode
+    always_report: &'a [String],
+    /// Global settings, for the rules limited to one side of managed regions
+    global: &'a rumdl::config::GlobalConfig,
+    /// Issue counts per rule, collected with `--statistics`
+    statistics: Option<&'a std::sync::Mutex<std::collections::BTreeMap<&'static str, usize>>>,
+    /// Problems found in the run, summarized at its end unless `--no-summary` is given
+    summary: Option<&'a std::sync::Mutex<rumdl::RunSummary>>,
+    /// Print columns counted in grapheme clusters rather than chars
+    grapheme_columns: bool,
+    /// List warnings in a section per rule, with `--group-by-rule`
+    group_by_rule: bool,
+    /// Show repeated warnings once, with `--dedupe`
+    dedupe: bool,
+    /// Results of previous runs, kept with `--cache-dir`
+    cache: Option<&'a std::sync::Mutex<rumdl::cache::ResultCache>>,
+}
+
+/// Lint, and with --fix rewrite, the markdown cells of Jupyter notebooks. Cells are numbered
+/// from 1 counting every cell, as the notebook shows them; exits with status 1 on issues.
+fn process_notebooks(
+    files_with_rules: &[(&String, &[Box<dyn Rule>])],
+    args: &CheckArgs,
+    opts: &LintOptions,
+) {
+    let mut total_issues = 0;
+    let mut total_fixed = 0;
+    let mut files_with_issues = 0;
+    for (file_path, rules) in files_with_rules {
+        let notebook = read_input(file_path, opts)
+            .map_err(|e| e.to_string())
+            .and_then(|content| {
+                rumdl::notebook::Notebook::parse(&content).map_err(|e| e.to_string())
+            });
+        let mut notebook = match notebook {
+            Ok(notebook) => notebook,
+            Err(e) => {
+                eprintln!("Error reading file {}: {}", file_path, e);
+                continue;
+            }
+        };
+        let warnings = match notebook.lint(rules) {
+            Ok(warnings) => warnings,
+            Err(e) => {
+                eprintln!("Error linting {}: {}", file_path, e);
+                continue;
+            }
+        };
+        let warnings: Vec<_> = warnings
+            .into_iter()
+            .filter(|w| !w.warning.suppressed)
+            .collect();
+        if warnings.is_empty() {
+            continue;
+        }
+        files_with_issues += 1;
+        total_issues += warnings.len();
+
+        let mut fixed = 0;
+        if opts.fix
+            && !opts
+                .virtual_inputs
+                .contains_key(file_path.as_str())
+        {
+            fixed = notebook.fix(rules, &warnings, opts.unsafe_fixes, &mut Vec::new());
+            if fixed > 0
+                && let Err(e) = std::fs::write(file_path, notebook.to_json())
+            {
+                eprintln!("Error writing file {}: {}", file_path, e);
+                fixed = 0;
+            }
+        }
+        total_fixed += fixed;
+
+        if !opts.quiet {
+            let cells = notebook.markdown_cells();
+            for cell_warning in &warnings {
+                let warning = &cell_warning.warning;
+                let source = cells
+                    .iter()
+                    .find(|(cell, _)| *cell == cell_warning.cell)
+                    .map_or("", |(_, source)| source.as_str());
+                let fix_indicator = match &warning.fix {
+                    Some(fix) if !fix.is_applicable(opts.unsafe_fixes) => " [unsafe fix]",
+                    Some(_) if fixed > 0 => " [fixed]",
+                    Some(_) => " [*]",
+                    None => "",
+                };
+                println!(
+                    "{}[cell {}]:{}:{}: {} {}{}{}",
+                    file_path.blue().underline(),
+                    cell_warning.cell + 1,
+                    warning.line.to_string().cyan(),
+                    display_column(source, warning, opts.grapheme_columns)
+                        .to_string()
+                        .cyan(),
+                    format!("[{:5}]", warning.rule_name.unwrap_or("unknown")).yellow(),
+                    warning.message,
+                    fix_indicator.green(),
+                    warning_id_suffix(warning).dimmed()
+                );
+            }
+        }
+    }
+
+    if !args.quiet {
+        if total_issues == 0 {
+            println!(
+                "\n{} No issues found in {} notebook(s)",
+                "Success:".green().bold(),
+                files_with_rules.len()
+            );
+        } else if opts.fix {
+            println!(
+                "\n{} Found {} issue(s) in {} notebook(s), fixed {}",
+                "Issues:".yellow().bold(),
+                total_issues,
+                files_with_issues,
+                total_fixed
+            );
+        } else {
+            println!(
+                "\n{} Found {} issue(s) in {} notebook(s)",
+                "Issues:".yellow().bold(),
+                total_issues,
+                files_with_issues
+            );
+        }
+    }
+    if total_issues > total_fixed {
+        process::exit(1);
+    }
+}
+
+/// POST the JSON results to `url` for --report-url. Failures are logged to stderr only,
+/// so they never change the exit status.
+fn report_results(url: &str, json: &str) {
+    use rumdl::webhook;
+    let result = webhook::parse_url(url).and_then(|url| {
+        let proxy = webhook::proxy_from_env(&url)?;
+        webhook::post_json(&url, proxy.as_ref(), json, Duration::from_secs(10), 3)
+    });
+    if let Err(e) = result {
+        eprintln!("{} {}", "Warning:".yellow().bold(), e);
+    }
+}
+
+/// Read a file to lint, taking archive members and stdin from memory
+fn read_input(file_path: &str, opts: &LintOptions) -> Result<String, Box<dyn Error>> {
+    match opts.virtual_inputs.get(file_path) {
+        Some(content) => Ok(content.clone()),
+        None => read_file_efficiently(Path::new(file_path)),
+    }
+}
+
+/// Save the results kept with `--cache-dir`, if any. Failing to save only costs the next
+/// run its cache, so it is reported as a warning.
+fn save_result_cache(opts: &LintOptions) {
+    let Some(cache) = opts.cache else {
+        return;
+    };
+    if let Err(e) = cache
+        .lock()
+        .map_err(|_| std::io::Error::other("cache lock poisoned"))
+        .and_then(|cache| cache.save())
+    {
+        eprintln!(
+            "{}: Failed to save the cache: {}",
+            "Warning".yellow().bold(),
+            e
+        );
+    }
+}
+
+/// Lint the content of one file, applying the rule timeout, `--report-disabled`, the
+/// configured rule severities and `--strict` from `opts`. Rules skipped for running too long are reported on stderr.
+fn lint_file(
+    file_path: &str,
+    content: &str,
+    rules: &[Box<dyn Rule>],
+    opts: &LintOptions,
+) -> rumdl::rule::LintResult {
+    let include_suppressed = opts.report_disabled.is_some();
+    let run = |rules: &[Box<dyn Rule>]| match opts.rule_timeout {
+        Some(timeout) => rumdl::lint_with_timeout(content, rules, timeout, include_suppressed).map(
+            |(warnings, timed_out)| {
+                if !opts.quiet {
+                    for rule_name in timed_out {
+                        eprintln!(
+                            "Note: skipped rule {} on {}: exceeded the {}ms rule timeout",
+                            rule_name,
+                            file_path,
+                            timeout.as_millis()
+                        );
+                    }
+                }
+                warnings
+            },
+        ),
+        None if include_suppressed => {
+            rumdl::lint_including_suppressed(content, rules, opts.verbose)
+        }
+        None => rumdl::lint(content, rules, opts.verbose),
+    };
+
+    // Set the environment variable for the file path
+    // This allows rules like MD057 to know which file is being processed
+    unsafe { std::env::set_var("RUMDL_FILE_PATH", file_path) };
+
+    // Cached results are those of plain linting, without timeouts or suppressed warnings.
+    // Rules reading other files, whose results can change with those files, always run.
+    let cache = opts
+        .cache
+        .filter(|_| opts.rule_timeout.is_none() && !include_suppressed);
+    let mut result = match cache {
+        Some(cache) => {
+            let (cached_rules, uncached) = rumdl::cache::split_cacheable(rules);
+            let cached = cache
+                .lock()
+                .ok()
+                .and_then(|mut cache| cache.get(file_path, content, &cached_rules));
+            let result = match cached {
+                Some(warnings) => Ok(warnings),
+                None => {
+                    let result = run(&cached_rules);
+                    if let (Ok(warnings), Ok(mut cache)) = (&result, cache.lock()) {
+                        cache.insert(file_path, content, &cached_rules, warnings);
+                    }
+                    result
+                }
+            };
+            result.and_then(|mut warnings| {
+                if !uncached.is_empty() {
+                    warnings.extend(run(&uncached)?);
+                    rumdl::sort_warnings(&mut warnings);
+                }
+                Ok(warnings)
+            })
+        }
+        None => run(rules),
+    };
+    if let (Ok(warnings), Some(all_rules)) = (
+        &mut result,
+        opts.report_disabled
+            .and_then(|all_rules| all_rules.get(file_path)),
+    ) {
+        let disabled_rules: Vec<Box<dyn Rule>> = all_rules
+            .iter()
+            .filter(|rule| {
+                !rules
+                    .iter()
+                    .any(|r| r.name() == rule.name())
+            })
+            .cloned()
+            .collect();
+        let mut disabled_warnings = run(&disabled_rules).unwrap_or_default();
+        rumdl::mark_suppressed(&mut disabled_warnings);
+        warnings.extend(disabled_warnings);
+        rumdl::sort_warnings(warnings);
+    }
+
+    // Clear the environment variable after processing
+    unsafe { std::env::remove_var("RUMDL_FILE_PATH") };
+
+    result = result.map(|warnings| rumdl::filter_managed_regions(content, warnings, opts.global));
+    if let Ok(warnings) = &mut result {
+        rumdl::apply_severity_overrides(warnings, opts.severities);
+        if opts.strict {
+            rumdl::apply_strict(warnings);
+        }
+    }
+    if opts.merge_adjacent {
+        result = result.map(|warnings| rumdl::merge_adjacent_warnings(content, warnings));
+    }
+    result
+}
+
+/// The number of issues found in one file, for `--quiet-count`. Unless options such as a
+/// rule timeout, `--merge-adjacent` or managed regions change which warnings are reported,
+/// the rules only count their issues, with [`rumdl::lint_count`].
+fn count_file_issues(file_path: &str, rules: &[Box<dyn Rule>], opts: &LintOptions) -> usize {
+    let content = match read_input(file_path, opts) {
+        Ok(content) => content,
+        Err(e) => {
+            eprintln!("Error reading file {}: {}", file_path, e);
+            return 0;
+        }
+    };
+    let count_only = opts.rule_timeout.is_none()
+        && !opts.merge_adjacent
+        && opts.global.managed_only.is_empty()
+        && opts.global.unmanaged_only.is_empty();
+    if !count_only {
+        return lint_file(file_path, &content, rules, opts)
+            .unwrap_or_default()
+            .iter()
+            .filter(|w| !w.suppressed)
+            .count();
+    }
+
+    // Rules like MD057 look up the file being linted, as in `lint_file`
+    unsafe { std::env::set_var("RUMDL_FILE_PATH", file_path) };
+    let count = rumdl::lint_count(&content, rules).unwrap_or_default();
+    unsafe { std::env::remove_var("RUMDL_FILE_PATH") };
+    count
+}
+
+/// What linting (and with --fix, fixing) one file found
+#[derive(Debug, Default)]
+struct FileResult {
+    /// Warnings reported, counting the ones that were fixed
+    issues: usize,
+    /// Of `issues`, the ones reported as errors
+    errors: usize,
+    fixed: usize,
+    fixable: usize,
+    /// Fixes that were attempted but not applied
+    fixes_skipped: usize,
+    /// `--verify-after` rejected the fixed content
+    verify_failed: bool,
+}
+
 // Process file operation
+/// Lint (and with --fix, fix) one file, writing its warnings as printed in text output
+/// to `out`
 fn process_file(
     file_path: &str,
     rules: &[Box<dyn Rule>],
-    _fix: bool,
-    verbose: bool,
-    quiet: bool,
-) -> (bool, usize, usize, usize) {
+    opts: &LintOptions,
+    out: &mut String,
+) -> FileResult {
+    use std::fmt::Write as _;
     use std::time::Instant;
 
+    let LintOptions {
+        fix: _fix,
+        verbose,
+        quiet,
+        ..
+    } = *opts;
+    // Fixes cannot be written back into an archive or stdin
+    let is_virtual = opts
+        .virtual_inputs
+        .contains_key(file_path);
+    let _fix = _fix && !is_virtual;
+
     let start_time = Instant::now();
     if verbose && !quiet {
         println!("Processing file: {}", file_path);
     }
 
     // Read file content efficiently
-    let mut content = match read_file_efficiently(Path::new(file_path)) {
+    let mut content = match read_input(file_path, opts) {
         Ok(content) => content,
         Err(e) => {
             if !quiet {
                 eprintln!("Error reading file {}: {}", file_path, e);
             }
-            return (false, 0, 0, 0);
+            return FileResult::default();
         }
     };
 
     // Early content analysis for ultra-fast skip decisions
     if content.is_empty() {
-        return (false, 0, 0, 0);
+        return FileResult::default();
     }
 
     let lint_start = Instant::now();
-    // Set the environment variable for the file path
-    // This allows rules like MD057 to know which file is being processed
-    unsafe { std::env::set_var("RUMDL_FILE_PATH", file_path) };
+    let all_warnings = lint_file(file_path, &content, rules, opts).unwrap_or_default();
 
-    // Use the standard lint function
-    let warnings_result = rumdl::lint(&content, rules, verbose);
-
-    // Clear the environment variable after processing
-    unsafe { std::env::remove_var("RUMDL_FILE_PATH") };
-
-    // Combine all warnings
-    let mut all_warnings = warnings_result.unwrap_or_default();
-
-    // Sort warnings by line number, then column
-    all_warnings.sort_by(|a, b| {
-        if a.line == b.line {
-            a.column.cmp(&b.column)
-        } else {
-            a.line.cmp(&b.line)
-        }
-    });
+    if let Some(statistics) = opts.statistics {
+        rumdl::count_warnings_by_rule(&all_warnings, &mut statistics.lock().unwrap());
+    }
+    // Suppressed warnings (from --report-disabled) are shown but never count as issues
+    let total_warnings = all_warnings
+        .iter()
+        .filter(|w| !w.suppressed)
+        .count();
 
-    let total_warnings = all_warnings.len();
+    let error_warnings = all_warnings
+        .iter()
+        .filter(|w| w.severity == rumdl::rule::Severity::Error && !w.suppressed)
+        .count();
 
     // Count fixable issues
     let fixable_warnings = all_warnings
         .iter()
-        .filter(|w| w.fix.is_some())
+        .filter(|w| {
+            w.fix
+                .as_ref()
+                .is_some_and(|fix| fix.is_applicable(opts.unsafe_fixes))
+                && !w.suppressed
+        })
         .count();
 
     // If no warnings, return early
-    if total_warnings == 0 {
-        return (false, 0, 0, 0);
+    if all_warnings.is_empty() {
+        return FileResult::default();
     }
 
-    // Print warnings regardless of fix mode (unless in quiet mode)
-    if !quiet {
+    if opts.fix && is_virtual && fixable_warnings > 0 && !quiet {
+        eprintln!(
+            "{} Not fixing {}: archive members, stdin and partially staged files are read-only",
+            "Warning:".yellow().bold(),
+            file_path
+        );
+    }
+
+    // Print warnings regardless of fix mode (in quiet mode, only those of always-report rules)
+    for group in rumdl::group_warnings(
+        all_warnings
+            .iter()
+            .filter(|w| !quiet || rumdl::is_always_reported(w, opts.always_report)),
+        opts.group_by_rule,
+        opts.dedupe,
+    ) {
+        if let Some(rule_name) = group.rule_name {
+            let _ = writeln!(
+                out,
+                "{}: {} {} warning(s)",
+                file_path.blue().underline(),
+                format!("[{}]", rule_name).yellow(),
+                group.len()
+            );
+        }
         // Print the individual warnings
-        for warning in &all_warnings {
+        for shown in &group.shown {
+            let warning = shown.warning;
             let rule_name = warning.rule_name.unwrap_or("unknown");
 
             // Add fix indicator if this warning has a fix
-            let fix_indicator = if warning.fix.is_some() {
-                if _fix { " [fixed]" } else { " [*]" }
+            let fix_indicator = if warning.suppressed {
+                " [suppressed]"
+            } else if let Some(fix) = &warning.fix {
+                if !fix.is_applicable(opts.unsafe_fixes) {
+                    " [unsafe fix]"
+                } else if _fix {
+                    " [fixed]"
+                } else {
+                    " [*]"
+                }
             } else {
                 ""
             };
 
-            // Print the warning in the format: file:line:column: [rule] message [*]
-            println!(
-                "{}:{}:{}: {} {}{}",
+            let column = display_column(&content, warning, opts.grapheme_columns);
+            if group.rule_name.is_some() {
+                // Under the rule's heading: line:column: message (and N more) [*] id
+                let _ = writeln!(
+                    out,
+                    "  {}:{}: {}{}{}{}",
+                    warning.line.to_string().cyan(),
+                    column.to_string().cyan(),
+                    warning.message,
+                    shown.more_suffix().dimmed(),
+                    fix_indicator.green(),
+                    warning_id_suffix(warning).dimmed()
+                );
+                continue;
+            }
+
+            // Print the warning in the format: file:line:column: [rule] message [*] id
+            let _ = writeln!(
+                out,
+                "{}:{}:{}: {} {}{}{}{}",
                 file_path.blue().underline(),
                 warning.line.to_string().cyan(),
-                warning.column.to_string().cyan(),
+                column.to_string().cyan(),
                 format!("[{:5}]", rule_name).yellow(), // Pad rule name to 5 characters for alignment
                 warning.message,
-                fix_indicator.green()
+                shown.more_suffix().dimmed(),
+                fix_indicator.green(),
+                warning_id_suffix(warning).dimmed()
             );
         }
     }
 
     // Fix issues if requested
     let mut warnings_fixed = 0;
+    let mut fixes_skipped = 0;
+    let mut verify_failed = false;
+    let mut remaining = None;
     if _fix {
-        // Apply fixes for rules that have warnings, regardless of whether individual warnings have fixes
-        for rule in rules {
-            let rule_warnings: Vec<_> = all_warnings
-                .iter()
-                .filter(|w| w.rule_name == Some(rule.name()))
-                .collect();
-
-            if !rule_warnings.is_empty() {
-                // Check if any warnings for this rule are in non-disabled regions
-                let has_non_disabled_warnings = rule_warnings.iter().any(|w| {
-                    !rumdl::rule::is_rule_disabled_at_line(
-                        &content,
-                        rule.name(),
-                        w.line.saturating_sub(1), // Convert to 0-based line index
-                    )
-                });
-
-                if has_non_disabled_warnings {
-                    let ctx = LintContext::new(&content);
-                    match rule.fix(&ctx) {
-                        Ok(fixed_content) => {
-                            if fixed_content != content {
-                                content = fixed_content;
-                                // Apply fixes for this rule - we consider all warnings for the rule fixed
-                                warnings_fixed += rule_warnings.len();
-                            }
-                        }
-                        Err(err) => {
-                            if !quiet {
-                                eprintln!(
-                                    "{} Failed to apply fix for rule {}: {}",
-                                    "Warning:".yellow().bold(),
-                                    rule.name(),
-                                    err
-                                );
-                            }
-                        }
-                    }
+        let original = opts
+            .verify_after
+            .then(|| content.clone());
+        let mut skipped = Vec::new();
+        warnings_fixed = rumdl::apply_fixes_in_regions(
+            &mut content,
+            rules,
+            &all_warnings,
+            opts.global,
+            opts.unsafe_fixes,
+            &mut skipped,
+        );
+        report_skipped_fixes(file_path, &skipped, quiet);
+        fixes_skipped = skipped.len();
+
+        // With --verify-after, the fixed content must not have warnings the original lacked
+        if let Some(original) = original
+            && warnings_fixed > 0
+        {
+            let fixed_warnings = lint_file(file_path, &content, rules, opts).unwrap_or_default();
+            let introduced = rumdl::introduced_warnings(&all_warnings, &fixed_warnings);
+            if !introduced.is_empty() {
+                eprintln!(
+                    "{} Fixes for {} introduced {} new issue(s); the file was not changed:",
+                    "Error:".red().bold(),
+                    file_path,
+                    introduced.len()
+                );
+                for warning in introduced {
+                    eprintln!(
+                        "  {}:{}:{}: [{:5}] {}",
+                        file_path,
+                        warning.line,
+                        warning.column,
+                        warning.rule_name.unwrap_or("unknown"),
+                        warning.message
+                    );
                 }
+                content = original;
+                warnings_fixed = 0;
+                verify_failed = true;
+            } else {
+                remaining = Some(fixed_warnings);
             }
         }
 
         // Write fixed content back to file
         if warnings_fixed > 0 {
-            if let Err(err) = std::fs::write(file_path, &content) {
-                if !quiet {
-                    eprintln!(
-                        "{} Failed to write fixed content to file {}: {}",
-                        "Error:".red().bold(),
-                        file_path,
-                        err
-                    );
-                }
+            if remaining.is_none() && opts.summary.is_some() {
+                remaining = Some(lint_file(file_path, &content, rules, opts).unwrap_or_default());
+            }
+            if let Err(err) = std::fs::write(file_path, &content)
+                && !quiet
+            {
+                eprintln!(
+                    "{} Failed to write fixed content to file {}: {}",
+                    "Error:".red().bold(),
+                    file_path,
+                    err
+                );
             }
         }
     }
 
+    // After --fix, the summary counts the problems that remain
+    if let Some(summary) = opts.summary {
+        summary.lock().unwrap().add_file(
+            remaining
+                .as_deref()
+                .unwrap_or(&all_warnings),
+            opts.unsafe_fixes,
+        );
+    }
+
     let lint_end_time = Instant::now();
     let lint_time = lint_end_time.duration_since(lint_start);
 
@@ -2334,41 +3473,236 @@ fn process_file(
         println!("Total processing time for {}: {:?}", file_path, total_time);
     }
 
-    (true, total_warnings, warnings_fixed, fixable_warnings)
+    FileResult {
+        issues: total_warnings,
+        errors: error_warnings,
+        fixed: warnings_fixed,
+        fixable: fixable_warnings,
+        fixes_skipped,
+        verify_failed,
+    }
+}
+
+/// Print the `--statistics` breakdown: the issues reported by each rule, most first
+fn print_statistics(
+    counts: &std::collections::BTreeMap<&'static str, usize>,
+    rules: &[Box<dyn Rule>],
+) {
+    if counts.is_empty() {
+        return;
+    }
+    let mut counts: Vec<_> = counts.iter().collect();
+    counts.sort_by(|a, b| b.1.cmp(a.1).then(a.0.cmp(b.0)));
+    println!("\n{}", "Statistics:".bold());
+    for (rule_name, count) in counts {
+        let description = rules
+            .iter()
+            .find(|rule| rule.name() == *rule_name)
+            .map_or("", |rule| rule.description());
+        println!(
+            "  {} {:>5}  {}",
+            format!("{:5}", rule_name).yellow(),
+            count,
+            description
+        );
+    }
+}
+
+/// The column printed for `warning` in text output: counted in chars, or in grapheme
+/// clusters with `--column-units graphemes`. `content` is the text the warning is in.
+fn display_column(content: &str, warning: &rumdl::rule::LintWarning, graphemes: bool) -> usize {
+    if !graphemes {
+        return warning.column;
+    }
+    let line = content
+        .lines()
+        .nth(warning.line.saturating_sub(1))
+        .unwrap_or_default();
+    rumdl::utils::range_utils::grapheme_column(line, warning.column)
 }
 
+/// The warning's id as printed after its message, e.g. ` MD050-3f9a1c2e`
+fn warning_id_suffix(warning: &rumdl::rule::LintWarning) -> String {
+    warning
+        .id
+        .as_deref()
+        .map(|id| format!(" {}", id))
+        .unwrap_or_default()
+}
+
+/// Print the fixes that were attempted on a file but not applied, unless in quiet mode
+fn report_skipped_fixes(file_path: &str, skipped: &[rumdl::rule::SkippedFix], quiet: bool) {
+    if quiet {
+        return;
+    }
+    for skip in skipped {
+        eprintln!(
+            "{} Skipped fix for {}:{}:{}: [{:5}] {}",
+            "Warning:".yellow().bold(),
+            file_path,
+            skip.line,
+            skip.column,
+            skip.rule,
+            skip.reason
+        );
+    }
+}
+
+/// Write content read from stdin, as fixed by --fix, to stdout, reporting the issues the
+/// fixes left on stderr. Returns whether there are any.
+fn write_fixed_stdin(
+    name: &str,
+    fixed: &str,
+    remaining: &[rumdl::rule::LintWarning],
+    quiet: bool,
+) -> bool {
+    print!("{}", fixed);
+    let remaining: Vec<_> = remaining
+        .iter()
+        .filter(|w| !w.suppressed)
+        .collect();
+    if !quiet {
+        for warning in &remaining {
+            eprintln!(
+                "{}:{}:{}: [{:5}] {}",
+                name,
+                warning.line,
+                warning.column,
+                warning.rule_name.unwrap_or("unknown"),
+                warning.message
+            );
+        }
+    }
+    !remaining.is_empty()
+}
+
+/// Lint a file and compute its fixed content without writing it back.
+///
+/// Returns the original content, the fixed content and whether any issues were found,
+/// or `None` if the file could not be read.
+fn process_file_fixed_content(
+    file_path: &str,
+    rules: &[Box<dyn Rule>],
+    opts: &LintOptions,
+) -> Option<(String, String, bool)> {
+    let LintOptions { verbose, quiet, .. } = *opts;
+    if verbose && !quiet {
+        eprintln!("Processing file: {}", file_path);
+    }
+
+    let original = match read_input(file_path, opts) {
+        Ok(content) => content,
+        Err(e) => {
+            if !quiet {
+                eprintln!("Error reading file {}: {}", file_path, e);
+            }
+            return None;
+        }
+    };
+
+    let warnings = lint_file(file_path, &original, rules, opts).unwrap_or_default();
+    let has_issues = warnings.iter().any(|w| !w.suppressed);
+
+    let mut fixed = original.clone();
+    if has_issues {
+        let mut skipped = Vec::new();
+        rumdl::apply_fixes_in_regions(
+            &mut fixed,
+            rules,
+            &warnings,
+            opts.global,
+            opts.unsafe_fixes,
+            &mut skipped,
+        );
+        report_skipped_fixes(file_path, &skipped, quiet);
+    }
+    Some((original, fixed, has_issues))
+}
+
+/// Path of a file as it should appear in a patch: relative to the current directory
+/// when possible, with forward slashes.
+fn patch_path(file_path: &str) -> String {
+    let path = Path::new(file_path);
+    let relative = std::env::current_dir()
+        .ok()
+        .and_then(|cwd| {
+            path.strip_prefix(cwd)
+                .ok()
+                .map(Path::to_path_buf)
+        })
+        .unwrap_or_else(|| path.to_path_buf());
+    let relative = relative.to_string_lossy();
+    relative
+        .strip_prefix("./")
+        .unwrap_or(&relative)
+        .replace('\\', "/")
+}
+
+/// Lint a file for structured output, applying fixes with `--fix`.
+///
+/// Returns the warnings found and the fixes that were attempted but not applied.
 fn process_file_collect_warnings(
     file_path: &str,
     rules: &[Box<dyn Rule>],
-    _fix: bool,
-    verbose: bool,
-    quiet: bool,
-) -> Vec<rumdl::rule::LintWarning> {
+    opts: &LintOptions,
+) -> (Vec<rumdl::rule::LintWarning>, Vec<rumdl::rule::SkippedFix>) {
+    let LintOptions { verbose, quiet, .. } = *opts;
     if verbose && !quiet {
         println!("Processing file: {}", file_path);
     }
 
     // Read file content efficiently
-    let content = match read_file_efficiently(Path::new(file_path)) {
+    let content = match read_input(file_path, opts) {
         Ok(content) => content,
         Err(e) => {
             if !quiet {
                 eprintln!("Error reading file {}: {}", file_path, e);
             }
-            return Vec::new();
+            return (Vec::new(), Vec::new());
         }
     };
 
-    unsafe { std::env::set_var("RUMDL_FILE_PATH", file_path) };
-    let warnings_result = rumdl::lint(&content, rules, verbose);
-    unsafe { std::env::remove_var("RUMDL_FILE_PATH") };
-    let mut all_warnings = warnings_result.unwrap_or_default();
-    all_warnings.sort_by(|a, b| {
-        if a.line == b.line {
-            a.column.cmp(&b.column)
-        } else {
-            a.line.cmp(&b.line)
+    let warnings = lint_file(file_path, &content, rules, opts).unwrap_or_default();
+    let mut skipped = Vec::new();
+    let mut remaining = None;
+    if opts.fix
+        && !opts
+            .virtual_inputs
+            .contains_key(file_path)
+    {
+        let mut fixed = content.clone();
+        if rumdl::apply_fixes_in_regions(
+            &mut fixed,
+            rules,
+            &warnings,
+            opts.global,
+            opts.unsafe_fixes,
+            &mut skipped,
+        ) > 0
+        {
+            if let Err(err) = std::fs::write(file_path, &fixed)
+                && !quiet
+            {
+                eprintln!(
+                    "{} Failed to write fixed content to file {}: {}",
+                    "Error:".red().bold(),
+                    file_path,
+                    err
+                );
+            }
+            if opts.summary.is_some() {
+                remaining = Some(lint_file(file_path, &fixed, rules, opts).unwrap_or_default());
+            }
         }
-    });
-    all_warnings
+    }
+    // After --fix, the summary counts the problems that remain
+    if let Some(summary) = opts.summary {
+        summary.lock().unwrap().add_file(
+            remaining
+                .as_deref()
+                .unwrap_or(&warnings),
+            opts.unsafe_fixes,
+        );
+    }
+    (warnings, skipped)
 }
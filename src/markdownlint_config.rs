@@ -86,6 +86,7 @@ fn markdownlint_to_rumdl_rule_key(key: &str) -> Option<&'static str> {
         "MD056" | "TABLE-COLUMN-COUNT" => Some("MD056"),
         "MD057" | "EXISTING-RELATIVE-LINKS" => Some("MD057"),
         "MD058" | "BLANKS-AROUND-TABLES" => Some("MD058"),
+        "MD063" | "HEADING-CAPITALIZATION" => Some("MD063"),
         _ => None,
     }
 }
@@ -0,0 +1,226 @@
+//! Linting the Markdown cells of Jupyter notebooks, for `--input-format ipynb`.
+//!
+//! Each markdown cell is linted as a document of its own, so warnings keep lines and columns
+//! relative to their cell and carry the cell's index. Fixes rewrite the cell's `source` in
+//! place; code cells, outputs and metadata are left as they were.
+
+use crate::rule::{LintError, LintWarning, Rule, SkippedFix};
+use serde_json::Value;
+
+/// Errors raised while reading a notebook
+#[derive(Debug, thiserror::Error)]
+pub enum NotebookError {
+    /// The file is not valid JSON
+    #[error("Invalid notebook JSON: {0}")]
+    Json(#[from] serde_json::Error),
+
+    /// The JSON is not shaped like a notebook
+    #[error("Invalid notebook: {0}")]
+    Format(String),
+}
+
+/// A warning reported in a markdown cell
+#[derive(Debug, Clone, PartialEq)]
+pub struct CellWarning {
+    /// Index of the cell among all cells of the notebook, from 0
+    pub cell: usize,
+    /// The warning, with lines and columns relative to the cell
+    pub warning: LintWarning,
+}
+
+/// A parsed notebook
+#[derive(Debug, Clone)]
+pub struct Notebook {
+    json: Value,
+}
+
+impl Notebook {
+    /// Parse notebook JSON, which must hold a `cells` array
+    pub fn parse(content: &str) -> Result<Self, NotebookError> {
+        let json: Value = serde_json::from_str(content)?;
+        if !json
+            .get("cells")
+            .is_some_and(Value::is_array)
+        {
+            return Err(NotebookError::Format("missing \"cells\" array".to_string()));
+        }
+        Ok(Self { json })
+    }
+
+    fn cells(&self) -> &[Value] {
+        self.json["cells"]
+            .as_array()
+            .map_or(&[], Vec::as_slice)
+    }
+
+    /// The markdown cells as (cell index, source) pairs. A source stored as a list of
+    /// lines is joined.
+    pub fn markdown_cells(&self) -> Vec<(usize, String)> {
+        self.cells()
+            .iter()
+            .enumerate()
+            .filter(|(_, cell)| cell["cell_type"] == "markdown")
+            .map(|(index, cell)| (index, cell_source(cell)))
+            .collect()
+    }
+
+    /// Lint every markdown cell with `rules`
+    pub fn lint(&self, rules: &[Box<dyn Rule>]) -> Result<Vec<CellWarning>, LintError> {
+        let mut warnings = Vec::new();
+        for (cell, source) in self.markdown_cells() {
+            warnings.extend(
+                crate::lint(&source, rules, false)?
+                    .into_iter()
+                    .map(|warning| CellWarning { cell, warning }),
+            );
+        }
+        Ok(warnings)
+    }
+
+    /// Apply the fixes of `rules` to every markdown cell, given the warnings from
+    /// [`Notebook::lint`], unsafe fixes only with `unsafe_fixes`. Returns the number of
+    /// warnings fixed; fixes that were not applied are appended to `skipped`.
+    pub fn fix(
+        &mut self,
+        rules: &[Box<dyn Rule>],
+        warnings: &[CellWarning],
+        unsafe_fixes: bool,
+        skipped: &mut Vec<SkippedFix>,
+    ) -> usize {
+        let mut fixed = 0;
+        for (cell, mut source) in self.markdown_cells() {
+            let cell_warnings: Vec<LintWarning> = warnings
+                .iter()
+                .filter(|w| w.cell == cell)
+                .map(|w| w.warning.clone())
+                .collect();
+            if cell_warnings.is_empty() {
+                continue;
+            }
+            let cell_fixed =
+                crate::apply_rule_fixes(&mut source, rules, &cell_warnings, unsafe_fixes, skipped);
+            if cell_fixed > 0 {
+                fixed += cell_fixed;
+                set_cell_source(&mut self.json["cells"][cell], &source);
+            }
+        }
+        fixed
+    }
+
+    /// Serialize the notebook as Jupyter writes it: sorted keys, one-space indentation and
+    /// a trailing newline
+    pub fn to_json(&self) -> String {
+        use serde::Serialize;
+        let mut out = Vec::new();
+        let formatter = serde_json::ser::PrettyFormatter::with_indent(b" ");
+        let mut serializer = serde_json::Serializer::with_formatter(&mut out, formatter);
+        self.json
+            .serialize(&mut serializer)
+            .expect("serializing a JSON value cannot fail");
+        let mut json = String::from_utf8(out).expect("serde_json writes UTF-8");
+        json.push('\n');
+        json
+    }
+}
+
+fn cell_source(cell: &Value) -> String {
+    match &cell["source"] {
+        Value::String(source) => source.clone(),
+        Value::Array(lines) => lines
+            .iter()
+            .filter_map(Value::as_str)
+            .collect(),
+        _ => String::new(),
+    }
+}
+
+// Store `source` in the same shape the cell used: one string, or a list of lines that
+// keep their line endings
+fn set_cell_source(cell: &mut Value, source: &str) {
+    cell["source"] = if cell["source"].is_string() {
+        Value::String(source.to_string())
+    } else {
+        Value::Array(
+            source
+                .split_inclusive('\n')
+                .map(|line| Value::String(line.to_string()))
+                .collect(),
+        )
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::MD050StrongStyle;
+    use crate::rules::strong_style::StrongStyle;
+
+    const NOTEBOOK: &str = r##"{
+ "cells": [
+  {
+   "cell_type": "code",
+   "execution_count": null,
+   "metadata": {},
+   "outputs": [],
+   "source": [
+    "x = __name__\n"
+   ]
+  },
+  {
+   "cell_type": "markdown",
+   "metadata": {},
+   "source": [
+    "# Results\n",
+    "\n",
+    "The **first** and __second__ runs\n"
+   ]
+  }
+ ],
+ "metadata": {},
+ "nbformat": 4,
+ "nbformat_minor": 5
+}
+"##;
+
+    #[test]
+    fn test_lint_and_fix_markdown_cell() {
+        let rules: Vec<Box<dyn Rule>> = vec![Box::new(MD050StrongStyle::new(
+            StrongStyle::Asterisk,
+        ))];
+        let mut notebook = Notebook::parse(NOTEBOOK).unwrap();
+        assert_eq!(notebook.to_json(), NOTEBOOK);
+
+        let warnings = notebook.lint(&rules).unwrap();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].cell, 1);
+        assert_eq!(
+            (warnings[0].warning.line, warnings[0].warning.column),
+            (3, 19)
+        );
+
+        let fixed = notebook.fix(&rules, &warnings, false, &mut Vec::new());
+        assert_eq!(fixed, 1);
+        assert_eq!(
+            notebook.to_json(),
+            NOTEBOOK.replace("__second__", "**second**")
+        );
+        assert!(
+            notebook
+                .lint(&rules)
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_parse_rejects_non_notebook_json() {
+        assert!(matches!(
+            Notebook::parse("{\"metadata\": {}}"),
+            Err(NotebookError::Format(_))
+        ));
+        assert!(matches!(
+            Notebook::parse("not json"),
+            Err(NotebookError::Json(_))
+        ));
+    }
+}
@@ -58,30 +58,17 @@ impl FileParallelProcessor {
                 .collect());
         }
 
-        // Set up thread pool if specified
-        if let Some(thread_count) = self.config.thread_count {
-            rayon::ThreadPoolBuilder::new()
-                .num_threads(thread_count)
-                .build_global()
-                .unwrap_or_else(|_| {
-                    log::warn!("Failed to set thread pool size to {}", thread_count)
-                });
-        }
-
-        let results: Vec<(String, LintResult)> = files
-            .par_iter()
-            .map(|(path, content)| {
-                let start = Instant::now();
-                let result = crate::lint(content, rules, false);
-                let duration = start.elapsed();
+        let results = map_in_order(files, self.config.thread_count, |(path, content)| {
+            let start = Instant::now();
+            let result = crate::lint(content, rules, false);
+            let duration = start.elapsed();
 
-                if duration.as_millis() > 1000 {
-                    log::debug!("File {} took {:?}", path, duration);
-                }
+            if duration.as_millis() > 1000 {
+                log::debug!("File {} took {:?}", path, duration);
+            }
 
-                (path.clone(), result)
-            })
-            .collect();
+            (path.clone(), result)
+        });
 
         Ok(results)
     }
@@ -107,6 +94,34 @@ impl FileParallelProcessor {
     }
 }
 
+/// Map `f` over `items` on up to `jobs` threads, or one per logical core if `None`,
+/// returning the results in the order of `items` whichever thread produced them. With a
+/// single job, `items` are processed in turn on the calling thread.
+///
+/// Rules may be shared by all threads: their caches, such as the name cache of
+/// `MD044ProperNames`, sit behind a mutex and only hold results that do not depend on which
+/// file computed them.
+pub fn map_in_order<T, R, F>(items: &[T], jobs: Option<usize>, f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync + Send,
+{
+    if jobs == Some(1) || items.len() < 2 {
+        return items.iter().map(f).collect();
+    }
+    match rayon::ThreadPoolBuilder::new()
+        .num_threads(jobs.unwrap_or(0))
+        .build()
+    {
+        Ok(pool) => pool.install(|| items.par_iter().map(f).collect()),
+        Err(e) => {
+            log::warn!("Failed to start {:?} worker threads: {}", jobs, e);
+            items.iter().map(f).collect()
+        }
+    }
+}
+
 /// Performance comparison utilities
 pub struct ParallelPerformanceComparison {
     pub sequential_time: std::time::Duration,
@@ -213,4 +228,45 @@ mod tests {
             assert!(result.is_ok());
         }
     }
+
+    #[test]
+    fn test_map_in_order_matches_sequential_linting() {
+        let mut config = Config::default();
+        config.rules.insert(
+            "MD044".to_string(),
+            crate::config::RuleConfig {
+                values: [(
+                    "names".to_string(),
+                    toml::Value::Array(vec![toml::Value::String("GitHub".to_string())]),
+                )]
+                .into_iter()
+                .collect(),
+            },
+        );
+        // One shared set of rules, so MD044's cache is used from every thread
+        let rules = all_rules(&config);
+        let files: Vec<(String, String)> = (0..50)
+            .map(|i| {
+                let content = match i % 3 {
+                    0 => format!("# File {}\n\nSee github and __bold__ text.\n", i),
+                    1 => format!("#File {}\n\n- item  \n* other\n", i),
+                    _ => format!("# File {}\n\nNothing on GitHub to report.\n", i),
+                };
+                (format!("docs/file{:02}.md", i), content)
+            })
+            .collect();
+
+        let lint = |(path, content): &(String, String)| {
+            (path.clone(), crate::lint(content, &rules, false).unwrap())
+        };
+        let sequential = map_in_order(&files, Some(1), lint);
+        let parallel = map_in_order(&files, Some(4), lint);
+        assert_eq!(parallel, sequential);
+        assert_eq!(map_in_order(&files, None, lint), sequential);
+        assert!(sequential.iter().any(|(_, warnings)| {
+            warnings
+                .iter()
+                .any(|w| w.rule_name == Some("MD044"))
+        }));
+    }
 }
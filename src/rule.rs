@@ -3,7 +3,8 @@
 //! Includes rule categories, dynamic dispatch helpers, and inline comment handling for rule enable/disable.
 
 use dyn_clone::DynClone;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
 use std::ops::Range;
 use thiserror::Error;
 
@@ -50,18 +51,181 @@ pub struct LintWarning {
     pub severity: Severity,
     pub fix: Option<Fix>,
     pub rule_name: Option<&'static str>,
+    /// Anchor within the rule's documentation page for this specific violation
+    /// (e.g. `asterisk-style`), for rules that document several subcases
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub doc_anchor: Option<String>,
+    /// Set when the warning would have been reported by a disabled rule or in an
+    /// inline-disabled region, and is only shown because suppressed warnings were requested
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub suppressed: bool,
+    /// Stable id of the warning, e.g. `MD050-3f9a1c2e`, set by the linter. It fingerprints
+    /// the flagged text and its surroundings rather than the line, so it survives edits
+    /// elsewhere in the file and can be given in place of a rule id in inline
+    /// `rumdl-disable` comments.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+}
+
+impl LintWarning {
+    /// A warning reported by `rule_name` over the given 1-indexed span, with `Warning`
+    /// severity and no fix. The linter sets `suppressed` and `id` later.
+    pub fn new(
+        rule_name: &'static str,
+        line: usize,
+        column: usize,
+        end_line: usize,
+        end_column: usize,
+        message: impl Into<String>,
+    ) -> Self {
+        Self {
+            message: message.into(),
+            line,
+            column,
+            end_line,
+            end_column,
+            severity: Severity::Warning,
+            fix: None,
+            rule_name: Some(rule_name),
+            doc_anchor: None,
+            suppressed: false,
+            id: None,
+        }
+    }
+
+    pub fn with_severity(mut self, severity: Severity) -> Self {
+        self.severity = severity;
+        self
+    }
+
+    /// Attach a fix (or `None`, for rules that only sometimes have one)
+    pub fn with_fix(mut self, fix: impl Into<Option<Fix>>) -> Self {
+        self.fix = fix.into();
+        self
+    }
+
+    /// Point the documentation link at `anchor` within the rule's page
+    pub fn with_doc_anchor(mut self, anchor: impl Into<String>) -> Self {
+        self.doc_anchor = Some(anchor.into());
+        self
+    }
+
+    /// Canonical ordering of warnings: by line, column, end line, end column, rule name
+    /// and message. Every place that sorts warnings uses this, so output is identical
+    /// across formatters regardless of the order rules ran in.
+    pub fn canonical_cmp(&self, other: &Self) -> Ordering {
+        (
+            self.line,
+            self.column,
+            self.end_line,
+            self.end_column,
+            self.rule_name,
+            &self.message,
+        )
+            .cmp(&(
+                other.line,
+                other.column,
+                other.end_line,
+                other.end_column,
+                other.rule_name,
+                &other.message,
+            ))
+    }
 }
 
 #[derive(Debug, PartialEq, Clone, Serialize)]
 pub struct Fix {
     pub range: Range<usize>,
     pub replacement: String,
+    pub safety: FixSafety,
+}
+
+impl Fix {
+    pub fn new(range: Range<usize>, replacement: impl Into<String>, safety: FixSafety) -> Self {
+        Self {
+            range,
+            replacement: replacement.into(),
+            safety,
+        }
+    }
+
+    /// A fix that `--fix` applies without `--unsafe-fixes`
+    pub fn safe(range: Range<usize>, replacement: impl Into<String>) -> Self {
+        Self::new(range, replacement, FixSafety::Safe)
+    }
+
+    /// Whether `--fix` applies this fix, given whether `--unsafe-fixes` was passed
+    pub fn is_applicable(&self, unsafe_fixes: bool) -> bool {
+        self.safety == FixSafety::Safe || unsafe_fixes
+    }
+}
+
+/// Whether a fix can be applied without review
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FixSafety {
+    /// Only changes how the content is written, such as the emphasis markers used
+    Safe,
+    /// May change what the content says, such as a word that was meant literally; only
+    /// applied with `--unsafe-fixes`
+    Unsafe,
+}
+
+/// A fix that a rule attempted but did not apply, with the reason it was skipped
+#[derive(Debug, PartialEq, Clone, Serialize)]
+pub struct SkippedFix {
+    pub rule: String,
+    pub line: usize,   // 1-indexed line of the warning whose fix was skipped
+    pub column: usize, // 1-indexed column of the warning whose fix was skipped
+    pub reason: String,
 }
 
-#[derive(Debug, PartialEq, Clone, Copy, Serialize)]
+#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum Severity {
     Error,
     Warning,
+    Info,
+}
+
+/// Where the rule documentation pages are published, unless configured otherwise
+pub const DEFAULT_DOC_BASE_URL: &str = "https://github.com/rvben/rumdl/blob/main/docs";
+
+/// The URL of a rule's documentation page, at `anchor` if given
+pub fn doc_url(rule_name: &str, anchor: Option<&str>) -> String {
+    doc_url_in(DEFAULT_DOC_BASE_URL, rule_name, anchor)
+}
+
+/// The URL of a rule's documentation page among the pages at `base_url`, at `anchor` if given
+pub fn doc_url_in(base_url: &str, rule_name: &str, anchor: Option<&str>) -> String {
+    let anchor = anchor
+        .map(|anchor| format!("#{}", anchor))
+        .unwrap_or_default();
+    format!(
+        "{}/{}.md{}",
+        base_url.trim_end_matches('/'),
+        rule_name.to_lowercase(),
+        anchor
+    )
+}
+
+/// Whether `warning`, reported for `ctx`, overlaps or touches the byte `range`
+pub fn warning_in_range(ctx: &LintContext, warning: &LintWarning, range: &Range<usize>) -> bool {
+    // Byte offset of a 1-indexed line and character column
+    let offset = |line: usize, column: usize| {
+        let Some(&line_start) = ctx
+            .line_offsets
+            .get(line.saturating_sub(1))
+        else {
+            return ctx.content.len();
+        };
+        ctx.content[line_start..]
+            .char_indices()
+            .nth(column.saturating_sub(1))
+            .map_or(ctx.content.len(), |(i, _)| line_start + i)
+    };
+    offset(warning.line, warning.column) <= range.end
+        && range.start <= offset(warning.end_line, warning.end_column)
 }
 
 /// Type of rule for selective processing
@@ -88,6 +252,60 @@ pub trait Rule: DynClone + Send + Sync {
     fn check(&self, ctx: &LintContext) -> LintResult;
     fn fix(&self, ctx: &LintContext) -> Result<String, LintError>;
 
+    /// Categories the rule belongs to, such as `"whitespace"`, `"style"` or `"content"`.
+    /// `check --select` and `--ignore` accept them as well as rule ids.
+    fn tags(&self) -> &[&'static str] {
+        &[]
+    }
+
+    /// Fix the content like [`Rule::fix`], recording fixes that were attempted but not
+    /// applied in `skipped` instead of dropping them silently.
+    /// By default, calls the regular fix method if not overridden
+    fn fix_with_report(
+        &self,
+        ctx: &LintContext,
+        _skipped: &mut Vec<SkippedFix>,
+    ) -> Result<String, LintError> {
+        self.fix(ctx)
+    }
+
+    /// Check only around the byte `range`: the warnings [`Rule::check`] would report that
+    /// overlap or touch it, at the same positions. By default the whole document is checked
+    /// and the other warnings dropped; rules that can skip the rest cheaply override this.
+    fn check_range(&self, ctx: &LintContext, range: Range<usize>) -> LintResult {
+        Ok(self
+            .check(ctx)?
+            .into_iter()
+            .filter(|w| warning_in_range(ctx, w, &range))
+            .collect())
+    }
+
+    /// The number of warnings [`Rule::check`] reports. By default the warnings are built and
+    /// counted; rules that can count their violations without building warnings override
+    /// this, and must return exactly as many.
+    fn check_count(&self, ctx: &LintContext) -> Result<usize, LintError> {
+        Ok(self.check(ctx)?.len())
+    }
+
+    /// Whether an edit anywhere in the document can change the rule's warnings elsewhere,
+    /// e.g. when the expected style is taken from its first use. [`crate::lint_range`]
+    /// reports every warning of such rules instead of those around the range.
+    fn is_document_wide(&self) -> bool {
+        false
+    }
+
+    /// Whether the rule's warnings depend on other files than the one checked, e.g. the
+    /// files its links point to. Results of such rules are never cached by `--cache-dir`.
+    fn reads_other_files(&self) -> bool {
+        false
+    }
+
+    /// A copy of the rule that shares no caches with it, for rules whose clones share state
+    /// behind an `Arc`. `None` when a plain clone already shares nothing.
+    fn uncached_copy(&self) -> Option<Box<dyn Rule>> {
+        None
+    }
+
     /// Enhanced check method using document structure
     /// By default, calls the regular check method if not overridden
     fn check_with_structure(
@@ -152,6 +370,13 @@ pub trait Rule: DynClone + Send + Sync {
         None
     }
 
+    /// Whether the rule runs when the configuration says nothing about it.
+    /// Config can override this with the global `enable`/`disable` lists or a per-rule
+    /// `enabled` key; see [`crate::config::rule_enabled`].
+    fn default_enabled(&self) -> bool {
+        true
+    }
+
     /// Factory: create a rule from config (if present), or use defaults.
     fn from_config(_config: &crate::config::Config) -> Box<dyn Rule>
     where
@@ -182,38 +407,11 @@ impl<R: Rule + 'static> RuleExt for Box<R> {
     }
 }
 
-/// Check if a rule is disabled at a specific line via inline comments
+/// Check if a rule is disabled at a specific 0-indexed line via inline comments, as
+/// [`InlineConfig`](crate::inline_config::InlineConfig) decides it for warnings
 pub fn is_rule_disabled_at_line(content: &str, rule_name: &str, line_num: usize) -> bool {
-    let lines: Vec<&str> = content.lines().collect();
-    let mut is_disabled = false;
-
-    // Check for both markdownlint-disable and rumdl-disable comments
-    for (i, line) in lines.iter().enumerate() {
-        // Stop processing once we reach the target line
-        if i > line_num {
-            break;
-        }
-
-        let line = line.trim();
-
-        // Check for disable comments (both global and rule-specific)
-        if let Some(rules) = parse_disable_comment(line) {
-            if rules.is_empty() || rules.contains(&rule_name) {
-                is_disabled = true;
-                continue;
-            }
-        }
-
-        // Check for enable comments (both global and rule-specific)
-        if let Some(rules) = parse_enable_comment(line) {
-            if rules.is_empty() || rules.contains(&rule_name) {
-                is_disabled = false;
-                continue;
-            }
-        }
-    }
-
-    is_disabled
+    crate::inline_config::InlineConfig::from_content(content)
+        .is_rule_disabled(rule_name, line_num + 1)
 }
 
 /// Parse a disable comment and return the list of rules (empty vec means all rules)
@@ -383,10 +581,101 @@ pub trait AstExtensions {
     fn has_relevant_ast_elements(&self, ctx: &LintContext, ast: &MarkdownAst) -> bool;
 }
 
+#[cfg(test)]
+type CheckFn = dyn Fn(&LintContext) -> LintResult + Send + Sync;
+#[cfg(test)]
+type FixFn = dyn Fn(&LintContext) -> Result<String, LintError> + Send + Sync;
+
+/// A rule for tests, defined by closures. Its fix applies the fixes of its warnings
+/// unless one is given.
+#[cfg(test)]
+#[derive(Clone)]
+pub(crate) struct TestRule {
+    name: &'static str,
+    check: std::sync::Arc<CheckFn>,
+    fix: Option<std::sync::Arc<FixFn>>,
+}
+
+#[cfg(test)]
+impl TestRule {
+    pub(crate) fn new(
+        name: &'static str,
+        check: impl Fn(&LintContext) -> LintResult + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            name,
+            check: std::sync::Arc::new(check),
+            fix: None,
+        }
+    }
+
+    pub(crate) fn with_fix(
+        mut self,
+        fix: impl Fn(&LintContext) -> Result<String, LintError> + Send + Sync + 'static,
+    ) -> Self {
+        self.fix = Some(std::sync::Arc::new(fix));
+        self
+    }
+}
+
+#[cfg(test)]
+impl Rule for TestRule {
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn description(&self) -> &'static str {
+        "Test rule"
+    }
+
+    fn check(&self, ctx: &LintContext) -> LintResult {
+        (self.check)(ctx)
+    }
+
+    fn fix(&self, ctx: &LintContext) -> Result<String, LintError> {
+        match &self.fix {
+            Some(fix) => fix(ctx),
+            None => crate::utils::fix_utils::apply_warning_fixes(ctx.content, &self.check(ctx)?)
+                .map_err(LintError::FixFailed),
+        }
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_canonical_cmp_ignores_insertion_order() {
+        let warning = |line, column, end_column, rule, message: &str| {
+            LintWarning::new(rule, line, column, line, end_column, message)
+        };
+        let expected = vec![
+            warning(1, 1, 5, "MD050", "b"),
+            warning(2, 3, 4, "MD044", "a"),
+            warning(2, 3, 4, "MD050", "a"),
+            warning(2, 3, 4, "MD050", "b"),
+            warning(2, 3, 9, "MD044", "a"),
+            warning(2, 7, 8, "MD001", "a"),
+            warning(10, 1, 2, "MD044", "a"),
+        ];
+
+        let mut reversed: Vec<_> = expected.iter().rev().cloned().collect();
+        reversed.sort_by(LintWarning::canonical_cmp);
+        assert_eq!(reversed, expected);
+
+        let mut shuffled: Vec<_> = [3, 6, 0, 4, 1, 5, 2]
+            .iter()
+            .map(|&i| expected[i].clone())
+            .collect();
+        shuffled.sort_by(LintWarning::canonical_cmp);
+        assert_eq!(shuffled, expected);
+    }
+
     #[test]
     fn test_parse_disable_comment() {
         // Test rumdl-disable global
@@ -474,7 +763,8 @@ Final line"#;
         // Test MD042 enabled at line 8 (0-indexed line 7)
         assert!(!is_rule_disabled_at_line(content, "MD042", 8));
 
-        // Test rule that's never disabled
-        assert!(!is_rule_disabled_at_line(content, "MD001", 5));
+        // Test rule that's never disabled, except on the comment lines themselves
+        assert!(!is_rule_disabled_at_line(content, "MD001", 4));
+        assert!(is_rule_disabled_at_line(content, "MD001", 5));
     }
 }
@@ -239,4 +239,21 @@ impl BlockquoteUtils {
     pub fn get_blockquote_content(line: &str) -> String {
         Self::extract_content(line)
     }
+
+    /// Strip every nested blockquote marker from a line, returning the innermost content.
+    /// Each `>` may be preceded by up to three spaces and followed by one optional space,
+    /// so indentation beyond that (e.g. an indented code block) is preserved.
+    pub fn strip_markers(line: &str) -> &str {
+        let mut rest = line;
+        loop {
+            let indent = rest.len() - rest.trim_start_matches(' ').len();
+            if indent > 3 {
+                return rest;
+            }
+            match rest[indent..].strip_prefix('>') {
+                Some(after) => rest = after.strip_prefix(' ').unwrap_or(after),
+                None => return rest,
+            }
+        }
+    }
 }
@@ -1,6 +1,7 @@
 use lazy_static::lazy_static;
 use regex::Regex;
 use std::collections::HashMap;
+use std::ops::Range;
 
 lazy_static! {
     // Standard front matter delimiter (three dashes)
@@ -25,6 +26,12 @@ lazy_static! {
 
     // Front matter field pattern
     static ref FRONT_MATTER_FIELD: Regex = Regex::new(r"^([^:]+):\s*(.*)$").unwrap();
+
+    // A YAML mapping key, quoted or plain, up to the `:` that ends it
+    static ref YAML_KEY: Regex = Regex::new(
+        r#"^(?:"(?:[^"\\]|\\.)*"|'(?:[^']|'')*'|[^\s'"\[\]{},#][^#]*?)\s*:(?:\s|$)"#
+    )
+    .unwrap();
 }
 
 /// Represents the type of front matter found in a document
@@ -42,10 +49,77 @@ pub enum FrontMatterType {
     None,
 }
 
+/// The delimiters configured for the run, see [`FrontMatterUtils::set_configured_delimiters`]
+static CONFIGURED_DELIMITERS: std::sync::RwLock<Option<Vec<String>>> = std::sync::RwLock::new(None);
+
 /// Utility functions for detecting and handling front matter in Markdown documents
 pub struct FrontMatterUtils;
 
 impl FrontMatterUtils {
+    /// Front matter delimiters recognized by default: YAML `---`, TOML `+++`, JSON `;;;`,
+    /// and `{`, which opens a JSON object closed by a line holding only `}`
+    pub const DEFAULT_DELIMITERS: &'static [&'static str] = &["---", "+++", ";;;", "{"];
+
+    /// The default delimiters as owned strings, for configuration defaults
+    pub fn default_delimiters() -> Vec<String> {
+        Self::DEFAULT_DELIMITERS
+            .iter()
+            .map(|d| d.to_string())
+            .collect()
+    }
+
+    /// Recognize front matter by `delimiters` in every [`LintContext::new`] from now on, as
+    /// configured with `front-matter-delimiters`. Rules with the setting, such as MD044, are
+    /// configured from the same value.
+    ///
+    /// [`LintContext::new`]: crate::lint_context::LintContext::new
+    pub fn set_configured_delimiters(delimiters: Vec<String>) {
+        *CONFIGURED_DELIMITERS.write().unwrap() = Some(delimiters);
+    }
+
+    /// The delimiters set with [`Self::set_configured_delimiters`], or the defaults
+    pub fn configured_delimiters() -> Vec<String> {
+        CONFIGURED_DELIMITERS
+            .read()
+            .unwrap()
+            .clone()
+            .unwrap_or_else(Self::default_delimiters)
+    }
+
+    /// Find the (0-based) line closing front matter that opens on the very first line with
+    /// one of `delimiters`. Returns `None` if the document does not start with front matter
+    /// or it is never closed, so a `---` rule further down is never mistaken for it.
+    pub fn front_matter_closing_line<S: AsRef<str>>(
+        lines: &[&str],
+        delimiters: &[S],
+    ) -> Option<usize> {
+        let first_line = lines.first()?.trim();
+        let opening = delimiters
+            .iter()
+            .map(AsRef::as_ref)
+            .find(|delimiter| *delimiter == first_line)?;
+        let closing = if opening == "{" { "}" } else { opening };
+        lines
+            .iter()
+            .skip(1)
+            .position(|line| line.trim() == closing)
+            .map(|i| i + 1)
+    }
+
+    /// Byte offset just past the closing front matter line (see
+    /// [`Self::front_matter_closing_line`]), or 0 if there is no front matter
+    pub fn front_matter_byte_end<S: AsRef<str>>(content: &str, delimiters: &[S]) -> usize {
+        let lines: Vec<&str> = content.lines().collect();
+        match Self::front_matter_closing_line(&lines, delimiters) {
+            Some(closing) => content
+                .split_inclusive('\n')
+                .take(closing + 1)
+                .map(str::len)
+                .sum(),
+            None => 0,
+        }
+    }
+
     /// Check if a line is inside front matter content
     pub fn is_in_front_matter(content: &str, line_num: usize) -> bool {
         let lines: Vec<&str> = content.lines().collect();
@@ -503,3 +577,233 @@ impl FrontMatterUtils {
         result.join("\n")
     }
 }
+
+/// The string values of YAML (`---`) or TOML (`+++`) front matter, as found by
+/// [`FrontMatterValues::new`]
+#[derive(Debug, Default)]
+pub struct FrontMatterValues {
+    /// Byte ranges of the value side of each entry, list item or continuation line
+    ranges: Vec<Range<usize>>,
+    /// Every string scalar of the parsed front matter, lowercased
+    strings: Vec<String>,
+}
+
+impl FrontMatterValues {
+    /// Locate the values of the front matter opening `content` with one of `delimiters`.
+    /// The block is parsed so that only string scalars count as values; keys, comments,
+    /// table headers and block scalar indicators never do. Front matter that does not
+    /// parse, or is neither YAML nor TOML, yields no values.
+    pub fn new<S: AsRef<str>>(content: &str, delimiters: &[S]) -> Self {
+        let lines: Vec<&str> = content.lines().collect();
+        let Some(closing) = FrontMatterUtils::front_matter_closing_line(&lines, delimiters) else {
+            return Self::default();
+        };
+        let body = lines[1..closing].join("\n");
+        let mut strings = Vec::new();
+        let is_yaml = match lines[0].trim() {
+            "---" => match serde_yaml::from_str::<serde_yaml::Value>(&body) {
+                Ok(value) => {
+                    collect_yaml_strings(&value, &mut strings);
+                    true
+                }
+                Err(_) => return Self::default(),
+            },
+            "+++" => match toml::from_str::<toml::Table>(&body) {
+                Ok(table) => {
+                    for value in table.values() {
+                        collect_toml_strings(value, &mut strings);
+                    }
+                    false
+                }
+                Err(_) => return Self::default(),
+            },
+            _ => return Self::default(),
+        };
+
+        let mut offset = lines[0].len() + 1;
+        let mut scanner = ValueScanner::default();
+        let mut ranges = Vec::new();
+        for line in &lines[1..closing] {
+            let span = if is_yaml {
+                scanner.yaml_value(line)
+            } else {
+                scanner.toml_value(line)
+            };
+            if let Some(span) = span
+                && !span.is_empty()
+            {
+                ranges.push(offset + span.start..offset + span.end);
+            }
+            offset += line.len() + 1;
+        }
+        Self { ranges, strings }
+    }
+
+    /// Whether `word` at byte `pos` is (part of) a string value
+    pub fn contains(&self, pos: usize, word: &str) -> bool {
+        let word = word.to_lowercase();
+        self.ranges
+            .iter()
+            .any(|range| range.contains(&pos))
+            && self
+                .strings
+                .iter()
+                .any(|value| value.contains(&word))
+    }
+}
+
+fn collect_yaml_strings(value: &serde_yaml::Value, out: &mut Vec<String>) {
+    match value {
+        serde_yaml::Value::String(s) => out.push(s.to_lowercase()),
+        serde_yaml::Value::Sequence(items) => {
+            for item in items {
+                collect_yaml_strings(item, out);
+            }
+        }
+        // Keys are not values
+        serde_yaml::Value::Mapping(map) => {
+            for item in map.values() {
+                collect_yaml_strings(item, out);
+            }
+        }
+        serde_yaml::Value::Tagged(tagged) => collect_yaml_strings(&tagged.value, out),
+        _ => {}
+    }
+}
+
+fn collect_toml_strings(value: &toml::Value, out: &mut Vec<String>) {
+    match value {
+        toml::Value::String(s) => out.push(s.to_lowercase()),
+        toml::Value::Array(items) => {
+            for item in items {
+                collect_toml_strings(item, out);
+            }
+        }
+        toml::Value::Table(table) => {
+            for item in table.values() {
+                collect_toml_strings(item, out);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Line-by-line state for finding the value side of front matter lines
+#[derive(Default)]
+struct ValueScanner {
+    /// Indentation of the YAML key whose block scalar (`|` or `>`) is being read
+    block_scalar_indent: Option<usize>,
+    /// Unclosed `[` and `{` of a TOML value spanning lines
+    open_brackets: usize,
+    /// Delimiter of a TOML multi-line string being read
+    multiline_string: Option<&'static str>,
+}
+
+impl ValueScanner {
+    fn yaml_value(&mut self, line: &str) -> Option<Range<usize>> {
+        let indent = line.len() - line.trim_start().len();
+        if let Some(key_indent) = self.block_scalar_indent {
+            if line.trim().is_empty() {
+                return None;
+            }
+            if indent > key_indent {
+                return Some(indent..line.trim_end().len());
+            }
+            self.block_scalar_indent = None;
+        }
+
+        let end = comment_start(line, true).unwrap_or(line.len());
+        let mut start = indent;
+        // List item markers, possibly nested (`- - value`)
+        while line[start..end].starts_with("- ") || &line[start..end] == "-" {
+            start += 1;
+            start += line[start..end].len() - line[start..end].trim_start().len();
+        }
+        if let Some(key) = YAML_KEY.find(&line[start..end]) {
+            start += key.end();
+            let value = line[start..end].trim();
+            if value.starts_with('|') || value.starts_with('>') {
+                self.block_scalar_indent = Some(indent);
+                return None;
+            }
+        }
+        let value = &line[start..end];
+        let start = start + (value.len() - value.trim_start().len());
+        Some(start..start.max(line[..end].trim_end().len()))
+    }
+
+    fn toml_value(&mut self, line: &str) -> Option<Range<usize>> {
+        if let Some(delimiter) = self.multiline_string {
+            if line.matches(delimiter).count() % 2 == 1 {
+                self.multiline_string = None;
+            }
+            return Some(0..line.len());
+        }
+
+        let end = comment_start(line, false).unwrap_or(line.len());
+        let start = if self.open_brackets > 0 {
+            0
+        } else {
+            let trimmed = line[..end].trim_start();
+            if trimmed.is_empty() || trimmed.starts_with('[') {
+                return None;
+            }
+            unquoted_find(line, '=')? + 1
+        };
+        let value = &line[start..end];
+        for (c, _) in unquoted_chars(value) {
+            match c {
+                '[' | '{' => self.open_brackets += 1,
+                ']' | '}' => self.open_brackets = self.open_brackets.saturating_sub(1),
+                _ => {}
+            }
+        }
+        for delimiter in ["\"\"\"", "'''"] {
+            if value.matches(delimiter).count() % 2 == 1 {
+                self.multiline_string = Some(delimiter);
+            }
+        }
+        let start = start + (value.len() - value.trim_start().len());
+        Some(start..start.max(line[..end].trim_end().len()))
+    }
+}
+
+// Characters of `text` outside single or double quotes, with their byte offsets
+fn unquoted_chars(text: &str) -> impl Iterator<Item = (char, usize)> + '_ {
+    let mut quote = None;
+    let mut escaped = false;
+    text.char_indices()
+        .filter_map(move |(i, c)| {
+            match quote {
+                Some(q) => {
+                    if escaped {
+                        escaped = false;
+                    } else if c == '\\' && q == '"' {
+                        escaped = true;
+                    } else if c == q {
+                        quote = None;
+                    }
+                    return None;
+                }
+                None if c == '"' || c == '\'' => {
+                    quote = Some(c);
+                    return None;
+                }
+                None => {}
+            }
+            Some((c, i))
+        })
+}
+
+fn unquoted_find(text: &str, target: char) -> Option<usize> {
+    unquoted_chars(text).find_map(|(c, i)| (c == target).then_some(i))
+}
+
+// Start of a `#` comment outside quotes. YAML only starts one at the start of the line or
+// after whitespace, so `C#` stays part of the value.
+fn comment_start(line: &str, after_space: bool) -> Option<usize> {
+    unquoted_chars(line).find_map(|(c, i)| {
+        (c == '#' && (!after_space || i == 0 || line[..i].ends_with(char::is_whitespace)))
+            .then_some(i)
+    })
+}
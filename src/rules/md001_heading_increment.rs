@@ -1,5 +1,5 @@
 use crate::HeadingStyle;
-use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory};
 use crate::rules::heading_utils::HeadingUtils;
 use crate::utils::range_utils::{LineIndex, calculate_heading_range};
 
@@ -64,6 +64,10 @@ impl Rule for MD001HeadingIncrement {
         "MD001"
     }
 
+    fn tags(&self) -> &[&'static str] {
+        &["headings", "structure"]
+    }
+
     fn description(&self) -> &'static str {
         "Heading levels should only increment by one level at a time"
     }
@@ -103,19 +107,20 @@ impl Rule for MD001HeadingIncrement {
                     let (start_line, start_col, end_line, end_col) =
                         calculate_heading_range(line_num + 1, line_content);
 
-                    warnings.push(LintWarning {
-                        rule_name: Some(self.name()),
-                        line: start_line,
-                        column: start_col,
-                        end_line,
-                        end_column: end_col,
-                        message: format!("Expected heading level {}", prev_level + 1),
-                        severity: Severity::Warning,
-                        fix: Some(Fix {
-                            range: line_index.line_content_range(line_num + 1),
-                            replacement: format!("{}{}", " ".repeat(indentation), replacement),
-                        }),
-                    });
+                    warnings.push(
+                        LintWarning::new(
+                            self.name(),
+                            start_line,
+                            start_col,
+                            end_line,
+                            end_col,
+                            format!("Expected heading level {}", prev_level + 1),
+                        )
+                        .with_fix(Fix::safe(
+                            line_index.line_content_range(line_num + 1),
+                            format!("{}{}", " ".repeat(indentation), replacement),
+                        )),
+                    );
                 }
 
                 prev_level = level;
@@ -184,6 +189,10 @@ impl Rule for MD001HeadingIncrement {
         content.is_empty()
     }
 
+    fn is_document_wide(&self) -> bool {
+        true
+    }
+
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
@@ -222,4 +231,14 @@ mod tests {
         assert_eq!(result.len(), 1);
         assert_eq!(result[0].line, 2);
     }
+
+    #[test]
+    fn test_range_reports_headings_after_the_range() {
+        // Demoting the first heading makes the later jump to ### valid, and the reverse
+        let rules: Vec<Box<dyn Rule>> = vec![Box::new(MD001HeadingIncrement)];
+        let content = "# Title\n\nText\n\n### Section\n";
+        let warnings = crate::lint_range(content, &rules, 0..7).unwrap();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].line, 5);
+    }
 }
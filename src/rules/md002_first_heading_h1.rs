@@ -1,5 +1,5 @@
 use crate::rule::Rule;
-use crate::rule::{Fix, LintError, LintResult, LintWarning, RuleCategory, Severity};
+use crate::rule::{Fix, LintError, LintResult, LintWarning, RuleCategory};
 use crate::rule_config_serde::RuleConfig;
 use crate::rules::heading_utils::HeadingStyle;
 use crate::utils::range_utils::calculate_heading_range;
@@ -112,6 +112,10 @@ impl Rule for MD002FirstHeadingH1 {
         "MD002"
     }
 
+    fn tags(&self) -> &[&'static str] {
+        &["headings", "structure"]
+    }
+
     fn description(&self) -> &'static str {
         "First heading should be top level"
     }
@@ -163,26 +167,27 @@ impl Rule for MD002FirstHeadingH1 {
 
                     // Use line content range to replace the entire heading line
                     let line_index = crate::utils::range_utils::LineIndex::new(content.to_string());
-                    Some(Fix {
-                        range: line_index.line_content_range(line_num + 1), // Convert to 1-indexed
+                    Some(Fix::safe(
+                        line_index.line_content_range(line_num + 1), // Convert to 1-indexed
                         replacement,
-                    })
+                    ))
                 };
 
                 // Calculate precise range: highlight the entire first heading
                 let (start_line, start_col, end_line, end_col) =
                     calculate_heading_range(line_num + 1, &line_info.content);
 
-                return Ok(vec![LintWarning {
-                    message,
-                    line: start_line,
-                    column: start_col,
-                    end_line,
-                    end_column: end_col,
-                    severity: Severity::Warning,
-                    fix,
-                    rule_name: Some(self.name()),
-                }]);
+                return Ok(vec![
+                    LintWarning::new(
+                        self.name(),
+                        start_line,
+                        start_col,
+                        end_line,
+                        end_col,
+                        message,
+                    )
+                    .with_fix(fix),
+                ]);
             }
         }
 
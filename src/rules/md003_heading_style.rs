@@ -3,7 +3,7 @@
 //!
 //! See [docs/md003.md](../../docs/md003.md) for full documentation, configuration, and examples.
 
-use crate::rule::{LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule::{LintError, LintResult, LintWarning, Rule, RuleCategory};
 use crate::rule_config_serde::RuleConfig;
 use crate::rules::heading_utils::HeadingStyle;
 use crate::utils::range_utils::calculate_heading_range;
@@ -85,6 +85,10 @@ impl Rule for MD003HeadingStyle {
         "MD003"
     }
 
+    fn tags(&self) -> &[&'static str] {
+        &["headings", "style", "consistency"]
+    }
+
     fn description(&self) -> &'static str {
         "Heading style"
     }
@@ -156,42 +160,40 @@ impl Rule for MD003HeadingStyle {
                         // Calculate the correct range for the heading
                         let range = line_index.line_content_range(line_num + 1);
 
-                        Some(crate::rule::Fix {
-                            range,
-                            replacement: final_heading,
-                        })
+                        Some(crate::rule::Fix::safe(range, final_heading))
                     };
 
                     // Calculate precise character range for the heading marker
                     let (start_line, start_col, end_line, end_col) =
                         calculate_heading_range(line_num + 1, &line_info.content);
 
-                    result.push(LintWarning {
-                        rule_name: Some(self.name()),
-                        line: start_line,
-                        column: start_col,
-                        end_line,
-                        end_column: end_col,
-                        message: format!(
-                            "Heading style should be {}, found {}",
-                            match expected_style {
-                                HeadingStyle::Atx => "# Heading",
-                                HeadingStyle::AtxClosed => "# Heading #",
-                                HeadingStyle::Setext1 => "Heading\n=======",
-                                HeadingStyle::Setext2 => "Heading\n-------",
-                                HeadingStyle::Consistent => "consistent with the first heading",
-                            },
-                            match current_style {
-                                HeadingStyle::Atx => "# Heading",
-                                HeadingStyle::AtxClosed => "# Heading #",
-                                HeadingStyle::Setext1 => "Heading (underlined with =)",
-                                HeadingStyle::Setext2 => "Heading (underlined with -)",
-                                HeadingStyle::Consistent => "consistent style",
-                            }
-                        ),
-                        severity: Severity::Warning,
-                        fix,
-                    });
+                    result.push(
+                        LintWarning::new(
+                            self.name(),
+                            start_line,
+                            start_col,
+                            end_line,
+                            end_col,
+                            format!(
+                                "Heading style should be {}, found {}",
+                                match expected_style {
+                                    HeadingStyle::Atx => "# Heading",
+                                    HeadingStyle::AtxClosed => "# Heading #",
+                                    HeadingStyle::Setext1 => "Heading\n=======",
+                                    HeadingStyle::Setext2 => "Heading\n-------",
+                                    HeadingStyle::Consistent => "consistent with the first heading",
+                                },
+                                match current_style {
+                                    HeadingStyle::Atx => "# Heading",
+                                    HeadingStyle::AtxClosed => "# Heading #",
+                                    HeadingStyle::Setext1 => "Heading (underlined with =)",
+                                    HeadingStyle::Setext2 => "Heading (underlined with -)",
+                                    HeadingStyle::Consistent => "consistent style",
+                                }
+                            ),
+                        )
+                        .with_fix(fix),
+                    );
                 }
             }
         }
@@ -239,6 +241,10 @@ impl Rule for MD003HeadingStyle {
         content.is_empty() || !QUICK_HEADING_CHECK.is_match(content)
     }
 
+    fn is_document_wide(&self) -> bool {
+        self.config.style == HeadingStyle::Consistent
+    }
+
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
@@ -49,7 +49,7 @@ use crate::LintContext;
 /// ## Rationale
 ///
 /// Consistent list markers improve readability and reduce distraction, especially in large documents or when collaborating with others. This rule helps enforce a uniform style across all unordered lists.
-use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory};
 use crate::utils::document_structure::DocumentStructureExtensions;
 use toml;
 
@@ -104,6 +104,10 @@ impl Rule for MD004UnorderedListStyle {
         "MD004"
     }
 
+    fn tags(&self) -> &[&'static str] {
+        &["lists", "style", "consistency"]
+    }
+
     fn description(&self) -> &'static str {
         "Use consistent style for unordered list markers"
     }
@@ -155,22 +159,22 @@ impl Rule for MD004UnorderedListStyle {
                                     // Check if current marker matches the first marker found
                                     if marker != first {
                                         let (line, col) = ctx.offset_to_line_col(offset);
-                                        warnings.push(LintWarning {
-                                            line,
-                                            column: col,
-                                            end_line: line,
-                                            end_column: col + 1,
-                                            message: format!(
-                                                "List marker '{}' does not match expected style '{}'",
-                                                marker, first
+                                        warnings.push(
+                                            LintWarning::new(
+                                                self.name(),
+                                                line,
+                                                col,
+                                                line,
+                                                col + 1,
+                                                format!(
+                                                    "List marker '{}' does not match expected style '{}'",
+                                                    marker, first
+                                                ),
+                                            )
+                                            .with_fix(
+                                                Fix::safe(offset..offset + 1, first.to_string()),
                                             ),
-                                            severity: Severity::Warning,
-                                            rule_name: Some(self.name()),
-                                            fix: Some(Fix {
-                                                range: offset..offset + 1,
-                                                replacement: first.to_string(),
-                                            }),
-                                        });
+                                        );
                                     }
                                 } else {
                                     // This is the first marker we've found - set the style
@@ -187,22 +191,25 @@ impl Rule for MD004UnorderedListStyle {
                                 };
                                 if marker != target_marker {
                                     let (line, col) = ctx.offset_to_line_col(offset);
-                                    warnings.push(LintWarning {
-                                        line,
-                                        column: col,
-                                        end_line: line,
-                                        end_column: col + 1,
-                                        message: format!(
-                                            "List marker '{}' does not match expected style '{}'",
-                                            marker, target_marker
+                                    warnings.push(
+                                        LintWarning::new(
+                                            self.name(),
+                                            line,
+                                            col,
+                                            line,
+                                            col + 1,
+                                            format!(
+                                                "List marker '{}' does not match expected style '{}'",
+                                                marker, target_marker
+                                            ),
+                                        )
+                                        .with_fix(
+                                            Fix::safe(
+                                                offset..offset + 1,
+                                                target_marker.to_string(),
+                                            ),
                                         ),
-                                        severity: Severity::Warning,
-                                        rule_name: Some(self.name()),
-                                        fix: Some(Fix {
-                                            range: offset..offset + 1,
-                                            replacement: target_marker.to_string(),
-                                        }),
-                                    });
+                                    );
                                 }
                             }
                         }
@@ -294,6 +301,10 @@ impl Rule for MD004UnorderedListStyle {
         ctx.content.is_empty() || !ctx.content.contains(['*', '-', '+'])
     }
 
+    fn is_document_wide(&self) -> bool {
+        self.config.style == UnorderedListStyle::Consistent
+    }
+
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
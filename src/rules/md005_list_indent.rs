@@ -5,7 +5,7 @@
 
 use crate::utils::range_utils::{LineIndex, calculate_match_range};
 
-use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory};
 use crate::utils::document_structure::DocumentStructure;
 use std::collections::HashMap;
 use toml;
@@ -172,19 +172,17 @@ impl MD005ListIndent {
                         String::new()
                     };
 
-                    warnings.push(LintWarning {
-                        rule_name: Some(self.name()),
-                        line: start_line,
-                        column: start_col,
-                        end_line,
-                        end_column: end_col,
-                        message: inconsistent_message,
-                        severity: Severity::Warning,
-                        fix: Some(Fix {
-                            range: fix_range,
-                            replacement,
-                        }),
-                    });
+                    warnings.push(
+                        LintWarning::new(
+                            self.name(),
+                            start_line,
+                            start_col,
+                            end_line,
+                            end_col,
+                            inconsistent_message,
+                        )
+                        .with_fix(Fix::safe(fix_range, replacement)),
+                    );
                 }
 
                 // Track level consistency
@@ -233,19 +231,17 @@ impl MD005ListIndent {
                                 String::new()
                             };
 
-                            warnings.push(LintWarning {
-                                rule_name: Some(self.name()),
-                                line: start_line,
-                                column: start_col,
-                                end_line,
-                                end_column: end_col,
-                                message: inconsistent_message,
-                                severity: Severity::Warning,
-                                fix: Some(Fix {
-                                    range: fix_range,
-                                    replacement,
-                                }),
-                            });
+                            warnings.push(
+                                LintWarning::new(
+                                    self.name(),
+                                    start_line,
+                                    start_col,
+                                    end_line,
+                                    end_col,
+                                    inconsistent_message,
+                                )
+                                .with_fix(Fix::safe(fix_range, replacement)),
+                            );
                         }
                     }
                 } else {
@@ -282,6 +278,10 @@ impl Rule for MD005ListIndent {
         "MD005"
     }
 
+    fn tags(&self) -> &[&'static str] {
+        &["lists", "whitespace", "consistency"]
+    }
+
     fn description(&self) -> &'static str {
         "List indentation should be consistent"
     }
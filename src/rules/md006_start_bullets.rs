@@ -1,6 +1,6 @@
 use crate::utils::range_utils::LineIndex;
 
-use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory};
 use crate::utils::document_structure::{DocumentStructure, DocumentStructureExtensions};
 use lazy_static::lazy_static;
 use regex::Regex;
@@ -101,22 +101,20 @@ impl MD006StartBullets {
                                 end_col - start_col,
                             );
 
-                            result.push(LintWarning {
-                                line: item_line,
-                                column: start_col,
-                                end_line: item_line,
-                                end_column: end_col,
-                                message: format!(
-                                    "Consider starting bulleted lists at the beginning of the line (found {} leading spaces)",
-                                    indent
-                                ),
-                                severity: Severity::Warning,
-                                rule_name: Some(self.name()),
-                                fix: Some(Fix {
-                                    range: fix_range,
-                                    replacement: bullet_part,
-                                }),
-                            });
+                            result.push(
+                                LintWarning::new(
+                                    self.name(),
+                                    item_line,
+                                    start_col,
+                                    item_line,
+                                    end_col,
+                                    format!(
+                                        "Consider starting bulleted lists at the beginning of the line (found {} leading spaces)",
+                                        indent
+                                    ),
+                                )
+                                .with_fix(Fix::safe(fix_range, bullet_part)),
+                            );
                         }
                     }
                 }
@@ -209,6 +207,10 @@ impl Rule for MD006StartBullets {
         "MD006"
     }
 
+    fn tags(&self) -> &[&'static str] {
+        &["lists", "whitespace"]
+    }
+
     fn description(&self) -> &'static str {
         "Consider starting bulleted lists at the beginning of the line"
     }
@@ -352,16 +354,17 @@ impl Rule for MD006StartBullets {
                     };
                     let replacement = bullet_part;
 
-                    result.push(LintWarning {
-                        rule_name: Some(self.name()),
-                        severity: Severity::Warning,
-                        line: line_num,
-                        column: start_col,
-                        end_line: line_num,
-                        end_column: end_col,
-                        message: "List item indentation".to_string(),
-                        fix: Some(Fix {
-                            range: {
+                    result.push(
+                        LintWarning::new(
+                            self.name(),
+                            line_num,
+                            start_col,
+                            line_num,
+                            end_col,
+                            "List item indentation",
+                        )
+                        .with_fix(Fix::safe(
+                            {
                                 let start_byte = line_index
                                     .line_col_to_byte_range(line_num, start_col)
                                     .start;
@@ -371,8 +374,8 @@ impl Rule for MD006StartBullets {
                                 start_byte..end_byte
                             },
                             replacement,
-                        }),
-                    });
+                        )),
+                    );
                 }
             }
         }
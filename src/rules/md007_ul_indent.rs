@@ -1,7 +1,7 @@
 /// Rule MD007: Unordered list indentation
 ///
 /// See [docs/md007.md](../../docs/md007.md) for full documentation, configuration, and examples.
-use crate::rule::{LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule::{LintError, LintResult, LintWarning, Rule, RuleCategory};
 use crate::rule_config_serde::RuleConfig;
 use crate::utils::document_structure::{DocumentStructure, DocumentStructureExtensions};
 use crate::utils::element_cache::{ElementCache, ListMarkerType};
@@ -68,6 +68,10 @@ impl Rule for MD007ULIndent {
         "MD007"
     }
 
+    fn tags(&self) -> &[&'static str] {
+        &["lists", "whitespace"]
+    }
+
     fn description(&self) -> &'static str {
         "Unordered list indentation"
     }
@@ -129,10 +133,7 @@ impl Rule for MD007ULIndent {
                                 // Replacement should be just the correct indentation
                                 let replacement = correct_indent;
 
-                                Some(crate::rule::Fix {
-                                    range: start_byte..end_byte,
-                                    replacement,
-                                })
+                                Some(crate::rule::Fix::safe(start_byte..end_byte, replacement))
                             } else {
                                 None
                             }
@@ -141,19 +142,20 @@ impl Rule for MD007ULIndent {
                         }
                     };
 
-                    warnings.push(LintWarning {
-                        rule_name: Some(self.name()),
-                        message: format!(
-                            "Incorrect indentation: expected {} spaces for nesting level {}, found {}",
-                            expected_indent, item.nesting_level, item.indentation
-                        ),
-                        line: item.line_number,
-                        column: item.blockquote_prefix.len() + 1, // Start of indentation
-                        end_line: item.line_number,
-                        end_column: item.blockquote_prefix.len() + item.indent_str.len() + 1, // End of actual indentation string
-                        severity: Severity::Warning,
-                        fix,
-                    });
+                    warnings.push(
+                        LintWarning::new(
+                            self.name(),
+                            item.line_number,
+                            item.blockquote_prefix.len() + 1, // Start of indentation
+                            item.line_number,
+                            item.blockquote_prefix.len() + item.indent_str.len() + 1, // End of actual indentation string
+                            format!(
+                                "Incorrect indentation: expected {} spaces for nesting level {}, found {}",
+                                expected_indent, item.nesting_level, item.indentation
+                            ),
+                        )
+                        .with_fix(fix),
+                    );
                 }
             }
         }
@@ -225,10 +227,7 @@ impl Rule for MD007ULIndent {
                                 // Replacement should be just the correct indentation
                                 let replacement = correct_indent;
 
-                                Some(crate::rule::Fix {
-                                    range: start_byte..end_byte,
-                                    replacement,
-                                })
+                                Some(crate::rule::Fix::safe(start_byte..end_byte, replacement))
                             } else {
                                 None
                             }
@@ -237,19 +236,20 @@ impl Rule for MD007ULIndent {
                         }
                     };
 
-                    warnings.push(LintWarning {
-                        rule_name: Some(self.name()),
-                        message: format!(
-                            "Incorrect indentation: expected {} spaces for nesting level {}, found {}",
-                            expected_indent, item.nesting_level, item.indentation
-                        ),
-                        line: item.line_number,
-                        column: item.blockquote_prefix.len() + 1, // Start of indentation
-                        end_line: item.line_number,
-                        end_column: item.blockquote_prefix.len() + item.indent_str.len() + 1, // End of actual indentation string
-                        severity: Severity::Warning,
-                        fix,
-                    });
+                    warnings.push(
+                        LintWarning::new(
+                            self.name(),
+                            item.line_number,
+                            item.blockquote_prefix.len() + 1, // Start of indentation
+                            item.line_number,
+                            item.blockquote_prefix.len() + item.indent_str.len() + 1, // End of actual indentation string
+                            format!(
+                                "Incorrect indentation: expected {} spaces for nesting level {}, found {}",
+                                expected_indent, item.nesting_level, item.indentation
+                            ),
+                        )
+                        .with_fix(fix),
+                    );
                 }
             }
         }
@@ -1,4 +1,4 @@
-use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory};
 use crate::rule_config_serde::RuleConfig;
 use crate::utils::range_utils::{LineIndex, calculate_trailing_range};
 use crate::utils::regex_cache::get_cached_regex;
@@ -60,6 +60,10 @@ impl Rule for MD009TrailingSpaces {
         "MD009"
     }
 
+    fn tags(&self) -> &[&'static str] {
+        &["whitespace"]
+    }
+
     fn description(&self) -> &'static str {
         "Trailing spaces should be removed"
     }
@@ -87,23 +91,24 @@ impl Rule for MD009TrailingSpaces {
                     let (start_line, start_col, end_line, end_col) =
                         calculate_trailing_range(line_num + 1, line, 0);
 
-                    warnings.push(LintWarning {
-                        rule_name: Some(self.name()),
-                        line: start_line,
-                        column: start_col,
-                        end_line,
-                        end_column: end_col,
-                        message: "Empty line has trailing spaces".to_string(),
-                        severity: Severity::Warning,
-                        fix: Some(Fix {
-                            range: _line_index.line_col_to_byte_range_with_length(
+                    warnings.push(
+                        LintWarning::new(
+                            self.name(),
+                            start_line,
+                            start_col,
+                            end_line,
+                            end_col,
+                            "Empty line has trailing spaces",
+                        )
+                        .with_fix(Fix::safe(
+                            _line_index.line_col_to_byte_range_with_length(
                                 line_num + 1,
                                 1,
                                 line.len(),
                             ),
-                            replacement: String::new(),
-                        }),
-                    });
+                            String::new(),
+                        )),
+                    );
                 }
                 continue;
             }
@@ -136,23 +141,24 @@ impl Rule for MD009TrailingSpaces {
                 let (start_line, start_col, end_line, end_col) =
                     calculate_trailing_range(line_num + 1, line, trimmed.len());
 
-                warnings.push(LintWarning {
-                    rule_name: Some(self.name()),
-                    line: start_line,
-                    column: start_col,
-                    end_line,
-                    end_column: end_col,
-                    message: "Empty blockquote line needs a space after >".to_string(),
-                    severity: Severity::Warning,
-                    fix: Some(Fix {
-                        range: _line_index.line_col_to_byte_range_with_length(
+                warnings.push(
+                    LintWarning::new(
+                        self.name(),
+                        start_line,
+                        start_col,
+                        end_line,
+                        end_col,
+                        "Empty blockquote line needs a space after >",
+                    )
+                    .with_fix(Fix::safe(
+                        _line_index.line_col_to_byte_range_with_length(
                             line_num + 1,
                             trimmed.len() + 1,
                             line.len() - trimmed.len(),
                         ),
-                        replacement: " ".to_string(),
-                    }),
-                });
+                        " ",
+                    )),
+                );
                 continue;
             }
 
@@ -161,34 +167,32 @@ impl Rule for MD009TrailingSpaces {
             let (start_line, start_col, end_line, end_col) =
                 calculate_trailing_range(line_num + 1, line, trimmed.len());
 
-            warnings.push(LintWarning {
-                rule_name: Some(self.name()),
-                line: start_line,
-                column: start_col,
-                end_line,
-                end_column: end_col,
-                message: if trailing_spaces == 1 {
-                    "Trailing space found".to_string()
-                } else {
-                    format!("{} trailing spaces found", trailing_spaces)
-                },
-                severity: Severity::Warning,
-                fix: Some(Fix {
-                    range: _line_index.line_col_to_byte_range_with_length(
+            warnings.push(
+                LintWarning::new(
+                    self.name(),
+                    start_line,
+                    start_col,
+                    end_line,
+                    end_col,
+                    if trailing_spaces == 1 {
+                        "Trailing space found".to_string()
+                    } else {
+                        format!("{} trailing spaces found", trailing_spaces)
+                    },
+                )
+                .with_fix(Fix::safe(
+                    _line_index.line_col_to_byte_range_with_length(
                         line_num + 1,
                         trimmed.len() + 1,
                         trailing_spaces,
                     ),
-                    replacement: if !self.config.strict
-                        && !is_truly_last_line
-                        && trailing_spaces >= 1
-                    {
+                    if !self.config.strict && !is_truly_last_line && trailing_spaces >= 1 {
                         " ".repeat(self.config.br_spaces)
                     } else {
                         String::new()
                     },
-                }),
-            });
+                )),
+            );
         }
 
         Ok(warnings)
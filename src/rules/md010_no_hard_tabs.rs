@@ -1,4 +1,4 @@
-use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory};
 use crate::rule_config_serde::RuleConfig;
 /// Rule MD010: No tabs
 ///
@@ -36,6 +36,7 @@ impl MD010NoHardTabs {
             config: MD010Config {
                 spaces_per_tab,
                 code_blocks,
+                ..Default::default()
             },
         }
     }
@@ -87,14 +88,27 @@ impl MD010NoHardTabs {
         count
     }
 
+    /// Byte offsets of all tabs in the line (a tab is always a single byte)
     fn find_tab_positions(line: &str) -> Vec<usize> {
-        line.chars()
-            .enumerate()
+        line.char_indices()
             .filter(|(_, c)| *c == '\t')
             .map(|(i, _)| i)
             .collect()
     }
 
+    /// Whether the line should be left alone because it is inside a code block
+    /// and code blocks are not checked
+    fn skip_code_block_line(
+        &self,
+        ctx: &crate::lint_context::LintContext,
+        line_num: usize,
+    ) -> bool {
+        !self.config.code_blocks
+            && ctx
+                .line_info(line_num)
+                .is_some_and(|info| info.in_code_block)
+    }
+
     fn group_consecutive_tabs(tab_positions: &[usize]) -> Vec<(usize, usize)> {
         if tab_positions.is_empty() {
             return Vec::new();
@@ -127,6 +141,10 @@ impl Rule for MD010NoHardTabs {
         "MD010"
     }
 
+    fn tags(&self) -> &[&'static str] {
+        &["whitespace"]
+    }
+
     fn description(&self) -> &'static str {
         "No tabs"
     }
@@ -148,13 +166,8 @@ impl Rule for MD010NoHardTabs {
             }
 
             // Skip if in code block and code_blocks is false
-            if !self.config.code_blocks {
-                // Use pre-computed line info
-                if let Some(line_info) = ctx.line_info(line_num + 1) {
-                    if line_info.in_code_block {
-                        continue;
-                    }
-                }
+            if self.skip_code_block_line(ctx, line_num + 1) {
+                continue;
             }
 
             let tab_positions = Self::find_tab_positions(line);
@@ -170,6 +183,11 @@ impl Rule for MD010NoHardTabs {
                 let tab_count = end_pos - start_pos;
                 let is_leading = start_pos < leading_tabs;
 
+                // Alignment tabs are allowed when only leading indentation is checked
+                if self.config.leading_only && !is_leading {
+                    continue;
+                }
+
                 // Calculate precise character range for the tab group
                 let (start_line, start_col, end_line, end_col) =
                     calculate_match_range(line_num + 1, line, start_pos, tab_count);
@@ -199,23 +217,24 @@ impl Rule for MD010NoHardTabs {
                     format!("Found {} tabs for alignment, use spaces instead", tab_count)
                 };
 
-                warnings.push(LintWarning {
-                    rule_name: Some(self.name()),
-                    line: start_line,
-                    column: start_col,
-                    end_line,
-                    end_column: end_col,
-                    message,
-                    severity: Severity::Warning,
-                    fix: Some(Fix {
-                        range: _line_index.line_col_to_byte_range_with_length(
+                warnings.push(
+                    LintWarning::new(
+                        self.name(),
+                        start_line,
+                        start_col,
+                        end_line,
+                        end_col,
+                        message,
+                    )
+                    .with_fix(Fix::safe(
+                        _line_index.line_col_to_byte_range_with_length(
                             line_num + 1,
                             start_pos + 1,
                             tab_count,
                         ),
-                        replacement: " ".repeat(tab_count * self.config.spaces_per_tab),
-                    }),
-                });
+                        " ".repeat(tab_count * self.config.spaces_per_tab),
+                    )),
+                );
             }
         }
 
@@ -224,32 +243,29 @@ impl Rule for MD010NoHardTabs {
 
     fn fix(&self, ctx: &crate::lint_context::LintContext) -> Result<String, LintError> {
         let content = ctx.content;
-        let _line_index = LineIndex::new(content.to_string());
 
         let mut result = String::new();
         let lines: Vec<&str> = content.lines().collect();
+        let spaces = " ".repeat(self.config.spaces_per_tab);
 
         // Pre-compute which lines are part of HTML comments
         let html_comment_lines = Self::find_html_comment_lines(&lines);
 
-        // Pre-compute line positions for code block detection
-        let mut line_positions = Vec::with_capacity(lines.len());
-        let mut pos = 0;
-        for line in &lines {
-            line_positions.push(pos);
-            pos += line.len() + 1; // +1 for newline
-        }
-
         for (i, line) in lines.iter().enumerate() {
             if html_comment_lines[i] {
                 // Preserve HTML comments as they are
                 result.push_str(line);
-            } else if !self.config.code_blocks && ctx.is_in_code_block_or_span(line_positions[i]) {
+            } else if self.skip_code_block_line(ctx, i + 1) {
                 // Preserve code blocks when code_blocks is false
                 result.push_str(line);
+            } else if self.config.leading_only {
+                // Replace only the leading indentation tabs
+                let leading_tabs = Self::count_leading_tabs(line);
+                result.push_str(&spaces.repeat(leading_tabs));
+                result.push_str(&line[leading_tabs..]);
             } else {
                 // Replace tabs with spaces
-                result.push_str(&line.replace('\t', &" ".repeat(self.config.spaces_per_tab)));
+                result.push_str(&line.replace('\t', &spaces));
             }
 
             // Add newline if not the last line without a newline
@@ -296,3 +312,76 @@ impl Rule for MD010NoHardTabs {
         Box::new(Self::from_config_struct(rule_config))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lint_context::LintContext;
+
+    #[test]
+    fn test_leading_tab_indentation_converted() {
+        let rule = MD010NoHardTabs::new(2, true);
+        let content = "* List\n\t* Nested\n\t\tDeep\n";
+        let ctx = LintContext::new(content);
+
+        let warnings = rule.check(&ctx).unwrap();
+        assert_eq!(warnings.len(), 2);
+        assert_eq!(
+            warnings[1].message,
+            "Found 2 leading tabs, use 4 spaces instead"
+        );
+
+        let fixed = rule.fix(&ctx).unwrap();
+        assert_eq!(fixed, "* List\n  * Nested\n    Deep\n");
+        assert_eq!(
+            crate::utils::fix_utils::apply_warning_fixes(content, &warnings).unwrap(),
+            fixed
+        );
+    }
+
+    #[test]
+    fn test_tab_in_fenced_code_block_untouched() {
+        let rule = MD010NoHardTabs::new(4, false);
+        let content = "# Title\n\n```go\nfunc main() {\n\tfmt.Println()\n}\n```\n\nSome\ttext\n";
+        let ctx = LintContext::new(content);
+
+        let warnings = rule.check(&ctx).unwrap();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].line, 9);
+
+        let fixed = rule.fix(&ctx).unwrap();
+        assert!(fixed.contains("\n\tfmt.Println()\n"));
+        assert!(fixed.ends_with("\nSome    text\n"));
+    }
+
+    #[test]
+    fn test_leading_only_ignores_alignment_tabs() {
+        let rule = MD010NoHardTabs::from_config_struct(MD010Config {
+            leading_only: true,
+            ..Default::default()
+        });
+        let content = "\tName:\tValue\n";
+        let ctx = LintContext::new(content);
+
+        let warnings = rule.check(&ctx).unwrap();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].column, 1);
+        assert_eq!(rule.fix(&ctx).unwrap(), "    Name:\tValue\n");
+
+        let default_rule = MD010NoHardTabs::default();
+        assert_eq!(default_rule.check(&ctx).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_fix_range_after_multibyte_text() {
+        let rule = MD010NoHardTabs::default();
+        let content = "Café\tau lait\n";
+        let ctx = LintContext::new(content);
+
+        let warnings = rule.check(&ctx).unwrap();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].column, 5);
+        let fix = warnings[0].fix.as_ref().unwrap();
+        assert_eq!(&content[fix.range.clone()], "\t");
+    }
+}
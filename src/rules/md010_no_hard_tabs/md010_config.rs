@@ -12,6 +12,11 @@ pub struct MD010Config {
     /// Check code blocks (default: true)
     #[serde(default = "default_code_blocks")]
     pub code_blocks: bool,
+
+    /// Only check tabs in leading indentation, leaving tabs used for
+    /// alignment later in a line untouched (default: false)
+    #[serde(default)]
+    pub leading_only: bool,
 }
 
 fn default_spaces_per_tab() -> usize {
@@ -27,6 +32,7 @@ impl Default for MD010Config {
         Self {
             spaces_per_tab: default_spaces_per_tab(),
             code_blocks: default_code_blocks(),
+            leading_only: false,
         }
     }
 }
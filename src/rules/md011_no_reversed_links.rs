@@ -1,7 +1,7 @@
 /// Rule MD011: No reversed link syntax
 ///
 /// See [docs/md011.md](../../docs/md011.md) for full documentation, configuration, and examples.
-use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, Severity};
+use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule};
 use crate::utils::range_utils::calculate_match_range;
 use lazy_static::lazy_static;
 use regex::Regex;
@@ -177,6 +177,10 @@ impl Rule for MD011NoReversedLinks {
         "MD011"
     }
 
+    fn tags(&self) -> &[&'static str] {
+        &["links"]
+    }
+
     fn description(&self) -> &'static str {
         "Link syntax should not be reversed"
     }
@@ -201,16 +205,17 @@ impl Rule for MD011NoReversedLinks {
                 let (start_line, start_col, end_line, end_col) =
                     calculate_match_range(line_num + 1, line, match_obj.start(), match_obj.len());
 
-                warnings.push(LintWarning {
-                    rule_name: Some(self.name()),
-                    message: "Reversed link syntax".to_string(),
-                    line: start_line,
-                    column: start_col,
-                    end_line,
-                    end_column: end_col,
-                    severity: Severity::Warning,
-                    fix: Some(Fix {
-                        range: {
+                warnings.push(
+                    LintWarning::new(
+                        self.name(),
+                        start_line,
+                        start_col,
+                        end_line,
+                        end_col,
+                        "Reversed link syntax",
+                    )
+                    .with_fix(Fix::safe(
+                        {
                             // Calculate proper byte range using line offsets and match position
                             let line_start_byte = ctx
                                 .line_offsets
@@ -221,9 +226,9 @@ impl Rule for MD011NoReversedLinks {
                             let match_end_byte = match_start_byte + match_obj.len();
                             match_start_byte..match_end_byte
                         },
-                        replacement: format!("[{}]({})", &cap[2], &cap[1]),
-                    }),
-                });
+                        format!("[{}]({})", &cap[2], &cap[1]),
+                    )),
+                );
             }
 
             // Part 2: Check for malformed link attempts where user intent is clear
@@ -233,16 +238,17 @@ impl Rule for MD011NoReversedLinks {
                 let (start_line, start_col, end_line, end_col) =
                     calculate_match_range(line_num + 1, line, start, len);
 
-                warnings.push(LintWarning {
-                    rule_name: Some(self.name()),
-                    message: "Malformed link syntax".to_string(),
-                    line: start_line,
-                    column: start_col,
-                    end_line,
-                    end_column: end_col,
-                    severity: Severity::Warning,
-                    fix: Some(Fix {
-                        range: {
+                warnings.push(
+                    LintWarning::new(
+                        self.name(),
+                        start_line,
+                        start_col,
+                        end_line,
+                        end_col,
+                        "Malformed link syntax",
+                    )
+                    .with_fix(Fix::safe(
+                        {
                             // Calculate proper byte range using line offsets and match position
                             let line_start_byte = ctx
                                 .line_offsets
@@ -253,9 +259,9 @@ impl Rule for MD011NoReversedLinks {
                             let match_end_byte = match_start_byte + len;
                             match_start_byte..match_end_byte
                         },
-                        replacement: format!("[{}]({})", text, url),
-                    }),
-                });
+                        format!("[{}]({})", text, url),
+                    )),
+                );
             }
 
             byte_pos += line.len() + 1; // Update byte position for next line
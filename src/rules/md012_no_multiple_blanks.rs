@@ -1,7 +1,7 @@
 use crate::utils::range_utils::{LineIndex, calculate_line_range};
 use toml;
 
-use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, Severity};
+use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule};
 use crate::rule_config_serde::RuleConfig;
 
 mod md012_config;
@@ -150,6 +150,10 @@ impl Rule for MD012NoMultipleBlanks {
         "MD012"
     }
 
+    fn tags(&self) -> &[&'static str] {
+        &["whitespace"]
+    }
+
     fn description(&self) -> &'static str {
         "Multiple consecutive blank lines"
     }
@@ -226,19 +230,20 @@ impl Rule for MD012NoMultipleBlanks {
                         let (start_line, start_col, end_line, end_col) =
                             calculate_line_range(excess_line, excess_line_content);
 
-                        warnings.push(LintWarning {
-                            rule_name: Some(self.name()),
-                            severity: Severity::Warning,
-                            message: format!(
-                                "Multiple consecutive blank lines {} (Expected: {}; Actual: {})",
-                                location, self.config.maximum, blank_count
-                            ),
-                            line: start_line,
-                            column: start_col,
-                            end_line,
-                            end_column: end_col,
-                            fix: Some(Fix {
-                                range: {
+                        warnings.push(
+                            LintWarning::new(
+                                self.name(),
+                                start_line,
+                                start_col,
+                                end_line,
+                                end_col,
+                                format!(
+                                    "Multiple consecutive blank lines {} (Expected: {}; Actual: {})",
+                                    location, self.config.maximum, blank_count
+                                ),
+                            )
+                            .with_fix(Fix::safe(
+                                {
                                     // Remove entire line including newline
                                     let line_start = _line_index
                                         .get_line_start_byte(excess_line)
@@ -248,9 +253,9 @@ impl Rule for MD012NoMultipleBlanks {
                                         .unwrap_or(line_start + 1);
                                     line_start..line_end
                                 },
-                                replacement: String::new(), // Remove the excess line
-                            }),
-                        });
+                                String::new(), // Remove the excess line
+                            )),
+                        );
                     }
                 }
                 blank_count = 0;
@@ -270,19 +275,20 @@ impl Rule for MD012NoMultipleBlanks {
                 let (start_line, start_col, end_line, end_col) =
                     calculate_line_range(excess_line, excess_line_content);
 
-                warnings.push(LintWarning {
-                    rule_name: Some(self.name()),
-                    severity: Severity::Warning,
-                    message: format!(
-                        "Multiple consecutive blank lines {} (Expected: {}; Actual: {})",
-                        location, self.config.maximum, blank_count
-                    ),
-                    line: start_line,
-                    column: start_col,
-                    end_line,
-                    end_column: end_col,
-                    fix: Some(Fix {
-                        range: {
+                warnings.push(
+                    LintWarning::new(
+                        self.name(),
+                        start_line,
+                        start_col,
+                        end_line,
+                        end_col,
+                        format!(
+                            "Multiple consecutive blank lines {} (Expected: {}; Actual: {})",
+                            location, self.config.maximum, blank_count
+                        ),
+                    )
+                    .with_fix(Fix::safe(
+                        {
                             // Remove entire line including newline
                             let line_start = _line_index
                                 .get_line_start_byte(excess_line)
@@ -292,9 +298,9 @@ impl Rule for MD012NoMultipleBlanks {
                                 .unwrap_or(line_start + 1);
                             line_start..line_end
                         },
-                        replacement: String::new(),
-                    }),
-                });
+                        String::new(),
+                    )),
+                );
             }
         }
 
@@ -1,7 +1,7 @@
 /// Rule MD013: Line length
 ///
 /// See [docs/md013.md](../../docs/md013.md) for full documentation, configuration, and examples.
-use crate::rule::{LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule::{LintError, LintResult, LintWarning, Rule, RuleCategory};
 use crate::rule_config_serde::RuleConfig;
 use crate::utils::document_structure::{DocumentStructure, DocumentStructureExtensions};
 use crate::utils::range_utils::calculate_excess_range;
@@ -137,6 +137,10 @@ impl Rule for MD013LineLength {
         "MD013"
     }
 
+    fn tags(&self) -> &[&'static str] {
+        &["line_length"]
+    }
+
     fn description(&self) -> &'static str {
         "Line length should not be excessive"
     }
@@ -255,10 +259,10 @@ impl Rule for MD013LineLength {
                     } else {
                         content.len()
                     };
-                    Some(crate::rule::Fix {
-                        range: line_start..line_end,
-                        replacement: trimmed.to_string(),
-                    })
+                    Some(crate::rule::Fix::safe(
+                        line_start..line_end,
+                        trimmed.to_string(),
+                    ))
                 } else {
                     None // Skip expensive sentence splitting for performance
                 }
@@ -282,16 +286,17 @@ impl Rule for MD013LineLength {
             let (start_line, start_col, end_line, end_col) =
                 calculate_excess_range(line_number, line, self.config.line_length);
 
-            warnings.push(LintWarning {
-                rule_name: Some(self.name()),
-                message,
-                line: start_line,
-                column: start_col,
-                end_line,
-                end_column: end_col,
-                severity: Severity::Warning,
-                fix,
-            });
+            warnings.push(
+                LintWarning::new(
+                    self.name(),
+                    start_line,
+                    start_col,
+                    end_line,
+                    end_col,
+                    message,
+                )
+                .with_fix(fix),
+            );
         }
         Ok(warnings)
     }
@@ -3,7 +3,7 @@
 //!
 //! See [docs/md014.md](../../docs/md014.md) for full documentation, configuration, and examples.
 
-use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, Severity};
+use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule};
 use crate::rule_config_serde::RuleConfig;
 use crate::utils::range_utils::{LineIndex, calculate_match_range};
 use lazy_static::lazy_static;
@@ -155,6 +155,10 @@ impl Rule for MD014CommandsShowOutput {
         "MD014"
     }
 
+    fn tags(&self) -> &[&'static str] {
+        &["code", "content"]
+    }
+
     fn description(&self) -> &'static str {
         "Commands in code blocks should show output"
     }
@@ -206,16 +210,17 @@ impl Rule for MD014CommandsShowOutput {
                                     )
                                 };
 
-                                warnings.push(LintWarning {
-                                    rule_name: Some(self.name()),
-                                    line: start_line,
-                                    column: start_col,
-                                    end_line,
-                                    end_column: end_col,
-                                    message,
-                                    severity: Severity::Warning,
-                                    fix: Some(Fix {
-                                        range: {
+                                warnings.push(
+                                    LintWarning::new(
+                                        self.name(),
+                                        start_line,
+                                        start_col,
+                                        end_line,
+                                        end_col,
+                                        message,
+                                    )
+                                    .with_fix(Fix::safe(
+                                        {
                                             // Replace the content line(s) between the fences
                                             let content_start_line = block_start_line + 1; // Line after opening fence (0-indexed)
                                             let content_end_line = line_num - 1; // Line before closing fence (0-indexed)
@@ -229,12 +234,9 @@ impl Rule for MD014CommandsShowOutput {
                                                 .unwrap_or(start_byte); // +2 to include newline after last content line
                                             start_byte..end_byte
                                         },
-                                        replacement: format!(
-                                            "{}\n",
-                                            self.fix_command_block(&current_block)
-                                        ),
-                                    }),
-                                });
+                                        format!("{}\n", self.fix_command_block(&current_block)),
+                                    )),
+                                );
                             }
                         }
                     }
@@ -1,7 +1,7 @@
 /// Rule MD018: No missing space after ATX heading marker
 ///
 /// See [docs/md018.md](../../docs/md018.md) for full documentation, configuration, and examples.
-use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory};
 use crate::utils::range_utils::calculate_single_line_range;
 
 #[derive(Clone)]
@@ -107,6 +107,10 @@ impl Rule for MD018NoMissingSpaceAtx {
         "MD018"
     }
 
+    fn tags(&self) -> &[&'static str] {
+        &["headings", "whitespace"]
+    }
+
     fn description(&self) -> &'static str {
         "No space after hash in heading"
     }
@@ -138,27 +142,28 @@ impl Rule for MD018NoMissingSpaceAtx {
                                     0, // Zero-width to indicate missing space
                                 );
 
-                            warnings.push(LintWarning {
-                                rule_name: Some(self.name()),
-                                message: format!(
-                                    "No space after {} in heading",
-                                    "#".repeat(heading.level as usize)
-                                ),
-                                line: start_line,
-                                column: start_col,
-                                end_line,
-                                end_column: end_col,
-                                severity: Severity::Warning,
-                                fix: Some(Fix {
-                                    range: self.get_line_byte_range(ctx.content, line_num + 1),
-                                    replacement: format!(
+                            warnings.push(
+                                LintWarning::new(
+                                    self.name(),
+                                    start_line,
+                                    start_col,
+                                    end_line,
+                                    end_col,
+                                    format!(
+                                        "No space after {} in heading",
+                                        "#".repeat(heading.level as usize)
+                                    ),
+                                )
+                                .with_fix(Fix::safe(
+                                    self.get_line_byte_range(ctx.content, line_num + 1),
+                                    format!(
                                         "{}{} {}",
                                         " ".repeat(line_info.indent),
                                         heading.marker,
                                         after_marker
                                     ),
-                                }),
-                            });
+                                )),
+                            );
                         }
                     }
                 }
@@ -173,19 +178,20 @@ impl Rule for MD018NoMissingSpaceAtx {
                         0,                // Zero-width to indicate missing space
                     );
 
-                    warnings.push(LintWarning {
-                        rule_name: Some(self.name()),
-                        message: "No space after hash in heading".to_string(),
-                        line: start_line,
-                        column: start_col,
-                        end_line,
-                        end_column: end_col,
-                        severity: Severity::Warning,
-                        fix: Some(Fix {
-                            range: self.get_line_byte_range(ctx.content, line_num + 1),
-                            replacement: fixed_line,
-                        }),
-                    });
+                    warnings.push(
+                        LintWarning::new(
+                            self.name(),
+                            start_line,
+                            start_col,
+                            end_line,
+                            end_col,
+                            "No space after hash in heading",
+                        )
+                        .with_fix(Fix::safe(
+                            self.get_line_byte_range(ctx.content, line_num + 1),
+                            fixed_line,
+                        )),
+                    );
                 }
             }
         }
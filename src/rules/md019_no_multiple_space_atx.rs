@@ -1,7 +1,7 @@
 /// Rule MD019: No multiple spaces after ATX heading marker
 ///
 /// See [docs/md019.md](../../docs/md019.md) for full documentation, configuration, and examples.
-use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory};
 use crate::utils::range_utils::{LineIndex, calculate_single_line_range};
 
 #[derive(Clone)]
@@ -33,6 +33,10 @@ impl Rule for MD019NoMultipleSpaceAtx {
         "MD019"
     }
 
+    fn tags(&self) -> &[&'static str] {
+        &["headings", "whitespace"]
+    }
+
     fn description(&self) -> &'static str {
         "Multiple spaces after hash in heading"
     }
@@ -88,23 +92,24 @@ impl Rule for MD019NoMultipleSpaceAtx {
                             let extra_spaces_start = marker_byte_pos;
                             let extra_spaces_end = marker_byte_pos + space_bytes;
 
-                            warnings.push(LintWarning {
-                                rule_name: Some(self.name()),
-                                message: format!(
-                                    "Multiple spaces ({}) after {} in heading",
-                                    space_count,
-                                    "#".repeat(heading.level as usize)
-                                ),
-                                line: start_line,
-                                column: start_col,
-                                end_line,
-                                end_column: end_col,
-                                severity: Severity::Warning,
-                                fix: Some(Fix {
-                                    range: extra_spaces_start..extra_spaces_end,
-                                    replacement: " ".to_string(), // Replace extra spaces with single space
-                                }),
-                            });
+                            warnings.push(
+                                LintWarning::new(
+                                    self.name(),
+                                    start_line,
+                                    start_col,
+                                    end_line,
+                                    end_col,
+                                    format!(
+                                        "Multiple spaces ({}) after {} in heading",
+                                        space_count,
+                                        "#".repeat(heading.level as usize)
+                                    ),
+                                )
+                                .with_fix(Fix::safe(
+                                    extra_spaces_start..extra_spaces_end,
+                                    " ", // Replace extra spaces with single space
+                                )),
+                            );
                         }
                     }
                 }
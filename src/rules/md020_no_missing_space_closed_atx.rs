@@ -1,7 +1,7 @@
 /// Rule MD020: No missing space inside closed ATX heading
 ///
 /// See [docs/md020.md](../../docs/md020.md) for full documentation, configuration, and examples.
-use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory};
 use crate::utils::range_utils::{LineIndex, calculate_single_line_range};
 use lazy_static::lazy_static;
 use regex::Regex;
@@ -80,6 +80,10 @@ impl Rule for MD020NoMissingSpaceClosedAtx {
         "MD020"
     }
 
+    fn tags(&self) -> &[&'static str] {
+        &["headings", "whitespace"]
+    }
+
     fn description(&self) -> &'static str {
         "No space inside hashes on closed heading"
     }
@@ -150,19 +154,17 @@ impl Rule for MD020NoMissingSpaceClosedAtx {
                         let (start_line, start_col_calc, end_line, end_col) =
                             calculate_single_line_range(line_num + 1, start_col, length);
 
-                        warnings.push(LintWarning {
-                            rule_name: Some(self.name()),
-                            message,
-                            line: start_line,
-                            column: start_col_calc,
-                            end_line,
-                            end_column: end_col,
-                            severity: Severity::Warning,
-                            fix: Some(Fix {
-                                range: line_range,
-                                replacement: self.fix_closed_atx_heading(line),
-                            }),
-                        });
+                        warnings.push(
+                            LintWarning::new(
+                                self.name(),
+                                start_line,
+                                start_col_calc,
+                                end_line,
+                                end_col,
+                                message,
+                            )
+                            .with_fix(Fix::safe(line_range, self.fix_closed_atx_heading(line))),
+                        );
                     }
                 }
             }
@@ -1,7 +1,7 @@
 /// Rule MD021: No multiple spaces inside closed ATX heading
 ///
 /// See [docs/md021.md](../../docs/md021.md) for full documentation, configuration, and examples.
-use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory};
 use crate::utils::range_utils::{LineIndex, calculate_line_range};
 use lazy_static::lazy_static;
 use regex::Regex;
@@ -75,6 +75,10 @@ impl Rule for MD021NoMultipleSpaceClosedAtx {
         "MD021"
     }
 
+    fn tags(&self) -> &[&'static str] {
+        &["headings", "whitespace"]
+    }
+
     fn description(&self) -> &'static str {
         "Multiple spaces inside hashes on closed heading"
     }
@@ -132,23 +136,24 @@ impl Rule for MD021NoMultipleSpaceClosedAtx {
                             calculate_line_range(line_num + 1, line);
                         let replacement = self.fix_closed_atx_heading(line);
 
-                        warnings.push(LintWarning {
-                            rule_name: Some(self.name()),
-                            message,
-                            line: start_line,
-                            column: start_col,
-                            end_line,
-                            end_column: end_col,
-                            severity: Severity::Warning,
-                            fix: Some(Fix {
-                                range: line_index.line_col_to_byte_range_with_length(
+                        warnings.push(
+                            LintWarning::new(
+                                self.name(),
+                                start_line,
+                                start_col,
+                                end_line,
+                                end_col,
+                                message,
+                            )
+                            .with_fix(Fix::safe(
+                                line_index.line_col_to_byte_range_with_length(
                                     start_line,
                                     1,
                                     line.len(),
                                 ),
                                 replacement,
-                            }),
-                        });
+                            )),
+                        );
                     }
                 }
             }
@@ -1,5 +1,5 @@
 /// Rule MD022: Headings should be surrounded by blank lines See [docs/md022.md](../../docs/md022.md) for full documentation, configuration, and examples.
-use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory};
 use crate::rule_config_serde::RuleConfig;
 use crate::utils::range_utils::calculate_heading_range;
 use toml;
@@ -317,6 +317,10 @@ impl Rule for MD022BlanksAroundHeadings {
         "MD022"
     }
 
+    fn tags(&self) -> &[&'static str] {
+        &["headings", "whitespace"]
+    }
+
     fn description(&self) -> &'static str {
         "Headings should be surrounded by blank lines"
     }
@@ -525,19 +529,17 @@ impl Rule for MD022BlanksAroundHeadings {
                 content_len..content_len
             };
 
-            result.push(LintWarning {
-                rule_name: Some(self.name()),
-                message,
-                line: start_line,
-                column: start_col,
-                end_line,
-                end_column: end_col,
-                severity: Severity::Warning,
-                fix: Some(Fix {
-                    range: byte_range,
-                    replacement: line_ending.repeat(needed_blanks),
-                }),
-            });
+            result.push(
+                LintWarning::new(
+                    self.name(),
+                    start_line,
+                    start_col,
+                    end_line,
+                    end_col,
+                    message,
+                )
+                .with_fix(Fix::safe(byte_range, line_ending.repeat(needed_blanks))),
+            );
         }
 
         Ok(result)
@@ -1,7 +1,7 @@
 /// Rule MD023: Headings must start at the left margin
 ///
 /// See [docs/md023.md](../../docs/md023.md) for full documentation, configuration, and examples.
-use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory};
 use crate::utils::range_utils::{LineIndex, calculate_single_line_range};
 
 #[derive(Clone)]
@@ -12,6 +12,10 @@ impl Rule for MD023HeadingStartLeft {
         "MD023"
     }
 
+    fn tags(&self) -> &[&'static str] {
+        &["headings", "whitespace"]
+    }
+
     fn description(&self) -> &'static str {
         "Headings must start at the beginning of the line"
     }
@@ -51,26 +55,27 @@ impl Rule for MD023HeadingStartLeft {
                             );
 
                         // Add warning for the heading text line
-                        warnings.push(LintWarning {
-                            rule_name: Some(self.name()),
-                            line: start_line_calc,
-                            column: start_col,
-                            end_line,
-                            end_column: end_col,
-                            severity: Severity::Warning,
-                            message: format!(
-                                "Setext heading should not be indented by {} spaces",
-                                indentation
-                            ),
-                            fix: Some(Fix {
-                                range: line_index.line_col_to_byte_range_with_length(
+                        warnings.push(
+                            LintWarning::new(
+                                self.name(),
+                                start_line_calc,
+                                start_col,
+                                end_line,
+                                end_col,
+                                format!(
+                                    "Setext heading should not be indented by {} spaces",
+                                    indentation
+                                ),
+                            )
+                            .with_fix(Fix::safe(
+                                line_index.line_col_to_byte_range_with_length(
                                     line_num + 1,
                                     start_col,
                                     indentation,
                                 ),
-                                replacement: String::new(), // Remove the indentation
-                            }),
-                        });
+                                String::new(), // Remove the indentation
+                            )),
+                        );
 
                         // Add warning for the underline - only if it's indented
                         if underline_line < ctx.lines.len() {
@@ -88,24 +93,24 @@ impl Rule for MD023HeadingStartLeft {
                                     underline_indentation,
                                 );
 
-                                warnings.push(LintWarning {
-                                    rule_name: Some(self.name()),
-                                    line: underline_start_line,
-                                    column: underline_start_col,
-                                    end_line: underline_end_line,
-                                    end_column: underline_end_col,
-                                    severity: Severity::Warning,
-                                    message: "Setext heading underline should not be indented"
-                                        .to_string(),
-                                    fix: Some(Fix {
-                                        range: line_index.line_col_to_byte_range_with_length(
+                                warnings.push(
+                                    LintWarning::new(
+                                        self.name(),
+                                        underline_start_line,
+                                        underline_start_col,
+                                        underline_end_line,
+                                        underline_end_col,
+                                        "Setext heading underline should not be indented",
+                                    )
+                                    .with_fix(Fix::safe(
+                                        line_index.line_col_to_byte_range_with_length(
                                             underline_line + 1,
                                             underline_start_col,
                                             underline_indentation,
                                         ),
-                                        replacement: String::new(), // Remove the indentation
-                                    }),
-                                });
+                                        String::new(), // Remove the indentation
+                                    )),
+                                );
                             }
                         }
                     } else {
@@ -119,26 +124,24 @@ impl Rule for MD023HeadingStartLeft {
                                 indentation,
                             );
 
-                        warnings.push(LintWarning {
-                            rule_name: Some(self.name()),
-                            line: atx_start_line,
-                            column: atx_start_col,
-                            end_line: atx_end_line,
-                            end_column: atx_end_col,
-                            severity: Severity::Warning,
-                            message: format!(
-                                "Heading should not be indented by {} spaces",
-                                indentation
-                            ),
-                            fix: Some(Fix {
-                                range: line_index.line_col_to_byte_range_with_length(
+                        warnings.push(
+                            LintWarning::new(
+                                self.name(),
+                                atx_start_line,
+                                atx_start_col,
+                                atx_end_line,
+                                atx_end_col,
+                                format!("Heading should not be indented by {} spaces", indentation),
+                            )
+                            .with_fix(Fix::safe(
+                                line_index.line_col_to_byte_range_with_length(
                                     line_num + 1,
                                     atx_start_col,
                                     indentation,
                                 ),
-                                replacement: String::new(), // Remove the indentation
-                            }),
-                        });
+                                String::new(), // Remove the indentation
+                            )),
+                        );
                     }
                 }
             }
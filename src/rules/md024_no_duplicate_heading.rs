@@ -1,6 +1,6 @@
 use toml;
 
-use crate::rule::{LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule::{LintError, LintResult, LintWarning, Rule, RuleCategory};
 use crate::rule_config_serde::RuleConfig;
 use crate::utils::range_utils::calculate_match_range;
 use std::collections::{HashMap, HashSet};
@@ -41,6 +41,10 @@ impl Rule for MD024NoDuplicateHeading {
         "MD024"
     }
 
+    fn tags(&self) -> &[&'static str] {
+        &["headings", "content"]
+    }
+
     fn description(&self) -> &'static str {
         "Multiple headings with the same content"
     }
@@ -98,32 +102,28 @@ impl Rule for MD024NoDuplicateHeading {
                         .entry(level)
                         .or_default();
                     if seen.contains(&heading_key) {
-                        warnings.push(LintWarning {
-                            rule_name: Some(self.name()),
-                            message: format!("Duplicate heading: '{}'.", heading.text),
-                            line: start_line,
-                            column: start_col,
+                        warnings.push(LintWarning::new(
+                            self.name(),
+                            start_line,
+                            start_col,
                             end_line,
-                            end_column: end_col,
-                            severity: Severity::Warning,
-                            fix: None,
-                        });
+                            end_col,
+                            format!("Duplicate heading: '{}'.", heading.text),
+                        ));
                     } else {
                         seen.insert(heading_key.clone());
                     }
                 } else {
                     // Flag all duplicates, regardless of level
                     if seen_headings.contains(&heading_key) {
-                        warnings.push(LintWarning {
-                            rule_name: Some(self.name()),
-                            message: format!("Duplicate heading: '{}'.", heading.text),
-                            line: start_line,
-                            column: start_col,
+                        warnings.push(LintWarning::new(
+                            self.name(),
+                            start_line,
+                            start_col,
                             end_line,
-                            end_column: end_col,
-                            severity: Severity::Warning,
-                            fix: None,
-                        });
+                            end_col,
+                            format!("Duplicate heading: '{}'.", heading.text),
+                        ));
                     } else {
                         seen_headings.insert(heading_key.clone());
                     }
@@ -151,6 +151,11 @@ impl Rule for MD024NoDuplicateHeading {
             .all(|line| line.heading.is_none())
     }
 
+    fn is_document_wide(&self) -> bool {
+        // Any heading may duplicate one elsewhere
+        true
+    }
+
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
@@ -1,7 +1,7 @@
 /// Rule MD025: Document must have a single top-level heading
 ///
 /// See [docs/md025.md](../../docs/md025.md) for full documentation, configuration, and examples.
-use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory};
 use crate::utils::range_utils::{LineIndex, calculate_match_range};
 use lazy_static::lazy_static;
 use regex::Regex;
@@ -202,6 +202,10 @@ impl Rule for MD025SingleTitle {
         "MD025"
     }
 
+    fn tags(&self) -> &[&'static str] {
+        &["headings", "structure"]
+    }
+
     fn description(&self) -> &'static str {
         "Multiple top-level headings in the same document"
     }
@@ -308,20 +312,21 @@ impl Rule for MD025SingleTitle {
                         heading_text.len(),
                     );
 
-                    warnings.push(LintWarning {
-                        rule_name: Some(self.name()),
-                        message: format!(
-                            "Multiple top-level headings (level {}) in the same document",
-                            self.config.level
-                        ),
-                        line: start_line,
-                        column: start_col,
-                        end_line,
-                        end_column: end_col,
-                        severity: Severity::Warning,
-                        fix: Some(Fix {
-                            range: line_index.line_content_range(line_num + 1),
-                            replacement: {
+                    warnings.push(
+                        LintWarning::new(
+                            self.name(),
+                            start_line,
+                            start_col,
+                            end_line,
+                            end_col,
+                            format!(
+                                "Multiple top-level headings (level {}) in the same document",
+                                self.config.level
+                            ),
+                        )
+                        .with_fix(Fix::safe(
+                            line_index.line_content_range(line_num + 1),
+                            {
                                 let leading_spaces =
                                     line_content.len() - line_content.trim_start().len();
                                 let indentation = " ".repeat(leading_spaces);
@@ -336,8 +341,8 @@ impl Rule for MD025SingleTitle {
                                     )
                                 }
                             },
-                        }),
-                    });
+                        )),
+                    );
                 }
             }
         }
@@ -506,6 +511,11 @@ impl Rule for MD025SingleTitle {
                 && !ctx.content.contains('-'))
     }
 
+    fn is_document_wide(&self) -> bool {
+        // Any top-level heading may be the second one
+        true
+    }
+
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
@@ -1,7 +1,7 @@
 /// Rule MD026: No trailing punctuation in headings
 ///
 /// See [docs/md026.md](../../docs/md026.md) for full documentation, configuration, and examples.
-use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, Severity};
+use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule};
 use crate::utils::range_utils::calculate_match_range;
 use lazy_static::lazy_static;
 use regex::Regex;
@@ -263,6 +263,10 @@ impl Rule for MD026NoTrailingPunctuation {
         "MD026"
     }
 
+    fn tags(&self) -> &[&'static str] {
+        &["headings", "content"]
+    }
+
     fn description(&self) -> &'static str {
         "Trailing punctuation in heading"
     }
@@ -348,29 +352,27 @@ impl Rule for MD026NoTrailingPunctuation {
                             .chars()
                             .last()
                             .unwrap_or(' ');
-                        warnings.push(LintWarning {
-                            rule_name: Some(self.name()),
-                            line: start_line,
-                            column: start_col,
-                            end_line,
-                            end_column: end_col,
-                            message: format!(
-                                "Heading '{}' ends with punctuation '{}'",
-                                heading.text, last_char
-                            ),
-                            severity: Severity::Warning,
-                            fix: Some(Fix {
-                                range: self.get_line_byte_range(content, line_num + 1),
-                                replacement: if matches!(
-                                    heading.style,
-                                    crate::lint_context::HeadingStyle::ATX
-                                ) {
+                        warnings.push(
+                            LintWarning::new(
+                                self.name(),
+                                start_line,
+                                start_col,
+                                end_line,
+                                end_col,
+                                format!(
+                                    "Heading '{}' ends with punctuation '{}'",
+                                    heading.text, last_char
+                                ),
+                            )
+                            .with_fix(Fix::safe(
+                                self.get_line_byte_range(content, line_num + 1),
+                                if matches!(heading.style, crate::lint_context::HeadingStyle::ATX) {
                                     self.fix_atx_heading(line, &re)
                                 } else {
                                     self.fix_setext_heading(line, &re)
                                 },
-                            }),
-                        });
+                            )),
+                        );
                     }
                 }
             }
@@ -1,6 +1,6 @@
 use crate::utils::range_utils::{LineIndex, calculate_match_range};
 
-use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, Severity};
+use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule};
 use lazy_static::lazy_static;
 use regex::Regex;
 
@@ -36,6 +36,10 @@ impl Rule for MD027MultipleSpacesBlockquote {
         "MD027"
     }
 
+    fn tags(&self) -> &[&'static str] {
+        &["blockquote", "whitespace"]
+    }
+
     fn description(&self) -> &'static str {
         "Multiple spaces after quote marker (>)"
     }
@@ -73,16 +77,17 @@ impl Rule for MD027MultipleSpacesBlockquote {
                         extra_spaces_len,
                     );
 
-                    warnings.push(LintWarning {
-                        rule_name: Some(self.name()),
-                        line: start_line,
-                        column: start_col,
-                        end_line,
-                        end_column: end_col,
-                        message: "Multiple spaces after quote marker (>)".to_string(),
-                        severity: Severity::Warning,
-                        fix: Some(Fix {
-                            range: {
+                    warnings.push(
+                        LintWarning::new(
+                            self.name(),
+                            start_line,
+                            start_col,
+                            end_line,
+                            end_col,
+                            "Multiple spaces after quote marker (>)",
+                        )
+                        .with_fix(Fix::safe(
+                            {
                                 let line_index = LineIndex::new(ctx.content.to_string());
                                 let start_byte = line_index
                                     .line_col_to_byte_range(line_num, start_col)
@@ -92,9 +97,9 @@ impl Rule for MD027MultipleSpacesBlockquote {
                                     .start;
                                 start_byte..end_byte
                             },
-                            replacement: "".to_string(), // Remove the extra spaces
-                        }),
-                    });
+                            "", // Remove the extra spaces
+                        )),
+                    );
                 }
             } else {
                 // Part 2: Check for malformed blockquote attempts on non-blockquote lines
@@ -104,22 +109,23 @@ impl Rule for MD027MultipleSpacesBlockquote {
                     let (start_line, start_col, end_line, end_col) =
                         calculate_match_range(line_num, &line_info.content, start, len);
 
-                    warnings.push(LintWarning {
-                        rule_name: Some(self.name()),
-                        line: start_line,
-                        column: start_col,
-                        end_line,
-                        end_column: end_col,
-                        message: format!("Malformed quote: {}", description),
-                        severity: Severity::Warning,
-                        fix: Some(Fix {
-                            range: {
+                    warnings.push(
+                        LintWarning::new(
+                            self.name(),
+                            start_line,
+                            start_col,
+                            end_line,
+                            end_col,
+                            format!("Malformed quote: {}", description),
+                        )
+                        .with_fix(Fix::safe(
+                            {
                                 let line_index = LineIndex::new(ctx.content.to_string());
                                 line_index.line_col_to_byte_range(line_num, 1)
                             },
-                            replacement: fixed_line,
-                        }),
-                    });
+                            fixed_line,
+                        )),
+                    );
                 }
             }
         }
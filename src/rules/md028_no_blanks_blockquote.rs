@@ -1,7 +1,7 @@
 /// Rule MD028: No blank lines inside blockquotes
 ///
 /// See [docs/md028.md](../../docs/md028.md) for full documentation, configuration, and examples.
-use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory};
 use crate::utils::document_structure::{DocumentStructure, DocumentStructureExtensions};
 use crate::utils::range_utils::{LineIndex, calculate_line_range};
 
@@ -35,6 +35,10 @@ impl Rule for MD028NoBlanksBlockquote {
         "MD028"
     }
 
+    fn tags(&self) -> &[&'static str] {
+        &["blockquote", "whitespace"]
+    }
+
     fn description(&self) -> &'static str {
         "Blank line inside blockquote"
     }
@@ -68,26 +72,24 @@ impl Rule for MD028NoBlanksBlockquote {
                     let (start_line, start_col, end_line, end_col) =
                         calculate_line_range(line_num, &line_info.content);
 
-                    warnings.push(LintWarning {
-                        rule_name: Some(self.name()),
-                        message: "Empty blockquote line should contain '>' marker".to_string(),
-                        line: start_line,
-                        column: start_col,
-                        end_line,
-                        end_column: end_col,
-                        severity: Severity::Warning,
-                        fix: Some(Fix {
-                            range: line_index.line_col_to_byte_range_with_length(
+                    warnings.push(
+                        LintWarning::new(
+                            self.name(),
+                            start_line,
+                            start_col,
+                            end_line,
+                            end_col,
+                            "Empty blockquote line should contain '>' marker",
+                        )
+                        .with_fix(Fix::safe(
+                            line_index.line_col_to_byte_range_with_length(
                                 line_num,
                                 1,
                                 line_info.content.len(),
                             ),
-                            replacement: Self::get_replacement(
-                                &blockquote.indent,
-                                blockquote.nesting_level,
-                            ),
-                        }),
-                    });
+                            Self::get_replacement(&blockquote.indent, blockquote.nesting_level),
+                        )),
+                    );
                 }
             }
         }
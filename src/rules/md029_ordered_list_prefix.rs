@@ -1,7 +1,7 @@
 /// Rule MD029: Ordered list item prefix
 ///
 /// See [docs/md029.md](../../docs/md029.md) for full documentation, configuration, and examples.
-use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory};
 use crate::utils::document_structure::{DocumentStructure, DocumentStructureExtensions};
 use crate::utils::range_utils::LineIndex;
 use lazy_static::lazy_static;
@@ -71,6 +71,10 @@ impl Rule for MD029OrderedListPrefix {
         "MD029"
     }
 
+    fn tags(&self) -> &[&'static str] {
+        &["lists", "style"]
+    }
+
     fn description(&self) -> &'static str {
         "Ordered list marker value"
     }
@@ -276,6 +280,10 @@ impl Rule for MD029OrderedListPrefix {
             || (!content.contains("1.") && !content.contains("2.") && !content.contains("0."))
     }
 
+    fn is_document_wide(&self) -> bool {
+        true
+    }
+
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
@@ -353,26 +361,27 @@ impl MD029OrderedListPrefix {
                         let number_start = line.find(char::is_numeric).unwrap_or(0);
                         let number_len = actual_num.to_string().len();
 
-                        warnings.push(LintWarning {
-                            rule_name: Some(self.name()),
-                            message: format!(
-                                "Ordered list item number {} does not match style (expected {})",
-                                actual_num, expected_num
-                            ),
-                            line: line_num + 1,
-                            column: number_start + 1,
-                            end_line: line_num + 1,
-                            end_column: number_start + number_len + 1,
-                            severity: Severity::Warning,
-                            fix: Some(Fix {
-                                range: line_index.line_col_to_byte_range_with_length(
+                        warnings.push(
+                            LintWarning::new(
+                                self.name(),
+                                line_num + 1,
+                                number_start + 1,
+                                line_num + 1,
+                                number_start + number_len + 1,
+                                format!(
+                                    "Ordered list item number {} does not match style (expected {})",
+                                    actual_num, expected_num
+                                ),
+                            )
+                            .with_fix(Fix::safe(
+                                line_index.line_col_to_byte_range_with_length(
                                     line_num + 1,
                                     number_start + 1,
                                     number_len,
                                 ),
-                                replacement: expected_num.to_string(),
-                            }),
-                        });
+                                expected_num.to_string(),
+                            )),
+                        );
                     }
                 }
             }
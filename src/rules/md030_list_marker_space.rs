@@ -3,7 +3,7 @@
 //!
 //! See [docs/md030.md](../../docs/md030.md) for full documentation, configuration, and examples.
 
-use crate::rule::{LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule::{LintResult, LintWarning, Rule, RuleCategory};
 use crate::rule_config_serde::RuleConfig;
 use crate::rules::list_utils::ListType;
 use crate::utils::document_structure::{DocumentStructure, DocumentStructureExtensions};
@@ -57,6 +57,10 @@ impl Rule for MD030ListMarkerSpace {
         "MD030"
     }
 
+    fn tags(&self) -> &[&'static str] {
+        &["lists", "whitespace"]
+    }
+
     fn description(&self) -> &'static str {
         "Spaces after list markers should be consistent"
     }
@@ -152,10 +156,10 @@ impl Rule for MD030ListMarkerSpace {
                         let whitespace_start_byte = line_start_byte + whitespace_start_pos;
                         let whitespace_end_byte = whitespace_start_byte + whitespace_len;
 
-                        let fix = Some(crate::rule::Fix {
-                            range: whitespace_start_byte..whitespace_end_byte,
-                            replacement: correct_spaces,
-                        });
+                        let fix = Some(crate::rule::Fix::safe(
+                            whitespace_start_byte..whitespace_end_byte,
+                            correct_spaces,
+                        ));
 
                         // Generate appropriate message
                         let message = format!(
@@ -163,16 +167,17 @@ impl Rule for MD030ListMarkerSpace {
                             expected_spaces, actual_spaces
                         );
 
-                        warnings.push(LintWarning {
-                            rule_name: Some(self.name()),
-                            severity: Severity::Warning,
-                            line: start_line,
-                            column: start_col,
-                            end_line,
-                            end_column: end_col,
-                            message,
-                            fix,
-                        });
+                        warnings.push(
+                            LintWarning::new(
+                                self.name(),
+                                start_line,
+                                start_col,
+                                end_line,
+                                end_col,
+                                message,
+                            )
+                            .with_fix(fix),
+                        );
                     }
                 }
             }
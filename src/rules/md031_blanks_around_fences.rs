@@ -1,7 +1,7 @@
 /// Rule MD031: Blank lines around fenced code blocks
 ///
 /// See [docs/md031.md](../../docs/md031.md) for full documentation, configuration, and examples.
-use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory};
 use crate::utils::document_structure::{DocumentStructure, DocumentStructureExtensions};
 use crate::utils::range_utils::{LineIndex, calculate_line_range};
 use lazy_static::lazy_static;
@@ -32,6 +32,10 @@ impl Rule for MD031BlanksAroundFences {
         "MD031"
     }
 
+    fn tags(&self) -> &[&'static str] {
+        &["code", "whitespace"]
+    }
+
     fn description(&self) -> &'static str {
         "Fenced code blocks should be surrounded by blank lines"
     }
@@ -94,23 +98,24 @@ impl Rule for MD031BlanksAroundFences {
                                 let (start_line, start_col, end_line, end_col) =
                                     calculate_line_range(i + 1, lines[i]);
 
-                                warnings.push(LintWarning {
-                                    rule_name: Some(self.name()),
-                                    line: start_line,
-                                    column: start_col,
-                                    end_line,
-                                    end_column: end_col,
-                                    message: "No blank line after fenced code block".to_string(),
-                                    severity: Severity::Warning,
-                                    fix: Some(Fix {
-                                        range: line_index.line_col_to_byte_range_with_length(
+                                warnings.push(
+                                    LintWarning::new(
+                                        self.name(),
+                                        start_line,
+                                        start_col,
+                                        end_line,
+                                        end_col,
+                                        "No blank line after fenced code block",
+                                    )
+                                    .with_fix(Fix::safe(
+                                        line_index.line_col_to_byte_range_with_length(
                                             i + 1,
                                             lines[i].len() + 1,
                                             0,
                                         ),
-                                        replacement: "\n".to_string(),
-                                    }),
-                                });
+                                        "\n",
+                                    )),
+                                );
                             }
                         }
                         // else: This is content inside a code block (different fence marker), ignore
@@ -125,19 +130,20 @@ impl Rule for MD031BlanksAroundFences {
                         let (start_line, start_col, end_line, end_col) =
                             calculate_line_range(i + 1, lines[i]);
 
-                        warnings.push(LintWarning {
-                            rule_name: Some(self.name()),
-                            line: start_line,
-                            column: start_col,
-                            end_line,
-                            end_column: end_col,
-                            message: "No blank line before fenced code block".to_string(),
-                            severity: Severity::Warning,
-                            fix: Some(Fix {
-                                range: line_index.line_col_to_byte_range_with_length(i + 1, 1, 0),
-                                replacement: "\n".to_string(),
-                            }),
-                        });
+                        warnings.push(
+                            LintWarning::new(
+                                self.name(),
+                                start_line,
+                                start_col,
+                                end_line,
+                                end_col,
+                                "No blank line before fenced code block",
+                            )
+                            .with_fix(Fix::safe(
+                                line_index.line_col_to_byte_range_with_length(i + 1, 1, 0),
+                                "\n",
+                            )),
+                        );
                     }
                 }
             }
@@ -290,19 +296,20 @@ impl Rule for MD031BlanksAroundFences {
                 let (start_line, start_col, end_line, end_col) =
                     calculate_line_range(line_num, lines[line_num - 1]);
 
-                warnings.push(LintWarning {
-                    rule_name: Some(self.name()),
-                    line: start_line,
-                    column: start_col,
-                    end_line,
-                    end_column: end_col,
-                    message: "No blank line before fenced code block".to_string(),
-                    severity: Severity::Warning,
-                    fix: Some(Fix {
-                        range: line_index.line_col_to_byte_range_with_length(line_num, 1, 0),
-                        replacement: "\n".to_string(),
-                    }),
-                });
+                warnings.push(
+                    LintWarning::new(
+                        self.name(),
+                        start_line,
+                        start_col,
+                        end_line,
+                        end_col,
+                        "No blank line before fenced code block",
+                    )
+                    .with_fix(Fix::safe(
+                        line_index.line_col_to_byte_range_with_length(line_num, 1, 0),
+                        "\n",
+                    )),
+                );
             }
         }
 
@@ -315,23 +322,24 @@ impl Rule for MD031BlanksAroundFences {
                 let (start_line_fence, start_col_fence, end_line_fence, end_col_fence) =
                     calculate_line_range(line_num, lines[line_num - 1]);
 
-                warnings.push(LintWarning {
-                    rule_name: Some(self.name()),
-                    line: start_line_fence,
-                    column: start_col_fence,
-                    end_line: end_line_fence,
-                    end_column: end_col_fence,
-                    message: "No blank line after fenced code block".to_string(),
-                    severity: Severity::Warning,
-                    fix: Some(Fix {
-                        range: line_index.line_col_to_byte_range_with_length(
+                warnings.push(
+                    LintWarning::new(
+                        self.name(),
+                        start_line_fence,
+                        start_col_fence,
+                        end_line_fence,
+                        end_col_fence,
+                        "No blank line after fenced code block",
+                    )
+                    .with_fix(Fix::safe(
+                        line_index.line_col_to_byte_range_with_length(
                             line_num,
                             lines[line_num - 1].len() + 1,
                             0,
                         ),
-                        replacement: "\n".to_string(),
-                    }),
-                });
+                        "\n",
+                    )),
+                );
             }
         }
 
@@ -315,19 +315,21 @@ impl MD032BlanksAroundLists {
                     let (start_line, start_col, end_line, end_col) =
                         calculate_line_range(start_line, lines[start_line - 1]);
 
-                    warnings.push(LintWarning {
-                        line: start_line,
-                        column: start_col,
-                        end_line,
-                        end_column: end_col,
-                        severity: Severity::Error,
-                        rule_name: Some(self.name()),
-                        message: "List should be preceded by blank line".to_string(),
-                        fix: Some(Fix {
-                            range: line_index.line_col_to_byte_range_with_length(start_line, 1, 0),
-                            replacement: format!("{}\n", prefix),
-                        }),
-                    });
+                    warnings.push(
+                        LintWarning::new(
+                            self.name(),
+                            start_line,
+                            start_col,
+                            end_line,
+                            end_col,
+                            "List should be preceded by blank line",
+                        )
+                        .with_severity(Severity::Error)
+                        .with_fix(Fix::safe(
+                            line_index.line_col_to_byte_range_with_length(start_line, 1, 0),
+                            format!("{}\n", prefix),
+                        )),
+                    );
                 }
             }
 
@@ -362,23 +364,21 @@ impl MD032BlanksAroundLists {
                     let (start_line_last, start_col_last, end_line_last, end_col_last) =
                         calculate_line_range(end_line, lines[end_line - 1]);
 
-                    warnings.push(LintWarning {
-                        line: start_line_last,
-                        column: start_col_last,
-                        end_line: end_line_last,
-                        end_column: end_col_last,
-                        severity: Severity::Error,
-                        rule_name: Some(self.name()),
-                        message: "List should be followed by blank line".to_string(),
-                        fix: Some(Fix {
-                            range: line_index.line_col_to_byte_range_with_length(
-                                end_line + 1,
-                                1,
-                                0,
-                            ),
-                            replacement: format!("{}\n", prefix),
-                        }),
-                    });
+                    warnings.push(
+                        LintWarning::new(
+                            self.name(),
+                            start_line_last,
+                            start_col_last,
+                            end_line_last,
+                            end_col_last,
+                            "List should be followed by blank line",
+                        )
+                        .with_severity(Severity::Error)
+                        .with_fix(Fix::safe(
+                            line_index.line_col_to_byte_range_with_length(end_line + 1, 1, 0),
+                            format!("{}\n", prefix),
+                        )),
+                    );
                 }
             }
         }
@@ -391,6 +391,10 @@ impl Rule for MD032BlanksAroundLists {
         "MD032"
     }
 
+    fn tags(&self) -> &[&'static str] {
+        &["lists", "whitespace"]
+    }
+
     fn description(&self) -> &'static str {
         "Lists should be surrounded by blank lines"
     }
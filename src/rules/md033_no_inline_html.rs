@@ -3,7 +3,7 @@
 //!
 //! See [docs/md033.md](../../docs/md033.md) for full documentation, configuration, and examples.
 
-use crate::rule::{LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule::{LintError, LintResult, LintWarning, Rule, RuleCategory};
 use crate::utils::document_structure::{DocumentStructure, DocumentStructureExtensions};
 use crate::utils::range_utils::calculate_html_tag_range;
 use crate::utils::regex_cache::*;
@@ -260,18 +260,14 @@ impl MD033NoInlineHtml {
                                         incomplete_match.start(),
                                         incomplete_match.len(),
                                     );
-                                warnings.push(LintWarning {
-                                    rule_name: Some(self.name()),
-                                    line: start_line,
-                                    column: start_col,
+                                warnings.push(LintWarning::new(
+                                    self.name(),
+                                    start_line,
+                                    start_col,
                                     end_line,
-                                    end_column: end_col,
-                                    message: format!(
-                                        "HTML tag found (use Markdown syntax instead)"
-                                    ),
-                                    severity: Severity::Warning,
-                                    fix: None,
-                                });
+                                    end_col,
+                                    format!("HTML tag found (use Markdown syntax instead)"),
+                                ));
                             }
                         }
                     }
@@ -286,6 +282,10 @@ impl Rule for MD033NoInlineHtml {
         "MD033"
     }
 
+    fn tags(&self) -> &[&'static str] {
+        &["html"]
+    }
+
     fn description(&self) -> &'static str {
         "Inline HTML is not allowed"
     }
@@ -367,16 +367,14 @@ impl Rule for MD033NoInlineHtml {
                 // Report each HTML tag individually (true markdownlint compatibility)
                 let (start_line, start_col, end_line, end_col) =
                     calculate_html_tag_range(line_num, line, tag_match.start(), tag_match.len());
-                warnings.push(LintWarning {
-                    rule_name: Some(self.name()),
-                    line: start_line,
-                    column: start_col,
+                warnings.push(LintWarning::new(
+                    self.name(),
+                    start_line,
+                    start_col,
                     end_line,
-                    end_column: end_col,
-                    message: format!("Inline HTML found (use Markdown syntax instead)"),
-                    severity: Severity::Warning,
-                    fix: None,
-                });
+                    end_col,
+                    format!("Inline HTML found (use Markdown syntax instead)"),
+                ));
             }
         }
 
@@ -3,9 +3,8 @@
 /// See [docs/md034.md](../../docs/md034.md) for full documentation, configuration, and examples.
 use crate::rule::{
     AstExtensions, Fix, LintError, LintResult, LintWarning, MarkdownAst, MaybeAst, Rule,
-    RuleCategory, Severity,
+    RuleCategory,
 };
-use crate::utils::early_returns;
 use crate::utils::range_utils::calculate_url_range;
 
 use crate::lint_context::LintContext;
@@ -14,9 +13,12 @@ use lazy_static::lazy_static;
 use markdown::mdast::Node;
 use regex::Regex;
 
+mod md034_config;
+use md034_config::{BareUrlStyle, MD034Config};
+
 lazy_static! {
     // Simple pattern to quickly check if a line might contain a URL or email
-    static ref URL_QUICK_CHECK: Regex = Regex::new(r#"(?:https?|ftp)://|@"#).unwrap();
+    static ref URL_QUICK_CHECK: Regex = Regex::new(r#"://|@"#).unwrap();
 
     // Use fancy-regex for look-behind/look-ahead
     static ref URL_REGEX: FancyRegex = FancyRegex::new(r#"(?<![\w\[\(\<])((?:https?|ftp)://[^
@@ -30,7 +32,7 @@ lazy_static! {
     static ref MARKDOWN_LINK_PATTERN: Regex = Regex::new(r#"\[(?:[^\[\]]|\[[^\]]*\])*\]\(([^)\s]+)(?:\s+(?:\"[^\"]*\"|\'[^\']*\'))?\)"#).unwrap();
 
     // Pattern to match angle bracket link format (URLs and emails)
-    static ref ANGLE_LINK_PATTERN: Regex = Regex::new(r#"<((?:https?|ftp)://[^>]+|[^@\s]+@[^@\s]+\.[^@\s>]+)>"#).unwrap();
+    static ref ANGLE_LINK_PATTERN: Regex = Regex::new(r#"<([a-zA-Z][a-zA-Z0-9+.-]*://[^>]+|[^@\s]+@[^@\s]+\.[^@\s>]+)>"#).unwrap();
 
     // Pattern to match code fences
     static ref CODE_FENCE_RE: Regex = Regex::new(r#"^(`{3,}|~{3,})"#).unwrap();
@@ -47,8 +49,9 @@ lazy_static! {
     // Add a simple regex for candidate URLs (no look-behind/look-ahead)
     // Updated to match markdownlint's behavior: URLs can have domains without dots
     // Handles URL components properly: scheme://domain[:port][/path][?query][#fragment]
+    // Any scheme is matched here; the configured allowlist is applied afterwards
     // Will post-process to remove trailing sentence punctuation
-    static ref SIMPLE_URL_REGEX: Regex = Regex::new(r#"(https?|ftp)://[^\s<>\[\]()\\'\"`]+(?:\.[^\s<>\[\]()\\'\"`]+)*(?::\d+)?(?:/[^\s<>\[\]()\\'\"`]*)?(?:\?[^\s<>\[\]()\\'\"`]*)?(?:#[^\s<>\[\]()\\'\"`]*)?"#).unwrap();
+    static ref SIMPLE_URL_REGEX: Regex = Regex::new(r#"([a-zA-Z][a-zA-Z0-9+-]*)://[^\s<>\[\]()\\'\"`]+(?:\.[^\s<>\[\]()\\'\"`]+)*(?::\d+)?(?:/[^\s<>\[\]()\\'\"`]*)?(?:\?[^\s<>\[\]()\\'\"`]*)?(?:#[^\s<>\[\]()\\'\"`]*)?"#).unwrap();
 
     // Add regex for email addresses - matches markdownlint behavior
     // Detects email addresses that should be autolinked like URLs
@@ -62,17 +65,42 @@ lazy_static! {
 }
 
 #[derive(Default, Clone)]
-pub struct MD034NoBareUrls;
+pub struct MD034NoBareUrls {
+    config: MD034Config,
+}
 
 impl MD034NoBareUrls {
+    pub fn new(style: BareUrlStyle, schemes: Vec<String>) -> Self {
+        Self::from_config_struct(MD034Config { style, schemes })
+    }
+
+    pub fn from_config_struct(config: MD034Config) -> Self {
+        Self { config }
+    }
+
     #[inline]
     pub fn should_skip(&self, content: &str) -> bool {
         // Skip if content has no URLs and no email addresses
-        let has_urls = early_returns::has_urls(content);
-        let has_emails = content.contains('@');
-        let should_skip = !has_urls && !has_emails;
+        !content.contains("://") && !content.contains('@')
+    }
 
-        should_skip
+    /// Whether the URL's scheme is one this rule is configured to flag
+    fn has_allowed_scheme(&self, url: &str) -> bool {
+        url.split_once("://")
+            .is_some_and(|(scheme, _)| {
+                self.config
+                    .schemes
+                    .iter()
+                    .any(|allowed| allowed.eq_ignore_ascii_case(scheme))
+            })
+    }
+
+    /// Replacement text for a bare URL in the configured style
+    fn url_replacement(&self, url: &str) -> String {
+        match self.config.style {
+            BareUrlStyle::AngleBrackets => format!("<{url}>"),
+            BareUrlStyle::Link => format!("[{url}]({url})"),
+        }
     }
 
     /// Remove trailing punctuation that is likely sentence punctuation, not part of the URL
@@ -116,10 +144,9 @@ impl MD034NoBareUrls {
         let mut excluded_ranges: Vec<(usize, usize)> = Vec::new();
 
         // Markdown links: [text](url) - handle multi-line
-        for cap in MARKDOWN_LINK_PATTERN.captures_iter(content) {
-            if let Some(dest) = cap.get(1) {
-                excluded_ranges.push((dest.start(), dest.end()));
-            }
+        // The whole link is excluded so a URL used as link text is not flagged either
+        for link in MARKDOWN_LINK_PATTERN.find_iter(content) {
+            excluded_ranges.push((link.start(), link.end()));
         }
 
         // Markdown images: ![alt](url) - handle multi-line
@@ -171,6 +198,10 @@ impl MD034NoBareUrls {
                 continue;
             }
 
+            if !self.has_allowed_scheme(trimmed_url) {
+                continue;
+            }
+
             // Manual boundary check: not part of a larger word
             let before = if url_start == 0 {
                 None
@@ -201,7 +232,7 @@ impl MD034NoBareUrls {
             }
 
             // Convert byte offset to line/column for warning
-            let (line_num, col_num) = ctx.offset_to_line_col(url_start);
+            let (line_num, _) = ctx.offset_to_line_col(url_start);
 
             // Skip if URL is within any excluded range (link/image dest)
             let in_any_range = merged
@@ -227,21 +258,22 @@ impl MD034NoBareUrls {
 
             let url_text = &content[url_start..url_end];
             let (start_line, start_col, end_line, end_col) =
-                calculate_url_range(line_num, line, col_num - 1, url_text.len());
-
-            warnings.push(LintWarning {
-                rule_name: Some(self.name()),
-                line: start_line,
-                column: start_col,
-                end_line,
-                end_column: end_col,
-                message: format!("URL without angle brackets or link formatting"),
-                severity: Severity::Warning,
-                fix: Some(Fix {
-                    range: url_start..url_end,
-                    replacement: format!("<{}>", url_text),
-                }),
-            });
+                calculate_url_range(line_num, line, url_start - line_start, url_text.len());
+
+            warnings.push(
+                LintWarning::new(
+                    self.name(),
+                    start_line,
+                    start_col,
+                    end_line,
+                    end_col,
+                    format!("URL without angle brackets or link formatting"),
+                )
+                .with_fix(Fix::safe(
+                    url_start..url_end,
+                    self.url_replacement(url_text),
+                )),
+            );
         }
 
         // Check for email addresses - similar logic to URLs
@@ -281,7 +313,7 @@ impl MD034NoBareUrls {
             }
 
             // Convert byte offset to line/column for warning
-            let (line_num, col_num) = ctx.offset_to_line_col(email_start);
+            let (line_num, _) = ctx.offset_to_line_col(email_start);
 
             // Skip if email is within any excluded range (link/image dest)
             let in_any_range = merged
@@ -302,21 +334,22 @@ impl MD034NoBareUrls {
                 .unwrap_or(content.len());
             let line = &content[line_start..line_end];
             let (start_line, start_col, end_line, end_col) =
-                calculate_url_range(line_num, line, col_num - 1, email_text.len());
-
-            warnings.push(LintWarning {
-                rule_name: Some(self.name()),
-                line: start_line,
-                column: start_col,
-                end_line,
-                end_column: end_col,
-                message: format!("Email address without angle brackets or link formatting"),
-                severity: Severity::Warning,
-                fix: Some(Fix {
-                    range: email_start..email_end,
-                    replacement: format!("<{}>", email_text),
-                }),
-            });
+                calculate_url_range(line_num, line, email_start - line_start, email_text.len());
+
+            warnings.push(
+                LintWarning::new(
+                    self.name(),
+                    start_line,
+                    start_col,
+                    end_line,
+                    end_col,
+                    format!("Email address without angle brackets or link formatting"),
+                )
+                .with_fix(Fix::safe(
+                    email_start..email_end,
+                    format!("<{}>", email_text),
+                )),
+            );
         }
 
         Ok(warnings)
@@ -351,6 +384,10 @@ impl MD034NoBareUrls {
                         continue;
                     }
 
+                    if !self.has_allowed_scheme(trimmed_url) {
+                        continue;
+                    }
+
                     let before = if url_start == 0 {
                         None
                     } else {
@@ -379,19 +416,20 @@ impl MD034NoBareUrls {
                         let url_text = &text_str[url_start..url_end];
                         let (start_line, start_col, end_line, end_col) =
                             (line, column, line, column + url_text.chars().count());
-                        warnings.push(LintWarning {
-                            rule_name: Some(self.name()),
-                            line: start_line,
-                            column: start_col,
-                            end_line,
-                            end_column: end_col,
-                            message: format!("URL without angle brackets or link formatting"),
-                            severity: Severity::Warning,
-                            fix: Some(Fix {
-                                range: offset..(offset + url_text.len()),
-                                replacement: format!("<{}>", url_text),
-                            }),
-                        });
+                        warnings.push(
+                            LintWarning::new(
+                                self.name(),
+                                start_line,
+                                start_col,
+                                end_line,
+                                end_col,
+                                format!("URL without angle brackets or link formatting"),
+                            )
+                            .with_fix(Fix::safe(
+                                offset..(offset + url_text.len()),
+                                self.url_replacement(url_text),
+                            )),
+                        );
                     }
                 }
 
@@ -429,19 +467,20 @@ impl MD034NoBareUrls {
                         let email_text = &text_str[email_start..email_end];
                         let (start_line, start_col, end_line, end_col) =
                             (line, column, line, column + email_text.chars().count());
-                        warnings.push(LintWarning {
-                            rule_name: Some(self.name()),
-                            line: start_line,
-                            column: start_col,
-                            end_line,
-                            end_column: end_col,
-                            message: format!("Email address without angle brackets or link formatting (wrap like: <email>)"),
-                            severity: Severity::Warning,
-                            fix: Some(Fix {
-                                range: offset..(offset + email_text.len()),
-                                replacement: format!("<{}>", email_text),
-                            }),
-                        });
+                        warnings.push(
+                            LintWarning::new(
+                                self.name(),
+                                start_line,
+                                start_col,
+                                end_line,
+                                end_col,
+                                format!("Email address without angle brackets or link formatting (wrap like: <email>)"),
+                            )
+                            .with_fix(Fix::safe(
+                                offset..(offset + email_text.len()),
+                                format!("<{}>", email_text),
+                            )),
+                        );
                     }
                 }
             }
@@ -467,6 +506,10 @@ impl MD034NoBareUrls {
                         continue;
                     }
 
+                    if !self.has_allowed_scheme(trimmed_url) {
+                        continue;
+                    }
+
                     let before = if url_start == 0 {
                         None
                     } else {
@@ -495,19 +538,20 @@ impl MD034NoBareUrls {
                         let url_text = &alt_str[url_start..url_end];
                         let (start_line, start_col, end_line, end_col) =
                             (line, column, line, column + url_text.chars().count());
-                        warnings.push(LintWarning {
-                            rule_name: Some(self.name()),
-                            line: start_line,
-                            column: start_col,
-                            end_line,
-                            end_column: end_col,
-                            message: format!("URL without angle brackets or link formatting"),
-                            severity: Severity::Warning,
-                            fix: Some(Fix {
-                                range: offset..(offset + url_text.len()),
-                                replacement: format!("<{}>", url_text),
-                            }),
-                        });
+                        warnings.push(
+                            LintWarning::new(
+                                self.name(),
+                                start_line,
+                                start_col,
+                                end_line,
+                                end_col,
+                                format!("URL without angle brackets or link formatting"),
+                            )
+                            .with_fix(Fix::safe(
+                                offset..(offset + url_text.len()),
+                                self.url_replacement(url_text),
+                            )),
+                        );
                     }
                 }
             }
@@ -537,6 +581,10 @@ impl Rule for MD034NoBareUrls {
         "MD034"
     }
 
+    fn tags(&self) -> &[&'static str] {
+        &["links"]
+    }
+
     fn description(&self) -> &'static str {
         "URL without angle brackets or link formatting"
     }
@@ -552,11 +600,7 @@ impl Rule for MD034NoBareUrls {
         }
 
         // Fast path: Early return if no potential URLs or emails
-        if !content.contains("http://")
-            && !content.contains("https://")
-            && !content.contains("ftp://")
-            && !content.contains('@')
-        {
+        if !content.contains("://") && !content.contains('@') {
             return Ok(Vec::new());
         }
 
@@ -594,9 +638,7 @@ impl Rule for MD034NoBareUrls {
         }
 
         // Get all warnings first - only fix URLs that are actually flagged
-        // Use AST-based detection to match the main linting path (since uses_ast() returns true)
-        let ast = crate::utils::ast_utils::get_cached_ast(content);
-        let warnings = self.check_with_ast(ctx, &ast)?;
+        let warnings = self.check(ctx)?;
         if warnings.is_empty() {
             return Ok(content.to_string());
         }
@@ -649,11 +691,20 @@ impl Rule for MD034NoBareUrls {
         Some(self)
     }
 
-    fn from_config(_config: &crate::config::Config) -> Box<dyn Rule>
+    fn default_config_section(&self) -> Option<(String, toml::Value)> {
+        let json_value = serde_json::to_value(&self.config).ok()?;
+        Some((
+            self.name().to_string(),
+            crate::rule_config_serde::json_to_toml_value(&json_value)?,
+        ))
+    }
+
+    fn from_config(config: &crate::config::Config) -> Box<dyn Rule>
     where
         Self: Sized,
     {
-        Box::new(MD034NoBareUrls)
+        let rule_config = crate::rule_config_serde::load_rule_config::<MD034Config>(config);
+        Box::new(Self::from_config_struct(rule_config))
     }
 }
 
@@ -665,11 +716,7 @@ impl crate::utils::document_structure::DocumentStructureExtensions for MD034NoBa
     ) -> bool {
         // This rule is only relevant if there might be URLs or emails in the content
         let content = ctx.content;
-        !content.is_empty()
-            && (content.contains("http://")
-                || content.contains("https://")
-                || content.contains("ftp://")
-                || content.contains('@'))
+        !content.is_empty() && (content.contains("://") || content.contains('@'))
     }
 }
 
@@ -697,7 +744,7 @@ mod tests {
     #[test]
     #[ignore]
     fn test_multiple_badges_and_links_on_one_line() {
-        let rule = MD034NoBareUrls;
+        let rule = MD034NoBareUrls::default();
         let content = "# [React](https://react.dev/) \
 &middot; [![GitHub license](https://img.shields.io/badge/license-MIT-blue.svg)](https://github.com/facebook/react/blob/main/LICENSE) \
 [![npm version](https://img.shields.io/npm/v/react.svg?style=flat)](https://www.npmjs.com/package/react) \
@@ -717,7 +764,7 @@ mod tests {
 
     #[test]
     fn test_bare_urls() {
-        let rule = MD034NoBareUrls;
+        let rule = MD034NoBareUrls::default();
         let content = "This is a bare URL: https://example.com/foobar";
         let ctx = LintContext::new(content);
         let result = rule.check(&ctx).unwrap();
@@ -726,6 +773,43 @@ mod tests {
         assert_eq!(result[0].column, 21);
     }
 
+    #[test]
+    fn test_fix_keeps_trailing_punctuation_outside_url() {
+        let rule = MD034NoBareUrls::default();
+        let content = "See https://example.com/docs. Or ftp://files.example.com, maybe!";
+        let ctx = LintContext::new(content);
+        assert_eq!(rule.check(&ctx).unwrap().len(), 2);
+        assert_eq!(
+            rule.fix(&ctx).unwrap(),
+            "See <https://example.com/docs>. Or <ftp://files.example.com>, maybe!"
+        );
+    }
+
+    #[test]
+    fn test_url_in_link_or_code_left_alone() {
+        let rule = MD034NoBareUrls::default();
+        let content = "Read [the docs](https://example.com/docs) or <https://example.com>.\n\n\
+                       See [https://example.com](https://example.com).\n\n\
+                       Run `curl https://example.com/api`.\n";
+        let ctx = LintContext::new(content);
+        assert!(rule.check(&ctx).unwrap().is_empty());
+        assert_eq!(rule.fix(&ctx).unwrap(), content);
+    }
+
+    #[test]
+    fn test_link_style_with_scheme_allowlist() {
+        let rule = MD034NoBareUrls::new(BareUrlStyle::Link, vec!["https".to_string()]);
+        let content = "Use https://example.com, not http://example.org.";
+        let ctx = LintContext::new(content);
+        let warnings = rule.check(&ctx).unwrap();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].column, 5);
+        assert_eq!(
+            rule.fix(&ctx).unwrap(),
+            "Use [https://example.com](https://example.com), not http://example.org."
+        );
+    }
+
     #[test]
     fn test_md034_performance_baseline() {
         use std::time::Instant;
@@ -772,7 +856,7 @@ mod tests {
             content.lines().count()
         );
 
-        let rule = MD034NoBareUrls;
+        let rule = MD034NoBareUrls::default();
         let ctx = LintContext::new(&content);
 
         // Warm up
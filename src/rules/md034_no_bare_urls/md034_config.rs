@@ -0,0 +1,47 @@
+use crate::rule_config_serde::RuleConfig;
+use serde::{Deserialize, Serialize};
+
+/// How a bare URL is rewritten by the fix
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum BareUrlStyle {
+    /// Wrap the URL in angle brackets: `<https://example.com>` (default)
+    #[default]
+    AngleBrackets,
+    /// Turn the URL into an inline link: `[https://example.com](https://example.com)`
+    Link,
+}
+
+/// Configuration for MD034 (No bare URLs)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub struct MD034Config {
+    /// Formatting applied when fixing a bare URL (default: angle-brackets)
+    #[serde(default)]
+    pub style: BareUrlStyle,
+
+    /// URL schemes that are flagged when bare (default: http, https, ftp)
+    #[serde(default = "default_schemes")]
+    pub schemes: Vec<String>,
+}
+
+fn default_schemes() -> Vec<String> {
+    vec![
+        "http".to_string(),
+        "https".to_string(),
+        "ftp".to_string(),
+    ]
+}
+
+impl Default for MD034Config {
+    fn default() -> Self {
+        Self {
+            style: BareUrlStyle::default(),
+            schemes: default_schemes(),
+        }
+    }
+}
+
+impl RuleConfig for MD034Config {
+    const RULE_NAME: &'static str = "MD034";
+}
@@ -5,7 +5,7 @@
 
 use crate::utils::range_utils::{LineIndex, calculate_line_range};
 
-use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, Severity};
+use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule};
 use lazy_static::lazy_static;
 use regex::Regex;
 use toml;
@@ -110,6 +110,10 @@ impl Rule for MD035HRStyle {
         "MD035"
     }
 
+    fn tags(&self) -> &[&'static str] {
+        &["style", "consistency"]
+    }
+
     fn description(&self) -> &'static str {
         "Horizontal rule style"
     }
@@ -144,23 +148,24 @@ impl Rule for MD035HRStyle {
                     let (start_line, start_col, end_line, end_col) =
                         calculate_line_range(i + 1, line);
 
-                    warnings.push(LintWarning {
-                        rule_name: Some(self.name()),
-                        line: start_line,
-                        column: start_col,
-                        end_line,
-                        end_column: end_col,
-                        message: if has_indentation {
-                            "Horizontal rule should not be indented".to_string()
-                        } else {
-                            format!("Horizontal rule style should be \"{}\"", expected_style)
-                        },
-                        severity: Severity::Warning,
-                        fix: Some(Fix {
-                            range: _line_index.line_col_to_byte_range(i + 1, 1),
-                            replacement: expected_style.clone(),
-                        }),
-                    });
+                    warnings.push(
+                        LintWarning::new(
+                            self.name(),
+                            start_line,
+                            start_col,
+                            end_line,
+                            end_col,
+                            if has_indentation {
+                                "Horizontal rule should not be indented".to_string()
+                            } else {
+                                format!("Horizontal rule style should be \"{}\"", expected_style)
+                            },
+                        )
+                        .with_fix(Fix::safe(
+                            _line_index.line_col_to_byte_range(i + 1, 1),
+                            expected_style.clone(),
+                        )),
+                    );
                 }
             }
         }
@@ -201,6 +206,10 @@ impl Rule for MD035HRStyle {
         Ok(result.join("\n"))
     }
 
+    fn is_document_wide(&self) -> bool {
+        self.config.style.is_empty() || self.config.style == "consistent"
+    }
+
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
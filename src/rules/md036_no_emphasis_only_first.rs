@@ -3,8 +3,7 @@
 //!
 //! See [docs/md036.md](../../docs/md036.md) for full documentation, configuration, and examples.
 
-use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, Severity};
-use crate::utils::document_structure::DocumentStructure;
+use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule};
 use crate::utils::range_utils::{LineIndex, calculate_emphasis_range};
 use lazy_static::lazy_static;
 use regex::Regex;
@@ -19,9 +18,8 @@ lazy_static! {
     static ref RE_UNDERSCORE_SINGLE: Regex = Regex::new(r"^\s*_([^_\n]+)_\s*$").unwrap();
     static ref RE_ASTERISK_DOUBLE: Regex = Regex::new(r"^\s*\*\*([^*\n]+)\*\*\s*$").unwrap();
     static ref RE_UNDERSCORE_DOUBLE: Regex = Regex::new(r"^\s*__([^_\n]+)__\s*$").unwrap();
-    static ref LIST_MARKER: Regex = Regex::new(r"^\s*(?:[*+-]|\d+\.)\s+").unwrap();
-    static ref BLOCKQUOTE_MARKER: Regex = Regex::new(r"^\s*>").unwrap();
-    static ref FENCED_CODE_BLOCK_START: Regex = Regex::new(r"^(\s*)(`{3,}|~{3,})").unwrap();
+    static ref RE_ASTERISK_TRIPLE: Regex = Regex::new(r"^\s*\*\*\*([^*\n]+)\*\*\*\s*$").unwrap();
+    static ref RE_UNDERSCORE_TRIPLE: Regex = Regex::new(r"^\s*___([^_\n]+)___\s*$").unwrap();
     static ref HEADING_MARKER: Regex = Regex::new(r"^#+\s").unwrap();
     static ref HEADING_WITH_EMPHASIS: Regex = Regex::new(r"^(#+\s+).*(?:\*\*|\*|__|_)").unwrap();
     // Pattern to match common Table of Contents labels that should not be converted to headings
@@ -53,9 +51,11 @@ impl MD036NoEmphasisAsHeading {
         Self { config }
     }
 
+    // The heading level, text and byte range of a line that consists of a single emphasis
+    // or strong span. Lines in code blocks, lists and blockquotes never count.
     fn is_entire_line_emphasized(
+        ctx: &crate::lint_context::LintContext,
         line: &str,
-        doc_structure: &DocumentStructure,
         line_num: usize,
     ) -> Option<(usize, String, usize, usize)> {
         let original_line = line;
@@ -76,89 +76,44 @@ impl MD036NoEmphasisAsHeading {
             return None;
         }
 
-        // Skip if line is in a list, blockquote, or code block using DocumentStructure
-        if LIST_MARKER.is_match(line)
-            || BLOCKQUOTE_MARKER.is_match(line)
-            || doc_structure.is_in_code_block(line_num + 1)
-        // line_num is 0-based, but DocumentStructure expects 1-based
+        // line_num is 0-based, but LintContext expects 1-based
+        let info = ctx.line_info(line_num + 1)?;
+        if info.in_code_block
+            || info.list_item.is_some()
+            || info.blockquote.is_some()
+            || ctx.is_in_list_block(line_num + 1)
         {
             return None;
         }
 
-        // Check specific patterns directly without additional requirements
-        // Check for *emphasis* pattern (entire line)
-        if let Some(caps) = RE_ASTERISK_SINGLE.captures(line) {
-            let full_match = caps.get(0).unwrap();
-            let start_pos = original_line
-                .find(full_match.as_str())
-                .unwrap_or(0);
-            let end_pos = start_pos + full_match.len();
-            return Some((
-                1,
-                caps.get(1)
-                    .unwrap()
-                    .as_str()
-                    .to_string(),
-                start_pos,
-                end_pos,
-            ));
-        }
-
-        // Check for _emphasis_ pattern (entire line)
-        if let Some(caps) = RE_UNDERSCORE_SINGLE.captures(line) {
-            let full_match = caps.get(0).unwrap();
-            let start_pos = original_line
-                .find(full_match.as_str())
-                .unwrap_or(0);
-            let end_pos = start_pos + full_match.len();
-            return Some((
-                1,
-                caps.get(1)
-                    .unwrap()
-                    .as_str()
-                    .to_string(),
-                start_pos,
-                end_pos,
-            ));
-        }
-
-        // Check for **strong** pattern (entire line)
-        if let Some(caps) = RE_ASTERISK_DOUBLE.captures(line) {
-            let full_match = caps.get(0).unwrap();
-            let start_pos = original_line
-                .find(full_match.as_str())
-                .unwrap_or(0);
-            let end_pos = start_pos + full_match.len();
-            return Some((
-                2,
-                caps.get(1)
-                    .unwrap()
-                    .as_str()
-                    .to_string(),
-                start_pos,
-                end_pos,
-            ));
-        }
-
-        // Check for __strong__ pattern (entire line)
-        if let Some(caps) = RE_UNDERSCORE_DOUBLE.captures(line) {
-            let full_match = caps.get(0).unwrap();
-            let start_pos = original_line
-                .find(full_match.as_str())
-                .unwrap_or(0);
-            let end_pos = start_pos + full_match.len();
-            return Some((
-                2,
-                caps.get(1)
-                    .unwrap()
-                    .as_str()
-                    .to_string(),
-                start_pos,
-                end_pos,
-            ));
-        }
-
-        None
+        // Emphasis stands for a level 1 heading, strong for level 2 and both for level 3
+        let patterns: [(&Regex, usize); 6] = [
+            (&RE_ASTERISK_TRIPLE, 3),
+            (&RE_UNDERSCORE_TRIPLE, 3),
+            (&RE_ASTERISK_SINGLE, 1),
+            (&RE_UNDERSCORE_SINGLE, 1),
+            (&RE_ASTERISK_DOUBLE, 2),
+            (&RE_UNDERSCORE_DOUBLE, 2),
+        ];
+        patterns
+            .iter()
+            .find_map(|(regex, level)| {
+                let caps = regex.captures(line)?;
+                let full_match = caps.get(0).unwrap();
+                let start_pos = original_line
+                    .find(full_match.as_str())
+                    .unwrap_or(0);
+                let end_pos = start_pos + full_match.len();
+                Some((
+                    *level,
+                    caps.get(1)
+                        .unwrap()
+                        .as_str()
+                        .to_string(),
+                    start_pos,
+                    end_pos,
+                ))
+            })
     }
 
     fn get_heading_for_emphasis(&self, level: usize, text: &str) -> String {
@@ -217,6 +172,10 @@ impl Rule for MD036NoEmphasisAsHeading {
         "MD036"
     }
 
+    fn tags(&self) -> &[&'static str] {
+        &["emphasis", "headings"]
+    }
+
     fn description(&self) -> &'static str {
         "Emphasis should not be used instead of a heading"
     }
@@ -228,23 +187,6 @@ impl Rule for MD036NoEmphasisAsHeading {
             return Ok(Vec::new());
         }
 
-        // Use the optimized document structure approach
-        let doc_structure = DocumentStructure::new(content);
-        self.check_with_structure(ctx, &doc_structure)
-    }
-
-    /// Optimized check using pre-computed document structure
-    fn check_with_structure(
-        &self,
-        ctx: &crate::lint_context::LintContext,
-        doc_structure: &DocumentStructure,
-    ) -> LintResult {
-        let content = ctx.content;
-        // Fast path for empty content or content without emphasis markers
-        if content.is_empty() || (!content.contains('*') && !content.contains('_')) {
-            return Ok(Vec::new());
-        }
-
         let mut warnings = Vec::new();
         let line_index = LineIndex::new(content.to_string());
 
@@ -255,24 +197,25 @@ impl Rule for MD036NoEmphasisAsHeading {
             }
 
             if let Some((level, text, start_pos, end_pos)) =
-                Self::is_entire_line_emphasized(line, doc_structure, i)
+                Self::is_entire_line_emphasized(ctx, line, i)
             {
                 let (start_line, start_col, end_line, end_col) =
                     calculate_emphasis_range(i + 1, line, start_pos, end_pos);
 
-                warnings.push(LintWarning {
-                    rule_name: Some(self.name()),
-                    line: start_line,
-                    column: start_col,
-                    end_line,
-                    end_column: end_col,
-                    message: format!("Emphasis used instead of a heading: '{}'", text),
-                    severity: Severity::Warning,
-                    fix: Some(Fix {
-                        range: line_index.line_content_range(i + 1),
-                        replacement: self.get_heading_for_emphasis(level, &text),
-                    }),
-                });
+                warnings.push(
+                    LintWarning::new(
+                        self.name(),
+                        start_line,
+                        start_col,
+                        end_line,
+                        end_col,
+                        format!("Emphasis used instead of a heading: '{}'", text),
+                    )
+                    .with_fix(Fix::safe(
+                        line_index.line_content_range(i + 1),
+                        self.get_heading_for_emphasis(level, &text),
+                    )),
+                );
             }
         }
 
@@ -289,12 +232,11 @@ impl Rule for MD036NoEmphasisAsHeading {
         let mut result = String::with_capacity(content.len());
         let lines: Vec<&str> = content.lines().collect();
         let ends_with_newline = content.ends_with('\n');
-        let doc_structure = DocumentStructure::new(content);
 
         for i in 0..lines.len() {
             let line = lines[i];
             if let Some((level, text, _start_pos, _end_pos)) =
-                Self::is_entire_line_emphasized(line, &doc_structure, i)
+                Self::is_entire_line_emphasized(ctx, line, i)
             {
                 result.push_str(&self.get_heading_for_emphasis(level, &text));
             } else {
@@ -336,3 +278,33 @@ impl Rule for MD036NoEmphasisAsHeading {
         Box::new(MD036NoEmphasisAsHeading::new(punctuation))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lint_context::{HeadingStyle, LintContext};
+
+    #[test]
+    fn test_fix_converts_emphasis_lines_to_atx_headings() {
+        let rule = MD036NoEmphasisAsHeading::default();
+        let content = "*Introduction*\n\nText\n\n__Setup:__\n\n***Usage***\n\n- **In a list**\n";
+        let fixed = rule
+            .fix(&LintContext::new(content))
+            .unwrap();
+        assert_eq!(
+            fixed,
+            "# Introduction\n\nText\n\n## Setup\n\n### Usage\n\n- **In a list**\n"
+        );
+
+        let ctx = LintContext::new(&fixed);
+        for (line, level) in [(1, 1), (5, 2), (7, 3)] {
+            let heading = ctx
+                .line_info(line)
+                .and_then(|info| info.heading.as_ref())
+                .unwrap();
+            assert_eq!(heading.style, HeadingStyle::ATX);
+            assert_eq!(heading.level, level);
+        }
+        assert!(rule.check(&ctx).unwrap().is_empty());
+    }
+}
@@ -1,7 +1,7 @@
 /// Rule MD037: No spaces around emphasis markers
 ///
 /// See [docs/md037.md](../../docs/md037.md) for full documentation, configuration, and examples.
-use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory};
 use crate::utils::document_structure::{DocumentStructure, DocumentStructureExtensions};
 use lazy_static::lazy_static;
 use regex::Regex;
@@ -289,6 +289,10 @@ impl Rule for MD037NoSpaceInEmphasis {
         "MD037"
     }
 
+    fn tags(&self) -> &[&'static str] {
+        &["emphasis", "whitespace"]
+    }
+
     fn description(&self) -> &'static str {
         "Spaces inside emphasis markers"
     }
@@ -342,7 +346,26 @@ impl Rule for MD037NoSpaceInEmphasis {
             let line_no_code = replace_inline_code(line);
 
             // Use the optimized emphasis parsing logic
+            let first = warnings.len();
             self.check_line_for_emphasis_issues_fast(&line_no_code, line_num + 1, &mut warnings);
+
+            // The line's warnings are located by byte in the line: count their columns in
+            // chars of the original line and make their fix ranges absolute
+            let line_start = ctx
+                .lines
+                .get(line_num)
+                .map_or(0, |info| info.byte_offset);
+            let char_column = |byte_column: usize| match line.get(..byte_column - 1) {
+                Some(before) => before.chars().count() + 1,
+                None => byte_column,
+            };
+            for warning in &mut warnings[first..] {
+                warning.column = char_column(warning.column);
+                warning.end_column = char_column(warning.end_column);
+                if let Some(fix) = &mut warning.fix {
+                    fix.range = line_start + fix.range.start..line_start + fix.range.end;
+                }
+            }
         }
 
         Ok(warnings)
@@ -368,14 +391,6 @@ impl Rule for MD037NoSpaceInEmphasis {
             return Ok(content.to_string());
         }
 
-        // Get all line positions to make it easier to apply fixes by warning
-        let mut line_positions = Vec::new();
-        let mut pos = 0;
-        for line in content.lines() {
-            line_positions.push(pos);
-            pos += line.len() + 1; // +1 for the newline
-        }
-
         // Apply fixes
         let mut result = content.to_string();
         let mut offset: isize = 0;
@@ -385,21 +400,13 @@ impl Rule for MD037NoSpaceInEmphasis {
             .iter()
             .filter(|w| w.fix.is_some())
             .collect();
-        sorted_warnings.sort_by_key(|w| (w.line, w.column));
+        sorted_warnings.sort_by(|a, b| a.canonical_cmp(b));
 
         for warning in sorted_warnings {
             if let Some(fix) = &warning.fix {
-                // Calculate the absolute position in the file
-                let line_start = line_positions
-                    .get(warning.line - 1)
-                    .copied()
-                    .unwrap_or(0);
-                let abs_start = line_start + warning.column - 1;
-                let abs_end = abs_start + (fix.range.end - fix.range.start);
-
                 // Apply fix with offset adjustment
-                let actual_start = (abs_start as isize + offset) as usize;
-                let actual_end = (abs_end as isize + offset) as usize;
+                let actual_start = (fix.range.start as isize + offset) as usize;
+                let actual_end = (fix.range.end as isize + offset) as usize;
 
                 // Make sure we're not out of bounds
                 if actual_start < result.len() && actual_end <= result.len() {
@@ -569,19 +576,18 @@ impl MD037NoSpaceInEmphasis {
                 let trimmed_content = span.content.trim();
                 let fixed_text = format!("{}{}{}", marker_str, trimmed_content, marker_str);
 
-                let warning = LintWarning {
-                    rule_name: Some(self.name()),
-                    message: format!("Spaces inside emphasis markers: {:?}", full_text),
-                    line: line_num,
-                    column: offset + full_start + 1, // +1 because columns are 1-indexed
-                    end_line: line_num,
-                    end_column: offset + full_end + 1,
-                    severity: Severity::Warning,
-                    fix: Some(Fix {
-                        range: (offset + full_start)..(offset + full_end),
-                        replacement: fixed_text,
-                    }),
-                };
+                let warning = LintWarning::new(
+                    self.name(),
+                    line_num,
+                    offset + full_start + 1, // +1 because columns are 1-indexed
+                    line_num,
+                    offset + full_end + 1,
+                    format!("Spaces inside emphasis markers: {:?}", full_text),
+                )
+                .with_fix(Fix::safe(
+                    (offset + full_start)..(offset + full_end),
+                    fixed_text,
+                ));
 
                 warnings.push(warning);
             }
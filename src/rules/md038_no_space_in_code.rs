@@ -1,4 +1,4 @@
-use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory};
 /// Rule MD038: No space inside code span markers
 ///
 /// See [docs/md038.md](../../docs/md038.md) for full documentation, configuration, and examples.
@@ -125,6 +125,10 @@ impl Rule for MD038NoSpaceInCode {
         "MD038"
     }
 
+    fn tags(&self) -> &[&'static str] {
+        &["code", "whitespace"]
+    }
+
     fn description(&self) -> &'static str {
         "Spaces inside code span elements"
     }
@@ -158,24 +162,25 @@ impl Rule for MD038NoSpaceInCode {
                     continue;
                 }
 
-                warnings.push(LintWarning {
-                    rule_name: Some(self.name()),
-                    line: code_span.line,
-                    column: code_span.start_col + 1, // Convert to 1-indexed
-                    end_line: code_span.line,
-                    end_column: code_span.end_col, // Don't add 1 to match test expectation
-                    message: "Spaces inside code span elements".to_string(),
-                    severity: Severity::Warning,
-                    fix: Some(Fix {
-                        range: code_span.byte_offset..code_span.byte_end,
-                        replacement: format!(
+                warnings.push(
+                    LintWarning::new(
+                        self.name(),
+                        code_span.line,
+                        ctx.char_column(code_span.line, code_span.start_col) + 1, // Convert to 1-indexed
+                        code_span.line,
+                        ctx.char_column(code_span.line, code_span.end_col), // Don't add 1 to match test expectation
+                        "Spaces inside code span elements",
+                    )
+                    .with_fix(Fix::safe(
+                        code_span.byte_offset..code_span.byte_end,
+                        format!(
                             "{}{}{}",
                             "`".repeat(code_span.backtick_count),
                             trimmed,
                             "`".repeat(code_span.backtick_count)
                         ),
-                    }),
-                });
+                    )),
+                );
             }
         }
 
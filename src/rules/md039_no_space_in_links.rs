@@ -1,4 +1,4 @@
-use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory};
 use lazy_static::lazy_static;
 use regex::Regex;
 
@@ -89,6 +89,10 @@ impl Rule for MD039NoSpaceInLinks {
         "MD039"
     }
 
+    fn tags(&self) -> &[&'static str] {
+        &["links", "whitespace"]
+    }
+
     fn description(&self) -> &'static str {
         "Spaces inside link text"
     }
@@ -151,19 +155,17 @@ impl Rule for MD039NoSpaceInLinks {
                     format!("[{}]{}", trimmed, url)
                 };
 
-                warnings.push(LintWarning {
-                    rule_name: Some(self.name()),
-                    line: link.line,
-                    column: link.start_col + 1, // Convert to 1-indexed
-                    end_line: link.line,
-                    end_column: link.end_col + 1, // Convert to 1-indexed
-                    message: WARNING_MESSAGE.to_string(),
-                    severity: Severity::Warning,
-                    fix: Some(Fix {
-                        range: link.byte_offset..link.byte_end,
-                        replacement: fixed,
-                    }),
-                });
+                warnings.push(
+                    LintWarning::new(
+                        self.name(),
+                        link.line,
+                        ctx.char_column(link.line, link.start_col) + 1, // Convert to 1-indexed
+                        link.line,
+                        ctx.char_column(link.line, link.end_col) + 1, // Convert to 1-indexed
+                        WARNING_MESSAGE.to_string(),
+                    )
+                    .with_fix(Fix::safe(link.byte_offset..link.byte_end, fixed)),
+                );
             }
         }
 
@@ -209,19 +211,17 @@ impl Rule for MD039NoSpaceInLinks {
                     format!("![{}]{}", trimmed, url)
                 };
 
-                warnings.push(LintWarning {
-                    rule_name: Some(self.name()),
-                    line: image.line,
-                    column: image.start_col + 1, // Convert to 1-indexed
-                    end_line: image.line,
-                    end_column: image.end_col + 1, // Convert to 1-indexed
-                    message: WARNING_MESSAGE.to_string(),
-                    severity: Severity::Warning,
-                    fix: Some(Fix {
-                        range: image.byte_offset..image.byte_end,
-                        replacement: fixed,
-                    }),
-                });
+                warnings.push(
+                    LintWarning::new(
+                        self.name(),
+                        image.line,
+                        ctx.char_column(image.line, image.start_col) + 1, // Convert to 1-indexed
+                        image.line,
+                        ctx.char_column(image.line, image.end_col) + 1, // Convert to 1-indexed
+                        WARNING_MESSAGE.to_string(),
+                    )
+                    .with_fix(Fix::safe(image.byte_offset..image.byte_end, fixed)),
+                );
             }
         }
 
@@ -1,4 +1,4 @@
-use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory};
 use crate::utils::document_structure::{DocumentStructure, DocumentStructureExtensions};
 use crate::utils::range_utils::{LineIndex, calculate_line_range};
 
@@ -14,6 +14,10 @@ impl Rule for MD040FencedCodeLanguage {
         "MD040"
     }
 
+    fn tags(&self) -> &[&'static str] {
+        &["code"]
+    }
+
     fn description(&self) -> &'static str {
         "Code blocks should have a language specified"
     }
@@ -105,16 +109,17 @@ impl Rule for MD040FencedCodeLanguage {
                         let (start_line, start_col, end_line, end_col) =
                             calculate_line_range(i + 1, line);
 
-                        warnings.push(LintWarning {
-                            rule_name: Some(self.name()),
-                            line: start_line,
-                            column: start_col,
-                            end_line,
-                            end_column: end_col,
-                            message: "Code block (```) missing language".to_string(),
-                            severity: Severity::Warning,
-                            fix: Some(Fix {
-                                range: {
+                        warnings.push(
+                            LintWarning::new(
+                                self.name(),
+                                start_line,
+                                start_col,
+                                end_line,
+                                end_col,
+                                "Code block (```) missing language",
+                            )
+                            .with_fix(Fix::safe(
+                                {
                                     // Replace just the fence marker with fence+language
                                     let trimmed_start = line.len() - line.trim_start().len();
                                     let fence_len = fence_marker.len();
@@ -127,9 +132,9 @@ impl Rule for MD040FencedCodeLanguage {
                                     let fence_end_byte = fence_start_byte + fence_len;
                                     fence_start_byte..fence_end_byte
                                 },
-                                replacement: format!("{}text", fence_marker),
-                            }),
-                        });
+                                format!("{}text", fence_marker),
+                            )),
+                        );
                     }
 
                     in_code_block = true;
@@ -1,6 +1,6 @@
 use crate::utils::range_utils::{LineIndex, calculate_line_range};
 
-use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, Severity};
+use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule};
 use crate::rules::front_matter_utils::FrontMatterUtils;
 
 /// Rule MD041: First line in file should be a top-level heading
@@ -44,6 +44,10 @@ impl Rule for MD041FirstLineHeading {
         "MD041"
     }
 
+    fn tags(&self) -> &[&'static str] {
+        &["headings", "structure"]
+    }
+
     fn description(&self) -> &'static str {
         "First line in file should be a top level heading"
     }
@@ -112,23 +116,24 @@ impl Rule for MD041FirstLineHeading {
             let (start_line, start_col, end_line, end_col) =
                 calculate_line_range(first_line, first_line_content);
 
-            warnings.push(LintWarning {
-                rule_name: Some(self.name()),
-                line: start_line,
-                column: start_col,
-                end_line,
-                end_column: end_col,
-                message: format!(
-                    "First line in file should be a level {} heading",
-                    self.level
-                ),
-                severity: Severity::Warning,
-                fix: Some(Fix {
-                    range: LineIndex::new(content.to_string())
+            warnings.push(
+                LintWarning::new(
+                    self.name(),
+                    start_line,
+                    start_col,
+                    end_line,
+                    end_col,
+                    format!(
+                        "First line in file should be a level {} heading",
+                        self.level
+                    ),
+                )
+                .with_fix(Fix::safe(
+                    LineIndex::new(content.to_string())
                         .line_col_to_byte_range_with_length(first_line, 1, 0),
-                    replacement: format!("{} Title\n\n", "#".repeat(self.level)),
-                }),
-            });
+                    format!("{} Title\n\n", "#".repeat(self.level)),
+                )),
+            );
         }
         Ok(warnings)
     }
@@ -238,6 +243,10 @@ impl Rule for MD041FirstLineHeading {
         Ok(result)
     }
 
+    fn is_document_wide(&self) -> bool {
+        true
+    }
+
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
@@ -1,4 +1,4 @@
-use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory};
 use crate::utils::document_structure::{DocumentStructure, DocumentStructureExtensions};
 use crate::utils::range_utils::LineIndex;
 
@@ -27,6 +27,10 @@ impl Rule for MD042NoEmptyLinks {
         "MD042"
     }
 
+    fn tags(&self) -> &[&'static str] {
+        &["links"]
+    }
+
     fn description(&self) -> &'static str {
         "No empty links"
     }
@@ -74,19 +78,17 @@ impl Rule for MD042NoEmptyLinks {
                     }
                 };
 
-                warnings.push(LintWarning {
-                    rule_name: Some(self.name()),
-                    message: format!("Empty link found: [{}]({})", link.text, effective_url),
-                    line: link.line,
-                    column: link.start_col + 1, // Convert to 1-indexed
-                    end_line: link.line,
-                    end_column: link.end_col + 1, // Convert to 1-indexed
-                    severity: Severity::Warning,
-                    fix: Some(Fix {
-                        range: link.byte_offset..link.byte_end,
-                        replacement,
-                    }),
-                });
+                warnings.push(
+                    LintWarning::new(
+                        self.name(),
+                        link.line,
+                        ctx.char_column(link.line, link.start_col) + 1, // Convert to 1-indexed
+                        link.line,
+                        ctx.char_column(link.line, link.end_col) + 1, // Convert to 1-indexed
+                        format!("Empty link found: [{}]({})", link.text, effective_url),
+                    )
+                    .with_fix(Fix::safe(link.byte_offset..link.byte_end, replacement)),
+                );
             }
         }
 
@@ -96,10 +98,10 @@ impl Rule for MD042NoEmptyLinks {
     /// Optimized check using document structure
     fn check_with_structure(
         &self,
-        _ctx: &crate::lint_context::LintContext,
+        ctx: &crate::lint_context::LintContext,
         structure: &DocumentStructure,
     ) -> LintResult {
-        let content = _ctx.content;
+        let content = ctx.content;
         // Early return if there are no links
         if structure.links.is_empty() {
             return Ok(Vec::new());
@@ -120,23 +122,24 @@ impl Rule for MD042NoEmptyLinks {
                 format!("[{}](https://example.com)", link.text)
             };
 
-            warnings.push(LintWarning {
-                rule_name: Some(self.name()),
-                message: format!("Empty link found: [{}]({})", link.text, link.url),
-                line: link.line,
-                column: link.start_col,
-                end_line: link.line,
-                end_column: link.end_col + 1,
-                severity: Severity::Warning,
-                fix: Some(Fix {
-                    range: line_index.line_col_to_byte_range_with_length(
+            warnings.push(
+                LintWarning::new(
+                    self.name(),
+                    link.line,
+                    ctx.char_column(link.line, link.start_col),
+                    link.line,
+                    ctx.char_column(link.line, link.end_col) + 1,
+                    format!("Empty link found: [{}]({})", link.text, link.url),
+                )
+                .with_fix(Fix::safe(
+                    line_index.line_col_to_byte_range_with_length(
                         link.line,
                         link.start_col,
                         (link.end_col + 1).saturating_sub(link.start_col),
                     ),
                     replacement,
-                }),
-            });
+                )),
+            );
         }
 
         Ok(warnings)
@@ -1,4 +1,4 @@
-use crate::rule::{LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule::{LintError, LintResult, LintWarning, Rule, RuleCategory};
 use crate::utils::document_structure::{DocumentStructure, DocumentStructureExtensions};
 use crate::utils::range_utils::calculate_heading_range;
 use lazy_static::lazy_static;
@@ -50,6 +50,10 @@ impl Rule for MD043RequiredHeadings {
         "MD043"
     }
 
+    fn tags(&self) -> &[&'static str] {
+        &["headings", "structure"]
+    }
+
     fn description(&self) -> &'static str {
         "Required heading structure"
     }
@@ -66,16 +70,14 @@ impl Rule for MD043RequiredHeadings {
         if actual_headings != self.headings {
             // If no headings found but we have required headings, create a warning
             if actual_headings.is_empty() && !self.headings.is_empty() {
-                warnings.push(LintWarning {
-                    rule_name: Some(self.name()),
-                    line: 1,
-                    column: 1,
-                    end_line: 1,
-                    end_column: 2,
-                    message: format!("Required headings not found: {:?}", self.headings),
-                    severity: Severity::Warning,
-                    fix: None,
-                });
+                warnings.push(LintWarning::new(
+                    self.name(),
+                    1,
+                    1,
+                    1,
+                    2,
+                    format!("Required headings not found: {:?}", self.headings),
+                ));
                 return Ok(warnings);
             }
 
@@ -86,36 +88,32 @@ impl Rule for MD043RequiredHeadings {
                     let (start_line, start_col, end_line, end_col) =
                         calculate_heading_range(i + 1, &line_info.content);
 
-                    warnings.push(LintWarning {
-                        rule_name: Some(self.name()),
-                        line: start_line,
-                        column: start_col,
+                    // Cannot automatically fix as we don't know the intended structure
+                    warnings.push(LintWarning::new(
+                        self.name(),
+                        start_line,
+                        start_col,
                         end_line,
-                        end_column: end_col,
-                        message: "Heading structure does not match the required structure"
-                            .to_string(),
-                        severity: Severity::Warning,
-                        fix: None, // Cannot automatically fix as we don't know the intended structure
-                    });
+                        end_col,
+                        "Heading structure does not match the required structure",
+                    ));
                 }
             }
 
             // If we have no warnings but headings don't match (could happen if we have no headings),
             // add a warning at the beginning of the file
             if warnings.is_empty() {
-                warnings.push(LintWarning {
-                    rule_name: Some(self.name()),
-                    line: 1,
-                    column: 1,
-                    end_line: 1,
-                    end_column: 2,
-                    message: format!(
+                warnings.push(LintWarning::new(
+                    self.name(),
+                    1,
+                    1,
+                    1,
+                    2,
+                    format!(
                         "Heading structure does not match required structure. Expected: {:?}, Found: {:?}",
                         self.headings, actual_headings
                     ),
-                    severity: Severity::Warning,
-                    fix: None,
-                });
+                ));
             }
         }
 
@@ -173,6 +171,10 @@ impl Rule for MD043RequiredHeadings {
         !has_heading
     }
 
+    fn is_document_wide(&self) -> bool {
+        !self.headings.is_empty()
+    }
+
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
@@ -1,18 +1,162 @@
 use crate::utils::fast_hash;
-use crate::utils::range_utils::LineIndex;
+use crate::utils::fix_utils::{collapse_boundary_spaces, validate_fixes};
 
-use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, Severity};
+use crate::rule::{Fix, FixSafety, LintError, LintResult, LintWarning, Rule, SkippedFix};
+use crate::rules::blockquote_utils::BlockquoteUtils;
+use crate::rules::front_matter_utils::{FrontMatterUtils, FrontMatterValues};
 use fancy_regex::Regex;
 use lazy_static::lazy_static;
-use std::collections::HashMap;
+use markdown::mdast::Node;
+use std::collections::{HashMap, VecDeque};
+use std::ops::Range;
 use std::sync::{Arc, Mutex};
 
-mod md044_config;
-use md044_config::MD044Config;
+pub(crate) mod md044_config;
+use md044_config::{MD044Config, NameContext};
 
-lazy_static! {}
+lazy_static! {
+    // A reference definition up to its destination, also inside blockquotes. Footnote
+    // definitions (`[^1]: text`) hold text instead.
+    static ref REFERENCE_DEFINITION: Regex =
+        Regex::new(r"^(?:[ \t]*>)*[ \t]{0,3}\[(?!\^)(?:[^\]\\]|\\.)+\]:[ \t]*").unwrap();
+}
+
+// A name to replace: the 1-indexed line it is on, the byte span of the match in the
+// document, as found, and its replacement
+#[derive(Debug, Clone, PartialEq)]
+struct WarningPosition {
+    line: usize,
+    span: Range<usize>,
+    found: String,
+    replacement: String,
+}
+
+impl WarningPosition {
+    // The 1-indexed column of the match, counted in chars as warnings report it
+    fn column(&self, ctx: &crate::lint_context::LintContext) -> usize {
+        let line_start = ctx.line_offsets[self.line - 1];
+        ctx.content[line_start..self.span.start]
+            .chars()
+            .count()
+            + 1
+    }
+}
+
+// The violations found in a document, with what is needed to tell it from another document
+// of the same hash
+#[derive(Debug, Clone)]
+struct CacheEntry {
+    len: usize,
+    // The full document, kept with `verify_cache_hits`
+    content: Option<String>,
+    violations: Vec<WarningPosition>,
+}
+
+// The entries of the most recently checked documents, by content hash. Once `capacity`
+// documents are cached, caching another evicts the least recently used one.
+#[derive(Debug)]
+struct ContentCache {
+    capacity: usize,
+    entries: HashMap<u64, CacheEntry>,
+    // Hashes from least to most recently used
+    order: VecDeque<u64>,
+}
+
+impl ContentCache {
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: HashMap::new(),
+            order: VecDeque::new(),
+        }
+    }
+
+    fn get(&mut self, hash: u64) -> Option<&CacheEntry> {
+        let entry = self.entries.get(&hash)?;
+        if let Some(pos) = self
+            .order
+            .iter()
+            .position(|&h| h == hash)
+        {
+            self.order.remove(pos);
+        }
+        self.order.push_back(hash);
+        Some(entry)
+    }
+
+    fn insert(&mut self, hash: u64, entry: CacheEntry) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self
+            .entries
+            .insert(hash, entry)
+            .is_some()
+        {
+            self.order.retain(|&h| h != hash);
+        } else if self.entries.len() > self.capacity
+            && let Some(oldest) = self.order.pop_front()
+        {
+            self.entries.remove(&oldest);
+        }
+        self.order.push_back(hash);
+    }
+}
+
+/// Check if a line is a table delimiter row (`|---|:---:|`), including single-column ones.
+/// These rows are table structure and must never be rewritten.
+fn is_table_delimiter_line(trimmed: &str) -> bool {
+    trimmed.contains('|')
+        && trimmed.contains('-')
+        && trimmed
+            .chars()
+            .all(|c| matches!(c, '|' | '-' | ':' | ' ' | '\t'))
+}
+
+/// Check if byte offset `pos` in `line` falls inside a URL (`https://...`, `www....`,
+/// `<https://...>`), an inline link or image destination (`[text](path)`, `![](path)`) or
+/// the destination of a reference definition (`[id]: path "title"`).
+fn is_in_url(line: &str, pos: usize) -> bool {
+    // The destination runs from the definition's `]:` to the next whitespace
+    if let Ok(Some(definition)) = REFERENCE_DEFINITION.find(line)
+        && pos >= definition.end()
+        && !line[definition.end()..pos].contains(char::is_whitespace)
+    {
+        return true;
+    }
+
+    let token_start = line[..pos]
+        .rfind(char::is_whitespace)
+        .map_or(0, |i| i + 1);
+    let before = &line[token_start..pos];
+
+    // Inside an inline link destination that has not been closed yet
+    if let Some(open) = before.rfind("](")
+        && !before[open..].contains(')')
+    {
+        return true;
+    }
 
-type WarningPosition = (usize, usize, String); // (line, column, found_name)
+    // Inside a bare URL or autolink: the scheme (or `www.`) must precede the match
+    let url_start = before
+        .rfind(['(', '<', '['])
+        .map_or(0, |i| i + 1);
+    let url_prefix = &before[url_start..];
+    url_prefix.contains("://") || url_prefix.starts_with("www.")
+}
+
+/// Check if byte offset `pos` in `line`, which starts at byte `line_start` of the content,
+/// falls inside code (a block, a span or an HTML code element) or a URL.
+fn is_in_code_or_url(
+    ctx: &crate::lint_context::LintContext,
+    line: &str,
+    line_start: usize,
+    pos: usize,
+) -> bool {
+    ctx.is_in_code_block_or_span(line_start + pos)
+        || ctx.is_in_html_code_element(line_start + pos)
+        || is_in_url(line, pos)
+}
 
 /// Rule MD044: Proper names should be capitalized
 ///
@@ -36,15 +180,16 @@ type WarningPosition = (usize, usize, String); // (line, column, found_name)
 /// ```yaml
 /// MD044:
 ///   names: []                # List of proper names to check for correct capitalization
-///   code_blocks_excluded: true  # Whether to exclude code blocks from checking
+///   code_blocks: true        # Whether to exclude fenced and indented code blocks from checking
+///   code_spans: true         # Whether to exclude inline code spans from checking
 /// ```
 ///
-/// Example configuration:
+/// Example configuration, checking inline code but not code blocks:
 ///
 /// ```yaml
 /// MD044:
 ///   names: ["JavaScript", "Node.js", "TypeScript"]
-///   code_blocks_excluded: true
+///   code_spans: false
 /// ```
 ///
 /// ## Performance Optimizations
@@ -62,31 +207,81 @@ type WarningPosition = (usize, usize, String); // (line, column, found_name)
 /// - **Case Sensitivity**: Properly handles case-specific matching
 /// - **Code Blocks**: Optionally excludes code blocks where capitalization may be intentionally different
 /// - **Markdown Formatting**: Handles proper names within Markdown formatting elements
+/// - **Front Matter**: Skips front matter opened by one of the global `front-matter-delimiters`,
+///   unless `check_front_matter_values` is set (see below)
+///
+/// ## Synonym Groups
+///
+/// `synonyms` maps a preferred term to terms that should be replaced by it, so
+/// `JavaScript = ["ECMAScript"]` flags "ECMAScript" (in any capitalization) and fixes it to
+/// "JavaScript". The preferred term itself is checked like a configured name. Synonyms are
+/// never replaced inside code blocks, code spans, URLs or link destinations.
+///
+/// ## Consistency Mode
+///
+/// With `consistency` enabled, the rule also flags words written with differing
+/// capitalization across the document (e.g. "GitHub" and "Github"), even when they are not
+/// configured, and suggests the most frequent form (the first one on a tie). Differences in
+/// the first letter only (sentence case) and all-caps forms are not flagged.
+///
+/// ## Required Contexts
+///
+/// `require_in` limits the rule to names inside the listed markup: `emphasis`, `strong`,
+/// `link_text` or `heading`. With `require_in = ["heading"]`, "github" in a heading is
+/// flagged but the same word in a paragraph is not. By default names are checked everywhere.
+///
+/// ## HTML
+///
+/// Names inside HTML tags, such as `class="javascript"`, are never flagged. The text of raw
+/// HTML blocks is only checked, and fixed, with `check_html_text` enabled. HTML comments are
+/// checked wherever they are unless `html_comments` is disabled.
+///
+/// ## URLs
+///
+/// Names inside URLs, autolinks and the destinations of links, images and reference
+/// definitions are not flagged, as changing them would break the link; the link text
+/// still is. Enable `check_urls` to check destinations too.
+///
+/// ## Front Matter Values
+///
+/// With `check_front_matter_values`, YAML (`---`) and TOML (`+++`) front matter is parsed and
+/// its string values are checked like text, including the items of flow or block sequences
+/// and values continued over several lines. Keys, comments and other front matter formats
+/// are left alone, as is front matter that does not parse.
 ///
 /// ## Fix Behavior
 ///
 /// When fixing issues, this rule replaces incorrect capitalization with the correct form
-/// as defined in the configuration.
+/// as defined in the configuration, and synonyms with their preferred term.
 ///
 #[derive(Clone)]
 pub struct MD044ProperNames {
     config: MD044Config,
-    #[allow(dead_code)] // TODO: Implement HTML comment checking in future
-    html_comments: bool,
     // Cache the combined regex pattern
     combined_regex: Arc<Mutex<Option<Regex>>>,
-    // Cache for name violations by content hash
-    content_cache: Arc<Mutex<HashMap<u64, Vec<WarningPosition>>>>,
+    // Cache for name violations by content hash, holding `cache_capacity` documents. Each
+    // instance, and the clones sharing its cache, has a fixed config, so options like
+    // `html_comments` never see stale entries.
+    content_cache: Arc<Mutex<ContentCache>>,
+    // Hash of a document for `content_cache`
+    cache_hasher: fn(&str) -> u64,
+    front_matter_delimiters: Vec<String>,
 }
 
 impl MD044ProperNames {
     pub fn new(names: Vec<String>, code_blocks: bool) -> Self {
-        let config = MD044Config { names, code_blocks };
+        let config = MD044Config {
+            names,
+            code_blocks,
+            ..Default::default()
+        };
+        let cache = ContentCache::new(config.cache_capacity);
         let mut instance = Self {
             config,
-            html_comments: true, // Default to checking HTML comments
             combined_regex: Arc::new(Mutex::new(None)),
-            content_cache: Arc::new(Mutex::new(HashMap::new())),
+            content_cache: Arc::new(Mutex::new(cache)),
+            cache_hasher: fast_hash,
+            front_matter_delimiters: FrontMatterUtils::default_delimiters(),
         };
 
         // Pre-compile the combined regex
@@ -95,11 +290,13 @@ impl MD044ProperNames {
     }
 
     pub fn from_config_struct(config: MD044Config) -> Self {
+        let cache = ContentCache::new(config.cache_capacity);
         let mut instance = Self {
             config,
-            html_comments: true,
             combined_regex: Arc::new(Mutex::new(None)),
-            content_cache: Arc::new(Mutex::new(HashMap::new())),
+            content_cache: Arc::new(Mutex::new(cache)),
+            cache_hasher: fast_hash,
+            front_matter_delimiters: FrontMatterUtils::default_delimiters(),
         };
         instance.compile_combined_regex();
         instance
@@ -119,39 +316,90 @@ impl MD044ProperNames {
         }
     }
 
-    // Create a combined regex pattern for all proper names
+    // All terms the rule looks for: proper names, preferred terms and their synonyms
+    fn terms(&self) -> impl Iterator<Item = &String> {
+        self.config.names.iter().chain(
+            self.config
+                .synonyms
+                .iter()
+                .flat_map(|(preferred, synonyms)| std::iter::once(preferred).chain(synonyms)),
+        )
+    }
+
+    fn has_terms(&self) -> bool {
+        self.terms().next().is_some()
+    }
+
+    // Check whether `text` contains any configured term, ignoring case and inner dots
+    fn may_contain_terms(&self, text: &str) -> bool {
+        let text_lower = text.to_lowercase();
+        self.terms()
+            .flat_map(|term| term_forms(term))
+            .any(|form| text_lower.contains(&form))
+    }
+
+    // Create a combined regex pattern for all proper names and synonyms
     fn create_combined_pattern(&self) -> Option<String> {
-        if self.config.names.is_empty() {
+        if !self.has_terms() {
             return None;
         }
 
-        // Create patterns for all names and their variations
-        let patterns: Vec<String> = self
-            .config
-            .names
+        // All forms of all terms, longest first: alternatives are tried in order, so a
+        // name such as "C" must not win over "C++" starting at the same place
+        let mut forms: Vec<String> = self
+            .terms()
+            .flat_map(|term| term_forms(term))
+            .collect();
+        forms.sort_by(|a, b| {
+            b.chars()
+                .count()
+                .cmp(&a.chars().count())
+                .then_with(|| a.cmp(b))
+        });
+        forms.dedup();
+        let patterns: Vec<String> = forms
             .iter()
-            .map(|name| {
-                let lower_name = name.to_lowercase();
-                let lower_name_no_dots = lower_name.replace('.', "");
-                if lower_name == lower_name_no_dots {
-                    fancy_regex::escape(&lower_name).to_string()
-                } else {
-                    format!(
-                        "(?:{}|{})",
-                        fancy_regex::escape(&lower_name),
-                        fancy_regex::escape(&lower_name_no_dots)
-                    )
-                }
-            })
+            .map(|form| fancy_regex::escape(form).to_string())
             .collect();
 
-        // Combine all patterns into a single regex with capture groups
+        // Combine all patterns into a single regex with capture groups. The boundaries apply
+        // to whatever the term starts and ends with, so ".NET" is not found in "asp.net"
+        // and "C" is not found in "Cat".
         Some(format!(
             r"(?<![a-zA-Z0-9])(?i)({})(?![a-zA-Z0-9])",
             patterns.join("|")
         ))
     }
 
+    // Whether the line starting at `byte_pos` is never checked: front matter, code fences,
+    // table delimiter rows and (when `code_blocks` is set) code blocks. Code spans, which
+    // may start anywhere on a line, are left to `code_spans` for each match.
+    fn is_skipped_line(
+        &self,
+        ctx: &crate::lint_context::LintContext,
+        line: &str,
+        byte_pos: usize,
+        front_matter_end: usize,
+    ) -> bool {
+        if byte_pos < front_matter_end {
+            return true;
+        }
+
+        // Code fence lines (```language or ~~~language), including inside blockquotes
+        let trimmed = BlockquoteUtils::strip_markers(line).trim_start();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            return true;
+        }
+
+        // Table delimiter rows. Names in table cells are still checked: `|` is not
+        // alphanumeric, so it acts as a word boundary and is never part of a match.
+        if is_table_delimiter_line(trimmed) {
+            return true;
+        }
+
+        self.config.code_blocks && ctx.is_in_code_block(byte_pos)
+    }
+
     // Find all name violations in the content and return positions
     fn find_name_violations(
         &self,
@@ -159,32 +407,59 @@ impl MD044ProperNames {
         ctx: &crate::lint_context::LintContext,
     ) -> Vec<WarningPosition> {
         // Early return: if no names configured or content is empty
-        if self.config.names.is_empty() || content.is_empty() {
+        if !self.has_terms() || content.is_empty() {
             return Vec::new();
         }
 
         // Early return: quick check if any of the configured names might be in content
-        let content_lower = content.to_lowercase();
-        let has_potential_matches = self.config.names.iter().any(|name| {
-            let name_lower = name.to_lowercase();
-            content_lower.contains(&name_lower)
-                || content_lower.contains(&name_lower.replace('.', ""))
-        });
-
-        if !has_potential_matches {
+        if !self.may_contain_terms(content) {
             return Vec::new();
         }
 
         // Check if we have cached results
-        let hash = fast_hash(content);
+        let hash = (self.cache_hasher)(content);
         {
             // Use a separate scope for borrowing to minimize lock time
-            let cache = self.content_cache.lock().unwrap();
-            if let Some(cached) = cache.get(&hash) {
-                return cached.clone();
+            let mut cache = self.content_cache.lock().unwrap();
+            if let Some(cached) = cache.get(hash)
+                && cached.len == content.len()
+                && cached
+                    .content
+                    .as_ref()
+                    .is_none_or(|cached| cached == content)
+            {
+                return cached.violations.clone();
             }
         }
 
+        let violations = self.scan_name_violations(content, ctx, 0..usize::MAX);
+
+        // Store in cache, replacing a colliding document's entry and evicting the least
+        // recently used one when full
+        self.content_cache
+            .lock()
+            .unwrap()
+            .insert(
+                hash,
+                CacheEntry {
+                    len: content.len(),
+                    content: self
+                        .config
+                        .verify_cache_hits
+                        .then(|| content.to_string()),
+                    violations: violations.clone(),
+                },
+            );
+        violations
+    }
+
+    // Name violations on the 0-indexed `lines` of the content, without the cache
+    fn scan_name_violations(
+        &self,
+        content: &str,
+        ctx: &crate::lint_context::LintContext,
+        lines: Range<usize>,
+    ) -> Vec<WarningPosition> {
         let mut violations = Vec::new();
 
         // Get the cached combined regex
@@ -196,32 +471,29 @@ impl MD044ProperNames {
             }
         };
 
-        let mut byte_pos = 0;
+        let front_matter_end =
+            FrontMatterUtils::front_matter_byte_end(content, &self.front_matter_delimiters);
+        let front_matter_values = (self.config.check_front_matter_values && front_matter_end > 0)
+            .then(|| FrontMatterValues::new(content, &self.front_matter_delimiters));
+        let html = HtmlRanges::new(ctx);
 
         for (line_num, line) in content.lines().enumerate() {
-            // Skip code fence lines (```language or ~~~language)
-            let trimmed = line.trim_start();
-            if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
-                byte_pos += line.len() + 1;
+            if !lines.contains(&line_num) {
                 continue;
             }
-
-            // Skip if in code block
-            if self.config.code_blocks && ctx.is_in_code_block_or_span(byte_pos) {
-                byte_pos += line.len() + 1;
+            let line_start = ctx.line_offsets[line_num];
+            let in_front_matter = line_start < front_matter_end;
+            let skipped = if in_front_matter {
+                front_matter_values.is_none()
+            } else {
+                self.is_skipped_line(ctx, line, line_start, front_matter_end)
+            };
+            if skipped {
                 continue;
             }
 
             // Early return: skip lines that don't contain any potential matches
-            let line_lower = line.to_lowercase();
-            let has_line_matches = self.config.names.iter().any(|name| {
-                let name_lower = name.to_lowercase();
-                line_lower.contains(&name_lower)
-                    || line_lower.contains(&name_lower.replace('.', ""))
-            });
-
-            if !has_line_matches {
-                byte_pos += line.len() + 1;
+            if !self.may_contain_terms(line) {
                 continue;
             }
 
@@ -230,15 +502,41 @@ impl MD044ProperNames {
                 match cap_result {
                     Ok(cap) => {
                         let found_name = &line[cap.start()..cap.end()];
+                        if in_front_matter
+                            && !front_matter_values
+                                .as_ref()
+                                .is_some_and(|values| {
+                                    values.contains(line_start + cap.start(), found_name)
+                                })
+                        {
+                            continue;
+                        }
+                        if html.is_excluded(line_start + cap.start(), &self.config) {
+                            continue;
+                        }
+                        if self.config.code_spans && ctx.is_in_code_span(line_start + cap.start()) {
+                            continue;
+                        }
+                        if !self.config.check_urls && is_in_url(line, cap.start()) {
+                            continue;
+                        }
                         // Find which proper name this matches
                         if let Some(proper_name) = self.get_proper_name_for(found_name) {
+                            // Replacing a synonym changes the word, so never do it in code or URLs
+                            if !is_same_term(found_name, &proper_name)
+                                && is_in_code_or_url(ctx, line, line_start, cap.start())
+                            {
+                                continue;
+                            }
                             // Only flag if it's not already correct
                             if found_name != proper_name {
-                                violations.push((
-                                    line_num + 1,
-                                    cap.start() + 1,
-                                    found_name.to_string(),
-                                ));
+                                // The match's own span, so the fix replaces exactly it
+                                violations.push(WarningPosition {
+                                    line: line_num + 1,
+                                    span: line_start + cap.start()..line_start + cap.end(),
+                                    found: found_name.to_string(),
+                                    replacement: proper_name,
+                                });
                             }
                         }
                     }
@@ -247,36 +545,453 @@ impl MD044ProperNames {
                     }
                 }
             }
+        }
+        violations
+    }
+
+    // Every violation to report and fix, flagged `true` when it comes from consistency mode.
+    // Inconsistent words overlapping a configured-name violation are dropped. Names are
+    // only looked for on the 0-indexed `lines`, if given.
+    fn all_violations(
+        &self,
+        content: &str,
+        ctx: &crate::lint_context::LintContext,
+        lines: Option<Range<usize>>,
+    ) -> Vec<(WarningPosition, bool)> {
+        let names = match lines {
+            Some(lines) => self.scan_name_violations(content, ctx, lines),
+            None => self.find_name_violations(content, ctx),
+        };
+        let mut inconsistent = Vec::new();
+        if self.config.consistency {
+            for violation in self.find_consistency_violations(content, ctx) {
+                let overlaps = names.iter().any(|name| {
+                    violation.span.start < name.span.end && name.span.start < violation.span.end
+                });
+                if !overlaps {
+                    inconsistent.push((violation, true));
+                }
+            }
+        }
 
-            byte_pos += line.len() + 1;
+        let mut violations: Vec<(WarningPosition, bool)> = names
+            .into_iter()
+            .map(|violation| (violation, false))
+            .chain(inconsistent)
+            .collect();
+        violations.sort_by_key(|(violation, _)| violation.span.start);
+
+        if !self.config.require_in.is_empty() {
+            let mut ranges = Vec::new();
+            self.collect_required_ranges(&ctx.ast, &mut ranges);
+            violations.retain(|(violation, _)| {
+                ranges
+                    .iter()
+                    .any(|range| range.contains(&violation.span.start))
+            });
         }
+        violations
+    }
 
-        // Store in cache
-        self.content_cache
-            .lock()
-            .unwrap()
-            .insert(hash, violations.clone());
+    // Byte ranges of the markup listed in `require_in`. Link text excludes the destination.
+    fn collect_required_ranges(&self, node: &Node, ranges: &mut Vec<std::ops::Range<usize>>) {
+        let kind = match node {
+            Node::Emphasis(_) => Some(NameContext::Emphasis),
+            Node::Strong(_) => Some(NameContext::Strong),
+            Node::Link(_) | Node::LinkReference(_) => Some(NameContext::LinkText),
+            Node::Heading(_) => Some(NameContext::Heading),
+            _ => None,
+        };
+        let children = node.children();
+        if let Some(kind) = kind
+            && self.config.require_in.contains(&kind)
+        {
+            let range = if kind == NameContext::LinkText {
+                children.and_then(|children| {
+                    let start = children
+                        .first()?
+                        .position()?
+                        .start
+                        .offset;
+                    let end = children.last()?.position()?.end.offset;
+                    Some(start..end)
+                })
+            } else {
+                node.position()
+                    .map(|position| position.start.offset..position.end.offset)
+            };
+            ranges.extend(range);
+        }
+        for child in children.into_iter().flatten() {
+            self.collect_required_ranges(child, ranges);
+        }
+    }
+
+    // Find words written with differing capitalization across the document (consistency mode).
+    // Words that differ only in their first letter (sentence case) or are written in all caps
+    // are not considered inconsistent; configured terms are left to the name check.
+    fn find_consistency_violations(
+        &self,
+        content: &str,
+        ctx: &crate::lint_context::LintContext,
+    ) -> Vec<WarningPosition> {
+        let front_matter_end =
+            FrontMatterUtils::front_matter_byte_end(content, &self.front_matter_delimiters);
+
+        let html = HtmlRanges::new(ctx);
+
+        // Occurrences of each word, keyed by its lowercase form: (line, byte offset, word)
+        let mut occurrences: HashMap<String, Vec<(usize, usize, &str)>> = HashMap::new();
+        for (line_num, line) in content.lines().enumerate() {
+            let line_start = ctx.line_offsets[line_num];
+            if !self.is_skipped_line(ctx, line, line_start, front_matter_end) {
+                for (start, word) in words(line) {
+                    if word.chars().count() < 2
+                        || !word.chars().any(char::is_alphabetic)
+                        || is_all_caps(word)
+                        || is_in_code_or_url(ctx, line, line_start, start)
+                        || html.is_excluded(line_start + start, &self.config)
+                        || self.get_proper_name_for(word).is_some()
+                    {
+                        continue;
+                    }
+                    occurrences
+                        .entry(word.to_lowercase())
+                        .or_default()
+                        .push((line_num + 1, line_start + start, word));
+                }
+            }
+        }
+
+        let mut violations = Vec::new();
+        for group in occurrences.values() {
+            // Count forms by their capitalization after the first letter, in order of appearance
+            let mut tails: Vec<(&str, usize)> = Vec::new();
+            for (_, _, word) in group {
+                let tail = case_tail(word);
+                match tails
+                    .iter_mut()
+                    .find(|(t, _)| *t == tail)
+                {
+                    Some(entry) => entry.1 += 1,
+                    None => tails.push((tail, 1)),
+                }
+            }
+            if tails.len() < 2 {
+                continue;
+            }
+
+            // The most frequent form wins; ties go to the one that appears first
+            let mut preferred_tail = tails[0];
+            for &candidate in &tails[1..] {
+                if candidate.1 > preferred_tail.1 {
+                    preferred_tail = candidate;
+                }
+            }
+            let Some(&(_, _, preferred)) = group
+                .iter()
+                .find(|(_, _, word)| case_tail(word) == preferred_tail.0)
+            else {
+                continue;
+            };
+
+            for &(line, offset, word) in group {
+                if case_tail(word) != preferred_tail.0 {
+                    violations.push(WarningPosition {
+                        line,
+                        span: offset..offset + word.len(),
+                        found: word.to_string(),
+                        replacement: preferred.to_string(),
+                    });
+                }
+            }
+        }
+        violations.sort_by_key(|violation| violation.span.start);
         violations
     }
 
+    // The fix replacing the match of `violation` in `content`
+    fn violation_fix(&self, content: &str, violation: &WarningPosition) -> Fix {
+        let fix = Fix::new(
+            violation.span.clone(),
+            violation.replacement.clone(),
+            FixSafety::Unsafe,
+        );
+        if self.config.collapse_fix_spaces {
+            collapse_boundary_spaces(content, &fix)
+        } else {
+            fix
+        }
+    }
+
+    /// Find every occurrence of a configured proper name in `text`, correctly capitalized
+    /// or not, as byte ranges paired with the canonical form.
+    ///
+    /// Used by rules that rewrite text (e.g. heading capitalization) to leave proper names intact.
+    pub(crate) fn proper_name_matches(&self, text: &str) -> Vec<(std::ops::Range<usize>, String)> {
+        let regex_lock = self.combined_regex.lock().unwrap();
+        let Some(regex) = &*regex_lock else {
+            return Vec::new();
+        };
+
+        regex
+            .find_iter(text)
+            .filter_map(|m| m.ok())
+            .filter_map(|m| {
+                self.get_proper_name_for(m.as_str())
+                    .map(|proper_name| (m.start()..m.end(), proper_name))
+            })
+            .collect()
+    }
+
     // Get the proper name that should be used for a found name
     fn get_proper_name_for(&self, found_name: &str) -> Option<String> {
         // Iterate through the configured proper names
         for name in &self.config.names {
-            // Perform a case-insensitive comparison between the found name
-            // and the configured proper name (and its dotless variation).
-            let lower_name = name.to_lowercase();
-            let lower_name_no_dots = lower_name.replace('.', "");
-            let found_lower = found_name.to_lowercase();
-
-            if found_lower == lower_name || found_lower == lower_name_no_dots {
+            // Compare case-insensitively with the configured proper name and the name
+            // without its inner dots
+            if is_same_term(found_name, name) {
                 // If they match case-insensitively, return the correctly capitalized name
                 return Some(name.clone());
             }
         }
+        // Then the synonym groups, which map every member to the preferred term
+        for (preferred, synonyms) in &self.config.synonyms {
+            if std::iter::once(preferred)
+                .chain(synonyms)
+                .any(|term| is_same_term(found_name, term))
+            {
+                return Some(preferred.clone());
+            }
+        }
         // If no match is found after checking all configured names, return None
         None
     }
+
+    // The warnings of `check`, looking for names only on the 0-indexed `lines` if given
+    fn check_lines(
+        &self,
+        ctx: &crate::lint_context::LintContext,
+        lines: Option<Range<usize>>,
+    ) -> LintResult {
+        let content = ctx.content;
+        if content.is_empty() || (!self.has_terms() && !self.config.consistency) {
+            return Ok(Vec::new());
+        }
+
+        let warnings = self
+            .all_violations(content, ctx, lines)
+            .into_iter()
+            .map(|(violation, inconsistent)| {
+                let fix = self.violation_fix(content, &violation);
+                let column = violation.column(ctx);
+                let WarningPosition {
+                    line,
+                    found: found_name,
+                    replacement: proper_name,
+                    ..
+                } = violation;
+                LintWarning::new(
+                    self.name(),
+                    line,
+                    column,
+                    line,
+                    column + found_name.chars().count(),
+                    if inconsistent {
+                        format!(
+                            "Inconsistent capitalization '{}'; '{}' is used elsewhere in the document",
+                            found_name, proper_name
+                        )
+                    } else if is_same_term(&found_name, &proper_name) {
+                        format!("Proper name '{}' should be '{}'", found_name, proper_name)
+                    } else {
+                        format!("Term '{}' should be replaced with '{}'", found_name, proper_name)
+                    },
+                )
+                .with_fix(fix)
+            })
+            .collect();
+
+        Ok(warnings)
+    }
+}
+
+/// Byte ranges of HTML tags (inline or in blocks) and of raw HTML blocks
+struct HtmlRanges {
+    tags: Vec<std::ops::Range<usize>>,
+    blocks: Vec<std::ops::Range<usize>>,
+    comments: Vec<std::ops::Range<usize>>,
+}
+
+impl HtmlRanges {
+    fn new(ctx: &crate::lint_context::LintContext) -> Self {
+        let mut ranges = Self {
+            tags: Vec::new(),
+            blocks: Vec::new(),
+            comments: Vec::new(),
+        };
+        ranges.collect(ctx.content, &ctx.ast, false);
+        ranges
+    }
+
+    // HTML nodes directly inside a block container are HTML blocks; the rest are inline tags
+    fn collect(&mut self, content: &str, node: &Node, parent_is_block: bool) {
+        if let Node::Html(_) = node
+            && let Some(position) = node.position()
+        {
+            let (start, end) = (position.start.offset, position.end.offset);
+            if parent_is_block {
+                self.blocks.push(start..end);
+            }
+            if let Some(html) = content.get(start..end) {
+                let (tags, comments) = html_spans(html);
+                let offset = |span: std::ops::Range<usize>| start + span.start..start + span.end;
+                self.tags
+                    .extend(tags.into_iter().map(offset));
+                self.comments
+                    .extend(comments.into_iter().map(offset));
+            }
+            return;
+        }
+        let is_block = matches!(
+            node,
+            Node::Root(_) | Node::Blockquote(_) | Node::ListItem(_) | Node::FootnoteDefinition(_)
+        );
+        for child in node.children().into_iter().flatten() {
+            self.collect(content, child, is_block);
+        }
+    }
+
+    // Whether a match at `pos` sits in a tag, or in HTML block text or a comment that
+    // `config` leaves unchecked. Comments follow `html_comments`, also inside HTML blocks.
+    fn is_excluded(&self, pos: usize, config: &MD044Config) -> bool {
+        let contains = |ranges: &[std::ops::Range<usize>]| {
+            ranges
+                .iter()
+                .any(|range| range.contains(&pos))
+        };
+        if contains(&self.comments) {
+            return !config.html_comments;
+        }
+        contains(&self.tags) || (!config.check_html_text && contains(&self.blocks))
+    }
+}
+
+/// Byte ranges of the tags in a piece of raw HTML, from `<` to the closing `>`, skipping
+/// `>` inside quoted attribute values, and of its comments, from `<!--` to `-->`. An
+/// unclosed comment runs to the end.
+fn html_spans(html: &str) -> (Vec<std::ops::Range<usize>>, Vec<std::ops::Range<usize>>) {
+    let bytes = html.as_bytes();
+    let mut spans = Vec::new();
+    let mut comments = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        if html[i..].starts_with("<!--") {
+            let start = i;
+            i = html[i + 4..]
+                .find("-->")
+                .map_or(bytes.len(), |end| i + 4 + end + 3);
+            comments.push(start..i);
+            continue;
+        }
+        let starts_tag = bytes[i] == b'<'
+            && bytes
+                .get(i + 1)
+                .is_some_and(|&b| b.is_ascii_alphabetic() || matches!(b, b'/' | b'!' | b'?'));
+        if !starts_tag {
+            i += 1;
+            continue;
+        }
+        let start = i;
+        let mut quote = None;
+        while i < bytes.len() {
+            match (quote, bytes[i]) {
+                (None, b'"' | b'\'') => quote = Some(bytes[i]),
+                (Some(q), b) if b == q => quote = None,
+                (None, b'>') => break,
+                _ => {}
+            }
+            i += 1;
+        }
+        i = (i + 1).min(bytes.len());
+        spans.push(start..i);
+    }
+    (spans, comments)
+}
+
+/// Split a line into alphanumeric words with their byte offsets
+fn words(line: &str) -> Vec<(usize, &str)> {
+    let mut words = Vec::new();
+    let mut start = None;
+    for (i, c) in line.char_indices() {
+        match (c.is_alphanumeric(), start) {
+            (true, None) => start = Some(i),
+            (false, Some(s)) => {
+                words.push((s, &line[s..i]));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(s) = start {
+        words.push((s, &line[s..]));
+    }
+    words
+}
+
+/// Whether every letter in `word` is uppercase (acronyms and shouted words)
+fn is_all_caps(word: &str) -> bool {
+    word.chars()
+        .filter(|c| c.is_alphabetic())
+        .all(char::is_uppercase)
+}
+
+/// The part of `word` after its first character, whose capitalization identifies the form
+fn case_tail(word: &str) -> &str {
+    word.char_indices()
+        .nth(1)
+        .map_or("", |(i, _)| &word[i..])
+}
+
+/// Check if `found` is `term` up to capitalization and inner dots (e.g. "nodejs" for
+/// "Node.js")
+fn is_same_term(found: &str, term: &str) -> bool {
+    let found_lower = found.to_lowercase();
+    term_forms(term).contains(&found_lower)
+}
+
+/// The lowercase forms `term` is recognized in: as written and, if it has dots inside, as
+/// without them ("nodejs" for "Node.js"). Leading and trailing dots are kept, since
+/// ".NET" without its dot is the word "net".
+fn term_forms(term: &str) -> Vec<String> {
+    let lower = term.to_lowercase();
+    let inner_start = lower.len() - lower.trim_start_matches('.').len();
+    let inner_end = lower
+        .trim_end_matches('.')
+        .len()
+        .max(inner_start);
+    let dotless = format!(
+        "{}{}{}",
+        &lower[..inner_start],
+        lower[inner_start..inner_end].replace('.', ""),
+        &lower[inner_end..]
+    );
+    if dotless == lower {
+        vec![lower]
+    } else {
+        vec![lower, dotless]
+    }
+}
+
+/// Read the proper names listed in a `names_file`, one per line, skipping blank lines
+/// and `#` comments
+fn read_names_file(path: &str) -> std::io::Result<Vec<String>> {
+    Ok(std::fs::read_to_string(path)?
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(str::to_string)
+        .collect())
 }
 
 impl Rule for MD044ProperNames {
@@ -284,96 +999,124 @@ impl Rule for MD044ProperNames {
         "MD044"
     }
 
+    fn tags(&self) -> &[&'static str] {
+        &["content"]
+    }
+
     fn description(&self) -> &'static str {
         "Proper names should have the correct capitalization"
     }
 
-    fn check(&self, ctx: &crate::lint_context::LintContext) -> LintResult {
-        let content = ctx.content;
-        if content.is_empty() || self.config.names.is_empty() {
-            return Ok(Vec::new());
-        }
+    fn uncached_copy(&self) -> Option<Box<dyn Rule>> {
+        let mut copy = Self::from_config_struct(self.config.clone());
+        copy.cache_hasher = self.cache_hasher;
+        copy.front_matter_delimiters = self.front_matter_delimiters.clone();
+        Some(Box::new(copy))
+    }
 
-        let line_index = LineIndex::new(content.to_string());
-        let violations = self.find_name_violations(content, ctx);
+    fn check(&self, ctx: &crate::lint_context::LintContext) -> LintResult {
+        self.check_lines(ctx, None)
+    }
 
-        let warnings = violations
+    fn check_range(
+        &self,
+        ctx: &crate::lint_context::LintContext,
+        range: Range<usize>,
+    ) -> LintResult {
+        // Only the lines the range spans are scanned
+        let line_of = |offset: usize| {
+            ctx.line_offsets
+                .partition_point(|&start| start <= offset)
+                .saturating_sub(1)
+        };
+        let lines = line_of(range.start)..line_of(range.end) + 1;
+        Ok(self
+            .check_lines(ctx, Some(lines))?
             .into_iter()
-            .filter_map(|(line, column, found_name)| {
-                self.get_proper_name_for(&found_name)
-                    .map(|proper_name| LintWarning {
-                        rule_name: Some(self.name()),
-                        line,
-                        column,
-                        end_line: line,
-                        end_column: column + found_name.len(),
-                        message: format!(
-                            "Proper name '{
-            }' should be '{}'",
-                            found_name, proper_name
-                        ),
-                        severity: Severity::Warning,
-                        fix: Some(Fix {
-                            range: line_index.line_col_to_byte_range(line, column),
-                            replacement: proper_name,
-                        }),
-                    })
-            })
-            .collect();
+            .filter(|w| crate::rule::warning_in_range(ctx, w, &range))
+            .collect())
+    }
 
-        Ok(warnings)
+    fn check_count(&self, ctx: &crate::lint_context::LintContext) -> Result<usize, LintError> {
+        let content = ctx.content;
+        if content.is_empty() || (!self.has_terms() && !self.config.consistency) {
+            return Ok(0);
+        }
+        Ok(self
+            .all_violations(content, ctx, None)
+            .len())
     }
 
     fn fix(&self, ctx: &crate::lint_context::LintContext) -> Result<String, LintError> {
+        self.fix_with_report(ctx, &mut Vec::new())
+    }
+
+    fn fix_with_report(
+        &self,
+        ctx: &crate::lint_context::LintContext,
+        skipped: &mut Vec<SkippedFix>,
+    ) -> Result<String, LintError> {
         let content = ctx.content;
-        if content.is_empty() || self.config.names.is_empty() {
+        if content.is_empty() || (!self.has_terms() && !self.config.consistency) {
             return Ok(content.to_string());
         }
 
-        let mut violations = self.find_name_violations(content, ctx);
+        let mut violations: Vec<WarningPosition> = self
+            .all_violations(content, ctx, None)
+            .into_iter()
+            .map(|(violation, _)| violation)
+            .collect();
         if violations.is_empty() {
             return Ok(content.to_string());
         }
 
-        // Sort violations in reverse order (by line, then by column) to apply fixes
-        // from end to beginning, avoiding range invalidation.
-        violations.sort_by(|a, b| b.0.cmp(&a.0).then(b.1.cmp(&a.1)));
+        // Sort violations in reverse order to apply fixes from end to beginning, avoiding
+        // range invalidation.
+        violations.sort_by_key(|violation| std::cmp::Reverse(violation.span.start));
 
         let mut fixed_content = content.to_string();
-        let line_index = LineIndex::new(content.to_string()); // Recreate for accurate byte ranges
-
-        for (line_num, col_num, found_name) in violations {
-            if let Some(proper_name) = self.get_proper_name_for(&found_name) {
-                // Calculate the byte range for the violation
-                let range = line_index.line_col_to_byte_range(line_num, col_num);
-                let start_byte = range.start;
-                let end_byte = start_byte + found_name.len();
-
-                // Ensure the calculated range is valid within the current fixed_content
-                if end_byte <= fixed_content.len()
-                    && fixed_content.is_char_boundary(start_byte)
-                    && fixed_content.is_char_boundary(end_byte)
-                {
-                    // Perform the replacement directly on the string using byte offsets
-                    fixed_content.replace_range(start_byte..end_byte, &proper_name);
-                } else {
-                    // Log error or handle invalid range - potentially due to overlapping fixes or calculation errors
-                    eprintln!(
-                        "Warning: Skipping fix for '{}' at {}:{} due to invalid byte range [{}..{}], content length {}.",
-                        found_name,
-                        line_num,
-                        col_num,
+
+        // Fixes are applied end to start against the original offsets, so they must not overlap
+        let fixes: Vec<Fix> = violations
+            .iter()
+            .map(|violation| self.violation_fix(content, violation))
+            .collect();
+        validate_fixes(&fixes).map_err(|conflict| LintError::FixFailed(conflict.to_string()))?;
+
+        for (violation, fix) in violations.iter().zip(fixes) {
+            let (start_byte, end_byte) = (fix.range.start, fix.range.end);
+
+            // Ensure the calculated range is valid within the current fixed_content
+            if end_byte <= fixed_content.len()
+                && fixed_content.is_char_boundary(start_byte)
+                && fixed_content.is_char_boundary(end_byte)
+            {
+                // Perform the replacement directly on the string using byte offsets
+                fixed_content.replace_range(start_byte..end_byte, &fix.replacement);
+            } else {
+                // Invalid range - potentially due to overlapping fixes or calculation errors
+                skipped.push(SkippedFix {
+                    rule: self.name().to_string(),
+                    line: violation.line,
+                    column: violation.column(ctx),
+                    reason: format!(
+                        "invalid byte range {}..{} for '{}' (content length {})",
                         start_byte,
                         end_byte,
+                        violation.found,
                         fixed_content.len()
-                    );
-                }
+                    ),
+                });
             }
         }
 
         Ok(fixed_content)
     }
 
+    fn is_document_wide(&self) -> bool {
+        self.config.consistency
+    }
+
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
@@ -390,7 +1133,813 @@ impl Rule for MD044ProperNames {
     where
         Self: Sized,
     {
-        let rule_config = crate::rule_config_serde::load_rule_config::<MD044Config>(config);
-        Box::new(Self::from_config_struct(rule_config))
+        let mut rule_config = crate::rule_config_serde::load_rule_config::<MD044Config>(config);
+        if !rule_config.names_file.is_empty() {
+            match read_names_file(&rule_config.names_file) {
+                Ok(names) => rule_config.names.extend(names),
+                Err(e) => eprintln!(
+                    "Warning: Failed to read MD044 names_file '{}': {}",
+                    rule_config.names_file, e
+                ),
+            }
+        }
+        let mut rule = Self::from_config_struct(rule_config);
+        rule.front_matter_delimiters = config
+            .global
+            .front_matter_delimiters
+            .clone();
+        Box::new(rule)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lint_context::LintContext;
+    use crate::utils::table_utils::TableUtils;
+
+    #[test]
+    fn test_names_in_table_cells() {
+        let rule = MD044ProperNames::new(vec!["JavaScript".to_string()], true);
+        let content =
+            "| Language | Notes |\n| --- | :---: |\n| javascript | fast |\n|javascript|x|\n";
+        let ctx = LintContext::new(content);
+
+        let warnings = rule.check(&ctx).unwrap();
+        assert_eq!(warnings.len(), 2);
+        assert_eq!((warnings[0].line, warnings[0].column), (3, 3));
+        assert_eq!((warnings[1].line, warnings[1].column), (4, 2));
+
+        let fixed = rule.fix(&ctx).unwrap();
+        assert_eq!(
+            fixed,
+            "| Language | Notes |\n| --- | :---: |\n| JavaScript | fast |\n|JavaScript|x|\n"
+        );
+
+        // The fixed table keeps its structure
+        let fixed_ctx = LintContext::new(&fixed);
+        let tables = TableUtils::find_table_blocks(&fixed, &fixed_ctx);
+        assert_eq!(tables.len(), 1);
+        assert_eq!(tables[0].delimiter_line, 1);
+        for line in fixed.lines() {
+            assert_eq!(TableUtils::count_cells(line), 2);
+        }
+    }
+
+    #[test]
+    fn test_names_with_symbols() {
+        let rule = MD044ProperNames::new(
+            vec![
+                "C".to_string(),
+                "C++".to_string(),
+                ".NET".to_string(),
+                "Node.js".to_string(),
+            ],
+            true,
+        );
+        let content = "# Languages\n\nWe write c++ and C, not cat.\n\nApps run on .net, not the net or asp.net.\n\nSome nodejs too.\n";
+        let ctx = LintContext::new(content);
+
+        let warnings = rule.check(&ctx).unwrap();
+        assert_eq!(
+            warnings
+                .iter()
+                .map(|w| (w.line, w.column, w.message.as_str()))
+                .collect::<Vec<_>>(),
+            vec![
+                (3, 10, "Proper name 'c++' should be 'C++'"),
+                (5, 13, "Proper name '.net' should be '.NET'"),
+                (7, 6, "Proper name 'nodejs' should be 'Node.js'"),
+            ]
+        );
+        assert_eq!(
+            rule.fix(&ctx).unwrap(),
+            "# Languages\n\nWe write C++ and C, not cat.\n\nApps run on .NET, not the net or asp.net.\n\nSome Node.js too.\n"
+        );
+    }
+
+    #[test]
+    fn test_check_count_matches_check() {
+        let fixtures = [
+            "",
+            "# Clean\n\nNothing to see\n",
+            "# Notes\n\njavascript, Javascript and `javascript`\n",
+            "Use github and Github on GitHub, then github again\n",
+            "café javascript\r\n\r\n```\njavascript\n```\n",
+        ];
+        let mut consistency = MD044ProperNames::new(vec!["JavaScript".to_string()], true);
+        consistency.config.consistency = true;
+        let rules = [
+            MD044ProperNames::new(vec!["JavaScript".to_string()], true),
+            MD044ProperNames::new(vec!["JavaScript".to_string()], false),
+            consistency,
+            // No names and no consistency mode: nothing is ever reported
+            MD044ProperNames::new(Vec::new(), true),
+        ];
+        for (i, rule) in rules.iter().enumerate() {
+            for content in fixtures {
+                let ctx = LintContext::new(content);
+                assert_eq!(
+                    rule.check_count(&ctx).unwrap(),
+                    rule.check(&ctx).unwrap().len(),
+                    "rule {} on {:?}",
+                    i,
+                    content
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_fix_after_multibyte_characters() {
+        let rule = MD044ProperNames::new(
+            vec![
+                "JavaScript".to_string(),
+                "Node.js".to_string(),
+            ],
+            true,
+        );
+        let content = "# Notes\n\ncafé javascript\n\n日本語 javascript と nodejs\n";
+        let ctx = LintContext::new(content);
+
+        let warnings = rule.check(&ctx).unwrap();
+        assert_eq!(
+            warnings
+                .iter()
+                .map(|w| (w.line, w.column, w.end_column))
+                .collect::<Vec<_>>(),
+            vec![(3, 6, 16), (5, 5, 15), (5, 18, 24)]
+        );
+        for warning in &warnings {
+            let fix = warning.fix.as_ref().unwrap();
+            assert!(["javascript", "nodejs"].contains(&&content[fix.range.clone()]));
+        }
+
+        let mut skipped = Vec::new();
+        assert_eq!(
+            rule.fix_with_report(&ctx, &mut skipped)
+                .unwrap(),
+            "# Notes\n\ncafé JavaScript\n\n日本語 JavaScript と Node.js\n"
+        );
+        assert!(skipped.is_empty());
+    }
+
+    #[test]
+    fn test_table_delimiter_row_is_never_touched() {
+        assert!(is_table_delimiter_line("|---|"));
+        assert!(is_table_delimiter_line("| :--- | ---: |"));
+        assert!(!is_table_delimiter_line("| javascript |"));
+
+        // Single-column table with names adjacent to the pipes
+        let rule = MD044ProperNames::new(vec!["JavaScript".to_string()], true);
+        let content = "|javascript|\n|---|\n|JAVASCRIPT|\n";
+        let ctx = LintContext::new(content);
+
+        let fixed = rule.fix(&ctx).unwrap();
+        assert_eq!(fixed, "|JavaScript|\n|---|\n|JavaScript|\n");
+        assert_eq!(fixed.lines().nth(1), Some("|---|"));
+    }
+
+    #[test]
+    fn test_synonym_rewritten_to_preferred_term() {
+        let config = MD044Config {
+            synonyms: [("JavaScript".to_string(), vec!["ECMAScript".to_string()])]
+                .into_iter()
+                .collect(),
+            ..Default::default()
+        };
+        let rule = MD044ProperNames::from_config_struct(config);
+        let content = "# Notes\n\nWe write ecmascript (or ECMAScript) daily, and javascript too.\n\n\
+                       Run `ecmascript --check` or see https://ecmascript.example/ecmascript and \
+                       [the ECMAScript spec](docs/ecmascript.md).\n\n```\nECMAScript\n```\n";
+        let ctx = LintContext::new(content);
+
+        let warnings = rule.check(&ctx).unwrap();
+        let messages: Vec<_> = warnings
+            .iter()
+            .map(|w| w.message.as_str())
+            .collect();
+        assert_eq!(
+            messages,
+            vec![
+                "Term 'ecmascript' should be replaced with 'JavaScript'",
+                "Term 'ECMAScript' should be replaced with 'JavaScript'",
+                "Proper name 'javascript' should be 'JavaScript'",
+                "Term 'ECMAScript' should be replaced with 'JavaScript'",
+            ]
+        );
+
+        let fixed = rule.fix(&ctx).unwrap();
+        assert_eq!(
+            fixed,
+            "# Notes\n\nWe write JavaScript (or JavaScript) daily, and JavaScript too.\n\n\
+             Run `ecmascript --check` or see https://ecmascript.example/ecmascript and \
+             [the JavaScript spec](docs/ecmascript.md).\n\n```\nECMAScript\n```\n"
+        );
+        assert!(
+            rule.check(&LintContext::new(&fixed))
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_fix_inside_nested_blockquotes() {
+        let rule = MD044ProperNames::new(vec!["JavaScript".to_string()], true);
+        let content = "# Quotes\n\n> > > Use javascript here\n> > lazy javascript line\n\
+                       lazy continuation javascript\n>>>javascript\n\n> > >\n\
+                       > > >     javascript in indented code\n> > > ~~~\n> > > javascript\n> > > ~~~\n";
+        let ctx = LintContext::new(content);
+
+        let warnings = rule.check(&ctx).unwrap();
+        let positions: Vec<_> = warnings
+            .iter()
+            .map(|w| (w.line, w.column))
+            .collect();
+        assert_eq!(positions, vec![(3, 11), (4, 10), (5, 19), (6, 4)]);
+
+        // Each warning's fix targets the name itself, never the quote markers
+        for warning in &warnings {
+            let fix = warning.fix.as_ref().unwrap();
+            assert_eq!(&content[fix.range.clone()], "javascript");
+        }
+
+        let expected = "# Quotes\n\n> > > Use JavaScript here\n> > lazy JavaScript line\n\
+                        lazy continuation JavaScript\n>>>JavaScript\n\n> > >\n\
+                        > > >     javascript in indented code\n> > > ~~~\n> > > javascript\n> > > ~~~\n";
+        assert_eq!(rule.fix(&ctx).unwrap(), expected);
+        assert_eq!(
+            crate::utils::fix_utils::apply_warning_fixes(content, &warnings).unwrap(),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_consistency_mode_unifies_casing() {
+        let config = MD044Config {
+            consistency: true,
+            ..Default::default()
+        };
+        let rule = MD044ProperNames::from_config_struct(config);
+        let content = "# GitHub Notes\n\nPush to GitHub, not Github. Github pages too.\n\n\
+                       GitHub is here. The repo and the README; `Github` in code.\n\n\
+                       Use TypeScript or Typescript.\n";
+        let ctx = LintContext::new(content);
+
+        let warnings = rule.check(&ctx).unwrap();
+        let found: Vec<_> = warnings
+            .iter()
+            .map(|w| (w.line, w.column, w.message.as_str()))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (
+                    3,
+                    21,
+                    "Inconsistent capitalization 'Github'; 'GitHub' is used elsewhere in the document"
+                ),
+                (
+                    3,
+                    29,
+                    "Inconsistent capitalization 'Github'; 'GitHub' is used elsewhere in the document"
+                ),
+                (
+                    7,
+                    19,
+                    "Inconsistent capitalization 'Typescript'; 'TypeScript' is used elsewhere in the document"
+                ),
+            ]
+        );
+
+        let fixed = rule.fix(&ctx).unwrap();
+        assert_eq!(
+            fixed,
+            "# GitHub Notes\n\nPush to GitHub, not GitHub. GitHub pages too.\n\n\
+             GitHub is here. The repo and the README; `Github` in code.\n\n\
+             Use TypeScript or TypeScript.\n"
+        );
+        assert!(
+            rule.check(&LintContext::new(&fixed))
+                .unwrap()
+                .is_empty()
+        );
+
+        // Off by default
+        let rule = MD044ProperNames::from_config_struct(MD044Config::default());
+        assert!(rule.check(&ctx).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_consistency_mode_with_crlf_line_endings() {
+        let config = MD044Config {
+            consistency: true,
+            ..Default::default()
+        };
+        let rule = MD044ProperNames::from_config_struct(config);
+        // Offsets summed as one byte per line ending would drift off the code span
+        let content = format!(
+            "# GitHub Notes\r\n{}See `Github` here\r\nUse Github\r\n",
+            "\r\nSome text\r\n".repeat(5) + "\r\n"
+        );
+        let ctx = LintContext::new(&content);
+        assert_eq!(
+            rule.check(&ctx)
+                .unwrap()
+                .iter()
+                .map(|w| (w.line, w.column))
+                .collect::<Vec<_>>(),
+            vec![(14, 5)]
+        );
+        assert!(
+            rule.fix(&ctx)
+                .unwrap()
+                .ends_with("See `Github` here\r\nUse GitHub\r\n")
+        );
+    }
+
+    #[test]
+    fn test_is_in_url() {
+        let line = "see https://js.example/x, <http://js.dev> or [js](js.md) and www.js.org js";
+        let at = |needle: &str| line.find(needle).unwrap();
+        assert!(is_in_url(line, at("js.example")));
+        assert!(is_in_url(line, at("js.dev")));
+        assert!(is_in_url(line, at("js.md")));
+        assert!(is_in_url(line, at("js.org")));
+        assert!(!is_in_url(line, at("js]")));
+        assert!(!is_in_url(line, line.len() - 2));
+    }
+
+    #[test]
+    fn test_front_matter_values_fixed_but_not_keys() {
+        let content = "---\njavascript: true\ntitle: Notes on javascript\ntags: [javascript, nodejs]\ncategories:\n  - javascript\n# javascript in a comment\ndescription: >\n  Learning javascript\ncount: 3\n---\n\nBody javascript\n";
+        let ctx = LintContext::new(content);
+        let positions = |rule: &MD044ProperNames| -> Vec<(usize, usize)> {
+            rule.check(&ctx)
+                .unwrap()
+                .iter()
+                .map(|w| (w.line, w.column))
+                .collect()
+        };
+
+        // Off by default: front matter is opaque
+        let rule = MD044ProperNames::new(vec!["JavaScript".to_string()], true);
+        assert_eq!(positions(&rule), vec![(13, 6)]);
+
+        let config = MD044Config {
+            names: vec!["JavaScript".to_string()],
+            check_front_matter_values: true,
+            ..Default::default()
+        };
+        let rule = MD044ProperNames::from_config_struct(config.clone());
+        assert_eq!(
+            positions(&rule),
+            vec![(3, 17), (4, 8), (6, 5), (9, 12), (13, 6)]
+        );
+        assert_eq!(
+            rule.fix(&ctx).unwrap(),
+            "---\njavascript: true\ntitle: Notes on JavaScript\ntags: [JavaScript, nodejs]\ncategories:\n  - JavaScript\n# javascript in a comment\ndescription: >\n  Learning JavaScript\ncount: 3\n---\n\nBody JavaScript\n"
+        );
+
+        // TOML front matter: the value of `tags` but not the `javascript` key
+        let content =
+            "+++\njavascript = \"yes\"\ntags = [\"javascript\", \"nodejs\"]\n+++\n\nText\n";
+        let ctx = LintContext::new(content);
+        let rule = MD044ProperNames::from_config_struct(config);
+        let warnings = rule.check(&ctx).unwrap();
+        assert_eq!(
+            warnings
+                .iter()
+                .map(|w| (w.line, w.column))
+                .collect::<Vec<_>>(),
+            vec![(3, 10)]
+        );
+    }
+
+    #[test]
+    fn test_require_in_heading_only_flags_headings() {
+        let config = MD044Config {
+            names: vec!["GitHub".to_string()],
+            require_in: vec![NameContext::Heading],
+            ..Default::default()
+        };
+        let rule = MD044ProperNames::from_config_struct(config);
+        let content = "# Using github\n\nPush to github first.\n\nSetext github\n---\n\n*github* and [github](https://github.com)\n";
+        let ctx = LintContext::new(content);
+        let warnings = rule.check(&ctx).unwrap();
+        assert_eq!(
+            warnings
+                .iter()
+                .map(|w| (w.line, w.column))
+                .collect::<Vec<_>>(),
+            vec![(1, 9), (5, 8)]
+        );
+        assert_eq!(
+            rule.fix(&ctx).unwrap(),
+            "# Using GitHub\n\nPush to github first.\n\nSetext GitHub\n---\n\n*github* and [github](https://github.com)\n"
+        );
+
+        // Link text is checked without touching the destination
+        let config = MD044Config {
+            names: vec!["GitHub".to_string()],
+            require_in: vec![NameContext::LinkText, NameContext::Emphasis],
+            ..Default::default()
+        };
+        let rule = MD044ProperNames::from_config_struct(config);
+        let warnings = rule.check(&ctx).unwrap();
+        assert_eq!(
+            warnings
+                .iter()
+                .map(|w| (w.line, w.column))
+                .collect::<Vec<_>>(),
+            vec![(8, 2), (8, 15)]
+        );
+    }
+
+    #[test]
+    fn test_html_block_text_checked_but_not_attributes() {
+        let content = "# Notes\n\n<div class=\"javascript\">\n<p>javascript rocks</p>\n</div>\n\nUse <span title=\"javascript\">javascript</span> here\n";
+        let config = MD044Config {
+            names: vec!["JavaScript".to_string()],
+            ..Default::default()
+        };
+        let ctx = LintContext::new(content);
+
+        // By default only prose outside HTML blocks is checked, and never attribute values
+        let rule = MD044ProperNames::from_config_struct(config.clone());
+        let warnings = rule.check(&ctx).unwrap();
+        assert_eq!(
+            warnings
+                .iter()
+                .map(|w| (w.line, w.column))
+                .collect::<Vec<_>>(),
+            vec![(7, 30)]
+        );
+
+        let rule = MD044ProperNames::from_config_struct(MD044Config {
+            check_html_text: true,
+            ..config
+        });
+        assert_eq!(
+            rule.fix(&ctx).unwrap(),
+            "# Notes\n\n<div class=\"javascript\">\n<p>JavaScript rocks</p>\n</div>\n\nUse <span title=\"javascript\">JavaScript</span> here\n"
+        );
+    }
+
+    #[test]
+    fn test_collapse_fix_spaces_only_at_replacement_edges() {
+        let mut config = MD044Config::default();
+        config.synonyms.insert(
+            "Visual Studio Code ".to_string(),
+            vec!["vscode".to_string()],
+        );
+        let content = "Open vscode today.  Or  later, in vscode\n";
+        let ctx = LintContext::new(content);
+
+        let rule = MD044ProperNames::from_config_struct(config.clone());
+        assert_eq!(
+            rule.fix(&ctx).unwrap(),
+            "Open Visual Studio Code  today.  Or  later, in Visual Studio Code \n"
+        );
+
+        let rule = MD044ProperNames::from_config_struct(MD044Config {
+            collapse_fix_spaces: true,
+            ..config
+        });
+        let fixed = rule.fix(&ctx).unwrap();
+        // The doubled spaces after "today." and "Or" were already there and are kept
+        assert_eq!(
+            fixed,
+            "Open Visual Studio Code today.  Or  later, in Visual Studio Code \n"
+        );
+        let warnings = rule.check(&ctx).unwrap();
+        assert_eq!(
+            crate::utils::fix_utils::apply_warning_fixes(content, &warnings).unwrap(),
+            fixed
+        );
+    }
+
+    #[test]
+    fn test_names_file_adds_names() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let names_path = temp_dir.path().join("names.txt");
+        std::fs::write(&names_path, "# Products\nGitHub\n\n  TypeScript  \n").unwrap();
+        let config: crate::config::Config = toml::from_str(&format!(
+            "[MD044]\nnames = [\"Rust\"]\nnames-file = {:?}\n",
+            names_path.display().to_string()
+        ))
+        .unwrap();
+        let rule = MD044ProperNames::from_config(&config);
+        let ctx = LintContext::new("Rust, typescript and github\n");
+        assert_eq!(rule.fix(&ctx).unwrap(), "Rust, TypeScript and GitHub\n");
+    }
+
+    #[test]
+    fn test_hash_collision_does_not_return_cached_warnings() {
+        let columns = |rule: &MD044ProperNames, content: &str| -> Vec<usize> {
+            rule.check(&LintContext::new(content))
+                .unwrap()
+                .iter()
+                .map(|w| w.column)
+                .collect()
+        };
+        // Every document collides; the two have the same length too
+        let mut rule = MD044ProperNames::new(vec!["JavaScript".to_string()], true);
+        rule.cache_hasher = |_| 0;
+        assert_eq!(columns(&rule, "javascript here\n"), vec![1]);
+        assert_eq!(columns(&rule, "here javascript\n"), vec![6]);
+        assert_eq!(columns(&rule, "javascript here\n"), vec![1]);
+
+        // Without verification only a length mismatch tells documents apart
+        let mut rule = MD044ProperNames::from_config_struct(MD044Config {
+            names: vec!["JavaScript".to_string()],
+            verify_cache_hits: false,
+            ..Default::default()
+        });
+        rule.cache_hasher = |_| 0;
+        assert_eq!(columns(&rule, "javascript here\n"), vec![1]);
+        assert_eq!(columns(&rule, "here again javascript\n"), vec![12]);
+        assert_eq!(columns(&rule, "javascript here\n"), vec![1]);
+        assert_eq!(columns(&rule, "here javascript\n"), vec![1]);
+    }
+
+    #[test]
+    fn test_code_blocks_and_code_spans_are_excluded_separately() {
+        let content = "# Notes\n\nRun `github login` on github.\n\n```sh\ngithub repo list\n```\n";
+        let lines = |code_blocks: bool, code_spans: bool| -> Vec<(usize, usize)> {
+            let rule = MD044ProperNames::from_config_struct(MD044Config {
+                names: vec!["GitHub".to_string()],
+                code_blocks,
+                code_spans,
+                ..Default::default()
+            });
+            rule.check(&LintContext::new(content))
+                .unwrap()
+                .iter()
+                .map(|w| (w.line, w.column))
+                .collect()
+        };
+
+        // By default only the prose is checked
+        let rule = MD044ProperNames::new(vec!["GitHub".to_string()], true);
+        assert_eq!(
+            rule.check(&LintContext::new(content))
+                .unwrap()
+                .iter()
+                .map(|w| (w.line, w.column))
+                .collect::<Vec<_>>(),
+            vec![(3, 23)]
+        );
+        assert_eq!(lines(true, true), vec![(3, 23)]);
+        assert_eq!(lines(true, false), vec![(3, 6), (3, 23)]);
+        assert_eq!(lines(false, true), vec![(3, 23), (6, 1)]);
+        assert_eq!(lines(false, false), vec![(3, 6), (3, 23), (6, 1)]);
+    }
+
+    #[test]
+    fn test_code_spans_are_skipped_with_crlf_line_endings() {
+        // Every line before the last ends with two bytes, so offsets summed as one byte per
+        // line ending would drift off the code span
+        let content = format!(
+            "# Notes\r\n{}See `javascript` here\r\nUse javascript\r\n",
+            "\r\nSome text\r\n".repeat(5) + "\r\n"
+        );
+        let rule = MD044ProperNames::new(vec!["JavaScript".to_string()], true);
+        let ctx = LintContext::new(&content);
+        assert_eq!(
+            rule.check(&ctx)
+                .unwrap()
+                .iter()
+                .map(|w| (w.line, w.column))
+                .collect::<Vec<_>>(),
+            vec![(14, 5)]
+        );
+        assert!(
+            rule.fix(&ctx)
+                .unwrap()
+                .ends_with("See `javascript` here\r\nUse JavaScript\r\n")
+        );
+    }
+
+    #[test]
+    fn test_content_cache_is_bounded() {
+        let rule = MD044ProperNames::from_config_struct(MD044Config {
+            names: vec!["JavaScript".to_string()],
+            cache_capacity: 4,
+            ..Default::default()
+        });
+        let docs: Vec<String> = (0..10)
+            .map(|i| format!("{} javascript\n", "x".repeat(i)))
+            .collect();
+        let cached = |doc: &str| {
+            rule.content_cache
+                .lock()
+                .unwrap()
+                .entries
+                .contains_key(&(rule.cache_hasher)(doc))
+        };
+        for doc in &docs {
+            rule.check(&LintContext::new(doc))
+                .unwrap();
+            assert!(
+                rule.content_cache
+                    .lock()
+                    .unwrap()
+                    .entries
+                    .len()
+                    <= 4
+            );
+        }
+        assert!(docs[6..].iter().all(|doc| cached(doc)));
+        assert!(!cached(&docs[5]));
+
+        // Checking a cached document again makes it the most recently used, so the next
+        // insertion evicts the oldest other one
+        let warnings = rule
+            .check(&LintContext::new(&docs[6]))
+            .unwrap();
+        assert_eq!(warnings[0].column, 8);
+        rule.check(&LintContext::new("new javascript\n"))
+            .unwrap();
+        assert!(cached(&docs[6]));
+        assert!(!cached(&docs[7]));
+        assert_eq!(
+            rule.content_cache
+                .lock()
+                .unwrap()
+                .entries
+                .len(),
+            4
+        );
+
+        // With no capacity nothing is cached
+        let rule = MD044ProperNames::from_config_struct(MD044Config {
+            names: vec!["JavaScript".to_string()],
+            cache_capacity: 0,
+            ..Default::default()
+        });
+        rule.check(&LintContext::new(&docs[0]))
+            .unwrap();
+        assert_eq!(
+            rule.content_cache
+                .lock()
+                .unwrap()
+                .entries
+                .len(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_link_destinations_are_not_checked() {
+        let content = "# Notes\n\nRead [the node.js docs](https://node.js/download) and [more](./node.js/api.md \"Node.js API\").\n\n![node.js logo](./node.js/logo.png)\n\nSee <http://node.js/> or [guide][ref].\n\n[ref]: ./node.js/guide.md \"node.js guide\"\n";
+        let ctx = LintContext::new(content);
+        let positions = |rule: &MD044ProperNames| -> Vec<(usize, usize)> {
+            rule.check(&ctx)
+                .unwrap()
+                .iter()
+                .map(|w| (w.line, w.column))
+                .collect()
+        };
+
+        // Only the link text, the image alt text and the definition title are flagged
+        let rule = MD044ProperNames::new(vec!["Node.js".to_string()], true);
+        assert_eq!(positions(&rule), vec![(3, 11), (5, 3), (9, 28)]);
+        assert_eq!(
+            rule.fix(&ctx).unwrap(),
+            content
+                .replace("[the node.js", "[the Node.js")
+                .replace("![node.js", "![Node.js")
+                .replace("\"node.js guide", "\"Node.js guide")
+        );
+
+        let rule = MD044ProperNames::from_config_struct(MD044Config {
+            names: vec!["Node.js".to_string()],
+            check_urls: true,
+            ..Default::default()
+        });
+        assert_eq!(
+            positions(&rule),
+            vec![
+                (3, 11),
+                (3, 33),
+                (3, 64),
+                (5, 3),
+                (5, 19),
+                (7, 13),
+                (9, 10),
+                (9, 28)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_html_comments_can_be_skipped() {
+        let content = "# Notes\n\n<!-- TODO: explain\nthe javascript setup -->\n\nUse javascript <!-- javascript? --> here\n";
+        let ctx = LintContext::new(content);
+        let positions = |rule: &dyn Rule| -> Vec<(usize, usize)> {
+            rule.check(&ctx)
+                .unwrap()
+                .iter()
+                .map(|w| (w.line, w.column))
+                .collect()
+        };
+        let config: crate::config::Config =
+            toml::from_str("[MD044]\nnames = [\"JavaScript\"]\nhtml-comments = false\n").unwrap();
+        let rule = MD044ProperNames::from_config(&config);
+        let (_, section) = rule.default_config_section().unwrap();
+        assert_eq!(section["html-comments"], toml::Value::Boolean(false));
+
+        // Both the multi-line comment and the one beside prose are skipped, the prose is not
+        assert_eq!(positions(rule.as_ref()), vec![(6, 5)]);
+        assert_eq!(
+            rule.fix(&ctx).unwrap(),
+            content.replacen("Use javascript", "Use JavaScript", 1)
+        );
+
+        // Comments are checked by default, even when they form an HTML block
+        let rule = MD044ProperNames::new(vec!["JavaScript".to_string()], true);
+        assert_eq!(positions(&rule), vec![(4, 5), (6, 5), (6, 21)]);
+    }
+
+    #[test]
+    fn test_range_check_matches_full_check() {
+        let content = "# Using javascript\n\nThe javascript runtime, with `javascript` in code.\nÉtés later: javascript\nand github too.\n\n```\njavascript\n```\n\nLast paragraph on github and javascript.\n";
+        let ctx = LintContext::new(content);
+        let rule = MD044ProperNames::new(
+            vec![
+                "JavaScript".to_string(),
+                "GitHub".to_string(),
+            ],
+            true,
+        );
+        let full = rule.check(&ctx).unwrap();
+        assert_eq!(full.len(), 6);
+
+        // Each paragraph, a range within one word, an edit point between two names, a
+        // range spanning paragraphs and the whole document
+        let second = content.find("The").unwrap();
+        let last = content.rfind("Last").unwrap();
+        let point = content.find("and github").unwrap();
+        let ranges = [
+            0..second,
+            second..content.find("```").unwrap(),
+            last..content.len(),
+            second + 5..second + 6,
+            point..point,
+            point + 4..last + 10,
+            0..content.len(),
+        ];
+        for range in ranges {
+            let expected: Vec<&LintWarning> = full
+                .iter()
+                .filter(|w| crate::rule::warning_in_range(&ctx, w, &range))
+                .collect();
+            let limited = rule
+                .check_range(&ctx, range.clone())
+                .unwrap();
+            assert_eq!(
+                limited.iter().collect::<Vec<_>>(),
+                expected,
+                "range {:?}",
+                range
+            );
+        }
+
+        // Warnings keep the positions of the full lint, columns counted in characters
+        let rules: Vec<Box<dyn Rule>> = vec![Box::new(rule.clone())];
+        let positions = |warnings: &[LintWarning]| -> Vec<(usize, usize, usize)> {
+            warnings
+                .iter()
+                .map(|w| (w.line, w.column, w.end_column))
+                .collect()
+        };
+        let name = content
+            .find("later: javascript")
+            .unwrap()
+            + 7;
+        assert_eq!(
+            positions(&crate::lint_range(content, &rules, name..name + 10).unwrap()),
+            vec![(4, 13, 23)]
+        );
+        assert_eq!(
+            positions(&crate::lint_range(content, &rules, 0..content.len()).unwrap()),
+            positions(&crate::lint(content, &rules, false).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_uncached_copy_shares_no_cache() {
+        let rule = MD044ProperNames::new(vec!["JavaScript".to_string()], true);
+        let copy = rule.uncached_copy().unwrap();
+        let copy = copy
+            .as_any()
+            .downcast_ref::<MD044ProperNames>()
+            .unwrap();
+        assert!(!Arc::ptr_eq(&rule.content_cache, &copy.content_cache));
+        assert!(!Arc::ptr_eq(&rule.combined_regex, &copy.combined_regex));
+
+        let ctx = LintContext::new("Use javascript here\n");
+        assert_eq!(copy.check(&ctx).unwrap(), rule.check(&ctx).unwrap());
     }
 }
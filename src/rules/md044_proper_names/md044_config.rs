@@ -1,13 +1,90 @@
 use crate::rule_config_serde::RuleConfig;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "kebab-case")]
 pub struct MD044Config {
     #[serde(default)]
     pub names: Vec<String>,
 
+    /// Leave names inside fenced and indented code blocks unchecked
     #[serde(default = "default_code_blocks")]
     pub code_blocks: bool,
+
+    /// Leave names inside inline code spans unchecked
+    #[serde(default = "default_code_spans")]
+    pub code_spans: bool,
+
+    /// Synonym groups: each preferred term maps to the terms that should be replaced by it
+    /// (e.g. `JavaScript = ["ECMAScript"]`)
+    #[serde(default)]
+    pub synonyms: BTreeMap<String, Vec<String>>,
+
+    /// Also flag words written with differing capitalization across the document
+    /// (e.g. "GitHub" and "Github"), suggesting the most frequent form
+    #[serde(default)]
+    pub consistency: bool,
+
+    /// File listing more proper names, one per line; blank lines and lines starting with
+    /// `#` are ignored. Relative paths are resolved against the current directory.
+    #[serde(default)]
+    pub names_file: String,
+
+    /// Only report names found inside one of these contexts; empty means everywhere
+    #[serde(default)]
+    pub require_in: Vec<NameContext>,
+
+    /// Check the text inside raw HTML blocks (`<p>javascript</p>`). Tag names and
+    /// attribute values are never checked.
+    #[serde(default)]
+    pub check_html_text: bool,
+
+    /// Check the text of HTML comments (`<!-- editorial note on javascript -->`), whether
+    /// they stand alone or share a line with prose
+    #[serde(default = "default_html_comments")]
+    pub html_comments: bool,
+
+    /// Drop spaces at the edges of a replacement (e.g. from a multi-word preferred term)
+    /// that would double up with the spaces around it. Other spacing is never changed.
+    #[serde(default)]
+    pub collapse_fix_spaces: bool,
+
+    /// Check, and fix, the string values of YAML or TOML front matter, such as the items
+    /// of `tags: [javascript, nodejs]`. Keys are never checked.
+    #[serde(default)]
+    pub check_front_matter_values: bool,
+
+    /// Also check names inside URLs, autolinks, and the destinations of links, images and
+    /// reference definitions. Synonyms are never replaced there.
+    #[serde(default)]
+    pub check_urls: bool,
+
+    /// Compare a document in full with the one cached under its hash before reusing the
+    /// cached warnings, so a hash collision cannot return another document's warnings.
+    /// When off, only the lengths are compared.
+    #[serde(default = "default_verify_cache_hits")]
+    pub verify_cache_hits: bool,
+
+    /// How many documents to keep cached warnings for, evicting the least recently checked
+    /// one beyond that. 0 disables the cache.
+    #[serde(default = "default_cache_capacity")]
+    pub cache_capacity: usize,
+}
+
+/// Markup a proper name can sit in, for `require_in`
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum NameContext {
+    /// `*text*` or `_text_`
+    Emphasis,
+    /// `**text**` or `__text__`
+    Strong,
+    /// The text of an inline or reference link, not its destination
+    #[serde(alias = "link-text")]
+    LinkText,
+    /// ATX or setext heading text
+    Heading,
 }
 
 impl Default for MD044Config {
@@ -15,6 +92,18 @@ impl Default for MD044Config {
         Self {
             names: Vec::new(),
             code_blocks: default_code_blocks(),
+            code_spans: default_code_spans(),
+            synonyms: BTreeMap::new(),
+            consistency: false,
+            names_file: String::new(),
+            require_in: Vec::new(),
+            check_html_text: false,
+            html_comments: default_html_comments(),
+            collapse_fix_spaces: false,
+            check_front_matter_values: false,
+            check_urls: false,
+            verify_cache_hits: default_verify_cache_hits(),
+            cache_capacity: default_cache_capacity(),
         }
     }
 }
@@ -23,6 +112,22 @@ fn default_code_blocks() -> bool {
     true
 }
 
+fn default_code_spans() -> bool {
+    true
+}
+
+fn default_html_comments() -> bool {
+    true
+}
+
+fn default_verify_cache_hits() -> bool {
+    true
+}
+
+fn default_cache_capacity() -> usize {
+    128
+}
+
 impl RuleConfig for MD044Config {
     const RULE_NAME: &'static str = "MD044";
 }
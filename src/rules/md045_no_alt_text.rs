@@ -1,4 +1,4 @@
-use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, Severity};
+use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule};
 use lazy_static::lazy_static;
 use regex::Regex;
 
@@ -31,6 +31,10 @@ impl Rule for MD045NoAltText {
         "MD045"
     }
 
+    fn tags(&self) -> &[&'static str] {
+        &["images", "accessibility"]
+    }
+
     fn description(&self) -> &'static str {
         "Images should have alternate text (alt text)"
     }
@@ -51,19 +55,20 @@ impl Rule for MD045NoAltText {
                     format!("({})", image.url)
                 };
 
-                warnings.push(LintWarning {
-                    rule_name: Some(self.name()),
-                    line: image.line,
-                    column: image.start_col + 1, // Convert to 1-indexed
-                    end_line: image.line,
-                    end_column: image.end_col + 1, // Convert to 1-indexed
-                    message: "Image missing alt text (add description for accessibility: ![description](url))".to_string(),
-                    severity: Severity::Warning,
-                    fix: Some(Fix {
-                        range: image.byte_offset..image.byte_offset + (image.end_col - image.start_col),
-                        replacement: format!("![TODO: Add image description]{}", url_part),
-                    }),
-                });
+                warnings.push(
+                    LintWarning::new(
+                        self.name(),
+                        image.line,
+                        ctx.char_column(image.line, image.start_col) + 1, // Convert to 1-indexed
+                        image.line,
+                        ctx.char_column(image.line, image.end_col) + 1, // Convert to 1-indexed
+                        "Image missing alt text (add description for accessibility: ![description](url))",
+                    )
+                    .with_fix(Fix::safe(
+                        image.byte_offset..image.byte_offset + (image.end_col - image.start_col),
+                        format!("![TODO: Add image description]{}", url_part),
+                    )),
+                );
             }
         }
 
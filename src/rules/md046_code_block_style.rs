@@ -1,4 +1,4 @@
-use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory};
 use crate::rules::code_block_utils::CodeBlockStyle;
 use crate::utils::document_structure::{DocumentStructure, DocumentStructureExtensions};
 use crate::utils::range_utils::{LineIndex, calculate_line_range};
@@ -335,20 +335,23 @@ impl MD046CodeBlockStyle {
                                 .map(|l| l.len() + 1)
                                 .sum::<usize>();
 
-                            warnings.push(LintWarning {
-                                    rule_name: Some(self.name()),
-                                    line: opening_start_line,
-                                    column: opening_start_col,
-                                    end_line: opening_end_line,
-                                    end_column: opening_end_col,
-                                    message: format!("Code block '{}' should be closed before starting new one at line {}",
-                                                   open_marker, i + 1),
-                                    severity: Severity::Warning,
-                                    fix: Some(Fix {
-                                        range: (line_start_byte..line_start_byte),
-                                        replacement: format!("{}\n\n", open_marker),
-                                    }),
-                                });
+                            warnings.push(
+                                    LintWarning::new(
+                                        self.name(),
+                                        opening_start_line,
+                                        opening_start_col,
+                                        opening_end_line,
+                                        opening_end_col,
+                                        format!(
+                                            "Code block '{}' should be closed before starting new one at line {}",
+                                            open_marker, i + 1
+                                        ),
+                                    )
+                                    .with_fix(Fix::safe(
+                                        line_start_byte..line_start_byte,
+                                        format!("{}\n\n", open_marker),
+                                    )),
+                                );
 
                             // Mark the current fence as flagged for nested issue
                             fence_stack.last_mut().unwrap().3 = true;
@@ -395,19 +398,20 @@ impl MD046CodeBlockStyle {
                 let (start_line, start_col, end_line, end_col) =
                     calculate_line_range(opening_line, lines[opening_line - 1]);
 
-                warnings.push(LintWarning {
-                    rule_name: Some(self.name()),
-                    line: start_line,
-                    column: start_col,
-                    end_line,
-                    end_column: end_col,
-                    message: format!("Code block opened with '{}' but never closed", fence_marker),
-                    severity: Severity::Warning,
-                    fix: Some(Fix {
-                        range: (ctx.content.len()..ctx.content.len()),
-                        replacement: format!("\n{}", fence_marker),
-                    }),
-                });
+                warnings.push(
+                    LintWarning::new(
+                        self.name(),
+                        start_line,
+                        start_col,
+                        end_line,
+                        end_col,
+                        format!("Code block opened with '{}' but never closed", fence_marker),
+                    )
+                    .with_fix(Fix::safe(
+                        ctx.content.len()..ctx.content.len(),
+                        format!("\n{}", fence_marker),
+                    )),
+                );
             }
         }
 
@@ -462,6 +466,10 @@ impl Rule for MD046CodeBlockStyle {
         "MD046"
     }
 
+    fn tags(&self) -> &[&'static str] {
+        &["code", "style", "consistency"]
+    }
+
     fn description(&self) -> &'static str {
         "Code blocks should use a consistent style"
     }
@@ -791,19 +799,20 @@ impl Rule for MD046CodeBlockStyle {
                         calculate_line_range(i + 1, line);
 
                     // Add warning for opening fence
-                    warnings.push(LintWarning {
-                        rule_name: Some(self.name()),
-                        line: start_line,
-                        column: start_col,
-                        end_line,
-                        end_column: end_col,
-                        message: "Use fenced code blocks".to_string(),
-                        severity: Severity::Warning,
-                        fix: Some(Fix {
-                            range: line_index.line_col_to_byte_range(i + 1, 1),
-                            replacement: String::new(), // Remove the opening fence
-                        }),
-                    });
+                    warnings.push(
+                        LintWarning::new(
+                            self.name(),
+                            start_line,
+                            start_col,
+                            end_line,
+                            end_col,
+                            "Use fenced code blocks",
+                        )
+                        .with_fix(Fix::safe(
+                            line_index.line_col_to_byte_range(i + 1, 1),
+                            String::new(), // Remove the opening fence
+                        )),
+                    );
 
                     // Find closing fence and add warnings for all lines in the fenced block
                     let mut j = i + 1;
@@ -822,24 +831,25 @@ impl Rule for MD046CodeBlockStyle {
                                 let (start_line, start_col, end_line, end_col) =
                                     calculate_line_range(k + 1, line_content);
 
-                                warnings.push(LintWarning {
-                                    rule_name: Some(self.name()),
-                                    line: start_line,
-                                    column: start_col,
-                                    end_line,
-                                    end_column: end_col,
-                                    message: "Use fenced code blocks".to_string(),
-                                    severity: Severity::Warning,
-                                    fix: Some(Fix {
-                                        range: line_index.line_col_to_byte_range(k + 1, 1),
-                                        replacement: if k == j {
+                                warnings.push(
+                                    LintWarning::new(
+                                        self.name(),
+                                        start_line,
+                                        start_col,
+                                        end_line,
+                                        end_col,
+                                        "Use fenced code blocks",
+                                    )
+                                    .with_fix(Fix::safe(
+                                        line_index.line_col_to_byte_range(k + 1, 1),
+                                        if k == j {
                                             String::new() // Remove closing fence
                                         } else {
                                             format!("    {}", line_content.trim_start())
                                             // Convert content to indented
                                         },
-                                    }),
-                                });
+                                    )),
+                                );
                             }
 
                             // Mark all lines in the fenced block as processed
@@ -882,19 +892,20 @@ impl Rule for MD046CodeBlockStyle {
                             calculate_line_range(i + 1, line);
 
                         // Add warning for indented block that should be fenced
-                        warnings.push(LintWarning {
-                            rule_name: Some(self.name()),
-                            line: start_line,
-                            column: start_col,
-                            end_line,
-                            end_column: end_col,
-                            message: "Use fenced code blocks".to_string(),
-                            severity: Severity::Warning,
-                            fix: Some(Fix {
-                                range: line_index.line_col_to_byte_range(i + 1, 1),
-                                replacement: "```\n".to_string() + line.trim_start(),
-                            }),
-                        });
+                        warnings.push(
+                            LintWarning::new(
+                                self.name(),
+                                start_line,
+                                start_col,
+                                end_line,
+                                end_col,
+                                "Use fenced code blocks",
+                            )
+                            .with_fix(Fix::safe(
+                                line_index.line_col_to_byte_range(i + 1, 1),
+                                "```\n".to_string() + line.trim_start(),
+                            )),
+                        );
                     }
                 }
 
@@ -906,6 +917,10 @@ impl Rule for MD046CodeBlockStyle {
         Ok(warnings)
     }
 
+    fn is_document_wide(&self) -> bool {
+        self.config.style == CodeBlockStyle::Consistent
+    }
+
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
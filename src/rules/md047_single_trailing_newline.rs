@@ -1,6 +1,6 @@
 use crate::utils::range_utils::LineIndex;
 
-use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, Severity};
+use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule};
 
 /// Rule MD047: File should end with a single newline
 ///
@@ -14,6 +14,10 @@ impl Rule for MD047SingleTrailingNewline {
         "MD047"
     }
 
+    fn tags(&self) -> &[&'static str] {
+        &["whitespace"]
+    }
+
     fn description(&self) -> &'static str {
         "Files should end with a single newline character"
     }
@@ -52,31 +56,32 @@ impl Rule for MD047SingleTrailingNewline {
                     let line_content = last_content_lines.last().unwrap_or(&"");
                     (
                         line_num,
-                        line_content.len() + 1,
+                        line_content.chars().count() + 1,
                         line_num,
-                        line_content.len() + 2,
+                        line_content.chars().count() + 2,
                     )
                 }
             } else {
                 // For missing newline, highlight the end of the last line
                 (
                     last_line_num,
-                    last_line_content.len() + 1,
+                    last_line_content.chars().count() + 1,
                     last_line_num,
-                    last_line_content.len() + 1,
+                    last_line_content.chars().count() + 1,
                 )
             };
 
-            warnings.push(LintWarning {
-                rule_name: Some(self.name()),
-                message: String::from("File should end with a single newline character"),
-                line: start_line,
-                column: start_col,
-                end_line,
-                end_column: end_col,
-                severity: Severity::Warning,
-                fix: Some(Fix {
-                    range: if has_trailing_newline {
+            warnings.push(
+                LintWarning::new(
+                    self.name(),
+                    start_line,
+                    start_col,
+                    end_line,
+                    end_col,
+                    String::from("File should end with a single newline character"),
+                )
+                .with_fix(Fix::safe(
+                    if has_trailing_newline {
                         // For multiple newlines, replace from the position to the end of file
                         let start_range =
                             line_index.line_col_to_byte_range_with_length(start_line, start_col, 0);
@@ -86,7 +91,7 @@ impl Rule for MD047SingleTrailingNewline {
                         let end_pos = content.len();
                         end_pos..end_pos
                     },
-                    replacement: if has_trailing_newline {
+                    if has_trailing_newline {
                         // If there are multiple newlines, fix by ensuring just one
                         let trimmed = content.trim_end();
                         if !trimmed.is_empty() {
@@ -99,8 +104,8 @@ impl Rule for MD047SingleTrailingNewline {
                         // If there's no newline, add one to the last line
                         String::from("\n")
                     },
-                }),
-            });
+                )),
+            );
         }
 
         Ok(warnings)
@@ -1,4 +1,4 @@
-use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, Severity};
+use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule};
 use crate::rules::code_fence_utils::CodeFenceStyle;
 use crate::utils::range_utils::{LineIndex, calculate_match_range};
 use toml;
@@ -52,6 +52,10 @@ impl Rule for MD048CodeFenceStyle {
         "MD048"
     }
 
+    fn tags(&self) -> &[&'static str] {
+        &["code", "style", "consistency"]
+    }
+
     fn description(&self) -> &'static str {
         "Code fence style should be consistent"
     }
@@ -109,23 +113,24 @@ impl Rule for MD048CodeFenceStyle {
                             fence_end - fence_start,
                         );
 
-                        warnings.push(LintWarning {
-                            rule_name: Some(self.name()),
-                            message: "Code fence style: use ~~~ instead of ```".to_string(),
-                            line: start_line,
-                            column: start_col,
-                            end_line,
-                            end_column: end_col,
-                            severity: Severity::Warning,
-                            fix: Some(Fix {
-                                range: _line_index.line_col_to_byte_range_with_length(
+                        warnings.push(
+                            LintWarning::new(
+                                self.name(),
+                                start_line,
+                                start_col,
+                                end_line,
+                                end_col,
+                                "Code fence style: use ~~~ instead of ```",
+                            )
+                            .with_fix(Fix::safe(
+                                _line_index.line_col_to_byte_range_with_length(
                                     line_num + 1,
                                     1,
                                     line.len(),
                                 ),
-                                replacement: line.replace("```", "~~~"),
-                            }),
-                        });
+                                line.replace("```", "~~~"),
+                            )),
+                        );
                     } else if trimmed.starts_with("~~~") && target_style == CodeFenceStyle::Backtick
                     {
                         // Find the position and length of the tilde fence
@@ -143,23 +148,24 @@ impl Rule for MD048CodeFenceStyle {
                             fence_end - fence_start,
                         );
 
-                        warnings.push(LintWarning {
-                            rule_name: Some(self.name()),
-                            message: "Code fence style: use ``` instead of ~~~".to_string(),
-                            line: start_line,
-                            column: start_col,
-                            end_line,
-                            end_column: end_col,
-                            severity: Severity::Warning,
-                            fix: Some(Fix {
-                                range: _line_index.line_col_to_byte_range_with_length(
+                        warnings.push(
+                            LintWarning::new(
+                                self.name(),
+                                start_line,
+                                start_col,
+                                end_line,
+                                end_col,
+                                "Code fence style: use ``` instead of ~~~",
+                            )
+                            .with_fix(Fix::safe(
+                                _line_index.line_col_to_byte_range_with_length(
                                     line_num + 1,
                                     1,
                                     line.len(),
                                 ),
-                                replacement: line.replace("~~~", "```"),
-                            }),
-                        });
+                                line.replace("~~~", "```"),
+                            )),
+                        );
                     }
                 } else if trimmed.starts_with(&code_block_fence)
                     && trimmed[code_block_fence.len()..]
@@ -183,23 +189,24 @@ impl Rule for MD048CodeFenceStyle {
                             fence_end - fence_start,
                         );
 
-                        warnings.push(LintWarning {
-                            rule_name: Some(self.name()),
-                            message: "Code fence style: use ~~~ instead of ```".to_string(),
-                            line: start_line,
-                            column: start_col,
-                            end_line,
-                            end_column: end_col,
-                            severity: Severity::Warning,
-                            fix: Some(Fix {
-                                range: _line_index.line_col_to_byte_range_with_length(
+                        warnings.push(
+                            LintWarning::new(
+                                self.name(),
+                                start_line,
+                                start_col,
+                                end_line,
+                                end_col,
+                                "Code fence style: use ~~~ instead of ```",
+                            )
+                            .with_fix(Fix::safe(
+                                _line_index.line_col_to_byte_range_with_length(
                                     line_num + 1,
                                     1,
                                     line.len(),
                                 ),
-                                replacement: line.replace("```", "~~~"),
-                            }),
-                        });
+                                line.replace("```", "~~~"),
+                            )),
+                        );
                     } else if trimmed.starts_with("~~~") && target_style == CodeFenceStyle::Backtick
                     {
                         // Find the position and length of the tilde fence
@@ -217,23 +224,24 @@ impl Rule for MD048CodeFenceStyle {
                             fence_end - fence_start,
                         );
 
-                        warnings.push(LintWarning {
-                            rule_name: Some(self.name()),
-                            message: "Code fence style: use ``` instead of ~~~".to_string(),
-                            line: start_line,
-                            column: start_col,
-                            end_line,
-                            end_column: end_col,
-                            severity: Severity::Warning,
-                            fix: Some(Fix {
-                                range: _line_index.line_col_to_byte_range_with_length(
+                        warnings.push(
+                            LintWarning::new(
+                                self.name(),
+                                start_line,
+                                start_col,
+                                end_line,
+                                end_col,
+                                "Code fence style: use ``` instead of ~~~",
+                            )
+                            .with_fix(Fix::safe(
+                                _line_index.line_col_to_byte_range_with_length(
                                     line_num + 1,
                                     1,
                                     line.len(),
                                 ),
-                                replacement: line.replace("~~~", "```"),
-                            }),
-                        });
+                                line.replace("~~~", "```"),
+                            )),
+                        );
                     }
 
                     in_code_block = false;
@@ -351,6 +359,10 @@ impl Rule for MD048CodeFenceStyle {
         Ok(result)
     }
 
+    fn is_document_wide(&self) -> bool {
+        self.config.style == CodeFenceStyle::Consistent
+    }
+
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
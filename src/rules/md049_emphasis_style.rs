@@ -1,5 +1,5 @@
 use crate::lint_context::LintContext;
-use crate::rule::{LintError, LintResult, LintWarning, Rule, Severity};
+use crate::rule::{LintError, LintResult, LintWarning, Rule};
 use crate::rules::emphasis_style::EmphasisStyle;
 use crate::utils::range_utils::calculate_match_range;
 use markdown::mdast::{Emphasis, Node};
@@ -84,6 +84,10 @@ impl Rule for MD049EmphasisStyle {
         "MD049"
     }
 
+    fn tags(&self) -> &[&'static str] {
+        &["emphasis", "style", "consistency"]
+    }
+
     fn description(&self) -> &'static str {
         "Emphasis style should be consistent"
     }
@@ -114,7 +118,11 @@ impl Rule for MD049EmphasisStyle {
                             .lines()
                             .nth(line - 1)
                             .unwrap_or("");
-                        let emphasis_start = col - 1; // Convert to 0-based
+                        // Byte offset of the char column in the line
+                        let emphasis_start = line_str
+                            .char_indices()
+                            .nth(col - 1)
+                            .map_or(line_str.len(), |(i, _)| i);
                         let emphasis_len = if let Some(pos) = &em.position {
                             pos.end.offset - pos.start.offset
                         } else {
@@ -134,13 +142,10 @@ impl Rule for MD049EmphasisStyle {
                                 && end_offset <= ctx.content.len()
                             {
                                 let inner_content = &ctx.content[start_offset + 1..end_offset - 1];
-                                Some(crate::rule::Fix {
-                                    range: start_offset..end_offset,
-                                    replacement: format!(
-                                        "{}{}{}",
-                                        target_marker, inner_content, target_marker
-                                    ),
-                                })
+                                Some(crate::rule::Fix::safe(
+                                    start_offset..end_offset,
+                                    format!("{}{}{}", target_marker, inner_content, target_marker),
+                                ))
                             } else {
                                 None
                             }
@@ -148,19 +153,20 @@ impl Rule for MD049EmphasisStyle {
                             None
                         };
 
-                        warnings.push(LintWarning {
-                            rule_name: Some(self.name()),
-                            line: start_line,
-                            column: start_col,
-                            end_line,
-                            end_column: end_col,
-                            message: format!(
-                                "Emphasis should use {} instead of {}",
-                                target_marker, marker
-                            ),
-                            fix,
-                            severity: Severity::Warning,
-                        });
+                        warnings.push(
+                            LintWarning::new(
+                                self.name(),
+                                start_line,
+                                start_col,
+                                end_line,
+                                end_col,
+                                format!(
+                                    "Emphasis should use {} instead of {}",
+                                    target_marker, marker
+                                ),
+                            )
+                            .with_fix(fix),
+                        );
                     }
                 }
             }
@@ -180,7 +186,11 @@ impl Rule for MD049EmphasisStyle {
                             .lines()
                             .nth(line - 1)
                             .unwrap_or("");
-                        let emphasis_start = col - 1; // Convert to 0-based
+                        // Byte offset of the char column in the line
+                        let emphasis_start = line_str
+                            .char_indices()
+                            .nth(col - 1)
+                            .map_or(line_str.len(), |(i, _)| i);
                         let emphasis_len = if let Some(pos) = &em.position {
                             pos.end.offset - pos.start.offset
                         } else {
@@ -200,13 +210,13 @@ impl Rule for MD049EmphasisStyle {
                                 && end_offset <= ctx.content.len()
                             {
                                 let inner_content = &ctx.content[start_offset + 1..end_offset - 1];
-                                Some(crate::rule::Fix {
-                                    range: start_offset..end_offset,
-                                    replacement: format!(
+                                Some(crate::rule::Fix::safe(
+                                    start_offset..end_offset,
+                                    format!(
                                         "{}{}{}",
                                         correct_marker, inner_content, correct_marker
                                     ),
-                                })
+                                ))
                             } else {
                                 None
                             }
@@ -214,20 +224,21 @@ impl Rule for MD049EmphasisStyle {
                             None
                         };
 
-                        warnings.push(LintWarning {
-                            rule_name: Some(self.name()),
-                            line: start_line,
-                            column: start_col,
-                            end_line,
-                            end_column: end_col,
-                            message: format!(
-                                "Emphasis should use {
+                        warnings.push(
+                            LintWarning::new(
+                                self.name(),
+                                start_line,
+                                start_col,
+                                end_line,
+                                end_col,
+                                format!(
+                                    "Emphasis should use {
             } instead of {}",
-                                correct_marker, wrong_marker
-                            ),
-                            fix,
-                            severity: Severity::Warning,
-                        });
+                                    correct_marker, wrong_marker
+                                ),
+                            )
+                            .with_fix(fix),
+                        );
                     }
                 }
             }
@@ -266,6 +277,10 @@ impl Rule for MD049EmphasisStyle {
         Ok(result)
     }
 
+    fn is_document_wide(&self) -> bool {
+        self.config.style == EmphasisStyle::Consistent
+    }
+
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
@@ -1,16 +1,31 @@
-use crate::utils::range_utils::{LineIndex, calculate_match_range};
-
-use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, Severity};
+use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule};
+use crate::rules::emphasis_style::{EmphasisStyle, get_emphasis_pattern};
+use crate::rules::front_matter_utils::FrontMatterUtils;
 use crate::rules::strong_style::StrongStyle;
 use lazy_static::lazy_static;
 use regex::Regex;
+use std::ops::Range;
 
 mod md050_config;
-use md050_config::MD050Config;
+use md050_config::{ConsistentScope, MD050Config};
 
 lazy_static! {
-    static ref UNDERSCORE_PATTERN: Regex = Regex::new(r"__[^_\\]+__").unwrap();
-    static ref ASTERISK_PATTERN: Regex = Regex::new(r"\*\*[^*\\]+\*\*").unwrap();
+    // Strong text may hold escapes and single markers of its own kind (`__foo_bar__`,
+    // `**a * b**`), but does not start with one of those markers: a run of three markers
+    // opens at its last two.
+    static ref UNDERSCORE_PATTERN: Regex =
+        Regex::new(r"__(?:[^_\\]|\\[\s\S])(?:[^_\\]|\\[\s\S]|_[^_])*__").unwrap();
+    static ref ASTERISK_PATTERN: Regex =
+        Regex::new(r"\*\*(?:[^*\\]|\\[\s\S])(?:[^*\\]|\\[\s\S]|\*[^*])*\*\*").unwrap();
+}
+
+/// Which markup a marker span is
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MarkerKind {
+    /// Strong text, `**x**` or `__x__`
+    Strong,
+    /// Single-marker emphasis, `*x*` or `_x_`, checked with `include_emphasis`
+    Emphasis,
 }
 
 /// Rule MD050: Strong style
@@ -18,46 +33,78 @@ lazy_static! {
 /// See [docs/md050.md](../../docs/md050.md) for full documentation, configuration, and examples.
 ///
 /// This rule is triggered when strong markers (** or __) are used in an inconsistent way.
-#[derive(Debug, Default, Clone)]
+/// Strong text may wrap over lines of a paragraph; it is reported at its opening marker.
+/// With `include_emphasis`, single-marker emphasis (* or _) is held to a consistent marker
+/// of its own in the same way. Front matter (see the global `front-matter-delimiters`
+/// setting) is never checked.
+#[derive(Debug, Clone)]
 pub struct MD050StrongStyle {
     config: MD050Config,
+    front_matter_delimiters: Vec<String>,
+}
+
+impl Default for MD050StrongStyle {
+    fn default() -> Self {
+        Self::from_config_struct(MD050Config::default())
+    }
 }
 
 impl MD050StrongStyle {
     pub fn new(style: StrongStyle) -> Self {
+        Self::from_config_struct(MD050Config {
+            style,
+            ..Default::default()
+        })
+    }
+
+    pub fn from_config_struct(config: MD050Config) -> Self {
         Self {
-            config: MD050Config { style },
+            config,
+            front_matter_delimiters: FrontMatterUtils::default_delimiters(),
         }
     }
 
-    pub fn from_config_struct(config: MD050Config) -> Self {
-        Self { config }
+    /// Check if a byte offset is outside code and front matter, where markers are never changed.
+    /// Inline HTML `<code>`, `<kbd>` and `<samp>` elements count as code.
+    fn is_checked(
+        &self,
+        ctx: &crate::lint_context::LintContext,
+        front_matter_end: usize,
+        pos: usize,
+    ) -> bool {
+        pos >= front_matter_end
+            && !ctx.is_in_code_block_or_span(pos)
+            && !ctx.is_in_html_code_element(pos)
     }
 
-    fn detect_style(&self, ctx: &crate::lint_context::LintContext) -> Option<StrongStyle> {
+    /// Detect the style of the first `kind` span starting within `range` of the content
+    fn detect_style(
+        &self,
+        ctx: &crate::lint_context::LintContext,
+        range: Range<usize>,
+        kind: MarkerKind,
+    ) -> Option<StrongStyle> {
         let content = ctx.content;
+        let front_matter_end =
+            FrontMatterUtils::front_matter_byte_end(content, &self.front_matter_delimiters);
 
         // Find the first occurrence of either style that's not in a code block
-        let mut first_asterisk = None;
-        for m in ASTERISK_PATTERN.find_iter(content) {
-            if !ctx.is_in_code_block_or_span(m.start()) {
-                first_asterisk = Some(m);
-                break;
-            }
-        }
-
-        let mut first_underscore = None;
-        for m in UNDERSCORE_PATTERN.find_iter(content) {
-            if !ctx.is_in_code_block_or_span(m.start()) {
-                first_underscore = Some(m);
-                break;
-            }
-        }
+        let first = |style: StrongStyle| {
+            marker_spans(content, kind, style)
+                .into_iter()
+                .skip_while(|&(start, _)| start < range.start)
+                .take_while(|&(start, _)| start < range.end)
+                .find(|&(start, end)| {
+                    self.is_checked(ctx, front_matter_end, start) && stays_in_block(ctx, start, end)
+                })
+        };
+        let first_asterisk = first(StrongStyle::Asterisk);
+        let first_underscore = first(StrongStyle::Underscore);
 
         match (first_asterisk, first_underscore) {
             (Some(a), Some(u)) => {
                 // Whichever pattern appears first determines the style
-                if a.start() < u.start() {
+                if a.0 < u.0 {
                     Some(StrongStyle::Asterisk)
                 } else {
                     Some(StrongStyle::Underscore)
@@ -69,150 +116,439 @@ impl MD050StrongStyle {
         }
     }
 
-    fn is_escaped(&self, text: &str, pos: usize) -> bool {
-        if pos == 0 {
-            return false;
+    /// The style each part of the document holds its `kind` spans to, as (start byte, style)
+    /// pairs in document order. With the `consistent` style and `section` scope, every
+    /// heading starts a part whose style is that of its first span.
+    fn target_styles(
+        &self,
+        ctx: &crate::lint_context::LintContext,
+        kind: MarkerKind,
+    ) -> Vec<(usize, StrongStyle)> {
+        if self.config.style != StrongStyle::Consistent {
+            return vec![(0, self.config.style)];
         }
+        let mut starts = vec![0];
+        if self.config.consistent_scope == ConsistentScope::Section {
+            starts.extend(
+                ctx.lines
+                    .iter()
+                    .filter(|line| line.heading.is_some() && line.byte_offset > 0)
+                    .map(|line| line.byte_offset),
+            );
+        }
+        let ends: Vec<usize> = starts
+            .iter()
+            .skip(1)
+            .copied()
+            .chain([ctx.content.len()])
+            .collect();
+        starts
+            .into_iter()
+            .zip(ends)
+            .map(|(start, end)| {
+                let style = self
+                    .detect_style(ctx, start..end, kind)
+                    .unwrap_or(StrongStyle::Asterisk);
+                (start, style)
+            })
+            .collect()
+    }
 
-        let mut backslash_count = 0;
-        let mut i = pos;
-        while i > 0 {
-            i -= 1;
-            let c = text.chars().nth(i).unwrap_or(' ');
-            if c != '\\' {
-                break;
+    /// Strong text, and with `include_emphasis` emphasis, held to the other style than its
+    /// part of the document, as (start, end, style it should use, kind) in document order
+    fn violations(
+        &self,
+        ctx: &crate::lint_context::LintContext,
+        front_matter_end: usize,
+    ) -> Vec<(usize, usize, StrongStyle, MarkerKind)> {
+        let content = ctx.content;
+        let kinds: &[MarkerKind] = if self.config.include_emphasis {
+            &[MarkerKind::Strong, MarkerKind::Emphasis]
+        } else {
+            &[MarkerKind::Strong]
+        };
+        let mut violations = Vec::new();
+        for &kind in kinds {
+            let targets = self.target_styles(ctx, kind);
+            let target_at = |pos: usize| {
+                targets
+                    .iter()
+                    .rev()
+                    .find(|(start, _)| *start <= pos)
+                    .map_or(StrongStyle::Asterisk, |&(_, style)| style)
+            };
+            for (style, found) in [
+                (StrongStyle::Asterisk, StrongStyle::Underscore),
+                (StrongStyle::Underscore, StrongStyle::Asterisk),
+            ] {
+                violations.extend(
+                    marker_spans(content, kind, found)
+                        .into_iter()
+                        .filter(|&(start, _)| target_at(start) == style)
+                        .filter(|&(start, _)| self.is_checked(ctx, front_matter_end, start))
+                        .filter(|&(start, end)| stays_in_block(ctx, start, end))
+                        .filter(|&(start, _)| !self.is_escaped(content, start))
+                        .map(|(start, end)| (start, end, style, kind)),
+                );
             }
-            backslash_count += 1;
         }
-        backslash_count % 2 == 1
+        violations.sort_unstable_by_key(|&(start, _, _, _)| start);
+        violations
     }
-}
 
-impl Rule for MD050StrongStyle {
-    fn name(&self) -> &'static str {
-        "MD050"
+    /// Build the fix for the `kind` span at `start..end` of `content`, converting it to
+    /// `target_style`
+    fn build_kind_fix(
+        &self,
+        content: &str,
+        (start, end, target_style, kind): (usize, usize, StrongStyle, MarkerKind),
+    ) -> Option<Fix> {
+        match kind {
+            MarkerKind::Strong => self.build_fix(content, start, end, target_style),
+            MarkerKind::Emphasis => self.build_emphasis_fix(content, start, end, target_style),
+        }
     }
 
-    fn description(&self) -> &'static str {
-        "Strong emphasis style should be consistent"
+    /// Build the fix converting the emphasis at `start..end` of `content` to the single
+    /// marker of `target_style`. Emphasis touching a letter or digit only converts to `_`
+    /// with `normalize_spacing`, since `a_b_c` is not emphasis. With
+    /// `avoid_marker_collisions`, returns `None` when the new marker would run into a
+    /// neighbouring one.
+    fn build_emphasis_fix(
+        &self,
+        content: &str,
+        start: usize,
+        end: usize,
+        target_style: StrongStyle,
+    ) -> Option<Fix> {
+        let marker = match target_style {
+            StrongStyle::Asterisk => '*',
+            StrongStyle::Underscore => '_',
+            StrongStyle::Consistent => unreachable!(),
+        };
+        let space_before = content[..start]
+            .chars()
+            .next_back()
+            .is_some_and(|c| c.is_alphanumeric());
+        let space_after = content[end..]
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_alphanumeric());
+        if marker == '_' && (space_before || space_after) && !self.config.normalize_spacing {
+            return None;
+        }
+        if self.config.avoid_marker_collisions
+            && (content[..start].ends_with(marker) || content[end..].starts_with(marker))
+        {
+            return None;
+        }
+
+        let mut replacement = String::with_capacity(end - start + 2);
+        if self.config.normalize_spacing && space_before {
+            replacement.push(' ');
+        }
+        replacement.push(marker);
+        replacement.push_str(&content[start + 1..end - 1]);
+        replacement.push(marker);
+        if self.config.normalize_spacing && space_after {
+            replacement.push(' ');
+        }
+
+        Some(Fix::safe(start..end, replacement))
     }
 
-    fn check(&self, ctx: &crate::lint_context::LintContext) -> LintResult {
+    /// Apply the fixes of one pass to the content of `ctx`. Emphasis and strong text nested
+    /// in one another have overlapping fixes: the outer one is applied, and whether the
+    /// inner ones were left for another pass is returned along with the content.
+    fn fix_pass(&self, ctx: &crate::lint_context::LintContext) -> (String, bool) {
         let content = ctx.content;
-        let _line_index = LineIndex::new(content.to_string());
 
-        let mut warnings = Vec::new();
+        // Build fixes against the original content so adjacency checks see the source text
+        let front_matter_end =
+            FrontMatterUtils::front_matter_byte_end(content, &self.front_matter_delimiters);
+        let mut fixes: Vec<Fix> = Vec::new();
+        let mut deferred = false;
+        for fix in self
+            .violations(ctx, front_matter_end)
+            .into_iter()
+            .filter_map(|violation| self.build_kind_fix(content, violation))
+        {
+            if fixes
+                .last()
+                .is_some_and(|last| last.range.end > fix.range.start)
+            {
+                deferred = true;
+            } else {
+                fixes.push(fix);
+            }
+        }
 
-        let target_style = match self.config.style {
-            StrongStyle::Consistent => self
-                .detect_style(ctx)
-                .unwrap_or(StrongStyle::Asterisk),
-            _ => self.config.style,
-        };
+        // Process fixes in reverse order to maintain correct indices
+        let mut result = content.to_string();
+        for fix in fixes.into_iter().rev() {
+            result.replace_range(fix.range, &fix.replacement);
+        }
+        (result, deferred)
+    }
 
-        let strong_regex = match target_style {
-            StrongStyle::Asterisk => &*UNDERSCORE_PATTERN,
-            StrongStyle::Underscore => &*ASTERISK_PATTERN,
+    /// Build the fix converting the strong span at `start..end` of `content` to `target_style`.
+    ///
+    /// Only the markers are rewritten; the surrounding text is left untouched unless
+    /// `normalize_spacing` is enabled, in which case a single space is inserted where
+    /// the span is directly adjacent to a letter or digit.
+    ///
+    /// With `avoid_marker_collisions`, returns `None` when the new markers would run into
+    /// neighbouring ones, except for strong text wrapped in single emphasis markers, whose
+    /// emphasis markers are swapped to the other character instead.
+    fn build_fix(
+        &self,
+        content: &str,
+        start: usize,
+        end: usize,
+        target_style: StrongStyle,
+    ) -> Option<Fix> {
+        let (marker, other) = match target_style {
+            StrongStyle::Asterisk => ("**", '_'),
+            StrongStyle::Underscore => ("__", '*'),
             StrongStyle::Consistent => unreachable!(),
         };
+        let text = &content[start + 2..end - 2];
 
-        // Track byte position for each line
-        let mut byte_pos = 0;
-
-        for (line_num, line) in content.lines().enumerate() {
-            for m in strong_regex.find_iter(line) {
-                // Calculate the byte position of this match in the document
-                let match_byte_pos = byte_pos + m.start();
-
-                // Skip if this strong text is inside a code block or code span
-                if ctx.is_in_code_block_or_span(match_byte_pos) {
-                    continue;
-                }
+        // Emphasis wrapping the text in the target marker (`**_x_**` to underscores) would
+        // run into the new markers, so it takes the other marker. Other text starting or
+        // ending with the target marker cannot be converted safely.
+        let marker_char = marker.as_bytes()[0] as char;
+        let swapped;
+        let text = match (text.starts_with(marker_char), text.ends_with(marker_char)) {
+            (false, false) => text,
+            (true, true) if text.len() > 2 && !text[1..text.len() - 1].contains(marker_char) => {
+                swapped = format!("{}{}{}", other, &text[1..text.len() - 1], other);
+                &swapped
+            }
+            _ => return None,
+        };
 
-                if !self.is_escaped(line, m.start()) {
-                    let text = &line[m.start() + 2..m.end() - 2];
-                    let message = match target_style {
-                        StrongStyle::Asterisk => "Strong emphasis should use ** instead of __",
-                        StrongStyle::Underscore => "Strong emphasis should use __ instead of **",
-                        StrongStyle::Consistent => unreachable!(),
-                    };
-
-                    // Calculate precise character range for the entire strong emphasis
-                    let (start_line, start_col, end_line, end_col) =
-                        calculate_match_range(line_num + 1, line, m.start(), m.len());
-
-                    warnings.push(LintWarning {
-                        rule_name: Some(self.name()),
-                        line: start_line,
-                        column: start_col,
-                        end_line,
-                        end_column: end_col,
-                        message: message.to_string(),
-                        severity: Severity::Warning,
-                        fix: Some(Fix {
-                            range: _line_index.line_col_to_byte_range(line_num + 1, m.start() + 1),
-                            replacement: match target_style {
-                                StrongStyle::Asterisk => format!(
-                                    "**{
-            }**",
-                                    text
-                                ),
-                                StrongStyle::Underscore => format!("__{}__", text),
-                                StrongStyle::Consistent => unreachable!(),
-                            },
-                        }),
-                    });
+        let mut emphasis = None;
+        let (start, end) = if self.config.avoid_marker_collisions {
+            let marker_byte = marker.as_bytes()[0];
+            let before = content[..start]
+                .bytes()
+                .rev()
+                .take_while(|&b| b == marker_byte)
+                .count();
+            let after = content[end..]
+                .bytes()
+                .take_while(|&b| b == marker_byte)
+                .count();
+            match (before, after) {
+                (0, 0) => (start, end),
+                (1, 1) => {
+                    emphasis = Some(other);
+                    (start - 1, end + 1)
                 }
+                _ => return None,
             }
+        } else {
+            (start, end)
+        };
 
-            // Update byte position for next line
-            byte_pos += line.len() + 1; // +1 for newline
+        let mut replacement = String::with_capacity(end - start + 2);
+        if self.config.normalize_spacing
+            && content[..start]
+                .chars()
+                .next_back()
+                .is_some_and(|c| c.is_alphanumeric())
+        {
+            replacement.push(' ');
+        }
+        replacement.extend(emphasis);
+        replacement.push_str(marker);
+        replacement.push_str(text);
+        replacement.push_str(marker);
+        replacement.extend(emphasis);
+        if self.config.normalize_spacing
+            && content[end..]
+                .chars()
+                .next()
+                .is_some_and(|c| c.is_alphanumeric())
+        {
+            replacement.push(' ');
         }
 
-        Ok(warnings)
+        Some(Fix::safe(start..end, replacement))
     }
 
-    fn fix(&self, ctx: &crate::lint_context::LintContext) -> Result<String, LintError> {
-        let content = ctx.content;
-
-        let target_style = match self.config.style {
-            StrongStyle::Consistent => self
-                .detect_style(ctx)
-                .unwrap_or(StrongStyle::Asterisk),
-            _ => self.config.style,
-        };
-
-        let strong_regex = match target_style {
-            StrongStyle::Asterisk => &*UNDERSCORE_PATTERN,
-            StrongStyle::Underscore => &*ASTERISK_PATTERN,
-            StrongStyle::Consistent => unreachable!(),
-        };
-
-        // Store matches with their positions
+    // Whether the marker at byte `pos` is escaped by an odd number of backslashes before
+    // it. `\` is ASCII, so the bytes before `pos` can be read without decoding characters.
+    fn is_escaped(&self, text: &str, pos: usize) -> bool {
+        text.as_bytes()[..pos.min(text.len())]
+            .iter()
+            .rev()
+            .take_while(|&&byte| byte == b'\\')
+            .count()
+            % 2
+            == 1
+    }
+}
 
-        let matches: Vec<(usize, usize)> = strong_regex
+/// The spans of `content` marked up as `kind` with the markers of `style`, which is not
+/// `Consistent`, as (start, end) byte offsets in document order
+fn marker_spans(content: &str, kind: MarkerKind, style: StrongStyle) -> Vec<(usize, usize)> {
+    let (strong, emphasis, marker) = match style {
+        StrongStyle::Asterisk => (&*ASTERISK_PATTERN, EmphasisStyle::Asterisk, b'*'),
+        StrongStyle::Underscore => (&*UNDERSCORE_PATTERN, EmphasisStyle::Underscore, b'_'),
+        StrongStyle::Consistent => unreachable!(),
+    };
+    if kind == MarkerKind::Strong {
+        return strong
             .find_iter(content)
-            .filter(|m| !ctx.is_in_code_block_or_span(m.start()))
-            .filter(|m| !self.is_escaped(content, m.start()))
             .map(|m| (m.start(), m.end()))
             .collect();
+    }
 
-        // Process matches in reverse order to maintain correct indices
+    // Single markers running into another marker of the kind belong to strong text or a
+    // longer run (`**bold**` is not two emphasis spans), and `_` does not emphasize inside
+    // a word (`snake_case_name`). A rejected match may hide one starting within it, so the
+    // search resumes just after its start.
+    let pattern = get_emphasis_pattern(emphasis);
+    let bytes = content.as_bytes();
+    let mut spans = Vec::new();
+    let mut pos = 0;
+    while let Some(m) = pattern.find_at(content, pos) {
+        let (start, end) = (m.start(), m.end());
+        let touches_marker =
+            (start > 0 && bytes[start - 1] == marker) || bytes.get(end) == Some(&marker);
+        let intraword = marker == b'_'
+            && (content[..start]
+                .chars()
+                .next_back()
+                .is_some_and(|c| c.is_alphanumeric())
+                || content[end..]
+                    .chars()
+                    .next()
+                    .is_some_and(|c| c.is_alphanumeric()));
+        if touches_marker || intraword {
+            pos = start + 1;
+        } else {
+            spans.push((start, end));
+            pos = end;
+        }
+    }
+    spans
+}
 
-        let mut result = content.to_string();
-        for (start, end) in matches.into_iter().rev() {
-            let text = &result[start + 2..end - 2];
-            let replacement = match target_style {
-                StrongStyle::Asterisk => format!("**{}**", text),
-                StrongStyle::Underscore => format!("__{}__", text),
-                StrongStyle::Consistent => unreachable!(),
+/// Whether strong text at `start..end` stays within one block: it cannot run into a code
+/// block or past a blank line
+fn stays_in_block(ctx: &crate::lint_context::LintContext, start: usize, end: usize) -> bool {
+    !ctx.code_blocks
+        .iter()
+        .any(|&(block_start, block_end)| block_start < end && block_end > start)
+        && !ctx.content[start..end]
+            .lines()
+            .skip(1)
+            .any(|line| line.trim().is_empty())
+}
+
+/// The 1-indexed line and character column of byte `offset`
+fn char_position(ctx: &crate::lint_context::LintContext, offset: usize) -> (usize, usize) {
+    let line = ctx
+        .line_offsets
+        .partition_point(|&line_start| line_start <= offset);
+    let line_start = ctx.line_offsets[line - 1];
+    (
+        line,
+        ctx.content[line_start..offset]
+            .chars()
+            .count()
+            + 1,
+    )
+}
+
+impl Rule for MD050StrongStyle {
+    fn name(&self) -> &'static str {
+        "MD050"
+    }
+
+    fn tags(&self) -> &[&'static str] {
+        &["emphasis", "style", "consistency"]
+    }
+
+    fn description(&self) -> &'static str {
+        "Strong emphasis style should be consistent"
+    }
+
+    fn check(&self, ctx: &crate::lint_context::LintContext) -> LintResult {
+        let content = ctx.content;
+        let front_matter_end =
+            FrontMatterUtils::front_matter_byte_end(content, &self.front_matter_delimiters);
+
+        let mut warnings = Vec::new();
+        for violation in self.violations(ctx, front_matter_end) {
+            let (start, end, target_style, kind) = violation;
+            let (message, doc_anchor) = match (kind, target_style) {
+                (MarkerKind::Strong, StrongStyle::Asterisk) => (
+                    "Strong emphasis should use ** instead of __",
+                    "asterisk-style",
+                ),
+                (MarkerKind::Strong, StrongStyle::Underscore) => (
+                    "Strong emphasis should use __ instead of **",
+                    "underscore-style",
+                ),
+                (MarkerKind::Emphasis, StrongStyle::Asterisk) => {
+                    ("Emphasis should use * instead of _", "emphasis")
+                }
+                (MarkerKind::Emphasis, StrongStyle::Underscore) => {
+                    ("Emphasis should use _ instead of *", "emphasis")
+                }
+                (_, StrongStyle::Consistent) => unreachable!(),
             };
-            result.replace_range(start..end, &replacement);
+
+            // Character range of the entire strong emphasis, which may wrap over lines
+            let (start_line, start_col) = char_position(ctx, start);
+            let (end_line, end_col) = char_position(ctx, end);
+
+            warnings.push(
+                LintWarning::new(
+                    self.name(),
+                    start_line,
+                    start_col,
+                    end_line,
+                    end_col,
+                    message.to_string(),
+                )
+                .with_fix(self.build_kind_fix(content, violation))
+                .with_doc_anchor(doc_anchor.to_string()),
+            );
         }
 
+        Ok(warnings)
+    }
+
+    fn check_count(&self, ctx: &crate::lint_context::LintContext) -> Result<usize, LintError> {
+        let front_matter_end =
+            FrontMatterUtils::front_matter_byte_end(ctx.content, &self.front_matter_delimiters);
+        Ok(self
+            .violations(ctx, front_matter_end)
+            .len())
+    }
+
+    fn fix(&self, ctx: &crate::lint_context::LintContext) -> Result<String, LintError> {
+        let (mut result, mut deferred) = self.fix_pass(ctx);
+        // Each pass applies one more level of nested emphasis and strong text
+        let mut passes = 1;
+        while deferred && passes < crate::MAX_FIX_PASSES {
+            let pass_ctx = crate::lint_context::LintContext::new(&result);
+            let (next, next_deferred) = self.fix_pass(&pass_ctx);
+            (result, deferred) = (next, next_deferred);
+            passes += 1;
+        }
         Ok(result)
     }
 
+    fn is_document_wide(&self) -> bool {
+        self.config.style == StrongStyle::Consistent
+    }
+
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
@@ -230,6 +566,518 @@ impl Rule for MD050StrongStyle {
         Self: Sized,
     {
         let rule_config = crate::rule_config_serde::load_rule_config::<MD050Config>(config);
-        Box::new(Self::from_config_struct(rule_config))
+        let mut rule = Self::from_config_struct(rule_config);
+        rule.front_matter_delimiters = config
+            .global
+            .front_matter_delimiters
+            .clone();
+        Box::new(rule)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lint_context::LintContext;
+
+    #[test]
+    fn test_check_count_matches_check() {
+        let fixtures = [
+            "",
+            "# Clean\n\nNo strong text\n",
+            "**a** and __b__ and __c__\n",
+            "---\ntitle: __not bold__\n---\n\n__x__ and `__code__`\n",
+            "__one\nwrapped__ **two**\r\n",
+        ];
+        for style in [
+            StrongStyle::Consistent,
+            StrongStyle::Asterisk,
+            StrongStyle::Underscore,
+        ] {
+            let rule = MD050StrongStyle::new(style);
+            for content in fixtures {
+                let ctx = LintContext::new(content);
+                assert_eq!(
+                    rule.check_count(&ctx).unwrap(),
+                    rule.check(&ctx).unwrap().len(),
+                    "{:?} on {:?}",
+                    style,
+                    content
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_fix_preserves_adjacent_text_byte_exact() {
+        let rule = MD050StrongStyle::new(StrongStyle::Asterisk);
+        let content = "a__b__c and  __d__  e\n";
+        let ctx = LintContext::new(content);
+        let fixed = rule.fix(&ctx).unwrap();
+        assert_eq!(fixed, "a**b**c and  **d**  e\n");
+    }
+
+    #[test]
+    fn test_is_escaped_counts_preceding_backslashes() {
+        let rule = MD050StrongStyle::new(StrongStyle::Underscore);
+        // An escaped backslash leaves the marker unescaped
+        assert!(!rule.is_escaped("\\\\**bold**", 2));
+        assert!(rule.is_escaped("\\**not bold**", 1));
+        assert!(rule.is_escaped("\\\\\\**x**", 3));
+        assert!(!rule.is_escaped("**bold**", 0));
+        // Offsets are bytes, also after multi-byte characters
+        assert!(rule.is_escaped("é\\**x**", 3));
+        assert!(!rule.is_escaped("é**x**", 2));
+
+        let warned = |content: &str| {
+            rule.check(&LintContext::new(content))
+                .unwrap()
+                .len()
+        };
+        assert_eq!(warned("Text \\\\**bold** here\n"), 1);
+        assert_eq!(warned("Text \\**not bold** here\n"), 0);
+    }
+
+    #[test]
+    fn test_warning_fix_replaces_whole_span() {
+        let rule = MD050StrongStyle::new(StrongStyle::Asterisk);
+        let content = "a__b__c";
+        let ctx = LintContext::new(content);
+        let warnings = rule.check(&ctx).unwrap();
+        assert_eq!(warnings.len(), 1);
+        let fix = warnings[0].fix.as_ref().unwrap();
+        assert_eq!(fix.range, 1..6);
+        assert_eq!(fix.replacement, "**b**");
+    }
+
+    #[test]
+    fn test_fix_inside_nested_blockquotes() {
+        let rule = MD050StrongStyle::new(StrongStyle::Asterisk);
+        let content = "> > > Some __bold__ text\n> > lazy __more__\nlazy __last__ line\n\n\
+                       > > >\n> > >     __code__\n";
+        let ctx = LintContext::new(content);
+
+        let warnings = rule.check(&ctx).unwrap();
+        let positions: Vec<_> = warnings
+            .iter()
+            .map(|w| (w.line, w.column, w.end_column))
+            .collect();
+        assert_eq!(positions, vec![(1, 12, 20), (2, 10, 18), (3, 6, 14)]);
+        let spans: Vec<_> = warnings
+            .iter()
+            .map(|w| &content[w.fix.as_ref().unwrap().range.clone()])
+            .collect();
+        assert_eq!(spans, vec!["__bold__", "__more__", "__last__"]);
+
+        let expected = "> > > Some **bold** text\n> > lazy **more**\nlazy **last** line\n\n\
+                        > > >\n> > >     __code__\n";
+        assert_eq!(rule.fix(&ctx).unwrap(), expected);
+        assert_eq!(
+            crate::utils::fix_utils::apply_warning_fixes(content, &warnings).unwrap(),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_normalize_spacing_inserts_spaces_only_when_configured() {
+        let rule = MD050StrongStyle::from_config_struct(MD050Config {
+            style: StrongStyle::Asterisk,
+            normalize_spacing: true,
+            ..Default::default()
+        });
+        let content = "a__b__c, x __y__. __z__";
+        let ctx = LintContext::new(content);
+        let fixed = rule.fix(&ctx).unwrap();
+        // Spaces are only added next to letters/digits; punctuation and existing spaces are kept
+        assert_eq!(fixed, "a **b** c, x **y**. **z**");
+
+        let warnings = rule.check(&ctx).unwrap();
+        let fix = warnings[0].fix.as_ref().unwrap();
+        assert_eq!(fix.replacement, " **b** ");
+    }
+
+    #[test]
+    fn test_warning_doc_anchor_in_lsp_url() {
+        let ctx = LintContext::new("Some __bold__ text\n");
+        let rule = MD050StrongStyle::new(StrongStyle::Asterisk);
+        let warnings = rule.check(&ctx).unwrap();
+        assert_eq!(warnings[0].doc_anchor.as_deref(), Some("asterisk-style"));
+        let diagnostic = crate::lsp::types::warning_to_diagnostic(
+            &warnings[0],
+            ctx.content,
+            Default::default(),
+            None,
+        );
+        let href = diagnostic
+            .code_description
+            .unwrap()
+            .href;
+        assert!(
+            href.as_str()
+                .ends_with("/docs/md050.md#asterisk-style")
+        );
+
+        let ctx = LintContext::new("Some **bold** text\n");
+        let rule = MD050StrongStyle::new(StrongStyle::Underscore);
+        let warnings = rule.check(&ctx).unwrap();
+        let diagnostic = crate::lsp::types::warning_to_diagnostic(
+            &warnings[0],
+            ctx.content,
+            Default::default(),
+            None,
+        );
+        let href = diagnostic
+            .code_description
+            .unwrap()
+            .href;
+        assert!(
+            href.as_str()
+                .ends_with("/docs/md050.md#underscore-style")
+        );
+    }
+
+    #[test]
+    fn test_front_matter_is_not_checked() {
+        let rule = MD050StrongStyle::default();
+        let toml = "+++\ntitle = \"__Intro__\"\n+++\n\n# Intro\n\nSome **bold** and __bold__\n";
+        let ctx = LintContext::new(toml);
+        // The style comes from the body, where ** appears first
+        let warnings = rule.check(&ctx).unwrap();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].line, 7);
+        assert_eq!(
+            rule.fix(&ctx).unwrap(),
+            "+++\ntitle = \"__Intro__\"\n+++\n\n# Intro\n\nSome **bold** and **bold**\n"
+        );
+
+        let json = "{\n  \"title\": \"__Intro__\"\n}\n\nSome **bold**\n";
+        assert!(
+            rule.check(&LintContext::new(json))
+                .unwrap()
+                .is_empty()
+        );
+
+        // Only recognized delimiters open front matter
+        let mut config = crate::config::Config::default();
+        config.global.front_matter_delimiters = vec!["---".to_string()];
+        let yaml_only = MD050StrongStyle::from_config(&config);
+        assert_eq!(yaml_only.check(&ctx).unwrap().len(), 1);
+        assert_eq!(yaml_only.check(&ctx).unwrap()[0].line, 7);
+        let ctx = LintContext::new("+++\ntitle = \"**Intro**\"\n+++\n\nSome __bold__\n");
+        assert_eq!(yaml_only.check(&ctx).unwrap()[0].line, 5);
+        assert!(rule.check(&ctx).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_body_horizontal_rules_are_not_front_matter() {
+        let rule = MD050StrongStyle::new(StrongStyle::Asterisk);
+        let content = "# Title\n\n---\n\nSome __bold__ text\n\n---\n";
+        let warnings = rule
+            .check(&LintContext::new(content))
+            .unwrap();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].line, 5);
+
+        // An unclosed delimiter on the first line is a rule, not front matter
+        let content = "---\n\nSome __bold__ text\n";
+        assert_eq!(
+            rule.check(&LintContext::new(content))
+                .unwrap()
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_style_accepts_markdownlint_spellings() {
+        let cases = [
+            ("asterisk", StrongStyle::Asterisk),
+            ("Asterisk", StrongStyle::Asterisk),
+            ("asterisks", StrongStyle::Asterisk),
+            ("**", StrongStyle::Asterisk),
+            ("underscore", StrongStyle::Underscore),
+            ("UNDERSCORE", StrongStyle::Underscore),
+            ("underscores", StrongStyle::Underscore),
+            ("__", StrongStyle::Underscore),
+            ("consistent", StrongStyle::Consistent),
+            (" Consistent ", StrongStyle::Consistent),
+        ];
+        for (spelling, expected) in cases {
+            let config: MD050Config = toml::from_str(&format!("style = {spelling:?}")).unwrap();
+            assert_eq!(config.style, expected, "style = {spelling:?}");
+        }
+
+        assert!(toml::from_str::<MD050Config>("style = \"bold\"").is_err());
+    }
+
+    #[test]
+    fn test_html_code_elements_are_not_converted() {
+        let rule = MD050StrongStyle::new(StrongStyle::Asterisk);
+        let content = "Call <code>__init__</code> or press <KBD class=\"key\">__x__</KBD>.\n\n\
+                       Output: <samp>__done__</samp>, then __bold__ text.\n";
+        let ctx = LintContext::new(content);
+        let warnings = rule.check(&ctx).unwrap();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!((warnings[0].line, warnings[0].column), (3, 37));
+        assert_eq!(
+            rule.fix(&ctx).unwrap(),
+            "Call <code>__init__</code> or press <KBD class=\"key\">__x__</KBD>.\n\n\
+             Output: <samp>__done__</samp>, then **bold** text.\n"
+        );
+    }
+
+    #[test]
+    fn test_avoid_marker_collisions_swaps_surrounding_emphasis() {
+        // Emphasis wrapping strong text, as rendered
+        fn is_emphasized_strong(content: &str) -> bool {
+            let ctx = LintContext::new(content);
+            let Some(paragraph) = ctx
+                .ast
+                .children()
+                .and_then(|c| c.first())
+            else {
+                return false;
+            };
+            matches!(
+                paragraph.children().map(Vec::as_slice),
+                Some([markdown::mdast::Node::Emphasis(em)])
+                    if matches!(em.children.as_slice(), [markdown::mdast::Node::Strong(_)])
+            )
+        }
+
+        for (style, content, expected) in [
+            (StrongStyle::Asterisk, "*__x__*\n", "_**x**_\n"),
+            (StrongStyle::Underscore, "_**x**_\n", "*__x__*\n"),
+        ] {
+            let rule = MD050StrongStyle::from_config_struct(MD050Config {
+                style,
+                avoid_marker_collisions: true,
+                ..Default::default()
+            });
+            let ctx = LintContext::new(content);
+            let warnings = rule.check(&ctx).unwrap();
+            assert_eq!(warnings.len(), 1);
+            let fixed = rule.fix(&ctx).unwrap();
+            assert_eq!(fixed, expected);
+            assert!(is_emphasized_strong(content));
+            assert!(is_emphasized_strong(&fixed));
+            assert!(
+                rule.check(&LintContext::new(&fixed))
+                    .unwrap()
+                    .is_empty()
+            );
+        }
+
+        // A conversion running into neighbouring markers is reported but not fixed
+        let rule = MD050StrongStyle::from_config_struct(MD050Config {
+            style: StrongStyle::Asterisk,
+            avoid_marker_collisions: true,
+            ..Default::default()
+        });
+        let ctx = LintContext::new("**a**__b__ and __c__\n");
+        let warnings = rule.check(&ctx).unwrap();
+        assert_eq!(warnings.len(), 2);
+        assert!(warnings[0].fix.is_none());
+        assert_eq!(rule.fix(&ctx).unwrap(), "**a**__b__ and **c**\n");
+
+        // Off by default
+        let rule = MD050StrongStyle::new(StrongStyle::Asterisk);
+        assert_eq!(
+            rule.fix(&LintContext::new("*__x__*\n"))
+                .unwrap(),
+            "***x***\n"
+        );
+    }
+
+    #[test]
+    fn test_consistent_scope_section_decides_style_per_section() {
+        let content =
+            "# Guide\n\nSome **bold** text\n\n## Part two\n\nMore __bold__ and __strong__ text\n";
+        let ctx = LintContext::new(content);
+
+        let rule = MD050StrongStyle::new(StrongStyle::Consistent);
+        let warnings = rule.check(&ctx).unwrap();
+        assert_eq!(
+            warnings
+                .iter()
+                .map(|w| (w.line, w.column))
+                .collect::<Vec<_>>(),
+            vec![(7, 6), (7, 19)]
+        );
+
+        let rule = MD050StrongStyle::from_config_struct(MD050Config {
+            consistent_scope: ConsistentScope::Section,
+            ..Default::default()
+        });
+        assert!(rule.check(&ctx).unwrap().is_empty());
+        assert_eq!(rule.fix(&ctx).unwrap(), content);
+
+        // Each section is still held to its own first marker
+        let mixed = content.replace("__strong__", "**strong**");
+        let ctx = LintContext::new(&mixed);
+        let warnings = rule.check(&ctx).unwrap();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(
+            (
+                warnings[0].line,
+                warnings[0].column,
+                warnings[0].message.as_str()
+            ),
+            (7, 19, "Strong emphasis should use __ instead of **")
+        );
+        assert_eq!(rule.fix(&ctx).unwrap(), content);
+    }
+
+    #[test]
+    fn test_strong_text_wrapping_over_lines() {
+        let rule = MD050StrongStyle::new(StrongStyle::Asterisk);
+        let ranges = |content: &str| -> Vec<(usize, usize, usize, usize)> {
+            rule.check(&LintContext::new(content))
+                .unwrap()
+                .iter()
+                .map(|w| (w.line, w.column, w.end_line, w.end_column))
+                .collect()
+        };
+
+        let content = "Some __wrapped\nstrong__ text\n";
+        assert_eq!(ranges(content), vec![(1, 6, 2, 9)]);
+        assert_eq!(
+            rule.fix(&LintContext::new(content))
+                .unwrap(),
+            "Some **wrapped\nstrong** text\n"
+        );
+
+        // A span followed by another on the next line is reported separately
+        let content = "First __one__\n__two__ second\n";
+        assert_eq!(ranges(content), vec![(1, 7, 1, 14), (2, 1, 2, 8)]);
+
+        // Strong text cannot run into a code block or past a blank line
+        let content = "Some __text\n```\ncode__\n```\n\nand __more\n\nafter__\n";
+        assert!(ranges(content).is_empty());
+        assert_eq!(
+            rule.fix(&LintContext::new(content))
+                .unwrap(),
+            content
+        );
+    }
+
+    #[test]
+    fn test_strong_text_holding_single_markers() {
+        let fix = |style: StrongStyle, content: &str| {
+            MD050StrongStyle::new(style)
+                .fix(&LintContext::new(content))
+                .unwrap()
+        };
+        let columns = |style: StrongStyle, content: &str| -> Vec<usize> {
+            MD050StrongStyle::new(style)
+                .check(&LintContext::new(content))
+                .unwrap()
+                .iter()
+                .map(|w| w.column)
+                .collect()
+        };
+
+        // Inner single markers of the same kind
+        let content = "Use __foo_bar_baz__ and __a * b__ or **a * b**\n";
+        assert_eq!(columns(StrongStyle::Asterisk, content), vec![5, 25]);
+        assert_eq!(
+            fix(StrongStyle::Asterisk, content),
+            "Use **foo_bar_baz** and **a * b** or **a * b**\n"
+        );
+        assert_eq!(columns(StrongStyle::Underscore, content), vec![38]);
+
+        // Adjacent spans on one line
+        assert_eq!(
+            fix(StrongStyle::Asterisk, "__one__ __two__,__three__\n"),
+            "**one** **two**,**three**\n"
+        );
+
+        // Nested emphasis in the target marker is swapped to the other one
+        assert_eq!(
+            fix(StrongStyle::Underscore, "An **_italic inside_** word\n"),
+            "An __*italic inside*__ word\n"
+        );
+        assert_eq!(
+            fix(StrongStyle::Asterisk, "An ___both___ word\n"),
+            "An _**both**_ word\n"
+        );
+
+        // Inner text ending with the target marker is reported but not fixed
+        let content = "Keep **snake_** as is\n";
+        assert_eq!(columns(StrongStyle::Underscore, content), vec![6]);
+        assert_eq!(fix(StrongStyle::Underscore, content), content);
+    }
+
+    #[test]
+    fn test_code_spans_are_skipped_with_crlf_line_endings() {
+        // Every line before the last ends with two bytes, so offsets summed as one byte per
+        // line ending would drift off the code spans
+        let content = "# Notes\r\n\r\nSome text\r\nAnother line\r\nUse `__init__` and __bold__ here\r\nMore `a __b__ c` then __real__\r\n";
+        let rule = MD050StrongStyle::new(StrongStyle::Asterisk);
+        let ctx = LintContext::new(content);
+        let warnings = rule.check(&ctx).unwrap();
+        assert_eq!(
+            warnings
+                .iter()
+                .map(|w| (w.line, w.column, w.end_column))
+                .collect::<Vec<_>>(),
+            vec![(5, 20, 28), (6, 23, 31)]
+        );
+        for warning in &warnings {
+            let fix = warning.fix.as_ref().unwrap();
+            assert!(content[fix.range.clone()].starts_with("__"));
+        }
+        assert_eq!(
+            rule.fix(&ctx).unwrap(),
+            "# Notes\r\n\r\nSome text\r\nAnother line\r\nUse `__init__` and **bold** here\r\nMore `a __b__ c` then **real**\r\n"
+        );
+    }
+
+    #[test]
+    fn test_include_emphasis_holds_single_markers_consistent() {
+        let content = "# Notes\n\nSome _a_ and *b* with **bold** and `*code*`\n\nAlso *__x__* and snake_case_name\n";
+        let ctx = LintContext::new(content);
+
+        // Off by default: only strong text is checked
+        let rule = MD050StrongStyle::new(StrongStyle::Consistent);
+        let warnings = rule.check(&ctx).unwrap();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].line, 5);
+        assert_eq!(
+            rule.fix(&ctx).unwrap(),
+            "# Notes\n\nSome _a_ and *b* with **bold** and `*code*`\n\nAlso ***x*** and snake_case_name\n"
+        );
+
+        // On: emphasis follows its first occurrence, `_a_`. The markers of `**bold**`, the
+        // code span and the intraword underscores are not emphasis.
+        let rule = MD050StrongStyle::from_config_struct(MD050Config {
+            include_emphasis: true,
+            ..Default::default()
+        });
+        let warnings = rule.check(&ctx).unwrap();
+        assert_eq!(
+            warnings
+                .iter()
+                .map(|w| (w.line, w.column, w.message.as_str()))
+                .collect::<Vec<_>>(),
+            vec![
+                (3, 14, "Emphasis should use _ instead of *"),
+                (5, 6, "Emphasis should use _ instead of *"),
+                (5, 7, "Strong emphasis should use ** instead of __"),
+            ]
+        );
+        assert_eq!(rule.check_count(&ctx).unwrap(), warnings.len());
+
+        // The nested emphasis and strong fixes overlap, and are applied one after the other
+        let fixed = rule.fix(&ctx).unwrap();
+        assert_eq!(
+            fixed,
+            "# Notes\n\nSome _a_ and _b_ with **bold** and `*code*`\n\nAlso _**x**_ and snake_case_name\n"
+        );
+        assert!(
+            rule.check(&LintContext::new(&fixed))
+                .unwrap()
+                .is_empty()
+        );
     }
 }
@@ -4,6 +4,7 @@ use serde::ser::Serializer;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "kebab-case")]
 pub struct MD050Config {
     #[serde(
         default = "default_style",
@@ -11,12 +12,48 @@ pub struct MD050Config {
         deserialize_with = "deserialize_style"
     )]
     pub style: StrongStyle,
+
+    /// Insert a space between converted strong text and directly adjacent
+    /// letters or digits (default: false, surrounding text is kept byte-exact)
+    #[serde(default)]
+    pub normalize_spacing: bool,
+
+    /// Never produce runs of three or more identical markers: strong text wrapped in
+    /// emphasis that uses the target marker (`*__x__*`) gets the emphasis markers swapped
+    /// (`_**x**_`), and other conversions that would collide are not fixed (default: false)
+    #[serde(default)]
+    pub avoid_marker_collisions: bool,
+
+    /// Where the `consistent` style is decided: once for the whole document, or separately
+    /// for each section between headings (default: document)
+    #[serde(default)]
+    pub consistent_scope: ConsistentScope,
+
+    /// Also hold single-marker emphasis (`*x*`, `_x_`) to one marker, picked by the same
+    /// style and scope as strong text but from the first emphasis found (default: false)
+    #[serde(default)]
+    pub include_emphasis: bool,
+}
+
+/// Extent over which the `consistent` style takes the first marker found
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ConsistentScope {
+    /// One style for the whole document
+    #[default]
+    Document,
+    /// A style for each section, starting at every heading
+    Section,
 }
 
 impl Default for MD050Config {
     fn default() -> Self {
         Self {
             style: default_style(),
+            normalize_spacing: false,
+            avoid_marker_collisions: false,
+            consistent_scope: ConsistentScope::Document,
+            include_emphasis: false,
         }
     }
 }
@@ -37,15 +74,8 @@ where
     D: serde::Deserializer<'de>,
 {
     let s = String::deserialize(deserializer)?;
-    match s.as_str() {
-        "asterisk" => Ok(StrongStyle::Asterisk),
-        "underscore" => Ok(StrongStyle::Underscore),
-        "consistent" => Ok(StrongStyle::Consistent),
-        _ => Err(serde::de::Error::custom(format!(
-            "Invalid strong style: {}",
-            s
-        ))),
-    }
+    s.parse()
+        .map_err(|_| serde::de::Error::custom(format!("Invalid strong style: {}", s)))
 }
 
 impl RuleConfig for MD050Config {
@@ -1,4 +1,4 @@
-use crate::rule::{LintError, LintResult, LintWarning, Rule, Severity};
+use crate::rule::{LintError, LintResult, LintWarning, Rule};
 use crate::utils::document_structure::{DocumentStructure, DocumentStructureExtensions};
 use crate::utils::regex_cache::*;
 use lazy_static::lazy_static;
@@ -303,6 +303,10 @@ impl Rule for MD051LinkFragments {
         "MD051"
     }
 
+    fn tags(&self) -> &[&'static str] {
+        &["links"]
+    }
+
     fn description(&self) -> &'static str {
         "Link anchors (# references) should exist in the current document"
     }
@@ -401,19 +405,17 @@ impl Rule for MD051LinkFragments {
 
                 // Check if the fragment exists in headings
                 if !has_headings || !headings.contains(fragment) {
-                    warnings.push(LintWarning {
-                        rule_name: Some(self.name()),
-                        line: link.line,
-                        column: link.start_col + 1, // Convert to 1-indexed
-                        end_line: link.line,
-                        end_column: link.end_col + 1, // Convert to 1-indexed
-                        message: format!(
+                    warnings.push(LintWarning::new(
+                        self.name(),
+                        link.line,
+                        ctx.char_column(link.line, link.start_col) + 1, // Convert to 1-indexed
+                        link.line,
+                        ctx.char_column(link.line, link.end_col) + 1, // Convert to 1-indexed
+                        format!(
                             "Link anchor '#{}' does not exist in document headings",
                             fragment
                         ),
-                        severity: Severity::Warning,
-                        fix: None,
-                    });
+                    ));
                 }
             }
         }
@@ -425,6 +427,11 @@ impl Rule for MD051LinkFragments {
         Ok(ctx.content.to_owned())
     }
 
+    fn is_document_wide(&self) -> bool {
+        // Fragments refer to headings anywhere in the document
+        true
+    }
+
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
@@ -1,4 +1,4 @@
-use crate::rule::{LintError, LintResult, LintWarning, Rule, Severity};
+use crate::rule::{LintError, LintResult, LintWarning, Rule};
 use crate::utils::range_utils::calculate_match_range;
 use fancy_regex::Regex as FancyRegex;
 use lazy_static::lazy_static;
@@ -257,6 +257,10 @@ impl Rule for MD052ReferenceLinkImages {
         "MD052"
     }
 
+    fn tags(&self) -> &[&'static str] {
+        &["links"]
+    }
+
     fn description(&self) -> &'static str {
         "Reference links and images should use a reference that exists"
     }
@@ -277,16 +281,14 @@ impl Rule for MD052ReferenceLinkImages {
             let (start_line, start_col, end_line, end_col) =
                 calculate_match_range(line_num + 1, line_content, col, match_len);
 
-            warnings.push(LintWarning {
-                rule_name: Some(self.name()),
-                line: start_line,
-                column: start_col,
+            warnings.push(LintWarning::new(
+                self.name(),
+                start_line,
+                start_col,
                 end_line,
-                end_column: end_col,
-                message: format!("Reference '{}' not found", reference),
-                severity: Severity::Warning,
-                fix: None,
-            });
+                end_col,
+                format!("Reference '{}' not found", reference),
+            ));
         }
 
         Ok(warnings)
@@ -298,6 +300,11 @@ impl Rule for MD052ReferenceLinkImages {
         Ok(content.to_string())
     }
 
+    fn is_document_wide(&self) -> bool {
+        // References may be defined anywhere in the document
+        true
+    }
+
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
@@ -1,4 +1,4 @@
-use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, Severity};
+use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule};
 use crate::utils::document_structure::DocumentStructure;
 use crate::utils::range_utils::{LineIndex, calculate_line_range};
 use fancy_regex::Regex as FancyRegex;
@@ -297,6 +297,10 @@ impl Rule for MD053LinkImageReferenceDefinitions {
         "MD053"
     }
 
+    fn tags(&self) -> &[&'static str] {
+        &["links"]
+    }
+
     fn description(&self) -> &'static str {
         "Link and image reference definitions should be needed"
     }
@@ -331,17 +335,18 @@ impl Rule for MD053LinkImageReferenceDefinitions {
             let (start_line, start_col, end_line, end_col) =
                 calculate_line_range(line_num, line_content);
 
-            warnings.push(LintWarning {
-                rule_name: Some(self.name()),
-                line: start_line,
-                column: start_col,
-                end_line,
-                end_column: end_col,
-                message: format!("Unused link/image reference: [{}]", definition),
-                severity: Severity::Warning,
-                fix: Some(Fix {
+            warnings.push(
+                LintWarning::new(
+                    self.name(),
+                    start_line,
+                    start_col,
+                    end_line,
+                    end_col,
+                    format!("Unused link/image reference: [{}]", definition),
+                )
+                .with_fix(Fix::safe(
                     // Remove the entire line including the newline
-                    range: {
+                    {
                         let line_start = line_index
                             .get_line_start_byte(line_num)
                             .unwrap_or(0);
@@ -350,9 +355,9 @@ impl Rule for MD053LinkImageReferenceDefinitions {
                             .unwrap_or(line_start + line_content.len());
                         line_start..line_end
                     },
-                    replacement: String::new(), // Remove the line
-                }),
-            });
+                    String::new(), // Remove the line
+                )),
+            );
         }
 
         Ok(warnings)
@@ -413,6 +418,11 @@ impl Rule for MD053LinkImageReferenceDefinitions {
         Ok(cleaned)
     }
 
+    fn is_document_wide(&self) -> bool {
+        // Definitions may be used anywhere in the document
+        true
+    }
+
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
@@ -3,7 +3,7 @@
 //!
 //! See [docs/md054.md](../../docs/md054.md) for full documentation, configuration, and examples.
 
-use crate::rule::{LintError, LintResult, LintWarning, Rule, Severity};
+use crate::rule::{LintError, LintResult, LintWarning, Rule};
 use crate::utils::document_structure::DocumentStructure;
 use crate::utils::range_utils::calculate_match_range;
 use lazy_static::lazy_static;
@@ -128,6 +128,10 @@ impl Rule for MD054LinkImageStyle {
         "MD054"
     }
 
+    fn tags(&self) -> &[&'static str] {
+        &["links", "style"]
+    }
+
     fn description(&self) -> &'static str {
         "Link and image style should be consistent"
     }
@@ -192,17 +196,14 @@ impl Rule for MD054LinkImageStyle {
                         let (start_line, start_col, end_line, end_col) =
                             calculate_match_range(line_num + 1, line, match_start_char, match_len);
 
-                        warnings.push(LintWarning {
-                            rule_name: Some(self.name()),
-                            line: start_line,
-                            column: start_col,
+                        warnings.push(LintWarning::new(
+                            self.name(),
+                            start_line,
+                            start_col,
                             end_line,
-                            end_column: end_col,
-                            message: "Link/image style 'full' is not consistent with document"
-                                .to_string(),
-                            severity: Severity::Warning,
-                            fix: None,
-                        });
+                            end_col,
+                            "Link/image style 'full' is not consistent with document",
+                        ));
                     }
                     idx = match_end_char;
                     continue;
@@ -223,17 +224,14 @@ impl Rule for MD054LinkImageStyle {
                         let (start_line, start_col, end_line, end_col) =
                             calculate_match_range(line_num + 1, line, match_start_char, match_len);
 
-                        warnings.push(LintWarning {
-                            rule_name: Some(self.name()),
-                            line: start_line,
-                            column: start_col,
+                        warnings.push(LintWarning::new(
+                            self.name(),
+                            start_line,
+                            start_col,
                             end_line,
-                            end_column: end_col,
-                            message: "Link/image style 'collapsed' is not consistent with document"
-                                .to_string(),
-                            severity: Severity::Warning,
-                            fix: None,
-                        });
+                            end_col,
+                            "Link/image style 'collapsed' is not consistent with document",
+                        ));
                     }
                     idx = match_end_char;
                     continue;
@@ -261,19 +259,17 @@ impl Rule for MD054LinkImageStyle {
                                 match_len,
                             );
 
-                            warnings.push(LintWarning {
-                                rule_name: Some(self.name()),
-                                line: start_line,
-                                column: start_col,
+                            warnings.push(LintWarning::new(
+                                self.name(),
+                                start_line,
+                                start_col,
                                 end_line,
-                                end_column: end_col,
-                                message: format!(
+                                end_col,
+                                format!(
                                     "Link/image style '{}' is not consistent with document",
                                     style
                                 ),
-                                severity: Severity::Warning,
-                                fix: None,
-                            });
+                            ));
                         }
                     }
                     idx = match_end_char;
@@ -295,17 +291,14 @@ impl Rule for MD054LinkImageStyle {
                         let (start_line, start_col, end_line, end_col) =
                             calculate_match_range(line_num + 1, line, match_start_char, match_len);
 
-                        warnings.push(LintWarning {
-                            rule_name: Some(self.name()),
-                            line: start_line,
-                            column: start_col,
+                        warnings.push(LintWarning::new(
+                            self.name(),
+                            start_line,
+                            start_col,
                             end_line,
-                            end_column: end_col,
-                            message: "Link/image style 'autolink' is not consistent with document"
-                                .to_string(),
-                            severity: Severity::Warning,
-                            fix: None,
-                        });
+                            end_col,
+                            "Link/image style 'autolink' is not consistent with document",
+                        ));
                     }
                     idx = match_end_char;
                     continue;
@@ -334,17 +327,14 @@ impl Rule for MD054LinkImageStyle {
                         let (start_line, start_col, end_line, end_col) =
                             calculate_match_range(line_num + 1, line, match_start_char, match_len);
 
-                        warnings.push(LintWarning {
-                            rule_name: Some(self.name()),
-                            line: start_line,
-                            column: start_col,
+                        warnings.push(LintWarning::new(
+                            self.name(),
+                            start_line,
+                            start_col,
                             end_line,
-                            end_column: end_col,
-                            message: "Link/image style 'shortcut' is not consistent with document"
-                                .to_string(),
-                            severity: Severity::Warning,
-                            fix: None,
-                        });
+                            end_col,
+                            "Link/image style 'shortcut' is not consistent with document",
+                        ));
                     }
                     idx = match_end_char;
                     continue;
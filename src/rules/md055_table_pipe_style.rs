@@ -1,4 +1,4 @@
-use crate::rule::{LintError, LintResult, LintWarning, Rule, Severity};
+use crate::rule::{LintError, LintResult, LintWarning, Rule};
 use crate::utils::range_utils::{LineIndex, calculate_line_range};
 use crate::utils::table_utils::TableUtils;
 
@@ -177,6 +177,10 @@ impl Rule for MD055TablePipeStyle {
         "MD055"
     }
 
+    fn tags(&self) -> &[&'static str] {
+        &["tables", "style", "consistency"]
+    }
+
     fn description(&self) -> &'static str {
         "Table pipe style should be consistent"
     }
@@ -270,23 +274,24 @@ impl Rule for MD055TablePipeStyle {
                         );
 
                         let fixed_line = self.fix_table_row(line, target_style);
-                        warnings.push(LintWarning {
-                            rule_name: Some(self.name()),
-                            severity: Severity::Warning,
-                            message,
-                            line: start_line,
-                            column: start_col,
-                            end_line,
-                            end_column: end_col,
-                            fix: Some(crate::rule::Fix {
-                                range: line_index.whole_line_range(line_idx + 1),
-                                replacement: if line_idx < lines.len() - 1 {
+                        warnings.push(
+                            LintWarning::new(
+                                self.name(),
+                                start_line,
+                                start_col,
+                                end_line,
+                                end_col,
+                                message,
+                            )
+                            .with_fix(crate::rule::Fix::safe(
+                                line_index.whole_line_range(line_idx + 1),
+                                if line_idx < lines.len() - 1 {
                                     format!("{}\n", fixed_line)
                                 } else {
                                     fixed_line
                                 },
-                            }),
-                        });
+                            )),
+                        );
                     }
                 }
             }
@@ -370,6 +375,10 @@ impl Rule for MD055TablePipeStyle {
         Ok(result_lines.join("\n"))
     }
 
+    fn is_document_wide(&self) -> bool {
+        self.config.style == "consistent"
+    }
+
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
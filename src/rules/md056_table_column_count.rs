@@ -1,4 +1,4 @@
-use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, Severity};
+use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule};
 use crate::utils::range_utils::{LineIndex, calculate_line_range};
 use crate::utils::table_utils::TableUtils;
 
@@ -81,6 +81,10 @@ impl Rule for MD056TableColumnCount {
         "MD056"
     }
 
+    fn tags(&self) -> &[&'static str] {
+        &["tables"]
+    }
+
     fn description(&self) -> &'static str {
         "Table column count should be consistent"
     }
@@ -128,23 +132,26 @@ impl Rule for MD056TableColumnCount {
                     let (start_line, start_col, end_line, end_col) =
                         calculate_line_range(line_idx + 1, line);
 
-                    warnings.push(LintWarning {
-                        rule_name: Some(self.name()),
-                        message: format!(
-                            "Table row has {} cells, but expected {}",
-                            count, expected_count
-                        ),
-                        line: start_line,
-                        column: start_col,
-                        end_line,
-                        end_column: end_col,
-                        severity: Severity::Warning,
-                        fix: fix_result.map(|fixed_row| Fix {
-                            range: LineIndex::new(content.to_string())
-                                .line_col_to_byte_range(line_idx + 1, 1),
-                            replacement: fixed_row,
-                        }),
-                    });
+                    warnings.push(
+                        LintWarning::new(
+                            self.name(),
+                            start_line,
+                            start_col,
+                            end_line,
+                            end_col,
+                            format!(
+                                "Table row has {} cells, but expected {}",
+                                count, expected_count
+                            ),
+                        )
+                        .with_fix(fix_result.map(|fixed_row| {
+                            Fix::safe(
+                                LineIndex::new(content.to_string())
+                                    .line_col_to_byte_range(line_idx + 1, 1),
+                                fixed_row,
+                            )
+                        })),
+                    );
                 }
             }
         }
@@ -3,7 +3,7 @@
 //!
 //! See [docs/md057.md](../../docs/md057.md) for full documentation, configuration, and examples.
 
-use crate::rule::{LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule::{LintError, LintResult, LintWarning, Rule, RuleCategory};
 use crate::utils::document_structure::{DocumentStructure, DocumentStructureExtensions};
 use crate::utils::element_cache::ElementCache;
 use lazy_static::lazy_static;
@@ -191,20 +191,19 @@ impl MD057ExistingRelativeLinks {
         if let Some(resolved_path) = self.resolve_link_path(url) {
             // Check if the file exists (with caching to avoid filesystem calls)
             if !file_exists_with_cache(&resolved_path) {
-                warnings.push(LintWarning {
-                    rule_name: Some(self.name()),
-                    line: line_num,
+                // No automatic fix for missing files
+                warnings.push(LintWarning::new(
+                    self.name(),
+                    line_num,
                     column,
-                    end_line: line_num,
-                    end_column: column + url.len(),
-                    message: format!(
+                    line_num,
+                    column + url.len(),
+                    format!(
                         "Relative link '{
             }' does not exist",
                         url
                     ),
-                    severity: Severity::Warning,
-                    fix: None, // No automatic fix for missing files
-                });
+                ));
             }
         }
     }
@@ -230,6 +229,22 @@ impl Rule for MD057ExistingRelativeLinks {
         "MD057"
     }
 
+    fn tags(&self) -> &[&'static str] {
+        &["links"]
+    }
+
+    fn reads_other_files(&self) -> bool {
+        true
+    }
+
+    fn uncached_copy(&self) -> Option<Box<dyn Rule>> {
+        let base_path = self.base_path.lock().unwrap().clone();
+        Some(Box::new(Self {
+            base_path: Arc::new(Mutex::new(base_path)),
+            config: self.config.clone(),
+        }))
+    }
+
     fn description(&self) -> &'static str {
         "Relative links should point to existing files"
     }
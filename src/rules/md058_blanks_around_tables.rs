@@ -1,4 +1,4 @@
-use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, Severity};
+use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule};
 use crate::utils::range_utils::LineIndex;
 use crate::utils::table_utils::TableUtils;
 
@@ -29,6 +29,10 @@ impl Rule for MD058BlanksAroundTables {
         "MD058"
     }
 
+    fn tags(&self) -> &[&'static str] {
+        &["tables", "whitespace"]
+    }
+
     fn description(&self) -> &'static str {
         "Tables should be surrounded by blank lines"
     }
@@ -52,41 +56,43 @@ impl Rule for MD058BlanksAroundTables {
             // Check for blank line before table
             if table_block.start_line > 0 && !self.is_blank_line(lines[table_block.start_line - 1])
             {
-                warnings.push(LintWarning {
-                    rule_name: Some(self.name()),
-                    message: "Missing blank line before table".to_string(),
-                    line: table_block.start_line + 1,
-                    column: 1,
-                    end_line: table_block.start_line + 1,
-                    end_column: 2,
-                    severity: Severity::Warning,
-                    fix: Some(Fix {
-                        range: _line_index.line_col_to_byte_range(table_block.start_line + 1, 1),
-                        replacement: format!("\n{}", lines[table_block.start_line]),
-                    }),
-                });
+                warnings.push(
+                    LintWarning::new(
+                        self.name(),
+                        table_block.start_line + 1,
+                        1,
+                        table_block.start_line + 1,
+                        2,
+                        "Missing blank line before table",
+                    )
+                    .with_fix(Fix::safe(
+                        _line_index.line_col_to_byte_range(table_block.start_line + 1, 1),
+                        format!("\n{}", lines[table_block.start_line]),
+                    )),
+                );
             }
 
             // Check for blank line after table
             if table_block.end_line < lines.len() - 1
                 && !self.is_blank_line(lines[table_block.end_line + 1])
             {
-                warnings.push(LintWarning {
-                    rule_name: Some(self.name()),
-                    message: "Missing blank line after table".to_string(),
-                    line: table_block.end_line + 1,
-                    column: lines[table_block.end_line].len() + 1,
-                    end_line: table_block.end_line + 1,
-                    end_column: lines[table_block.end_line].len() + 2,
-                    severity: Severity::Warning,
-                    fix: Some(Fix {
-                        range: _line_index.line_col_to_byte_range(
+                warnings.push(
+                    LintWarning::new(
+                        self.name(),
+                        table_block.end_line + 1,
+                        lines[table_block.end_line].len() + 1,
+                        table_block.end_line + 1,
+                        lines[table_block.end_line].len() + 2,
+                        "Missing blank line after table",
+                    )
+                    .with_fix(Fix::safe(
+                        _line_index.line_col_to_byte_range(
                             table_block.end_line + 1,
                             lines[table_block.end_line].len() + 1,
                         ),
-                        replacement: format!("{}\n", lines[table_block.end_line]),
-                    }),
-                });
+                        format!("{}\n", lines[table_block.end_line]),
+                    )),
+                );
             }
         }
 
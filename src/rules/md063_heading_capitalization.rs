@@ -0,0 +1,438 @@
+use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory};
+use crate::rules::md044_proper_names::MD044ProperNames;
+use crate::rules::md044_proper_names::md044_config::MD044Config;
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::ops::Range;
+
+mod md063_config;
+use md063_config::{HeadingCapStyle, MD063Config};
+
+lazy_static! {
+    // Inline constructs whose text is never recased: code spans, links and images
+    // (inline, reference and shortcut forms), autolinks/HTML tags and bare URLs
+    static ref PROTECTED_REGEX: Regex = Regex::new(
+        r"`+[^`]*`+|!?\[[^\]]*\](?:\([^)]*\)|\[[^\]]*\])?|<[^>]+>|https?://\S+"
+    ).unwrap();
+}
+
+/// Words kept lowercase in title case unless they start or end the heading
+const MINOR_WORDS: &[&str] = &[
+    "a", "an", "and", "as", "at", "but", "by", "for", "in", "nor", "of", "on", "or", "so", "the",
+    "to", "up", "via", "vs", "yet",
+];
+
+/// Rule MD063: Heading capitalization
+///
+/// See [docs/md063.md](../../docs/md063.md) for full documentation, configuration, and examples.
+///
+/// Enforces title case or sentence case for heading text. Inline code, links and the
+/// proper names configured for MD044 are never recased; proper names are written in
+/// their canonical MD044 form. Words with capitals after the first letter (acronyms such
+/// as "API", names such as "iPhone") are left as written.
+#[derive(Clone)]
+pub struct MD063HeadingCapitalization {
+    config: MD063Config,
+    proper_names: MD044ProperNames,
+}
+
+impl Default for MD063HeadingCapitalization {
+    fn default() -> Self {
+        Self::from_config_struct(MD063Config::default(), Vec::new())
+    }
+}
+
+impl MD063HeadingCapitalization {
+    pub fn new(style: HeadingCapStyle, exceptions: Vec<String>, proper_names: Vec<String>) -> Self {
+        Self::from_config_struct(MD063Config { style, exceptions }, proper_names)
+    }
+
+    pub fn from_config_struct(config: MD063Config, proper_names: Vec<String>) -> Self {
+        Self {
+            config,
+            proper_names: MD044ProperNames::new(proper_names, true),
+        }
+    }
+
+    /// Rewrite heading text in the given style
+    fn recase_heading(&self, text: &str, style: HeadingCapStyle) -> String {
+        let mut protected: Vec<Range<usize>> = PROTECTED_REGEX
+            .find_iter(text)
+            .map(|m| m.range())
+            .collect();
+        let mut edits: Vec<(Range<usize>, String)> = Vec::new();
+
+        // Proper names are protected too, and always written in their canonical form
+        for (range, proper_name) in self
+            .proper_names
+            .proper_name_matches(text)
+        {
+            if protected
+                .iter()
+                .any(|p| overlaps(p, &range))
+            {
+                continue;
+            }
+            protected.push(range.clone());
+            edits.push((range, proper_name));
+        }
+
+        let tokens = whitespace_tokens(text);
+        let is_protected = |token: &Range<usize>| {
+            protected
+                .iter()
+                .any(|p| overlaps(p, token))
+        };
+        let words: Vec<usize> = tokens
+            .iter()
+            .enumerate()
+            .filter(|(_, token)| {
+                is_protected(token)
+                    || text[(*token).clone()]
+                        .chars()
+                        .any(char::is_alphabetic)
+            })
+            .map(|(i, _)| i)
+            .collect();
+
+        for (i, token) in tokens.iter().enumerate() {
+            if is_protected(token) {
+                continue;
+            }
+            let word = &text[token.clone()];
+            let is_first = words.first() == Some(&i);
+            let is_last = words.last() == Some(&i);
+            let recased = self.recase_word(word, style, is_first, is_last);
+            if recased != word {
+                edits.push((token.clone(), recased));
+            }
+        }
+
+        edits.sort_by_key(|(range, _)| range.start);
+        let mut result = String::with_capacity(text.len());
+        let mut pos = 0;
+        for (range, replacement) in edits {
+            result.push_str(&text[pos..range.start]);
+            result.push_str(&replacement);
+            pos = range.end;
+        }
+        result.push_str(&text[pos..]);
+        result
+    }
+
+    /// Recase a single whitespace-delimited word, keeping surrounding punctuation and markup
+    fn recase_word(
+        &self,
+        word: &str,
+        style: HeadingCapStyle,
+        is_first: bool,
+        is_last: bool,
+    ) -> String {
+        let Some(core_start) = word.find(char::is_alphanumeric) else {
+            return word.to_string();
+        };
+        let core_end = word
+            .char_indices()
+            .rev()
+            .find(|(_, c)| c.is_alphanumeric())
+            .map(|(i, c)| i + c.len_utf8())
+            .unwrap_or(word.len());
+        let (prefix, core, suffix) = (
+            &word[..core_start],
+            &word[core_start..core_end],
+            &word[core_end..],
+        );
+
+        if let Some(exception) = self
+            .config
+            .exceptions
+            .iter()
+            .find(|e| e.to_lowercase() == core.to_lowercase())
+        {
+            return format!("{}{}{}", prefix, exception, suffix);
+        }
+
+        let letters: Vec<char> = core
+            .chars()
+            .filter(|c| c.is_alphabetic())
+            .collect();
+        let Some(first_letter) = letters.first() else {
+            return word.to_string();
+        };
+        let rest_lower = letters[1..]
+            .iter()
+            .all(|c| !c.is_uppercase());
+        let is_lowercase = !first_letter.is_uppercase() && rest_lower;
+        let is_capitalized = first_letter.is_uppercase() && rest_lower;
+
+        // Leave mixed-case words (acronyms, camelCase names) and the pronoun "I" alone
+        if !(is_lowercase || is_capitalized) || core == "I" || core.starts_with("I'") {
+            return word.to_string();
+        }
+
+        let recased = match style {
+            HeadingCapStyle::Sentence if is_first => capitalize(core),
+            HeadingCapStyle::Sentence => core.to_lowercase(),
+            HeadingCapStyle::Title
+                if !is_first && !is_last && MINOR_WORDS.contains(&core.to_lowercase().as_str()) =>
+            {
+                core.to_lowercase()
+            }
+            HeadingCapStyle::Title => capitalize(core),
+            HeadingCapStyle::Off => return word.to_string(),
+        };
+        format!("{}{}{}", prefix, recased, suffix)
+    }
+}
+
+/// Byte ranges of the whitespace-separated tokens in `text`
+fn whitespace_tokens(text: &str) -> Vec<Range<usize>> {
+    let mut tokens = Vec::new();
+    let mut start = None;
+    for (i, c) in text.char_indices() {
+        match (c.is_whitespace(), start) {
+            (true, Some(s)) => {
+                tokens.push(s..i);
+                start = None;
+            }
+            (false, None) => start = Some(i),
+            _ => {}
+        }
+    }
+    if let Some(s) = start {
+        tokens.push(s..text.len());
+    }
+    tokens
+}
+
+fn overlaps(a: &Range<usize>, b: &Range<usize>) -> bool {
+    a.start < b.end && b.start < a.end
+}
+
+/// Uppercase the first letter of a word, leaving the rest as written
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first
+            .to_uppercase()
+            .chain(chars)
+            .collect(),
+        None => String::new(),
+    }
+}
+
+impl Rule for MD063HeadingCapitalization {
+    fn name(&self) -> &'static str {
+        "MD063"
+    }
+
+    fn tags(&self) -> &[&'static str] {
+        &["headings", "content"]
+    }
+
+    fn description(&self) -> &'static str {
+        "Heading capitalization should follow the configured style"
+    }
+
+    fn check(&self, ctx: &crate::lint_context::LintContext) -> LintResult {
+        let style = self.config.style;
+        let style_name = match style {
+            HeadingCapStyle::Off => return Ok(Vec::new()),
+            HeadingCapStyle::Title => "title",
+            HeadingCapStyle::Sentence => "sentence",
+        };
+
+        let mut warnings = Vec::new();
+        for (line_num, line_info) in ctx.lines.iter().enumerate() {
+            let Some(heading) = &line_info.heading else {
+                continue;
+            };
+            if heading.text.is_empty() {
+                continue;
+            }
+            let Some(text_start) = line_info
+                .content
+                .get(heading.content_column..)
+                .and_then(|rest| rest.find(&heading.text))
+                .map(|offset| heading.content_column + offset)
+            else {
+                continue;
+            };
+
+            let expected = self.recase_heading(&heading.text, style);
+            if expected == heading.text {
+                continue;
+            }
+
+            let start = line_info.byte_offset + text_start;
+            let column = ctx.char_column(line_num + 1, text_start) + 1;
+            warnings.push(
+                LintWarning::new(
+                    self.name(),
+                    line_num + 1,
+                    column,
+                    line_num + 1,
+                    column + heading.text.chars().count(),
+                    format!("Heading should use {} case: '{}'", style_name, expected),
+                )
+                .with_fix(Fix::safe(start..start + heading.text.len(), expected)),
+            );
+        }
+
+        Ok(warnings)
+    }
+
+    fn fix(&self, ctx: &crate::lint_context::LintContext) -> Result<String, LintError> {
+        let mut fixed = ctx.content.to_string();
+        let warnings = self.check(ctx)?;
+        for warning in warnings.iter().rev() {
+            if let Some(fix) = &warning.fix {
+                fixed.replace_range(fix.range.clone(), &fix.replacement);
+            }
+        }
+        Ok(fixed)
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Heading
+    }
+
+    fn should_skip(&self, ctx: &crate::lint_context::LintContext) -> bool {
+        self.config.style == HeadingCapStyle::Off
+            || !ctx
+                .lines
+                .iter()
+                .any(|line| line.heading.is_some())
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn default_config_section(&self) -> Option<(String, toml::Value)> {
+        let json_value = serde_json::to_value(&self.config).ok()?;
+        Some((
+            self.name().to_string(),
+            crate::rule_config_serde::json_to_toml_value(&json_value)?,
+        ))
+    }
+
+    fn from_config(config: &crate::config::Config) -> Box<dyn Rule>
+    where
+        Self: Sized,
+    {
+        let rule_config = crate::rule_config_serde::load_rule_config::<MD063Config>(config);
+        // Proper names come from MD044 so both rules agree on their canonical forms
+        let proper_names = crate::rule_config_serde::load_rule_config::<MD044Config>(config).names;
+        Box::new(Self::from_config_struct(rule_config, proper_names))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lint_context::LintContext;
+
+    fn fix(rule: &MD063HeadingCapitalization, content: &str) -> String {
+        rule.fix(&LintContext::new(content))
+            .unwrap()
+    }
+
+    #[test]
+    fn test_sentence_case_keeps_proper_names() {
+        let rule = MD063HeadingCapitalization::new(
+            HeadingCapStyle::Sentence,
+            Vec::new(),
+            vec![
+                "JavaScript".to_string(),
+                "GitHub Actions".to_string(),
+            ],
+        );
+        let content = "# Getting Started With javascript\n\n## Deploying With GitHub Actions\n";
+        let warnings = rule
+            .check(&LintContext::new(content))
+            .unwrap();
+        assert_eq!(warnings.len(), 2);
+        assert_eq!(warnings[0].column, 3);
+        assert_eq!(
+            fix(&rule, content),
+            "# Getting started with JavaScript\n\n## Deploying with GitHub Actions\n"
+        );
+    }
+
+    #[test]
+    fn test_columns_count_chars() {
+        let rule =
+            MD063HeadingCapitalization::new(HeadingCapStyle::Sentence, Vec::new(), Vec::new());
+        let content = "## Café Au Lait\n";
+        let warnings = rule
+            .check(&LintContext::new(content))
+            .unwrap();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!((warnings[0].column, warnings[0].end_column), (4, 16));
+        assert_eq!(fix(&rule, content), "## Café au lait\n");
+    }
+
+    #[test]
+    fn test_title_case_preserves_code_and_links() {
+        let rule = MD063HeadingCapitalization::new(HeadingCapStyle::Title, Vec::new(), Vec::new());
+        let content = "## the guide to `my_func` and [the docs](https://example.com/the-docs)\n";
+        assert_eq!(
+            fix(&rule, content),
+            "## The Guide to `my_func` and [the docs](https://example.com/the-docs)\n"
+        );
+
+        let content = "Working with the **command line**\n===\n";
+        assert_eq!(
+            fix(&rule, content),
+            "Working With the **Command Line**\n===\n"
+        );
+    }
+
+    #[test]
+    fn test_exceptions_and_acronyms() {
+        let rule = MD063HeadingCapitalization::new(
+            HeadingCapStyle::Sentence,
+            vec!["iOS".to_string()],
+            Vec::new(),
+        );
+        let content = "# Using The API On IOS\n";
+        assert_eq!(fix(&rule, content), "# Using the API on iOS\n");
+
+        let content = "# Already fine, I think\n";
+        assert!(
+            rule.check(&LintContext::new(content))
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_from_config_uses_md044_names() {
+        let config: crate::config::Config = toml::from_str(
+            r#"
+[MD044]
+names = ["rumdl"]
+
+[MD063]
+style = "sentence"
+"#,
+        )
+        .unwrap();
+        let rule = MD063HeadingCapitalization::from_config(&config);
+        let content = "# Configuring Rumdl For Teams\n";
+        assert_eq!(
+            rule.fix(&LintContext::new(content))
+                .unwrap(),
+            "# Configuring rumdl for teams\n"
+        );
+
+        // Inactive until a style is configured
+        let rule = MD063HeadingCapitalization::from_config(&crate::config::Config::default());
+        assert!(
+            rule.check(&LintContext::new(content))
+                .unwrap()
+                .is_empty()
+        );
+    }
+}
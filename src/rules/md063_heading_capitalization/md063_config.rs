@@ -0,0 +1,32 @@
+use crate::rule_config_serde::RuleConfig;
+use serde::{Deserialize, Serialize};
+
+/// Capitalization style enforced for heading text
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HeadingCapStyle {
+    /// No style enforced; the rule is inactive (default)
+    #[default]
+    Off,
+    /// Capitalize every word except minor words such as "a", "of" and "the"
+    Title,
+    /// Capitalize only the first word
+    Sentence,
+}
+
+/// Configuration for MD063 (Heading capitalization)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(rename_all = "kebab-case")]
+pub struct MD063Config {
+    /// Capitalization style for headings (default: off, the rule is inactive)
+    #[serde(default)]
+    pub style: HeadingCapStyle,
+
+    /// Words always written exactly as given here (e.g. "iOS", "README")
+    #[serde(default)]
+    pub exceptions: Vec<String>,
+}
+
+impl RuleConfig for MD063Config {
+    const RULE_NAME: &'static str = "MD063";
+}
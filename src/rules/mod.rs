@@ -8,6 +8,9 @@ pub mod strong_style;
 
 pub mod blockquote_utils;
 
+pub mod registry;
+pub use registry::RuleFactory;
+
 mod md001_heading_increment;
 mod md002_first_heading_h1;
 mod md003_heading_style;
@@ -54,6 +57,7 @@ mod md054_link_image_style;
 mod md055_table_pipe_style;
 mod md056_table_column_count;
 mod md058_blanks_around_tables;
+mod md063_heading_capitalization;
 
 pub use md001_heading_increment::MD001HeadingIncrement;
 pub use md002_first_heading_h1::MD002FirstHeadingH1;
@@ -102,6 +106,7 @@ pub use md054_link_image_style::MD054LinkImageStyle;
 pub use md055_table_pipe_style::MD055TablePipeStyle;
 pub use md056_table_column_count::MD056TableColumnCount;
 pub use md058_blanks_around_tables::MD058BlanksAroundTables;
+pub use md063_heading_capitalization::MD063HeadingCapitalization;
 
 mod md012_no_multiple_blanks;
 pub use md012_no_multiple_blanks::MD012NoMultipleBlanks;
@@ -129,71 +134,135 @@ pub use md057_existing_relative_links::MD057ExistingRelativeLinks;
 
 use crate::rule::Rule;
 
+type RuleCtor = fn(&crate::config::Config) -> Box<dyn Rule>;
+
+/// Constructors for every built-in rule, keyed by rule id
+pub(crate) const BUILTIN_RULES: &[(&str, RuleCtor)] = &[
+    ("MD001", MD001HeadingIncrement::from_config),
+    ("MD002", MD002FirstHeadingH1::from_config),
+    ("MD003", MD003HeadingStyle::from_config),
+    ("MD004", MD004UnorderedListStyle::from_config),
+    ("MD005", MD005ListIndent::from_config),
+    ("MD006", MD006StartBullets::from_config),
+    ("MD007", MD007ULIndent::from_config),
+    ("MD009", MD009TrailingSpaces::from_config),
+    ("MD010", MD010NoHardTabs::from_config),
+    ("MD011", MD011NoReversedLinks::from_config),
+    ("MD012", MD012NoMultipleBlanks::from_config),
+    ("MD013", MD013LineLength::from_config),
+    ("MD014", MD014CommandsShowOutput::from_config),
+    ("MD018", MD018NoMissingSpaceAtx::from_config),
+    ("MD019", MD019NoMultipleSpaceAtx::from_config),
+    ("MD020", MD020NoMissingSpaceClosedAtx::from_config),
+    ("MD021", MD021NoMultipleSpaceClosedAtx::from_config),
+    ("MD022", MD022BlanksAroundHeadings::from_config),
+    ("MD023", MD023HeadingStartLeft::from_config),
+    ("MD024", MD024NoDuplicateHeading::from_config),
+    ("MD025", MD025SingleTitle::from_config),
+    ("MD026", MD026NoTrailingPunctuation::from_config),
+    ("MD027", MD027MultipleSpacesBlockquote::from_config),
+    ("MD028", MD028NoBlanksBlockquote::from_config),
+    ("MD029", MD029OrderedListPrefix::from_config),
+    ("MD030", MD030ListMarkerSpace::from_config),
+    ("MD031", MD031BlanksAroundFences::from_config),
+    ("MD032", MD032BlanksAroundLists::from_config),
+    ("MD033", MD033NoInlineHtml::from_config),
+    ("MD034", MD034NoBareUrls::from_config),
+    ("MD035", MD035HRStyle::from_config),
+    ("MD036", MD036NoEmphasisAsHeading::from_config),
+    ("MD037", MD037NoSpaceInEmphasis::from_config),
+    ("MD038", MD038NoSpaceInCode::from_config),
+    ("MD039", MD039NoSpaceInLinks::from_config),
+    ("MD040", MD040FencedCodeLanguage::from_config),
+    ("MD041", MD041FirstLineHeading::from_config),
+    ("MD042", MD042NoEmptyLinks::from_config),
+    ("MD043", MD043RequiredHeadings::from_config),
+    ("MD044", MD044ProperNames::from_config),
+    ("MD045", MD045NoAltText::from_config),
+    ("MD046", MD046CodeBlockStyle::from_config),
+    ("MD047", MD047SingleTrailingNewline::from_config),
+    ("MD048", MD048CodeFenceStyle::from_config),
+    ("MD049", MD049EmphasisStyle::from_config),
+    ("MD050", MD050StrongStyle::from_config),
+    ("MD051", MD051LinkFragments::from_config),
+    ("MD052", MD052ReferenceLinkImages::from_config),
+    ("MD053", MD053LinkImageReferenceDefinitions::from_config),
+    ("MD054", MD054LinkImageStyle::from_config),
+    ("MD055", MD055TablePipeStyle::from_config),
+    ("MD056", MD056TableColumnCount::from_config),
+    ("MD057", MD057ExistingRelativeLinks::from_config),
+    ("MD058", MD058BlanksAroundTables::from_config),
+    ("MD063", MD063HeadingCapitalization::from_config),
+];
+
 /// Returns all rule instances for config validation and CLI
 pub fn all_rules(config: &crate::config::Config) -> Vec<Box<dyn Rule>> {
-    type RuleCtor = fn(&crate::config::Config) -> Box<dyn Rule>;
-    const RULES: &[(&str, RuleCtor)] = &[
-        ("MD001", MD001HeadingIncrement::from_config),
-        ("MD002", MD002FirstHeadingH1::from_config),
-        ("MD003", MD003HeadingStyle::from_config),
-        ("MD004", MD004UnorderedListStyle::from_config),
-        ("MD005", MD005ListIndent::from_config),
-        ("MD006", MD006StartBullets::from_config),
-        ("MD007", MD007ULIndent::from_config),
-        ("MD009", MD009TrailingSpaces::from_config),
-        ("MD010", MD010NoHardTabs::from_config),
-        ("MD011", MD011NoReversedLinks::from_config),
-        ("MD012", MD012NoMultipleBlanks::from_config),
-        ("MD013", MD013LineLength::from_config),
-        ("MD014", MD014CommandsShowOutput::from_config),
-        ("MD018", MD018NoMissingSpaceAtx::from_config),
-        ("MD019", MD019NoMultipleSpaceAtx::from_config),
-        ("MD020", MD020NoMissingSpaceClosedAtx::from_config),
-        ("MD021", MD021NoMultipleSpaceClosedAtx::from_config),
-        ("MD022", MD022BlanksAroundHeadings::from_config),
-        ("MD023", MD023HeadingStartLeft::from_config),
-        ("MD024", MD024NoDuplicateHeading::from_config),
-        ("MD025", MD025SingleTitle::from_config),
-        ("MD026", MD026NoTrailingPunctuation::from_config),
-        ("MD027", MD027MultipleSpacesBlockquote::from_config),
-        ("MD028", MD028NoBlanksBlockquote::from_config),
-        ("MD029", MD029OrderedListPrefix::from_config),
-        ("MD030", MD030ListMarkerSpace::from_config),
-        ("MD031", MD031BlanksAroundFences::from_config),
-        ("MD032", MD032BlanksAroundLists::from_config),
-        ("MD033", MD033NoInlineHtml::from_config),
-        ("MD034", MD034NoBareUrls::from_config),
-        ("MD035", MD035HRStyle::from_config),
-        ("MD036", MD036NoEmphasisAsHeading::from_config),
-        ("MD037", MD037NoSpaceInEmphasis::from_config),
-        ("MD038", MD038NoSpaceInCode::from_config),
-        ("MD039", MD039NoSpaceInLinks::from_config),
-        ("MD040", MD040FencedCodeLanguage::from_config),
-        ("MD041", MD041FirstLineHeading::from_config),
-        ("MD042", MD042NoEmptyLinks::from_config),
-        ("MD043", MD043RequiredHeadings::from_config),
-        ("MD044", MD044ProperNames::from_config),
-        ("MD045", MD045NoAltText::from_config),
-        ("MD046", MD046CodeBlockStyle::from_config),
-        ("MD047", MD047SingleTrailingNewline::from_config),
-        ("MD048", MD048CodeFenceStyle::from_config),
-        ("MD049", MD049EmphasisStyle::from_config),
-        ("MD050", MD050StrongStyle::from_config),
-        ("MD051", MD051LinkFragments::from_config),
-        ("MD052", MD052ReferenceLinkImages::from_config),
-        ("MD053", MD053LinkImageReferenceDefinitions::from_config),
-        ("MD054", MD054LinkImageStyle::from_config),
-        ("MD055", MD055TablePipeStyle::from_config),
-        ("MD056", MD056TableColumnCount::from_config),
-        ("MD057", MD057ExistingRelativeLinks::from_config),
-        ("MD058", MD058BlanksAroundTables::from_config),
-    ];
-    RULES
+    BUILTIN_RULES
         .iter()
         .map(|(_, ctor)| ctor(config))
         .collect()
 }
 
+/// Rules that are active under `config`: every rule for which
+/// [`crate::config::rule_enabled`] holds, configured from `config`.
+pub fn enabled_rules(config: &crate::config::Config) -> Vec<Box<dyn Rule>> {
+    all_rules(config)
+        .into_iter()
+        .filter(|rule| crate::config::rule_enabled(config, rule.name(), rule.default_enabled()))
+        .collect()
+}
+
+// How specifically a list of selectors names a rule
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Selection {
+    // Not named, but active without `--select`
+    Default,
+    Tag,
+    Id,
+}
+
+// How `selectors` name `rule`: by its id, by one of its tags, or not at all
+fn selection(rule: &dyn Rule, selectors: &[String]) -> Option<Selection> {
+    if selectors
+        .iter()
+        .any(|s| s.eq_ignore_ascii_case(rule.name()))
+    {
+        Some(Selection::Id)
+    } else if selectors.iter().any(|s| {
+        rule.tags()
+            .iter()
+            .any(|tag| s.eq_ignore_ascii_case(tag))
+    }) {
+        Some(Selection::Tag)
+    } else {
+        None
+    }
+}
+
+/// Narrow `rules` with the `--select` and `--ignore` selectors, each a rule id or a tag
+/// (see [`Rule::tags`]), ignoring case. With `select`, only the rules it names are kept.
+/// `ignore` drops the rules it names unless `select` names them more specifically: a
+/// selected id wins over an ignored tag, while an ignored id wins over a selected tag. On a
+/// tie, such as a rule selected and ignored by id, `ignore` wins.
+pub fn select_rules(
+    rules: Vec<Box<dyn Rule>>,
+    select: Option<&[String]>,
+    ignore: &[String],
+) -> Vec<Box<dyn Rule>> {
+    rules
+        .into_iter()
+        .filter(|rule| {
+            let selected = match select {
+                Some(select) => selection(rule.as_ref(), select),
+                None => Some(Selection::Default),
+            };
+            selected.is_some_and(|selected| {
+                selection(rule.as_ref(), ignore).is_none_or(|ignored| ignored < selected)
+            })
+        })
+        .collect()
+}
+
 // Filter rules based on config (moved from main.rs)
 // Note: This needs access to GlobalConfig from the config module.
 use crate::config::GlobalConfig;
@@ -263,3 +332,72 @@ pub fn filter_rules(rules: &[Box<dyn Rule>], global_config: &GlobalConfig) -> Ve
 // Add `dyn_clone::clone_trait_object!(Rule);` in src/rule.rs
 
 // TODO: Need to add dyn_clone dependency and update Rule trait definition.
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+
+    fn selected(select: Option<&[&str]>, ignore: &[&str]) -> Vec<&'static str> {
+        let strings = |selectors: &[&str]| -> Vec<String> {
+            selectors
+                .iter()
+                .map(|s| s.to_string())
+                .collect()
+        };
+        let select = select.map(strings);
+        select_rules(
+            all_rules(&Config::default()),
+            select.as_deref(),
+            &strings(ignore),
+        )
+        .iter()
+        .map(|rule| rule.name())
+        .collect()
+    }
+
+    #[test]
+    fn test_select_and_ignore_by_tag_or_id() {
+        // Selecting a tag runs its rules only
+        let style = selected(Some(&["style"]), &[]);
+        assert!(style.contains(&"MD050"));
+        assert!(!style.contains(&"MD044"));
+        let rules: Vec<Box<dyn Rule>> = all_rules(&Config::default())
+            .into_iter()
+            .filter(|rule| style.contains(&rule.name()))
+            .collect();
+        let warnings =
+            crate::lint("# Notes\n\n**a** and __b__ on github\n", &rules, false).unwrap();
+        assert!(
+            warnings
+                .iter()
+                .all(|w| w.rule_name == Some("MD050"))
+        );
+        assert_eq!(warnings.len(), 1);
+
+        // Ids and tags add up, ignoring case
+        assert_eq!(
+            selected(Some(&["Content", "md050"]), &[]),
+            vec![
+                "MD014", "MD024", "MD026", "MD044", "MD050", "MD063"
+            ]
+        );
+
+        // An id wins over a tag, either way round
+        let without_md050 = selected(Some(&["style"]), &["MD050"]);
+        assert!(!without_md050.contains(&"MD050"));
+        assert!(without_md050.contains(&"MD049"));
+        assert_eq!(selected(Some(&["MD044"]), &["content"]), vec!["MD044"]);
+
+        // On a tie, ignore wins
+        assert!(selected(Some(&["MD050"]), &["MD050"]).is_empty());
+        let consistency = selected(Some(&["style"]), &["consistency"]);
+        assert!(!consistency.contains(&"MD050"));
+        assert!(consistency.contains(&"MD029"));
+
+        // Without --select, ignore drops from every rule
+        let all = selected(None, &["whitespace"]);
+        assert!(all.contains(&"MD044"));
+        assert!(!all.contains(&"MD009"));
+    }
+}
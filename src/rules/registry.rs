@@ -0,0 +1,167 @@
+//! Registry of rule constructors for building a custom rule set
+//!
+//! A [`RuleFactory`] starts with every built-in rule registered. Embedders can remove
+//! rules, register their own, and then instantiate the active rules for a [`Config`].
+
+use crate::config::Config;
+use crate::rule::Rule;
+use std::fmt;
+use std::sync::Arc;
+
+/// Constructor that builds a configured rule instance
+pub type RuleConstructor = Arc<dyn Fn(&Config) -> Box<dyn Rule> + Send + Sync>;
+
+/// Ordered collection of rule constructors keyed by rule id
+#[derive(Clone)]
+pub struct RuleFactory {
+    entries: Vec<(String, RuleConstructor)>,
+}
+
+impl RuleFactory {
+    /// Create a factory with all built-in rules registered
+    pub fn new() -> Self {
+        let mut registry = Self::empty();
+        for (id, ctor) in super::BUILTIN_RULES {
+            registry.register(id, *ctor);
+        }
+        registry
+    }
+
+    /// Create a factory with no rules registered
+    pub fn empty() -> Self {
+        Self {
+            entries: Vec::new(),
+        }
+    }
+
+    /// Register a rule constructor under `id`, replacing any existing rule with that id
+    pub fn register<F>(&mut self, id: &str, ctor: F) -> &mut Self
+    where
+        F: Fn(&Config) -> Box<dyn Rule> + Send + Sync + 'static,
+    {
+        let id = id.to_ascii_uppercase();
+        let ctor: RuleConstructor = Arc::new(ctor);
+        match self
+            .entries
+            .iter_mut()
+            .find(|(existing, _)| *existing == id)
+        {
+            Some(entry) => entry.1 = ctor,
+            None => self.entries.push((id, ctor)),
+        }
+        self
+    }
+
+    /// Remove the rule registered under `id`. Returns whether a rule was removed.
+    pub fn remove(&mut self, id: &str) -> bool {
+        let len = self.entries.len();
+        self.entries
+            .retain(|(existing, _)| !existing.eq_ignore_ascii_case(id));
+        self.entries.len() != len
+    }
+
+    /// Whether a rule is registered under `id`
+    pub fn contains(&self, id: &str) -> bool {
+        self.entries
+            .iter()
+            .any(|(existing, _)| existing.eq_ignore_ascii_case(id))
+    }
+
+    /// Registered rule ids, in registration order
+    pub fn ids(&self) -> impl Iterator<Item = &str> {
+        self.entries
+            .iter()
+            .map(|(id, _)| id.as_str())
+    }
+
+    /// Instantiate every registered rule, configured from `config`
+    pub fn all_rules(&self, config: &Config) -> Vec<Box<dyn Rule>> {
+        self.entries
+            .iter()
+            .map(|(_, ctor)| ctor(config))
+            .collect()
+    }
+
+    /// Instantiate the rules that are active under `config`, applying the same
+    /// enablement precedence as [`super::enabled_rules`]
+    pub fn rules(&self, config: &Config) -> Vec<Box<dyn Rule>> {
+        self.all_rules(config)
+            .into_iter()
+            .filter(|rule| crate::config::rule_enabled(config, rule.name(), rule.default_enabled()))
+            .collect()
+    }
+}
+
+impl Default for RuleFactory {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for RuleFactory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(self.ids())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rule::{LintWarning, TestRule};
+
+    fn no_fixme_rule() -> TestRule {
+        TestRule::new("FIXME", |ctx| {
+            Ok(ctx
+                .content
+                .lines()
+                .enumerate()
+                .filter(|(_, line)| line.contains("FIXME"))
+                .map(|(idx, _)| LintWarning::new("FIXME", idx + 1, 1, idx + 1, 1, "Resolve FIXME"))
+                .collect())
+        })
+    }
+
+    #[test]
+    fn test_custom_registry_engine() {
+        let mut registry = RuleFactory::default();
+        assert!(registry.contains("MD050"));
+        assert!(registry.remove("md050"));
+        assert!(!registry.remove("MD050"));
+        registry.register("FIXME", |_| Box::new(no_fixme_rule()));
+        assert_eq!(registry.ids().last(), Some("FIXME"));
+
+        let rules = registry.rules(&Config::default());
+        assert!(
+            rules
+                .iter()
+                .all(|rule| rule.name() != "MD050")
+        );
+
+        let content = "# Notes\n\nSome **strong** and __bold__ text\n\nFIXME: finish this\n";
+        let warnings = crate::lint(content, &rules, false).unwrap();
+        let fired: Vec<_> = warnings
+            .iter()
+            .filter_map(|w| w.rule_name)
+            .collect();
+        assert!(!fired.contains(&"MD050"));
+        assert_eq!(
+            warnings
+                .iter()
+                .filter(|w| w.rule_name == Some("FIXME"))
+                .map(|w| w.line)
+                .collect::<Vec<_>>(),
+            vec![5]
+        );
+
+        // The built-in engine still reports the strong style issue
+        let builtin = RuleFactory::new().rules(&Config::default());
+        let warnings = crate::lint(content, &builtin, false).unwrap();
+        assert!(
+            warnings
+                .iter()
+                .any(|w| w.rule_name == Some("MD050"))
+        );
+    }
+}
@@ -1,6 +1,7 @@
 use fancy_regex::Regex as FancyRegex;
 use lazy_static::lazy_static;
 use std::fmt;
+use std::str::FromStr;
 
 /// The style for strong emphasis (MD050)
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Hash)]
@@ -24,6 +25,19 @@ impl fmt::Display for StrongStyle {
     }
 }
 
+impl FromStr for StrongStyle {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // Case-insensitive, also accepting plurals and the markers themselves
+        match s.trim().to_ascii_lowercase().as_str() {
+            "asterisk" | "asterisks" | "**" => Ok(StrongStyle::Asterisk),
+            "underscore" | "underscores" | "__" => Ok(StrongStyle::Underscore),
+            "consistent" => Ok(StrongStyle::Consistent),
+            _ => Err(()),
+        }
+    }
+}
+
 /// Get regex pattern for finding strong emphasis markers
 pub fn get_strong_pattern() -> &'static FancyRegex {
     lazy_static! {
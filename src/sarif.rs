@@ -0,0 +1,257 @@
+//! Building SARIF 2.1.0 reports, for `check --output sarif`.
+//!
+//! The report has a single run whose driver lists the rules that reported warnings. Each
+//! warning becomes a result located by line and column, counted in Unicode code points as
+//! warnings report them; warnings with a fix carry it as a replacement of the fixed
+//! characters, converted from the byte range of the fix.
+//! Warnings from inline-disabled regions, reported with `--report-disabled`, are marked as
+//! suppressed in source.
+
+use crate::rule::{LintWarning, Rule, Severity};
+use serde_json::{Value, json};
+
+/// The SARIF schema the reports follow
+pub const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+/// The SARIF report for `results`, the content of each file and the warnings found in it,
+/// where `rules` provides the description of every rule that may have reported them
+pub fn sarif_report(results: &[(&str, &str, &[LintWarning])], rules: &[&dyn Rule]) -> Value {
+    // The fired rules, in order of their first warning
+    let mut fired: Vec<&str> = Vec::new();
+    for (_, _, warnings) in results {
+        for name in warnings
+            .iter()
+            .filter_map(|w| w.rule_name)
+        {
+            if !fired.contains(&name) {
+                fired.push(name);
+            }
+        }
+    }
+    let driver_rules: Vec<Value> = fired
+        .iter()
+        .map(|name| {
+            let description = rules
+                .iter()
+                .find(|rule| rule.name() == *name)
+                .map_or("", |rule| rule.description());
+            json!({
+                "id": name,
+                "name": name,
+                "shortDescription": { "text": description },
+                "helpUri": crate::rule::doc_url(name, None),
+            })
+        })
+        .collect();
+
+    let fired = &fired;
+    let sarif_results: Vec<Value> = results
+        .iter()
+        .flat_map(|(file, content, warnings)| {
+            let uri = artifact_uri(file);
+            warnings.iter().map(move |warning| {
+                sarif_result(
+                    &uri,
+                    content,
+                    warning,
+                    fired
+                        .iter()
+                        .position(|name| Some(*name) == warning.rule_name),
+                )
+            })
+        })
+        .collect();
+
+    json!({
+        "$schema": SARIF_SCHEMA,
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "rumdl",
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "informationUri": "https://github.com/rvben/rumdl",
+                    "rules": driver_rules,
+                },
+            },
+            "columnKind": "unicodeCodePoints",
+            "results": sarif_results,
+        }],
+    })
+}
+
+// The result for one warning in the file at `uri` with `content`, referring to the driver
+// rule at `rule_index`
+fn sarif_result(
+    uri: &str,
+    content: &str,
+    warning: &LintWarning,
+    rule_index: Option<usize>,
+) -> Value {
+    let level = match warning.severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+        Severity::Info => "note",
+    };
+    let location = json!({ "uri": uri });
+    let mut result = json!({
+        "ruleId": warning.rule_name.unwrap_or("rumdl"),
+        "level": level,
+        "message": { "text": warning.message },
+        "locations": [{
+            "physicalLocation": {
+                "artifactLocation": location,
+                "region": {
+                    "startLine": warning.line,
+                    "startColumn": warning.column,
+                    "endLine": warning.end_line,
+                    "endColumn": warning.end_column,
+                },
+            },
+        }],
+    });
+    let obj = result
+        .as_object_mut()
+        .expect("a result is an object");
+    if let Some(index) = rule_index {
+        obj.insert("ruleIndex".to_string(), index.into());
+    }
+    if let Some(id) = &warning.id {
+        obj.insert(
+            "partialFingerprints".to_string(),
+            json!({ "rumdlWarningId": id }),
+        );
+    }
+    if let Some(fix) = &warning.fix
+        && let Some((offset, length)) = char_region(content, fix.range.clone())
+    {
+        obj.insert(
+            "fixes".to_string(),
+            json!([{
+                "description": { "text": warning.message },
+                "artifactChanges": [{
+                    "artifactLocation": location,
+                    "replacements": [{
+                        "deletedRegion": {
+                            "charOffset": offset,
+                            "charLength": length,
+                        },
+                        "insertedContent": { "text": fix.replacement },
+                    }],
+                }],
+            }]),
+        );
+    }
+    if warning.suppressed {
+        obj.insert("suppressions".to_string(), json!([{ "kind": "inSource" }]));
+    }
+    result
+}
+
+// The offset and length in code points of the byte `range` of `content`, as the
+// `unicodeCodePoints` column kind counts them
+fn char_region(content: &str, range: std::ops::Range<usize>) -> Option<(usize, usize)> {
+    let before = content.get(..range.start)?;
+    let deleted = content.get(range)?;
+    Some((before.chars().count(), deleted.chars().count()))
+}
+
+// A file path as a relative URI reference: forward slashes, without a leading `./`
+fn artifact_uri(path: &str) -> String {
+    let path = path.replace('\\', "/");
+    path.strip_prefix("./")
+        .unwrap_or(&path)
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::strong_style::StrongStyle;
+    use crate::rules::{MD044ProperNames, MD050StrongStyle};
+
+    #[test]
+    fn test_sarif_report_structure() {
+        let rules: Vec<Box<dyn Rule>> = vec![
+            Box::new(MD050StrongStyle::new(StrongStyle::Asterisk)),
+            Box::new(MD044ProperNames::new(vec!["GitHub".to_string()], true)),
+        ];
+        let content = "# Notes\n\n__é__ on github\n\n__b__\n";
+        let warnings = crate::lint(content, &rules, false).unwrap();
+        assert_eq!(warnings.len(), 3);
+        let rule_refs: Vec<&dyn Rule> = rules
+            .iter()
+            .map(|rule| rule.as_ref())
+            .collect();
+        let report = sarif_report(&[("./docs/notes.md", content, &warnings)], &rule_refs);
+
+        assert_eq!(report["version"], "2.1.0");
+        assert_eq!(report["$schema"], SARIF_SCHEMA);
+        let runs = report["runs"].as_array().unwrap();
+        assert_eq!(runs.len(), 1);
+        let driver = &runs[0]["tool"]["driver"];
+        assert_eq!(driver["name"], "rumdl");
+        let driver_rules = driver["rules"].as_array().unwrap();
+        assert_eq!(
+            driver_rules
+                .iter()
+                .map(|rule| rule["id"].as_str().unwrap())
+                .collect::<Vec<_>>(),
+            vec!["MD050", "MD044"]
+        );
+        assert_eq!(
+            driver_rules[0]["shortDescription"]["text"],
+            rules[0].description()
+        );
+        assert!(
+            driver_rules[1]["helpUri"]
+                .as_str()
+                .unwrap()
+                .ends_with("/docs/md044.md")
+        );
+
+        let results = runs[0]["results"].as_array().unwrap();
+        assert_eq!(results.len(), 3);
+        for result in results {
+            let rule_id = result["ruleId"].as_str().unwrap();
+            let index = result["ruleIndex"].as_u64().unwrap() as usize;
+            assert_eq!(driver_rules[index]["id"], rule_id);
+            assert_eq!(result["level"], "warning");
+            assert!(result["message"]["text"].is_string());
+            let location = &result["locations"][0]["physicalLocation"];
+            assert_eq!(location["artifactLocation"]["uri"], "docs/notes.md");
+            for key in [
+                "startLine",
+                "startColumn",
+                "endLine",
+                "endColumn",
+            ] {
+                assert!(
+                    location["region"][key]
+                        .as_u64()
+                        .unwrap()
+                        >= 1,
+                    "{}",
+                    key
+                );
+            }
+        }
+
+        let github = results
+            .iter()
+            .find(|r| r["ruleId"] == "MD044")
+            .unwrap();
+        assert_eq!(
+            github["locations"][0]["physicalLocation"]["region"],
+            json!({ "startLine": 3, "startColumn": 10, "endLine": 3, "endColumn": 16 })
+        );
+        let replacement = &github["fixes"][0]["artifactChanges"][0]["replacements"][0];
+        assert_eq!(
+            replacement,
+            &json!({
+                "deletedRegion": { "charOffset": 18, "charLength": 6 },
+                "insertedContent": { "text": "GitHub" },
+            })
+        );
+    }
+}
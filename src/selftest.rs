@@ -0,0 +1,232 @@
+//! Checking the rule documentation against the rules, for `rumdl selftest`.
+//!
+//! Each `docs/{rule}.md` page illustrates its rule with fenced `markdown` examples under
+//! headings marked correct, incorrect or fixed. The rule, configured from the page's first
+//! `yaml` configuration block, must report at least one warning for every incorrect
+//! example and none for the correct and fixed ones.
+
+use crate::config::{Config, RuleConfig, normalize_key};
+use crate::rules::RuleFactory;
+use std::collections::BTreeMap;
+
+/// What a documentation example claims about its rule
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExampleKind {
+    /// Under a "Correct" heading: the rule reports nothing
+    Correct,
+    /// Under an "Incorrect" heading: the rule reports at least one warning
+    Incorrect,
+    /// Under a "Fixed" heading: the rule reports nothing
+    Fixed,
+}
+
+/// A fenced `markdown` example from a rule's documentation page
+#[derive(Debug, Clone, PartialEq)]
+pub struct DocExample {
+    pub kind: ExampleKind,
+    /// 1-indexed line of the opening fence in the page
+    pub line: usize,
+    pub content: String,
+}
+
+/// An example that does not behave as its page claims
+#[derive(Debug, Clone, PartialEq)]
+pub struct SelftestFailure {
+    pub kind: ExampleKind,
+    /// 1-indexed line of the example's opening fence
+    pub line: usize,
+    pub warnings: usize,
+}
+
+// The kind claimed by a heading such as "### ❌ Incorrect (mixed styles)"
+fn heading_kind(heading: &str) -> Option<ExampleKind> {
+    let heading = heading.to_lowercase();
+    if heading.contains("incorrect") {
+        Some(ExampleKind::Incorrect)
+    } else if heading.contains("correct") {
+        Some(ExampleKind::Correct)
+    } else if heading.contains("fixed") {
+        Some(ExampleKind::Fixed)
+    } else {
+        None
+    }
+}
+
+// Fenced blocks of `doc` as (info string, line of the opening fence, content, heading
+// they sit under)
+fn fenced_blocks(doc: &str) -> Vec<(String, usize, String, String)> {
+    let mut blocks = Vec::new();
+    let mut heading = String::new();
+    let mut open: Option<(String, String, usize, String)> = None;
+    for (idx, line) in doc.lines().enumerate() {
+        let trimmed = line.trim_start();
+        if let Some((fence, info, start, content)) = &mut open {
+            if trimmed.starts_with(fence.as_str()) && trimmed.trim_end() == fence.as_str() {
+                blocks.push((
+                    info.clone(),
+                    *start,
+                    std::mem::take(content),
+                    heading.clone(),
+                ));
+                open = None;
+            } else {
+                content.push_str(line);
+                content.push('\n');
+            }
+            continue;
+        }
+        let marker = trimmed
+            .chars()
+            .next()
+            .filter(|c| *c == '`' || *c == '~');
+        if let Some(marker) = marker {
+            let len = trimmed
+                .chars()
+                .take_while(|c| *c == marker)
+                .count();
+            if len >= 3 {
+                let fence = marker.to_string().repeat(len);
+                let info = trimmed[len..].trim().to_lowercase();
+                open = Some((fence, info, idx + 1, String::new()));
+                continue;
+            }
+        }
+        if trimmed.starts_with('#') {
+            heading = trimmed.to_string();
+        }
+    }
+    blocks
+}
+
+/// The `markdown` examples of a rule's documentation page, in page order
+pub fn extract_examples(doc: &str) -> Vec<DocExample> {
+    fenced_blocks(doc)
+        .into_iter()
+        .filter(|(info, ..)| info == "markdown" || info == "md")
+        .filter_map(|(_, line, content, heading)| {
+            Some(DocExample {
+                kind: heading_kind(&heading)?,
+                line,
+                content,
+            })
+        })
+        .collect()
+}
+
+/// The options a page's first `yaml` block sets for `rule_name`, keys normalized as in
+/// configuration files. Options naming other files are left out, as examples cannot rely
+/// on them existing.
+pub fn doc_config(doc: &str, rule_name: &str) -> BTreeMap<String, toml::Value> {
+    let Some((_, _, yaml, _)) = fenced_blocks(doc)
+        .into_iter()
+        .find(|(info, ..)| info == "yaml" || info == "yml")
+    else {
+        return BTreeMap::new();
+    };
+    let Ok(serde_yaml::Value::Mapping(root)) = serde_yaml::from_str(&yaml) else {
+        return BTreeMap::new();
+    };
+    let Some(serde_yaml::Value::Mapping(options)) = root.get(rule_name) else {
+        return BTreeMap::new();
+    };
+    options
+        .iter()
+        .filter_map(|(key, value)| {
+            let key = normalize_key(key.as_str()?);
+            if crate::config::FILE_REFERENCE_OPTIONS.contains(&(rule_name, key.as_str())) {
+                return None;
+            }
+            let value = toml::Value::try_from(value).ok()?;
+            Some((key, value))
+        })
+        .collect()
+}
+
+/// Check the examples of `doc`, the documentation page of `rule_name`, against the rule
+/// built by `registry` with the page's configuration. Returns the examples that do not
+/// behave as claimed.
+pub fn check_rule_doc(rule_name: &str, doc: &str, registry: &RuleFactory) -> Vec<SelftestFailure> {
+    let mut config = Config::default();
+    config.rules.insert(
+        rule_name.to_string(),
+        RuleConfig {
+            values: doc_config(doc, rule_name),
+        },
+    );
+    let Some(rule) = registry
+        .all_rules(&config)
+        .into_iter()
+        .find(|rule| rule.name() == rule_name)
+    else {
+        return Vec::new();
+    };
+    let rules = [rule];
+
+    extract_examples(doc)
+        .into_iter()
+        .filter_map(|example| {
+            let warnings =
+                crate::lint(&example.content, &rules, false).map_or(0, |warnings| warnings.len());
+            let passed = match example.kind {
+                ExampleKind::Incorrect => warnings > 0,
+                ExampleKind::Correct | ExampleKind::Fixed => warnings == 0,
+            };
+            (!passed).then_some(SelftestFailure {
+                kind: example.kind,
+                line: example.line,
+                warnings,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DOC: &str = "# MD050 - Keep bold text consistent\n\n## Examples\n\n### ✅ Correct\n\n```markdown\nSome **bold** and **more** text.\n```\n\n### ❌ Incorrect\n\n```markdown\nSome **bold** and __more__ text.\n```\n\n### 🔧 Fixed\n\n```markdown\nSome **bold** and **more** text.\n```\n\n## Configuration\n\n```yaml\nMD050:\n  style: \"consistent\"\n```\n";
+
+    #[test]
+    fn test_selftest_passes_correct_doc_and_fails_wrong_one() {
+        let registry = RuleFactory::new();
+        let examples = extract_examples(DOC);
+        assert_eq!(
+            examples
+                .iter()
+                .map(|e| (e.kind, e.line))
+                .collect::<Vec<_>>(),
+            vec![
+                (ExampleKind::Correct, 7),
+                (ExampleKind::Incorrect, 13),
+                (ExampleKind::Fixed, 19),
+            ]
+        );
+        assert!(check_rule_doc("MD050", DOC, &registry).is_empty());
+
+        // A "fixed" example that still mixes styles drifted from the rule
+        let wrong = DOC.replacen(
+            "### 🔧 Fixed\n\n```markdown\nSome **bold** and **more**",
+            "### 🔧 Fixed\n\n```markdown\nSome **bold** and __more__",
+            1,
+        );
+        assert_eq!(
+            check_rule_doc("MD050", &wrong, &registry),
+            vec![SelftestFailure {
+                kind: ExampleKind::Fixed,
+                line: 19,
+                warnings: 1,
+            }]
+        );
+
+        // The page's configuration applies: with underscores required the correct
+        // example is reported as well
+        let underscore = DOC.replace("style: \"consistent\"", "style: \"underscore\"");
+        assert_eq!(
+            check_rule_doc("MD050", &underscore, &registry)
+                .iter()
+                .map(|f| f.kind)
+                .collect::<Vec<_>>(),
+            vec![ExampleKind::Correct, ExampleKind::Fixed]
+        );
+    }
+}
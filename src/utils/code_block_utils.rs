@@ -1,6 +1,7 @@
 //!
 //! Utility functions for detecting and handling code blocks and code spans in Markdown for rumdl.
 
+use crate::rules::blockquote_utils::BlockquoteUtils;
 use lazy_static::lazy_static;
 use regex::Regex;
 
@@ -28,11 +29,17 @@ impl CodeBlockUtils {
             pos += line.len() + 1; // +1 for newline
         }
 
+        // Line content inside any (nested) blockquote markers, so blocks in quotes are found too
+        let unquoted: Vec<&str> = lines
+            .iter()
+            .map(|line| BlockquoteUtils::strip_markers(line))
+            .collect();
+
         // Find fenced code blocks
         for (i, line) in lines.iter().enumerate() {
             let line_start = line_positions[i];
 
-            if CODE_BLOCK_PATTERN.is_match(line.trim()) {
+            if CODE_BLOCK_PATTERN.is_match(unquoted[i].trim()) {
                 if !in_code_block {
                     code_block_start = line_start;
                     in_code_block = true;
@@ -55,7 +62,7 @@ impl CodeBlockUtils {
         let mut in_indented_block = false;
         let mut indented_block_start = 0;
 
-        for (line_idx, line) in lines.iter().enumerate() {
+        for (line_idx, line) in unquoted.iter().enumerate() {
             let line_start = if line_idx < line_positions.len() {
                 line_positions[line_idx]
             } else {
@@ -80,7 +87,7 @@ impl CodeBlockUtils {
                         .map_or(false, |c| c == '.' || c == ')');
 
             // Check if previous line was blank
-            let prev_blank = line_idx > 0 && lines[line_idx - 1].trim().is_empty();
+            let prev_blank = line_idx > 0 && unquoted[line_idx - 1].trim().is_empty();
 
             if is_indented && !line.trim().is_empty() && !is_list_item {
                 if !in_indented_block {
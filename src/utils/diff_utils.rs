@@ -0,0 +1,589 @@
+//! Line-based diff utilities for showing and exporting fixes
+//!
+//! Produces unified diffs and `git apply`-compatible patches from original and fixed content,
+//! and focused previews of a single fix.
+
+use crate::rule::Fix;
+
+/// A single step of a line diff
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DiffOp {
+    /// Line present in both (old index, new index)
+    Equal(usize, usize),
+    /// Line only in the old content
+    Delete(usize),
+    /// Line only in the new content
+    Insert(usize),
+}
+
+/// Changed lines beyond which [`diff_lines`] replaces them all at once instead of diffing them
+const MAX_DIFF_LINES: usize = 4000;
+
+/// Compute a shortest edit script between two line slices. The common prefix and suffix
+/// are matched first; the lines between them are diffed with the `similar` crate's
+/// linear-space Myers implementation, or, past [`MAX_DIFF_LINES`], all deleted and
+/// inserted again.
+fn diff_lines(a: &[&str], b: &[&str]) -> Vec<DiffOp> {
+    let prefix = a
+        .iter()
+        .zip(b)
+        .take_while(|(x, y)| x == y)
+        .count();
+    let suffix = a[prefix..]
+        .iter()
+        .rev()
+        .zip(b[prefix..].iter().rev())
+        .take_while(|(x, y)| x == y)
+        .count();
+    let (a_end, b_end) = (a.len() - suffix, b.len() - suffix);
+
+    let mut ops: Vec<DiffOp> = (0..prefix)
+        .map(|i| DiffOp::Equal(i, i))
+        .collect();
+    if (a_end - prefix) + (b_end - prefix) <= MAX_DIFF_LINES {
+        for op in similar::capture_diff_slices(
+            similar::Algorithm::Myers,
+            &a[prefix..a_end],
+            &b[prefix..b_end],
+        ) {
+            let (tag, old, new) = op.as_tag_tuple();
+            let (old, new) = (
+                old.start + prefix..old.end + prefix,
+                new.start + prefix..new.end + prefix,
+            );
+            match tag {
+                similar::DiffTag::Equal => ops.extend(
+                    old.zip(new)
+                        .map(|(x, y)| DiffOp::Equal(x, y)),
+                ),
+                _ => {
+                    ops.extend(old.map(DiffOp::Delete));
+                    ops.extend(new.map(DiffOp::Insert));
+                }
+            }
+        }
+    } else {
+        ops.extend((prefix..a_end).map(DiffOp::Delete));
+        ops.extend((prefix..b_end).map(DiffOp::Insert));
+    }
+    ops.extend((0..suffix).map(|i| DiffOp::Equal(a_end + i, b_end + i)));
+    ops
+}
+
+/// The fixes turning `old` into `new`, one per run of changed lines, in document order.
+/// Each fix replaces whole lines of `old`, or inserts lines between them with an empty
+/// range; no fix touches an unchanged line. Returns no fixes when both contents are
+/// identical.
+pub fn line_fixes(old: &str, new: &str) -> Vec<Fix> {
+    let a: Vec<&str> = old.split_inclusive('\n').collect();
+    let b: Vec<&str> = new.split_inclusive('\n').collect();
+    let ops = diff_lines(&a, &b);
+
+    let mut fixes = Vec::new();
+    let mut pos = 0;
+    let mut current: Option<Fix> = None;
+    let empty_at = |pos: usize| Fix::safe(pos..pos, String::new());
+    for op in ops {
+        match op {
+            DiffOp::Equal(x, _) => {
+                fixes.extend(current.take());
+                pos += a[x].len();
+            }
+            DiffOp::Delete(x) => {
+                pos += a[x].len();
+                current
+                    .get_or_insert_with(|| empty_at(pos - a[x].len()))
+                    .range
+                    .end = pos;
+            }
+            DiffOp::Insert(y) => current
+                .get_or_insert_with(|| empty_at(pos))
+                .replacement
+                .push_str(b[y]),
+        }
+    }
+    fixes.extend(current);
+    fixes
+}
+
+/// Append one diff line, marking a missing trailing newline the way `diff` does
+fn push_line(out: &mut String, prefix: char, line: &str) {
+    out.push(prefix);
+    out.push_str(line);
+    if !line.ends_with('\n') {
+        out.push_str("\n\\ No newline at end of file\n");
+    }
+}
+
+/// Format a hunk range (`start,count`), using the preceding line number for empty ranges
+fn hunk_range(start: usize, count: usize) -> String {
+    if count == 0 {
+        format!("{},0", start)
+    } else if count == 1 {
+        format!("{}", start + 1)
+    } else {
+        format!("{},{}", start + 1, count)
+    }
+}
+
+/// Produce the hunks of a unified diff (without file headers).
+/// Returns an empty string when both contents are identical.
+pub fn unified_diff_hunks(old: &str, new: &str, context: usize) -> String {
+    let a: Vec<&str> = old.split_inclusive('\n').collect();
+    let b: Vec<&str> = new.split_inclusive('\n').collect();
+    let ops = diff_lines(&a, &b);
+
+    let mut out = String::new();
+    let mut i = 0;
+    while i < ops.len() {
+        // Find the next change
+        let Some(first_change) = ops[i..]
+            .iter()
+            .position(|op| !matches!(op, DiffOp::Equal(..)))
+            .map(|p| p + i)
+        else {
+            break;
+        };
+
+        // Extend the hunk while changes are separated by at most 2 * context equal lines
+        let start = first_change.saturating_sub(context);
+        let mut end = first_change;
+        let mut j = first_change;
+        while j < ops.len() {
+            if !matches!(ops[j], DiffOp::Equal(..)) {
+                end = j;
+                j += 1;
+                continue;
+            }
+            let run = ops[j..]
+                .iter()
+                .take_while(|op| matches!(op, DiffOp::Equal(..)))
+                .count();
+            if j + run < ops.len() && run <= 2 * context {
+                j += run;
+            } else {
+                break;
+            }
+        }
+        let stop = (end + 1 + context).min(ops.len());
+        let hunk = &ops[start..stop];
+
+        // Line numbers where the hunk begins in each file
+        let (mut old_start, mut new_start) = (0, 0);
+        for op in &ops[..start] {
+            match op {
+                DiffOp::Equal(..) => {
+                    old_start += 1;
+                    new_start += 1;
+                }
+                DiffOp::Delete(_) => old_start += 1,
+                DiffOp::Insert(_) => new_start += 1,
+            }
+        }
+        let old_count = hunk
+            .iter()
+            .filter(|op| !matches!(op, DiffOp::Insert(_)))
+            .count();
+        let new_count = hunk
+            .iter()
+            .filter(|op| !matches!(op, DiffOp::Delete(_)))
+            .count();
+
+        out.push_str(&format!(
+            "@@ -{} +{} @@\n",
+            hunk_range(old_start, old_count),
+            hunk_range(new_start, new_count)
+        ));
+        for op in hunk {
+            match *op {
+                DiffOp::Equal(x, _) => push_line(&mut out, ' ', a[x]),
+                DiffOp::Delete(x) => push_line(&mut out, '-', a[x]),
+                DiffOp::Insert(y) => push_line(&mut out, '+', b[y]),
+            }
+        }
+
+        i = stop;
+    }
+    out
+}
+
+/// Produce a unified diff with `---`/`+++` headers, or an empty string if nothing changed
+pub fn unified_diff(old: &str, new: &str, old_label: &str, new_label: &str) -> String {
+    let hunks = unified_diff_hunks(old, new, 3);
+    if hunks.is_empty() {
+        return hunks;
+    }
+    format!("--- {}\n+++ {}\n{}", old_label, new_label, hunks)
+}
+
+/// Produce a single patch in git format covering several files, suitable for `git apply`.
+///
+/// Each entry is `(path, original content, fixed content)`; unchanged files are skipped.
+/// Paths should be relative to the directory the patch will be applied from.
+pub fn git_patch(files: &[(String, String, String)]) -> String {
+    let mut patch = String::new();
+    for (path, old, new) in files {
+        let hunks = unified_diff_hunks(old, new, 3);
+        if hunks.is_empty() {
+            continue;
+        }
+        let path = path.replace('\\', "/");
+        patch.push_str(&format!(
+            "diff --git a/{0} b/{0}\n--- a/{0}\n+++ b/{0}\n{1}",
+            path, hunks
+        ));
+    }
+    patch
+}
+
+/// Longest line shown in a fix preview, in characters, before it is clipped
+const PREVIEW_WIDTH: usize = 100;
+
+/// Escape control characters (other than tabs) so a preview line prints on one line
+fn escape_preview(text: &str) -> String {
+    text.chars()
+        .map(|c| {
+            if c.is_control() && c != '\t' {
+                c.escape_default().to_string()
+            } else {
+                c.to_string()
+            }
+        })
+        .collect()
+}
+
+/// Clip `line` to [`PREVIEW_WIDTH`] characters, keeping the window around byte offset `focus`
+fn clip_preview(line: &str, focus: usize) -> String {
+    let chars: Vec<char> = line.chars().collect();
+    if chars.len() <= PREVIEW_WIDTH {
+        return escape_preview(line);
+    }
+    let focus = line[..focus.min(line.len())]
+        .chars()
+        .count();
+    let start = focus
+        .saturating_sub(PREVIEW_WIDTH / 2)
+        .min(chars.len() - PREVIEW_WIDTH);
+    let end = start + PREVIEW_WIDTH;
+    let mut clipped = String::new();
+    if start > 0 {
+        clipped.push('…');
+    }
+    clipped.push_str(&escape_preview(
+        &chars[start..end]
+            .iter()
+            .collect::<String>(),
+    ));
+    if end < chars.len() {
+        clipped.push('…');
+    }
+    clipped
+}
+
+/// Preview a single fix: the affected lines before (`-`) and after (`+`) the replacement,
+/// with up to `context` unchanged lines on each side, all numbered by their original line.
+///
+/// Long lines are clipped around the change and control characters are escaped.
+pub fn fix_preview(content: &str, fix: &Fix, context: usize) -> String {
+    let lines: Vec<&str> = content.split_inclusive('\n').collect();
+    if lines.is_empty() {
+        return String::new();
+    }
+    let mut line_starts = Vec::with_capacity(lines.len());
+    let mut pos = 0;
+    for line in &lines {
+        line_starts.push(pos);
+        pos += line.len();
+    }
+    let line_of = |offset: usize| line_starts.partition_point(|&start| start <= offset) - 1;
+
+    let start = fix.range.start.min(content.len());
+    let end = fix
+        .range
+        .end
+        .clamp(start, content.len());
+    let first = line_of(start);
+    // A range ending right after a newline does not touch the next line
+    let last = if end > start && content[..end].ends_with('\n') {
+        line_of(end - 1)
+    } else {
+        line_of(
+            end.min(content.len().saturating_sub(1))
+                .max(start),
+        )
+    };
+    let block_start = line_starts[first];
+    let block_end = line_starts[last] + lines[last].len();
+
+    let old_block = &content[block_start..block_end];
+    let new_block = format!(
+        "{}{}{}",
+        &content[block_start..start],
+        fix.replacement,
+        &content[end..block_end]
+    );
+
+    let context_start = first.saturating_sub(context);
+    let context_end = (last + 1 + context).min(lines.len());
+    let width = context_end.to_string().len();
+    let focus = start - block_start;
+
+    let mut out = String::new();
+    let mut push = |marker: char, number: usize, line: &str, focus: usize| {
+        let line = line.strip_suffix('\n').unwrap_or(line);
+        let line = line.strip_suffix('\r').unwrap_or(line);
+        out.push_str(&format!(
+            "{} {:>width$} | {}\n",
+            marker,
+            number,
+            clip_preview(line, focus),
+            width = width
+        ));
+    };
+    for (idx, line) in lines
+        .iter()
+        .enumerate()
+        .take(first)
+        .skip(context_start)
+    {
+        push(' ', idx + 1, line, 0);
+    }
+    for (idx, line) in old_block
+        .split_inclusive('\n')
+        .enumerate()
+    {
+        push('-', first + idx + 1, line, if idx == 0 { focus } else { 0 });
+    }
+    for (idx, line) in new_block
+        .split_inclusive('\n')
+        .enumerate()
+    {
+        push('+', first + idx + 1, line, if idx == 0 { focus } else { 0 });
+    }
+    for (idx, line) in lines
+        .iter()
+        .enumerate()
+        .take(context_end)
+        .skip(last + 1)
+    {
+        push(' ', idx + 1, line, 0);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_line_fixes_replace_changed_lines() {
+        let old = "# Notes\n\none\ntwo\nthree\n";
+        let new = "# Notes\n\nONE\ntwo\nthree\nfour\n";
+        let fixes = line_fixes(old, new);
+        assert_eq!(
+            fixes
+                .iter()
+                .map(|fix| (fix.range.clone(), fix.replacement.as_str()))
+                .collect::<Vec<_>>(),
+            vec![(9..13, "ONE\n"), (23..23, "four\n")]
+        );
+        let mut fixed = old.to_string();
+        for fix in fixes.iter().rev() {
+            fixed.replace_range(fix.range.clone(), &fix.replacement);
+        }
+        assert_eq!(fixed, new);
+        assert!(line_fixes(old, old).is_empty());
+    }
+
+    #[test]
+    fn test_large_diff_changes_only_the_changed_lines() {
+        // Every other line changes, as tabs fixed throughout a file do
+        let lines = |count: usize| -> String {
+            (0..count)
+                .map(|i| {
+                    if i % 2 == 0 {
+                        format!("\tline {}\n", i)
+                    } else {
+                        format!("line {}\n", i)
+                    }
+                })
+                .collect()
+        };
+        let old = lines(1800);
+        let new = old.replace('\t', "    ");
+        let hunks = unified_diff_hunks(&old, &new, 0);
+        assert_eq!(
+            hunks
+                .lines()
+                .filter(|l| l.starts_with('-'))
+                .count(),
+            900
+        );
+        assert_eq!(line_fixes(&old, &new).len(), 900);
+
+        // Past the size guard, the changed lines are replaced as one block
+        let old = lines(6000);
+        let new = old.replace('\t', "    ");
+        let fixes = line_fixes(&old, &new);
+        assert_eq!(fixes.len(), 1);
+        assert_eq!(fixes[0].range, 0..old.rfind("line 5999").unwrap());
+
+        // A small change in a long file is diffed around the shared prefix and suffix
+        let edited = old.replacen("line 3001\n", "line 3001 edited\n", 1);
+        let fixes = line_fixes(&old, &edited);
+        assert_eq!(fixes.len(), 1);
+        assert_eq!(fixes[0].replacement, "line 3001 edited\n");
+    }
+
+    #[test]
+    fn test_identical_content_has_no_diff() {
+        assert_eq!(unified_diff("a\nb\n", "a\nb\n", "a", "b"), "");
+        assert_eq!(
+            git_patch(&[("x.md".into(), "a\n".into(), "a\n".into())]),
+            ""
+        );
+    }
+
+    #[test]
+    fn test_unified_diff_hunk_with_context() {
+        let old = "1\n2\n3\n4\n5\n6\n7\n8\n9\n";
+        let new = "1\n2\n3\n4\nfive\n6\n7\n8\n9\n";
+        let diff = unified_diff(old, new, "a/x.md", "b/x.md");
+        assert_eq!(
+            diff,
+            "--- a/x.md\n+++ b/x.md\n@@ -2,7 +2,7 @@\n 2\n 3\n 4\n-5\n+five\n 6\n 7\n 8\n"
+        );
+    }
+
+    #[test]
+    fn test_fix_diff_previews_rule_fixes() {
+        use crate::rule::Rule;
+        use crate::rules::MD050StrongStyle;
+        use crate::rules::strong_style::StrongStyle;
+
+        // As `check --fix --diff` does: the fixes are applied to a copy of the file's content
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("notes.md");
+        let original = "# Notes\n\nSome **bold** and __more__ text.\n\n__Last__ line.\n";
+        std::fs::write(&path, original).unwrap();
+
+        let rules: Vec<Box<dyn Rule>> = vec![Box::new(MD050StrongStyle::new(
+            StrongStyle::Consistent,
+        ))];
+        let content = std::fs::read_to_string(&path).unwrap();
+        let warnings = crate::lint(&content, &rules, false).unwrap();
+        let mut fixed = content.clone();
+        crate::apply_rule_fixes(&mut fixed, &rules, &warnings, false, &mut Vec::new());
+        let diff = unified_diff(&content, &fixed, "notes.md (original)", "notes.md (fixed)");
+
+        assert!(diff.starts_with("--- notes.md (original)\n+++ notes.md (fixed)\n"));
+        assert!(
+            diff.contains(
+                "\n-Some **bold** and __more__ text.\n+Some **bold** and **more** text.\n"
+            )
+        );
+        assert!(diff.contains("\n-__Last__ line.\n+**Last** line.\n"));
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), original);
+
+        // Content the rules leave unchanged prints nothing
+        assert_eq!(unified_diff(&fixed, &fixed, "a", "b"), "");
+    }
+
+    #[test]
+    fn test_missing_trailing_newline_is_marked() {
+        let diff = unified_diff_hunks("a\nb", "a\nc", 3);
+        assert_eq!(
+            diff,
+            "@@ -1,2 +1,2 @@\n a\n-b\n\\ No newline at end of file\n+c\n\\ No newline at end of file\n"
+        );
+    }
+
+    #[test]
+    fn test_git_patch_applies_cleanly() {
+        use std::fs;
+        use std::process::Command;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let dir = temp_dir.path();
+        let files = vec![
+            (
+                "README.md".to_string(),
+                "# Title\n\nSome __bold__ text\n".to_string(),
+                "# Title\n\nSome **bold** text\n".to_string(),
+            ),
+            (
+                "docs/guide.md".to_string(),
+                "# Guide\n\n__a__\n\nkeep\nkeep\nkeep\nkeep\nkeep\nkeep\nkeep\n\n__b__".to_string(),
+                "# Guide\n\n**a**\n\nkeep\nkeep\nkeep\nkeep\nkeep\nkeep\nkeep\n\n**b**\n"
+                    .to_string(),
+            ),
+            (
+                "unchanged.md".to_string(),
+                "# Same\n".to_string(),
+                "# Same\n".to_string(),
+            ),
+        ];
+        for (path, old, _) in &files {
+            let full = dir.join(path);
+            fs::create_dir_all(full.parent().unwrap()).unwrap();
+            fs::write(full, old).unwrap();
+        }
+
+        let patch = git_patch(&files);
+        assert_eq!(patch.matches("diff --git ").count(), 2);
+        fs::write(dir.join("fixes.patch"), &patch).unwrap();
+
+        let Ok(output) = Command::new("git")
+            .args(["apply", "--check", "fixes.patch"])
+            .current_dir(dir)
+            .output()
+        else {
+            // git is not available in this environment
+            return;
+        };
+        assert!(
+            output.status.success(),
+            "git apply --check failed: {}\n{}",
+            String::from_utf8_lossy(&output.stderr),
+            patch
+        );
+        let status = Command::new("git")
+            .args(["apply", "fixes.patch"])
+            .current_dir(dir)
+            .status()
+            .unwrap();
+        assert!(status.success());
+        for (path, _, new) in &files {
+            assert_eq!(&fs::read_to_string(dir.join(path)).unwrap(), new);
+        }
+    }
+
+    #[test]
+    fn test_fix_preview_shows_change_with_context() {
+        use crate::lint_context::LintContext;
+        use crate::rule::Rule;
+        use crate::rules::{MD050StrongStyle, strong_style::StrongStyle};
+
+        let content = "# Title\n\nfirst\nSome __x__ text\nlast\n\nfar away\n";
+        let rule = MD050StrongStyle::new(StrongStyle::Asterisk);
+        let warnings = rule
+            .check(&LintContext::new(content))
+            .unwrap();
+        let fix = warnings[0].fix.as_ref().unwrap();
+
+        assert_eq!(
+            fix_preview(content, fix, 2),
+            "  2 | \n  3 | first\n- 4 | Some __x__ text\n+ 4 | Some **x** text\n  5 | last\n  6 | \n"
+        );
+
+        // Long lines are clipped around the change
+        let long = format!("{}__x__{}\n", "a ".repeat(100), "b ".repeat(100));
+        let fix = Fix::safe(200..205, "**x**");
+        let preview = fix_preview(&long, &fix, 0);
+        let removed = preview.lines().next().unwrap();
+        assert!(removed.starts_with("- 1 | …"));
+        assert!(removed.ends_with('…'));
+        assert!(removed.contains("__x__"));
+        assert_eq!(removed.chars().count(), "- 1 | ".len() + PREVIEW_WIDTH + 2);
+    }
+}
@@ -4,6 +4,8 @@
 //! that both CLI batch fixes and LSP individual fixes produce identical results.
 
 use crate::rule::{Fix, LintWarning};
+use std::ops::Range;
+use thiserror::Error;
 
 /// Apply a list of warning fixes to content, simulating how the LSP client would apply them
 /// This is used for testing consistency between CLI and LSP fix methods
@@ -164,27 +166,96 @@ pub fn validate_fix_range(content: &str, fix: &Fix) -> Result<(), String> {
     Ok(())
 }
 
+/// Adjust `fix` so that applying it to `content` does not leave two spaces in a row at either
+/// edge of the replaced range where there was only one before.
+///
+/// A leading or trailing space of the replacement is dropped when it would touch a space
+/// outside the range, and a removal that would join the spaces on both sides also removes
+/// the one after it. Spaces already doubled in `content`, inside or outside the range, are kept.
+pub fn collapse_boundary_spaces(content: &str, fix: &Fix) -> Fix {
+    let Range { start, mut end } = fix.range;
+    let original = &content[start..end];
+    let space_before = content[..start].ends_with(' ');
+    let space_after = content[end..].starts_with(' ');
+
+    let mut replacement = fix.replacement.as_str();
+    if space_before && !original.starts_with(' ') {
+        replacement = replacement
+            .strip_prefix(' ')
+            .unwrap_or(replacement);
+    }
+    if space_after && !original.ends_with(' ') {
+        replacement = replacement
+            .strip_suffix(' ')
+            .unwrap_or(replacement);
+    }
+    if replacement.is_empty()
+        && space_before
+        && space_after
+        && !original.is_empty()
+        && !original.starts_with(' ')
+        && !original.ends_with(' ')
+    {
+        end += 1;
+    }
+
+    Fix::new(start..end, replacement.to_string(), fix.safety)
+}
+
+/// Two fixes whose byte ranges overlap, so they cannot both be applied to the same content
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("Conflicting fixes: byte range {}..{} overlaps {}..{}", first.start, first.end, second.start, second.end)]
+pub struct FixConflict {
+    /// Range of the fix that starts first
+    pub first: Range<usize>,
+    /// Range of the fix that overlaps it
+    pub second: Range<usize>,
+}
+
+/// Check that no two fixes target overlapping byte ranges.
+///
+/// Ranges that merely touch (one ends where the next starts) do not conflict, and neither do
+/// insertions (empty ranges) at the edge of another range. An insertion strictly inside another
+/// range, or two fixes sharing any byte, is reported as the first conflicting pair in document order.
+pub fn validate_fixes(fixes: &[Fix]) -> Result<(), FixConflict> {
+    let mut sorted: Vec<&Range<usize>> = fixes
+        .iter()
+        .map(|fix| &fix.range)
+        .collect();
+    sorted.sort_by_key(|range| (range.start, range.end));
+
+    // The range reaching furthest so far is the only one the next range can overlap first
+    let mut furthest: Option<&Range<usize>> = None;
+    for range in sorted {
+        if let Some(prev) = furthest
+            && range.start < prev.end
+            && prev.start < range.end
+        {
+            return Err(FixConflict {
+                first: prev.clone(),
+                second: range.clone(),
+            });
+        }
+        if furthest.is_none_or(|prev| range.end > prev.end) {
+            furthest = Some(range);
+        }
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::rule::{Fix, LintWarning, Severity};
+    use crate::rule::{Fix, LintWarning};
 
     #[test]
     fn test_apply_single_fix() {
         let content = "1.  Multiple spaces";
-        let warning = LintWarning {
-            message: "Too many spaces".to_string(),
-            line: 1,
-            column: 3,
-            end_line: 1,
-            end_column: 5,
-            severity: Severity::Warning,
-            fix: Some(Fix {
-                range: 2..4,                  // "  " (two spaces)
-                replacement: " ".to_string(), // single space
-            }),
-            rule_name: Some("MD030"),
-        };
+        let warning = LintWarning::new("MD030", 1, 3, 1, 5, "Too many spaces").with_fix(Fix::safe(
+            2..4, // "  " (two spaces)
+            " ",  // single space
+        ));
 
         let result = apply_warning_fixes(content, &[warning]).unwrap();
         assert_eq!(result, "1. Multiple spaces");
@@ -194,32 +265,14 @@ mod tests {
     fn test_apply_multiple_fixes() {
         let content = "1.  First\n*   Second";
         let warnings = vec![
-            LintWarning {
-                message: "Too many spaces".to_string(),
-                line: 1,
-                column: 3,
-                end_line: 1,
-                end_column: 5,
-                severity: Severity::Warning,
-                fix: Some(Fix {
-                    range: 2..4, // First line "  "
-                    replacement: " ".to_string(),
-                }),
-                rule_name: Some("MD030"),
-            },
-            LintWarning {
-                message: "Too many spaces".to_string(),
-                line: 2,
-                column: 2,
-                end_line: 2,
-                end_column: 5,
-                severity: Severity::Warning,
-                fix: Some(Fix {
-                    range: 11..14, // Second line "   " (after newline + "*")
-                    replacement: " ".to_string(),
-                }),
-                rule_name: Some("MD030"),
-            },
+            LintWarning::new("MD030", 1, 3, 1, 5, "Too many spaces").with_fix(Fix::safe(
+                2..4, // First line "  "
+                " ",
+            )),
+            LintWarning::new("MD030", 2, 2, 2, 5, "Too many spaces").with_fix(Fix::safe(
+                11..14, // Second line "   " (after newline + "*")
+                " ",
+            )),
         ];
 
         let result = apply_warning_fixes(content, &warnings).unwrap();
@@ -231,26 +284,76 @@ mod tests {
         let content = "Hello world";
 
         // Valid range
-        let valid_fix = Fix {
-            range: 0..5,
-            replacement: "Hi".to_string(),
-        };
+        let valid_fix = Fix::safe(0..5, "Hi");
         assert!(validate_fix_range(content, &valid_fix).is_ok());
 
         // Invalid range (end > content length)
-        let invalid_fix = Fix {
-            range: 0..20,
-            replacement: "Hi".to_string(),
-        };
+        let invalid_fix = Fix::safe(0..20, "Hi");
         assert!(validate_fix_range(content, &invalid_fix).is_err());
 
         // Invalid range (start > end) - create reversed range
         let start = 5;
         let end = 3;
-        let invalid_fix2 = Fix {
-            range: start..end,
-            replacement: "Hi".to_string(),
-        };
+        let invalid_fix2 = Fix::safe(start..end, "Hi");
         assert!(validate_fix_range(content, &invalid_fix2).is_err());
     }
+
+    fn fix(range: Range<usize>) -> Fix {
+        Fix::safe(range, "x")
+    }
+
+    #[test]
+    fn test_collapse_boundary_spaces() {
+        let fix =
+            |range: Range<usize>, replacement: &str| Fix::safe(range, replacement.to_string());
+        let content = "use vscode today";
+        let collapsed = collapse_boundary_spaces(content, &fix(4..10, " VS Code "));
+        assert_eq!(collapsed, fix(4..10, "VS Code"));
+
+        // Removing a word takes one of the spaces around it along
+        let collapsed = collapse_boundary_spaces(content, &fix(4..10, ""));
+        assert_eq!(collapsed, fix(4..11, ""));
+
+        // Spacing that was already doubled is left alone
+        let content = "use  vscode  today";
+        assert_eq!(
+            collapse_boundary_spaces(content, &fix(5..11, " VS Code ")),
+            fix(5..11, "VS Code")
+        );
+        assert_eq!(
+            collapse_boundary_spaces(content, &fix(4..12, " VS Code ")),
+            fix(4..12, " VS Code ")
+        );
+    }
+
+    #[test]
+    fn test_validate_fixes_non_overlapping() {
+        assert!(validate_fixes(&[]).is_ok());
+        assert!(validate_fixes(&[fix(10..12), fix(0..3), fix(5..8)]).is_ok());
+    }
+
+    #[test]
+    fn test_validate_fixes_touching_ranges() {
+        assert!(validate_fixes(&[fix(0..3), fix(3..6), fix(6..6)]).is_ok());
+        assert!(validate_fixes(&[fix(4..4), fix(4..9)]).is_ok());
+    }
+
+    #[test]
+    fn test_validate_fixes_overlapping() {
+        let conflict = validate_fixes(&[fix(20..25), fix(0..10), fix(8..12)]).unwrap_err();
+        assert_eq!(conflict.first, 0..10);
+        assert_eq!(conflict.second, 8..12);
+        assert_eq!(
+            conflict.to_string(),
+            "Conflicting fixes: byte range 0..10 overlaps 8..12"
+        );
+
+        // A range nested inside an earlier, longer one
+        let conflict = validate_fixes(&[fix(0..10), fix(2..3), fix(12..14)]).unwrap_err();
+        assert_eq!((conflict.first, conflict.second), (0..10, 2..3));
+
+        // Insertion strictly inside another fix
+        let conflict = validate_fixes(&[fix(5..5), fix(3..7)]).unwrap_err();
+        assert_eq!((conflict.first, conflict.second), (3..7, 5..5));
+    }
 }
@@ -4,6 +4,7 @@
 
 pub mod ast_utils;
 pub mod code_block_utils;
+pub mod diff_utils;
 pub mod document_structure;
 pub mod early_returns;
 pub mod element_cache;
@@ -2,6 +2,7 @@
 
 use std::collections::HashSet;
 use std::ops::Range;
+use unicode_segmentation::UnicodeSegmentation;
 
 #[derive(Debug)]
 pub struct LineIndex {
@@ -318,11 +319,30 @@ pub fn calculate_single_line_range(
 
 /// Calculate range for entire line
 pub fn calculate_line_range(line: usize, line_content: &str) -> (usize, usize, usize, usize) {
-    let trimmed_len = line_content.trim_end().len();
+    let trimmed_len = line_content.trim_end().chars().count();
     (line, 1, line, trimmed_len + 1)
 }
 
 /// Calculate range from regex match on a line
+/// Convert a 1-indexed column counted in `char`s, as warnings report them, to one counted in
+/// grapheme clusters, so that a combining sequence such as `e\u{301}` takes one column.
+/// A column inside a cluster maps to that cluster.
+pub fn grapheme_column(line: &str, column: usize) -> usize {
+    let offset = column.saturating_sub(1);
+    let mut chars = 0;
+    let mut clusters = 0;
+    for grapheme in line.graphemes(true) {
+        let len = grapheme.chars().count();
+        if offset < chars + len {
+            return clusters + 1;
+        }
+        chars += len;
+        clusters += 1;
+    }
+    // Past the end of the line, each further column is one more
+    clusters + 1 + (offset - chars)
+}
+
 pub fn calculate_match_range(
     line: usize,
     line_content: &str,
@@ -418,6 +438,27 @@ pub fn calculate_excess_range(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::rule::Rule;
+
+    #[test]
+    fn test_grapheme_column_after_combining_sequence() {
+        // "é" written as e + U+0301 is two chars but one grapheme cluster
+        let line = "Cafe\u{301} for __all__";
+        let rule =
+            crate::rules::MD050StrongStyle::new(crate::rules::strong_style::StrongStyle::Asterisk);
+        let warnings = rule
+            .check(&crate::lint_context::LintContext::new(line))
+            .unwrap();
+        assert_eq!(warnings[0].column, 11);
+        assert_eq!(grapheme_column(line, warnings[0].column), 10);
+
+        // Columns inside a cluster map to it; columns past the end keep counting
+        assert_eq!(grapheme_column(line, 4), 4);
+        assert_eq!(grapheme_column(line, 5), 4);
+        assert_eq!(grapheme_column(line, 6), 5);
+        assert_eq!(grapheme_column("e\u{301}", 4), 3);
+        assert_eq!(grapheme_column("plain", 3), 3);
+    }
 
     #[test]
     fn test_single_line_range() {
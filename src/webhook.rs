@@ -0,0 +1,306 @@
+//! Posting the JSON results of a run to an HTTP endpoint, for `check --report-url`.
+//!
+//! Requests are sent with `ureq`, over TLS (rustls) for `https://` endpoints, directly or
+//! through the proxy named by the standard `https_proxy`, `http_proxy` and `all_proxy`
+//! variables, minus the hosts in `no_proxy`. Failed attempts are retried with a growing
+//! delay, except when the endpoint rejects the request outright.
+
+use std::time::Duration;
+use url::Url;
+
+/// Errors raised while reporting results
+#[derive(Debug, thiserror::Error)]
+pub enum WebhookError {
+    /// The endpoint or proxy URL cannot be used
+    #[error("Invalid report URL {url}: {reason}")]
+    Url { url: String, reason: String },
+
+    /// The connection failed or timed out
+    #[error("Failed to reach the report endpoint: {0}")]
+    Request(#[from] ureq::Error),
+
+    /// The endpoint answered with a status other than 2xx
+    #[error("Report endpoint answered with HTTP status {0}")]
+    Status(u16),
+}
+
+type WebhookResult<T> = Result<T, WebhookError>;
+
+/// Parse an endpoint or proxy URL, which must be `http://` or `https://` with a host
+pub fn parse_url(url: &str) -> WebhookResult<Url> {
+    let invalid = |reason: &str| WebhookError::Url {
+        url: url.to_string(),
+        reason: reason.to_string(),
+    };
+    let parsed = Url::parse(url).map_err(|e| invalid(&e.to_string()))?;
+    if !matches!(parsed.scheme(), "http" | "https") {
+        return Err(invalid("only http:// and https:// endpoints are supported"));
+    }
+    if parsed.host_str().is_none() {
+        return Err(invalid("missing host"));
+    }
+    Ok(parsed)
+}
+
+/// The proxy to send requests for `url` through, given the proxy setting for its scheme
+/// and the value of `no_proxy`. `no_proxy` lists hosts, which also match their subdomains,
+/// or `*` for all.
+pub fn proxy_for(
+    url: &Url,
+    proxy: Option<&str>,
+    no_proxy: Option<&str>,
+) -> WebhookResult<Option<Url>> {
+    let Some(proxy) = proxy.filter(|proxy| !proxy.trim().is_empty()) else {
+        return Ok(None);
+    };
+    // Bracketed IPv6 hosts are compared without their brackets
+    let host = url
+        .host_str()
+        .unwrap_or_default()
+        .trim_start_matches('[')
+        .trim_end_matches(']');
+    let bypassed = no_proxy
+        .unwrap_or_default()
+        .split(',')
+        .map(|entry| {
+            entry
+                .trim()
+                .trim_start_matches('.')
+                .trim_start_matches('[')
+                .trim_end_matches(']')
+        })
+        .filter(|entry| !entry.is_empty())
+        .any(|entry| {
+            entry == "*"
+                || host.eq_ignore_ascii_case(entry)
+                || host
+                    .to_ascii_lowercase()
+                    .ends_with(&format!(".{}", entry.to_ascii_lowercase()))
+        });
+    if bypassed {
+        return Ok(None);
+    }
+    parse_url(proxy.trim()).map(Some)
+}
+
+/// The proxy for `url` from the variables `var` looks up: `https_proxy` for `https://`
+/// endpoints and `http_proxy` for `http://` ones, else `all_proxy`, and `no_proxy`
+pub fn proxy_from_vars(
+    url: &Url,
+    var: impl Fn(&str) -> Option<String>,
+) -> WebhookResult<Option<Url>> {
+    let scheme_var = if url.scheme() == "https" {
+        "https_proxy"
+    } else {
+        "http_proxy"
+    };
+    let proxy = var(scheme_var).or_else(|| var("all_proxy"));
+    proxy_for(url, proxy.as_deref(), var("no_proxy").as_deref())
+}
+
+/// The proxy for `url` from the environment, as [`proxy_from_vars`] picks it, the
+/// variables either lower or upper case
+pub fn proxy_from_env(url: &Url) -> WebhookResult<Option<Url>> {
+    proxy_from_vars(url, |name| {
+        std::env::var(name)
+            .or_else(|_| std::env::var(name.to_ascii_uppercase()))
+            .ok()
+    })
+}
+
+/// POST `body` as JSON to `url`, through `proxy` if given. Each attempt may take up to
+/// `timeout` to connect, and again to send and to read the answer. Connection failures and
+/// 5xx answers are retried up to `retries` times; other non-2xx answers are not.
+pub fn post_json(
+    url: &Url,
+    proxy: Option<&Url>,
+    body: &str,
+    timeout: Duration,
+    retries: u32,
+) -> WebhookResult<()> {
+    let proxy = proxy
+        .map(|proxy| ureq::Proxy::new(proxy.as_str()))
+        .transpose()?;
+    let agent: ureq::Agent = ureq::Agent::config_builder()
+        .http_status_as_error(false)
+        .proxy(proxy)
+        .timeout_connect(Some(timeout))
+        .timeout_send_body(Some(timeout))
+        .timeout_recv_response(Some(timeout))
+        .user_agent(format!("rumdl/{}", env!("CARGO_PKG_VERSION")))
+        .build()
+        .into();
+
+    let mut attempt = 0;
+    loop {
+        let sent = agent
+            .post(url.as_str())
+            .header("Content-Type", "application/json")
+            .send(body)
+            .map(|response| response.status().as_u16());
+        match sent {
+            Ok(status) if (200..300).contains(&status) => return Ok(()),
+            Ok(status) if status < 500 || attempt == retries => {
+                return Err(WebhookError::Status(status));
+            }
+            Err(e) if attempt == retries => return Err(e.into()),
+            Ok(status) => log::warn!("Report endpoint answered {}, retrying", status),
+            Err(e) => log::warn!("Failed to reach the report endpoint: {}, retrying", e),
+        }
+        std::thread::sleep(Duration::from_millis(250 << attempt.min(4)));
+        attempt += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rule::Rule;
+    use crate::rules::MD050StrongStyle;
+    use crate::rules::strong_style::StrongStyle;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::{TcpListener, TcpStream};
+    use std::sync::mpsc;
+
+    // Read one request from `stream`, returning its head and body
+    fn read_request(stream: &mut TcpStream) -> (String, String) {
+        let mut reader = BufReader::new(stream);
+        let mut head = String::new();
+        loop {
+            let mut line = String::new();
+            reader.read_line(&mut line).unwrap();
+            if line == "\r\n" || line.is_empty() {
+                break;
+            }
+            head.push_str(&line);
+        }
+        let length: usize = head
+            .lines()
+            .find_map(|line| {
+                line.to_ascii_lowercase()
+                    .strip_prefix("content-length: ")
+                    .map(str::to_string)
+            })
+            .unwrap()
+            .parse()
+            .unwrap();
+        let mut body = vec![0; length];
+        reader.read_exact(&mut body).unwrap();
+        (head, String::from_utf8(body).unwrap())
+    }
+
+    // Serve `statuses`, one connection each, sending the requests received to the channel
+    fn mock_endpoint(
+        listener: TcpListener,
+        statuses: &'static [&'static str],
+    ) -> mpsc::Receiver<(String, String)> {
+        let (tx, rx) = mpsc::channel();
+        std::thread::spawn(move || {
+            for (status, mut stream) in statuses
+                .iter()
+                .zip(listener.incoming().map(Result::unwrap))
+            {
+                let request = read_request(&mut stream);
+                write!(
+                    stream,
+                    "HTTP/1.1 {}\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+                    status
+                )
+                .unwrap();
+                tx.send(request).unwrap();
+            }
+        });
+        rx
+    }
+
+    #[test]
+    fn test_post_json_retries_until_endpoint_accepts() {
+        // The mock endpoint fails the first request and records the second
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let rx = mock_endpoint(listener, &["503 Service Unavailable", "200 OK"]);
+
+        let rules: Vec<Box<dyn Rule>> = vec![Box::new(MD050StrongStyle::new(
+            StrongStyle::Asterisk,
+        ))];
+        let warnings = crate::lint("# Notes\n\nSome __bold__ text\n", &rules, false).unwrap();
+        let body = serde_json::to_string_pretty(&warnings).unwrap();
+        let url = parse_url(&format!("http://127.0.0.1:{}/rumdl/results?run=1", port)).unwrap();
+        post_json(&url, None, &body, Duration::from_secs(5), 2).unwrap();
+
+        let _failed = rx.recv().unwrap();
+        let (head, received) = rx.recv().unwrap();
+        assert!(head.starts_with("POST /rumdl/results?run=1 HTTP/1.1\r\n"));
+        let head = head.to_ascii_lowercase();
+        assert!(head.contains(&format!("host: 127.0.0.1:{}\r\n", port)));
+        assert!(head.contains("content-type: application/json\r\n"));
+        let json: serde_json::Value = serde_json::from_str(&received).unwrap();
+        assert_eq!(json[0]["rule_name"], "MD050");
+        assert_eq!(json[0]["line"], 3);
+    }
+
+    #[test]
+    fn test_proxy_for_honors_no_proxy() {
+        let url = parse_url("http://dash.example.com/results").unwrap();
+        let proxy = Some("http://proxy.local:3128");
+        assert_eq!(
+            proxy_for(&url, proxy, None)
+                .unwrap()
+                .map(String::from),
+            Some("http://proxy.local:3128/".to_string())
+        );
+        assert!(
+            proxy_for(&url, proxy, Some("localhost, .example.com"))
+                .unwrap()
+                .is_none()
+        );
+        assert!(
+            proxy_for(&url, None, None)
+                .unwrap()
+                .is_none()
+        );
+        assert!(matches!(
+            parse_url("ftp://dash.example.com/results"),
+            Err(WebhookError::Url { .. })
+        ));
+    }
+
+    #[test]
+    fn test_proxy_follows_the_endpoint_scheme() {
+        let vars = |name: &str| match name {
+            "https_proxy" => Some("http://secure.proxy:3128".to_string()),
+            "all_proxy" => Some("http://any.proxy:3128".to_string()),
+            "no_proxy" => Some("::1".to_string()),
+            _ => None,
+        };
+        let proxy = |url: &str| {
+            proxy_from_vars(&parse_url(url).unwrap(), vars)
+                .unwrap()
+                .map(|proxy| proxy.host_str().unwrap().to_string())
+        };
+        assert_eq!(
+            proxy("https://dash.example.com/"),
+            Some("secure.proxy".to_string())
+        );
+        assert_eq!(
+            proxy("http://dash.example.com/"),
+            Some("any.proxy".to_string())
+        );
+        assert_eq!(proxy("https://[::1]:8443/results"), None);
+    }
+
+    #[test]
+    fn test_post_json_to_ipv6_host() {
+        // Skipped where the loopback has no IPv6 address
+        let Ok(listener) = TcpListener::bind("[::1]:0") else {
+            return;
+        };
+        let port = listener.local_addr().unwrap().port();
+        let rx = mock_endpoint(listener, &["204 No Content"]);
+        let url = parse_url(&format!("http://[::1]:{}/results", port)).unwrap();
+        post_json(&url, None, "[]", Duration::from_secs(5), 0).unwrap();
+        let (head, body) = rx.recv().unwrap();
+        assert!(head.starts_with("POST /results HTTP/1.1\r\n"));
+        assert_eq!(body, "[]");
+    }
+}
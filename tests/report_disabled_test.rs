@@ -0,0 +1,39 @@
+use assert_cmd::Command;
+use std::fs;
+
+#[test]
+fn test_report_disabled_uses_the_nested_config_of_each_file() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::create_dir(dir.path().join("sub")).unwrap();
+    fs::write(dir.path().join(".rumdl.toml"), "").unwrap();
+    // The nested config disables MD050 and sets a style the document does not use
+    fs::write(
+        dir.path().join("sub/.rumdl.toml"),
+        "[global]\ndisable = [\"MD050\"]\n\n[MD050]\nstyle = \"underscore\"\n",
+    )
+    .unwrap();
+    fs::write(
+        dir.path().join("sub/doc.md"),
+        "# Title\n\nSome **bold** text\n",
+    )
+    .unwrap();
+
+    let output = Command::cargo_bin("rumdl")
+        .unwrap()
+        .current_dir(dir.path())
+        .args([
+            "check",
+            "sub/doc.md",
+            "--report-disabled",
+            "--output",
+            "json",
+        ])
+        .assert()
+        .success();
+    let json: serde_json::Value = serde_json::from_slice(&output.get_output().stdout).unwrap();
+    let warnings = json.as_array().unwrap();
+    assert_eq!(warnings.len(), 1, "unexpected warnings: {:?}", warnings);
+    assert_eq!(warnings[0]["rule_name"], "MD050");
+    assert_eq!(warnings[0]["doc_anchor"], "underscore-style");
+    assert_eq!(warnings[0]["suppressed"], true);
+}
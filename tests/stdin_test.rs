@@ -0,0 +1,19 @@
+use assert_cmd::Command;
+use predicates::prelude::*;
+
+const DISABLED_REGION: &str = "# Title\n\nSome **strong** text\n\n<!-- rumdl-disable MD050 -->\nSome text __bold__ here\n<!-- rumdl-enable MD050 -->\n";
+
+#[test]
+fn test_stdin_flag_respects_inline_disable_regions() {
+    let dir = tempfile::tempdir().unwrap();
+    for stdin_arg in ["--stdin", "-"] {
+        Command::cargo_bin("rumdl")
+            .unwrap()
+            .current_dir(dir.path())
+            .args(["check", stdin_arg])
+            .write_stdin(DISABLED_REGION)
+            .assert()
+            .success()
+            .stdout(predicates::str::contains("[MD050]").not());
+    }
+}
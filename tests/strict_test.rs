@@ -0,0 +1,47 @@
+use assert_cmd::Command;
+use std::fs;
+
+fn check(dir: &std::path::Path, args: &[&str]) -> assert_cmd::assert::Assert {
+    Command::cargo_bin("rumdl")
+        .unwrap()
+        .current_dir(dir)
+        .arg("check")
+        .arg("doc.md")
+        .args(args)
+        .assert()
+}
+
+#[test]
+fn test_strict_turns_md050_warning_into_failing_error() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(dir.path().join("doc.md"), "# Title\n\n**a** and __b__\n").unwrap();
+
+    // The MD050 warning is within the budget, so the run passes
+    check(dir.path(), &["--max-warnings", "5"])
+        .success()
+        .stdout(predicates::str::contains("[MD050]"));
+    let output = check(dir.path(), &["--output", "json", "--max-warnings", "5"]).success();
+    let json: serde_json::Value = serde_json::from_slice(&output.get_output().stdout).unwrap();
+    assert_eq!(json[0]["rule_name"], "MD050");
+    assert_eq!(json[0]["severity"], "warning");
+
+    // Under --strict it is an error, which fails the run regardless of the budget
+    check(dir.path(), &["--max-warnings", "5", "--strict"]).code(1);
+    let output = check(
+        dir.path(),
+        &[
+            "--output",
+            "json",
+            "--max-warnings",
+            "5",
+            "--strict",
+        ],
+    )
+    .code(1);
+    let json: serde_json::Value = serde_json::from_slice(&output.get_output().stdout).unwrap();
+    assert_eq!(json[0]["severity"], "error");
+
+    // The config setting does the same
+    fs::write(dir.path().join(".rumdl.toml"), "[global]\nstrict = true\n").unwrap();
+    check(dir.path(), &["--max-warnings", "5"]).code(1);
+}